@@ -27,7 +27,7 @@ async fn main_v1() -> Result<(), LibError> {
     let asset_code = "BUSD".to_string();
     let request = V1TransferFundRequest {
         request_id,
-        merchant_id: merchant_id,
+        merchant_id,
         currency: asset_code,
         amount: d("4"),
         transfer_type: TransferType::ToMain,
@@ -1,5 +1,7 @@
 use std::env::var;
+use std::sync::Arc;
 
+pub use ccx_api_lib::HttpTransport;
 use ccx_api_lib::Proxy;
 use ccx_api_lib::env_var_with_prefix;
 use url::Url;
@@ -43,6 +45,8 @@ pub struct Config<S: BinancePaySigner> {
     pub api_base: Url,
     pub merchant_id: MerchantId,
     pub proxy: Option<Proxy>,
+    /// Sends the REST requests; over the network when `None`.
+    pub transport: Option<Arc<dyn HttpTransport>>,
 }
 
 impl<S> Config<S>
@@ -60,9 +64,15 @@ where
             api_base,
             merchant_id,
             proxy,
+            transport: None,
         }
     }
 
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
     pub fn env_var(postfix: &str) -> Option<String> {
         env_var_with_prefix(CCX_BINANCE_PAY_API_PREFIX, postfix)
     }
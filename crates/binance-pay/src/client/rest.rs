@@ -1,15 +1,11 @@
 use std::sync::Arc;
 use std::time::Instant;
 
-use actix_http::BoxedPayloadStream;
-use actix_http::Payload;
-use actix_http::encoding::Decoder;
-use awc::http::Method;
-use awc::http::StatusCode;
-use ccx_api_lib::awc::Client;
-use ccx_api_lib::awc::ClientRequest;
-use ccx_api_lib::awc::ClientResponse;
-use ccx_api_lib::awc::make_client;
+use ccx_api_lib::reqwest::Client;
+use ccx_api_lib::reqwest::ClientRequest;
+use ccx_api_lib::reqwest::Method;
+use ccx_api_lib::reqwest::StatusCode;
+use ccx_api_lib::reqwest::make_client_with;
 use serde::Serialize;
 
 use crate::Config;
@@ -44,6 +40,7 @@ where
     S: BinancePaySigner,
 {
     config: Config<S>,
+    client: Client,
 }
 
 pub struct RequestBuilder<S, J>
@@ -63,12 +60,14 @@ where
     S: BinancePaySigner,
 {
     pub fn with_config(config: Config<S>) -> Self {
-        let inner = Arc::new(ClientInner { config });
+        let client = make_client_with(config.transport.as_ref(), false, config.proxy.as_ref());
+        let inner = Arc::new(ClientInner { config, client });
         RestClient { inner }
     }
 
-    pub(super) fn client(&self) -> Client {
-        make_client(false, self.inner.config.proxy.as_ref())
+    /// REST client sending through the configured transport.
+    pub(super) fn client(&self) -> &Client {
+        &self.inner.client
     }
 
     pub fn request<T: Serialize + Clone + 'static>(
@@ -112,7 +111,12 @@ where
     J: Serialize + Clone + Sync + Send + 'static,
 {
     pub fn uri(&self) -> String {
-        self.request.get_uri().to_string()
+        let query = self.request.query_params();
+        if query.is_empty() {
+            self.request.url().to_string()
+        } else {
+            format!("{}?{}", self.request.url(), query)
+        }
     }
 
     fn json(mut self, value: J) -> Self {
@@ -121,16 +125,15 @@ where
     }
 
     pub fn query_args<T: Serialize>(mut self, query: &T) -> LibResult<Self> {
-        self.request = self.request.query(query)?;
+        self.request = self.request.query(query);
         Ok(self)
     }
 
     pub fn timestamp_header(mut self) -> LibResult<Self> {
         if let Some(time_window) = self.sign {
-            self.request = self.request.append_header((
-                "BinancePay-Timestamp",
-                &*time_window.timestamp().to_string(),
-            ));
+            self.request = self
+                .request
+                .header("BinancePay-Timestamp", time_window.timestamp().to_string());
         }
         Ok(self)
     }
@@ -138,9 +141,7 @@ where
     pub fn nonce_header(mut self) -> LibResult<Self> {
         if let Some(nonce) = self.nonce.as_ref() {
             log::debug!("nonce_header :: {}", nonce);
-            self.request = self
-                .request
-                .append_header(("BinancePay-Nonce", nonce.as_str()));
+            self.request = self.request.header("BinancePay-Nonce", nonce.as_str());
         }
         Ok(self)
     }
@@ -148,7 +149,7 @@ where
     pub fn api_key_header(mut self) -> LibResult<Self> {
         self.request = self
             .request
-            .append_header(("BinancePay-Certificate-SN", self.api_client.api_key()));
+            .header("BinancePay-Certificate-SN", self.api_client.api_key());
         Ok(self)
     }
 
@@ -174,9 +175,7 @@ where
             .sign_data(time.timestamp(), nonce, &json)
             .await?;
         let signature = signature.to_uppercase();
-        self.request = self
-            .request
-            .append_header(("BinancePay-Signature", &*signature));
+        self.request = self.request.header("BinancePay-Signature", &signature);
         Ok(self)
     }
 
@@ -202,19 +201,24 @@ where
     {
         self = self.api_key_header()?;
         self = self.payload_header().await?;
-        let json = self.json.unwrap();
+        let json = self.json.take().unwrap();
 
-        log::debug!("{}  {}", self.request.get_method(), self.request.get_uri(),);
+        log::debug!("{}  {}", self.request.method(), self.uri());
         let tm = Instant::now();
         // if true {
         //     log::debug!("send request :: {:?}", self.request);
         //     Err(LibError::other("STUB"))?
         // }
-        log::debug!("send request :: {:?}", self.request);
         // let mut res = request.send().await?;
-        let mut res = self.request.send_json(&json).await?;
+        let res = self
+            .request
+            .header("Content-Type", "application/json")
+            .body(serde_json::to_vec(&json)?)
+            .send()
+            .await?;
         let d1 = tm.elapsed();
-        let resp = res.body().limit(16 * 1024 * 1024).await?;
+        let status = res.status();
+        let resp = res.bytes().await?;
         let d2 = tm.elapsed() - d1;
         log::debug!(
             "Request time elapsed:  {:0.1}ms + {:0.1}ms",
@@ -223,8 +227,8 @@ where
         );
 
         // log::debug!("Response: {} «{}»", res.status(), String::from_utf8_lossy(&resp));
-        log::debug!("Response: {} «{:#?}»", res.status(), resp);
-        check_response(res, &resp)?;
+        log::debug!("Response: {} «{:#?}»", status, resp);
+        check_response(status, &resp)?;
         match serde_json::from_slice(&resp) {
             Ok(json) => Ok(json),
             Err(err) => {
@@ -235,11 +239,9 @@ where
     }
 }
 
-type AwcClientResponse = ClientResponse<Decoder<Payload<BoxedPayloadStream>>>;
-
-fn check_response(res: AwcClientResponse, resp: &[u8]) -> LibResult<AwcClientResponse> {
-    match res.status() {
-        StatusCode::OK => Ok(res),
+fn check_response(status: StatusCode, resp: &[u8]) -> LibResult<()> {
+    match status {
+        StatusCode::OK => Ok(()),
         StatusCode::INTERNAL_SERVER_ERROR => Err(ServiceError::ServerError.into()),
         StatusCode::SERVICE_UNAVAILABLE => Err(ServiceError::ServiceUnavailable.into()),
         StatusCode::UNAUTHORIZED => {
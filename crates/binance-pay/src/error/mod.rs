@@ -12,12 +12,9 @@ pub mod common_error;
 
 #[cfg(feature = "with_network")]
 mod with_network {
-    pub use awc::error::JsonPayloadError;
-    pub use awc::error::PayloadError;
-    pub use awc::error::SendRequestError;
-    pub use awc::error::WsClientError;
-    pub use awc::error::WsProtocolError;
-    pub use awc::http::header::InvalidHeaderValue;
+    pub use ccx_api_lib::http::StatusCode;
+    pub use ccx_api_lib::http::header::InvalidHeaderValue;
+    pub use ccx_api_lib::reqwest::SendRequestError;
 }
 
 #[cfg(feature = "with_network")]
@@ -82,11 +79,9 @@ pub enum LibError {
     #[error("Service Error: {0}")]
     ServiceError(#[from] ServiceError),
     #[error("Unknown Status: {0}")]
-    UnknownStatus(awc::http::StatusCode),
+    UnknownStatus(StatusCode),
     #[error("Request Error: {0}")]
     RequestError(#[from] SendRequestError),
-    #[error("Payload Error: {0}")]
-    Payload(#[from] PayloadError),
     #[error("Json Error: {0}")]
     Json(#[from] serde_json::Error),
     #[error("Url Encoded Error: {0}")]
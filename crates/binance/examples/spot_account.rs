@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 // use ccx_binance::api::spot::NewOrder;
 use ccx_binance::ApiCred;
 use ccx_binance::BinanceResult;
@@ -9,7 +11,6 @@ use ccx_binance::api::spot::OrderResponseType;
 use ccx_binance::api::spot::OrderSide;
 use ccx_binance::api::spot::OrderType;
 use ccx_binance::client::Task;
-use ccx_binance_examples_util::d;
 
 const BTCBUSD: &str = "BTCBUSD";
 // const EURBUSD: &str = "EURBUSD";
@@ -23,7 +24,7 @@ async fn main_() -> BinanceResult<()> {
     let _ = dotenv::dotenv();
     env_logger::init();
 
-    let binance = SpotApi::<ApiCred>::from_env();
+    let _binance = SpotApi::<ApiCred>::from_env();
 
     // let book = print_res(binance.ticker_book(BTCBUSD).await)?;
     // let time = print_res(binance.time().await)?;
@@ -108,7 +109,7 @@ fn limit_order(
     price: Decimal,
     quantity: Quantity,
 ) -> BinanceResult<Task<NewOrderResult>> {
-    let (quantity, quote_quantity) = quantity.to_arg();
+    let (quantity, quote_quantity) = quantity.into_arg();
     let task = binance
         .create_order(
             symbol,
@@ -135,7 +136,7 @@ fn market_order(
     side: OrderSide,
     quantity: Quantity,
 ) -> BinanceResult<Task<NewOrderResult>> {
-    let (quantity, quote_quantity) = quantity.to_arg();
+    let (quantity, quote_quantity) = quantity.into_arg();
     let task = binance
         .create_order(
             symbol,
@@ -162,7 +163,7 @@ enum Quantity {
 }
 
 impl Quantity {
-    pub fn to_arg(self) -> (Option<Decimal>, Option<Decimal>) {
+    pub fn into_arg(self) -> (Option<Decimal>, Option<Decimal>) {
         match self {
             Quantity::Base(quantity) => (Some(quantity), None),
             Quantity::Quote(quantity) => (None, Some(quantity)),
@@ -1,5 +1,4 @@
 use core::fmt;
-use std::collections::BTreeMap;
 
 use ccx_binance::ApiCred;
 use ccx_binance::BinanceError;
@@ -18,8 +17,6 @@ use futures::stream;
 use rust_decimal::Decimal;
 use smart_string::DisplayExt;
 use smart_string::SmartString;
-use string_cache::DefaultAtom as Atom;
-use string_cache::DefaultAtom;
 // use ccx_binance_examples_util::*;
 
 enum X {
@@ -0,0 +1,117 @@
+use crate::api::cm::RL_WEIGHT_PER_MINUTE;
+use crate::api::cm::prelude::*;
+use crate::api::um::PositionSide;
+use crate::client::Task;
+
+pub const DAPI_V1_ACCOUNT: &str = "/dapi/v1/account";
+pub const DAPI_V1_BALANCE: &str = "/dapi/v1/balance";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    pub assets: Vec<AccountAsset>,
+    pub positions: Vec<AccountPosition>,
+    pub can_deposit: bool,
+    pub can_trade: bool,
+    pub can_withdraw: bool,
+    pub fee_tier: u32,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    pub asset: Atom,
+    pub wallet_balance: Decimal,
+    pub unrealized_profit: Decimal,
+    pub margin_balance: Decimal,
+    pub maint_margin: Decimal,
+    pub initial_margin: Decimal,
+    pub position_initial_margin: Decimal,
+    pub open_order_initial_margin: Decimal,
+    pub max_withdraw_amount: Decimal,
+    pub cross_wallet_balance: Decimal,
+    pub cross_un_pnl: Decimal,
+    pub available_balance: Decimal,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_time: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPosition {
+    pub symbol: Atom,
+    pub position_amt: Decimal,
+    pub initial_margin: Decimal,
+    pub maint_margin: Decimal,
+    pub unrealized_profit: Decimal,
+    pub position_initial_margin: Decimal,
+    pub open_order_initial_margin: Decimal,
+    pub leverage: Decimal,
+    pub isolated: bool,
+    pub position_side: PositionSide,
+    pub entry_price: Decimal,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub break_even_price: Option<Decimal>,
+    /// Maximum quantity of base asset.
+    pub max_qty: Decimal,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalance {
+    /// Unique account code.
+    pub account_alias: String,
+    pub asset: Atom,
+    pub balance: Decimal,
+    pub withdraw_available: Decimal,
+    pub cross_wallet_balance: Decimal,
+    pub cross_un_pnl: Decimal,
+    pub available_balance: Decimal,
+    pub update_time: u64,
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> CmApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Account Information (USER_DATA)
+        ///
+        /// Get current account information.
+        ///
+        /// Weight: 5
+        pub fn account(
+            &self,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<AccountInformation>> {
+            Ok(self
+                .rate_limiter
+                .task(self.client.get(DAPI_V1_ACCOUNT)?.signed(time_window)?)
+                .cost(RL_WEIGHT_PER_MINUTE, 5)
+                .send())
+        }
+
+        /// Futures Account Balance (USER_DATA)
+        ///
+        /// Weight: 1
+        pub fn balance(
+            &self,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<AccountBalance>>> {
+            Ok(self
+                .rate_limiter
+                .task(self.client.get(DAPI_V1_BALANCE)?.signed(time_window)?)
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod information;
+mod order;
+mod position;
+
+pub use self::information::*;
+pub use self::order::*;
+pub use self::position::*;
@@ -0,0 +1,309 @@
+use crate::api::cm::RL_ORDERS_PER_MINUTE;
+use crate::api::cm::RL_WEIGHT_PER_MINUTE;
+use crate::api::cm::prelude::*;
+use crate::api::spot::RlPriorityLevel;
+use crate::api::um::NewOrder;
+use crate::api::um::OrderSide;
+use crate::api::um::OrderStatus;
+use crate::api::um::OrderType;
+use crate::api::um::PositionSide;
+use crate::api::um::PriceMatch;
+use crate::api::um::SelfTradePreventionMode;
+use crate::api::um::StatusResponse;
+use crate::api::um::TimeInForce;
+use crate::api::um::WorkingType;
+use crate::client::Task;
+
+pub const DAPI_V1_ORDER: &str = "/dapi/v1/order";
+pub const DAPI_V1_ALL_OPEN_ORDERS: &str = "/dapi/v1/allOpenOrders";
+pub const DAPI_V1_OPEN_ORDERS: &str = "/dapi/v1/openOrders";
+pub const DAPI_V1_ALL_ORDERS: &str = "/dapi/v1/allOrders";
+
+/// COIN-M futures order as returned by the trade endpoints.
+///
+/// Quantities are in contracts; `cum_base` is the filled amount in the base asset.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub symbol: Atom,
+    pub pair: Atom,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub price: Decimal,
+    pub avg_price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cum_qty: Option<Decimal>,
+    pub cum_base: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub orig_type: OrderType,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub stop_price: Decimal,
+    pub close_position: bool,
+    pub reduce_only: bool,
+    /// Present only for `TRAILING_STOP_MARKET` orders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activate_price: Option<Decimal>,
+    /// Present only for `TRAILING_STOP_MARKET` orders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price_rate: Option<Decimal>,
+    pub working_type: WorkingType,
+    pub price_protect: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price_match: Option<PriceMatch>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    /// Present only in the query endpoints.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<u64>,
+    pub update_time: u64,
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+    use crate::api::um::order_query_args;
+
+    impl<S> CmApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// New Order (TRADE)
+        ///
+        /// Send in a new order.
+        ///
+        /// Weight: 1 on IP rate limit; 1 on order rate limit
+        ///
+        /// The order quantity is a number of contracts. `GTD` time in force and
+        ///   `good_till_date` are not supported by COIN-M futures.
+        pub fn create_order(
+            &self,
+            order: NewOrder,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Order>> {
+            order.check()?;
+            if order.good_till_date.is_some()
+                || order.time_in_force == Some(TimeInForce::GoodTilDate)
+            {
+                Err(ApiError::OutOfBounds)?
+            }
+            Ok(self
+                .rate_limiter
+                .task(order_query_args(
+                    self.client.post(DAPI_V1_ORDER)?.signed(time_window)?,
+                    &order,
+                )?)
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .cost(RL_ORDERS_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Cancel Order (TRADE)
+        ///
+        /// Cancel an active order.
+        ///
+        /// Weight: 1
+        ///
+        /// Either orderId or origClientOrderId must be sent.
+        pub fn cancel_order(
+            &self,
+            symbol: impl Serialize,
+            order_id: Option<u64>,
+            orig_client_order_id: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Order>> {
+            if order_id.is_none() && orig_client_order_id.is_none() {
+                Err(ApiError::mandatory_field_omitted(
+                    "order_id or orig_client_order_id",
+                ))?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .delete(DAPI_V1_ORDER)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .try_query_arg("orderId", &order_id)?
+                        .try_query_arg("origClientOrderId", &orig_client_order_id)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Cancel All Open Orders (TRADE)
+        ///
+        /// Weight: 1
+        pub fn cancel_all_orders(
+            &self,
+            symbol: impl Serialize,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<StatusResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .delete(DAPI_V1_ALL_OPEN_ORDERS)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Query Order (USER_DATA)
+        ///
+        /// Check an order's status.
+        ///
+        /// Weight: 1
+        ///
+        /// Either orderId or origClientOrderId must be sent.
+        pub fn get_order(
+            &self,
+            symbol: impl Serialize,
+            order_id: Option<u64>,
+            orig_client_order_id: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Order>> {
+            if order_id.is_none() && orig_client_order_id.is_none() {
+                Err(ApiError::mandatory_field_omitted(
+                    "order_id or orig_client_order_id",
+                ))?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(DAPI_V1_ORDER)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .try_query_arg("orderId", &order_id)?
+                        .try_query_arg("origClientOrderId", &orig_client_order_id)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Current All Open Orders (USER_DATA)
+        ///
+        /// Get all open orders on a symbol or a pair. Careful when accessing this with
+        ///   neither of them.
+        ///
+        /// Weight: 1 for a single symbol; 40 for a pair or all symbols
+        pub fn open_orders(
+            &self,
+            symbol: Option<impl Serialize>,
+            pair: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<Order>>> {
+            let weight: u32 = if symbol.is_some() { 1 } else { 40 };
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(DAPI_V1_OPEN_ORDERS)?
+                        .signed(time_window)?
+                        .try_query_arg("symbol", &symbol)?
+                        .try_query_arg("pair", &pair)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, weight)
+                .send())
+        }
+
+        /// All Orders (USER_DATA)
+        ///
+        /// Get all account orders; active, canceled, or filled.
+        ///
+        /// Weight: 20 with symbol; 40 with pair
+        ///
+        /// * limit: Default 50; max 100.
+        ///
+        /// Either symbol or pair must be sent. The query time period must be less than
+        ///   7 days (default as the recent 7 days).
+        pub fn all_orders(
+            &self,
+            symbol: Option<impl Serialize>,
+            pair: Option<impl Serialize>,
+            order_id: Option<u64>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u16>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<Order>>> {
+            let weight: u32 = match (&symbol, &pair) {
+                (Some(_), _) => 20,
+                (None, Some(_)) => 40,
+                (None, None) => Err(ApiError::mandatory_field_omitted("symbol or pair"))?,
+            };
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(DAPI_V1_ALL_ORDERS)?
+                        .signed(time_window)?
+                        .try_query_arg("symbol", &symbol)?
+                        .try_query_arg("pair", &pair)?
+                        .try_query_arg("orderId", &order_id)?
+                        .try_query_arg("startTime", &start_time)?
+                        .try_query_arg("endTime", &end_time)?
+                        .try_query_arg("limit", &limit)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, weight)
+                .send())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize_order() {
+        let json = r#"{
+            "avgPrice": "0.0",
+            "clientOrderId": "abc",
+            "cumBase": "0",
+            "executedQty": "0",
+            "orderId": 1917641,
+            "origQty": "0.40",
+            "origType": "TRAILING_STOP_MARKET",
+            "price": "0",
+            "reduceOnly": false,
+            "side": "BUY",
+            "positionSide": "SHORT",
+            "status": "NEW",
+            "stopPrice": "9300",
+            "closePosition": false,
+            "symbol": "BTCUSD_200925",
+            "pair": "BTCUSD",
+            "time": 1579276756075,
+            "timeInForce": "GTC",
+            "type": "TRAILING_STOP_MARKET",
+            "activatePrice": "9020",
+            "priceRate": "0.3",
+            "updateTime": 1579276756075,
+            "workingType": "CONTRACT_PRICE",
+            "priceProtect": false,
+            "priceMatch": "NONE",
+            "selfTradePreventionMode": "NONE"
+        }"#;
+        let res: Order = serde_json::from_str(json).unwrap();
+        assert_eq!(res.pair.as_ref(), "BTCUSD");
+        assert_eq!(res.orig_qty, dec!(0.4));
+        assert_eq!(res.time, Some(1579276756075));
+    }
+}
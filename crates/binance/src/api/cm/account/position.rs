@@ -0,0 +1,189 @@
+use crate::api::cm::RL_WEIGHT_PER_MINUTE;
+use crate::api::cm::prelude::*;
+use crate::api::spot::RlPriorityLevel;
+use crate::api::um::MarginType;
+use crate::api::um::PositionMode;
+use crate::api::um::PositionSide;
+use crate::api::um::StatusResponse;
+use crate::client::Task;
+
+pub const DAPI_V1_POSITION_RISK: &str = "/dapi/v1/positionRisk";
+pub const DAPI_V1_LEVERAGE: &str = "/dapi/v1/leverage";
+pub const DAPI_V1_MARGIN_TYPE: &str = "/dapi/v1/marginType";
+pub const DAPI_V1_POSITION_SIDE_DUAL: &str = "/dapi/v1/positionSide/dual";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRisk {
+    pub symbol: Atom,
+    /// Position size in contracts.
+    pub position_amt: Decimal,
+    pub entry_price: Decimal,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub break_even_price: Option<Decimal>,
+    pub mark_price: Decimal,
+    pub un_realized_profit: Decimal,
+    pub liquidation_price: Decimal,
+    pub leverage: Decimal,
+    /// Maximum quantity of base asset.
+    pub max_qty: Decimal,
+    pub margin_type: MarginType,
+    pub isolated_margin: Decimal,
+    /// `"true"` or `"false"`.
+    pub is_auto_add_margin: String,
+    pub position_side: PositionSide,
+    /// Notional value in the base asset.
+    pub notional_value: Decimal,
+    pub isolated_wallet: Decimal,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageChanged {
+    pub leverage: u32,
+    /// Maximum quantity of base asset.
+    pub max_qty: Decimal,
+    pub symbol: Atom,
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> CmApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Position Information (USER_DATA)
+        ///
+        /// Get current position information.
+        ///
+        /// Weight: 1
+        ///
+        /// * margin_asset, pair - filter the positions; all positions are returned
+        ///   if both are omitted.
+        pub fn position_risk(
+            &self,
+            margin_asset: Option<impl Serialize>,
+            pair: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<PositionRisk>>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(DAPI_V1_POSITION_RISK)?
+                        .signed(time_window)?
+                        .try_query_arg("marginAsset", &margin_asset)?
+                        .try_query_arg("pair", &pair)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Change Initial Leverage (TRADE)
+        ///
+        /// Change user's initial leverage in the specific symbol market.
+        ///
+        /// Weight: 1
+        ///
+        /// * leverage - target initial leverage: int from 1 to 125.
+        pub fn change_leverage(
+            &self,
+            symbol: impl Serialize,
+            leverage: u32,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<LeverageChanged>> {
+            if !(1..=125).contains(&leverage) {
+                Err(ApiError::OutOfBounds)?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(DAPI_V1_LEVERAGE)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .query_arg("leverage", &leverage)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Change Margin Type (TRADE)
+        ///
+        /// Change user's margin type in the specific symbol market.
+        ///
+        /// Weight: 1
+        pub fn change_margin_type(
+            &self,
+            symbol: impl Serialize,
+            margin_type: MarginType,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<StatusResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(DAPI_V1_MARGIN_TYPE)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .query_arg("marginType", &margin_type)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Change Position Mode (TRADE)
+        ///
+        /// Change user's position mode (Hedge Mode or One-way Mode) on EVERY symbol.
+        ///
+        /// Weight: 1
+        ///
+        /// * dual_side_position - `true`: Hedge Mode; `false`: One-way Mode.
+        pub fn change_position_mode(
+            &self,
+            dual_side_position: bool,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<StatusResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(DAPI_V1_POSITION_SIDE_DUAL)?
+                        .signed(time_window)?
+                        .query_arg("dualSidePosition", &dual_side_position)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Get Current Position Mode (USER_DATA)
+        ///
+        /// Get user's position mode (Hedge Mode or One-way Mode) on EVERY symbol.
+        ///
+        /// Weight: 30
+        pub fn position_mode(
+            &self,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<PositionMode>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(DAPI_V1_POSITION_SIDE_DUAL)?
+                        .signed(time_window)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 30)
+                .send())
+        }
+    }
+}
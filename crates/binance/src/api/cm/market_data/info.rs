@@ -0,0 +1,170 @@
+use super::super::RL_WEIGHT_PER_MINUTE;
+use super::prelude::*;
+use crate::api::um::ContractStatus;
+use crate::api::um::ContractType;
+use crate::api::um::Filter;
+use crate::api::um::OrderType;
+use crate::api::um::RateLimit;
+use crate::api::um::TimeInForce;
+use crate::api::um::UnderlyingType;
+use crate::client::Task;
+
+pub const DAPI_V1_EXCHANGE_INFO: &str = "/dapi/v1/exchangeInfo";
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub exchange_filters: Vec<ExchangeFilter>,
+    pub rate_limits: Vec<RateLimit>,
+    /// Ignore please.
+    /// If you want to check current server time, please check via `CmApi::time()`.
+    pub server_time: u64,
+    pub symbols: Vec<Symbol>,
+    pub timezone: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeFilter {}
+
+/// COIN-M contract. Both perpetual and delivery contracts are margined and settled
+/// in the base asset; the order quantity is a number of contracts of `contract_size`
+/// quote asset (USD) each.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Symbol {
+    /// E.g. `BTCUSD_PERP` or `BTCUSD_240927`.
+    pub symbol: Atom,
+    /// Underlying pair, e.g. `BTCUSD`.
+    pub pair: Atom,
+    pub contract_type: ContractType,
+    /// Delivery time of the delivery contracts; far in the future for perpetuals.
+    pub delivery_date: u64,
+    pub onboard_date: u64,
+    pub contract_status: ContractStatus,
+    /// Value of one contract in the quote asset.
+    pub contract_size: u32,
+    pub margin_asset: Atom,
+    /// Ignore
+    pub maint_margin_percent: Decimal,
+    /// Ignore
+    pub required_margin_percent: Decimal,
+    pub base_asset: Atom,
+    pub quote_asset: Atom,
+    /// Please do not use it as tickSize.
+    pub price_precision: u16,
+    /// Please do not use it as stepSize.
+    pub quantity_precision: u16,
+    pub base_asset_precision: u16,
+    pub equal_qty_precision: u16,
+    /// Max price difference rate (from mark price) a limit order can make.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_move_order_limit: Option<u32>,
+    /// Threshold for algo order with "priceProtect".
+    pub trigger_protect: Decimal,
+    pub underlying_type: UnderlyingType,
+    pub underlying_sub_type: Vec<String>,
+    pub filters: Vec<Filter>,
+    pub order_types: Vec<OrderType>,
+    pub time_in_force: Vec<TimeInForce>,
+    /// Liquidation fee rate.
+    pub liquidation_fee: Decimal,
+    /// The max price difference rate (from mark price) a market order can make.
+    pub market_take_bound: Decimal,
+}
+
+impl Symbol {
+    /// Whether the contract has a fixed delivery date.
+    pub fn is_delivery(&self) -> bool {
+        !matches!(
+            self.contract_type,
+            ContractType::Perpetual | ContractType::PerpetualDelivering
+        )
+    }
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+    use crate::client::RateLimiter;
+
+    impl<S> CmApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Current exchange trading rules and symbol information.
+        ///
+        /// The `rateLimits` of the response are applied to the rate limiter.
+        ///
+        /// Weight: 1
+        pub fn exchange_info(&self) -> BinanceResult<Task<ExchangeInformation>> {
+            let rate_limiter = self.rate_limiter.clone();
+            Ok(self
+                .rate_limiter
+                .task(self.client.get(DAPI_V1_EXCHANGE_INFO)?)
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send()
+                .inspect_ok(move |info: &ExchangeInformation| {
+                    apply_rate_limits(&rate_limiter, &info.rate_limits)
+                }))
+        }
+    }
+
+    /// Applies `rateLimits` of the exchange information to the rate limiter.
+    fn apply_rate_limits(rate_limiter: &RateLimiter, rate_limits: &[RateLimit]) {
+        for rate_limit in rate_limits {
+            rate_limiter.set_limit(rate_limit.counter(), rate_limit.period(), rate_limit.limit);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize_delivery_symbol() {
+        let json = r#"{
+            "filters": [
+                {"filterType": "PRICE_FILTER", "maxPrice": "4520958", "minPrice": "1000", "tickSize": "0.1"},
+                {"filterType": "LOT_SIZE", "maxQty": "100000", "minQty": "1", "stepSize": "1"}
+            ],
+            "orderTypes": ["LIMIT", "MARKET", "STOP", "TAKE_PROFIT", "TRAILING_STOP_MARKET"],
+            "timeInForce": ["GTC", "IOC", "FOK", "GTX"],
+            "liquidationFee": "0.010000",
+            "marketTakeBound": "0.30",
+            "symbol": "BTCUSD_200925",
+            "pair": "BTCUSD",
+            "contractType": "CURRENT_QUARTER",
+            "deliveryDate": 1601020800000,
+            "onboardDate": 1590739200000,
+            "contractStatus": "TRADING",
+            "contractSize": 100,
+            "quoteAsset": "USD",
+            "baseAsset": "BTC",
+            "marginAsset": "BTC",
+            "pricePrecision": 1,
+            "quantityPrecision": 0,
+            "baseAssetPrecision": 8,
+            "quotePrecision": 8,
+            "equalQtyPrecision": 4,
+            "maxMoveOrderLimit": 10000,
+            "triggerProtect": "0.0500",
+            "underlyingType": "COIN",
+            "underlyingSubType": [],
+            "maintMarginPercent": "2.5000",
+            "requiredMarginPercent": "5.0000"
+        }"#;
+        let symbol: Symbol = serde_json::from_str(json).unwrap();
+        assert!(symbol.is_delivery());
+        assert_eq!(symbol.contract_size, 100);
+        assert_eq!(symbol.delivery_date, 1601020800000);
+        assert_eq!(symbol.liquidation_fee, dec!(0.01));
+    }
+}
@@ -0,0 +1,8 @@
+mod info;
+mod ping;
+mod time;
+
+pub use self::info::*;
+pub use self::ping::*;
+pub use self::time::*;
+use super::prelude;
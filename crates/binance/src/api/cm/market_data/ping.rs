@@ -0,0 +1,33 @@
+use super::super::RL_WEIGHT_PER_MINUTE;
+use super::prelude::*;
+use crate::client::Task;
+
+pub const DAPI_V1_PING: &str = "/dapi/v1/ping";
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Pong {}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> CmApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Test connectivity to the Rest API.
+        ///
+        /// Weight: 1
+        pub fn ping(&self) -> BinanceResult<Task<Pong>> {
+            Ok(self
+                .rate_limiter
+                .task(self.client.get(DAPI_V1_PING)?)
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use super::super::RL_WEIGHT_PER_MINUTE;
+use super::prelude::*;
+use crate::client::Task;
+
+pub const DAPI_V1_TIME: &str = "/dapi/v1/time";
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerTime {
+    pub server_time: u64,
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> CmApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Test connectivity to the Rest API and get the current server time.
+        ///
+        /// Weight: 1
+        pub fn time(&self) -> BinanceResult<Task<ServerTime>> {
+            Ok(self
+                .rate_limiter
+                .task(self.client.get(DAPI_V1_TIME)?)
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::time::Duration;
+
+use ccx_api_lib::env_var_with_prefix;
+use url::Url;
+
+use crate::client::ApiCred;
+use crate::client::BinanceSigner;
+use crate::client::CCX_BINANCE_API_PREFIX;
+use crate::client::Config;
+use crate::client::Proxy;
+use crate::client::RateLimitCounter;
+use crate::client::RateLimiter;
+use crate::client::RateLimiterBucket;
+use crate::client::RateLimiterBuilder;
+use crate::client::ReconnectingWebsocketStream;
+use crate::client::RestClient;
+use crate::client::WebsocketStream;
+use crate::error::*;
+
+mod account;
+mod market_data;
+
+pub use self::account::*;
+pub use self::market_data::*;
+
+mod prelude {
+    #[cfg(feature = "with_network")]
+    pub use super::CmApi;
+    pub use crate::api::prelude::*;
+}
+
+pub const API_BASE: &str = "https://dapi.binance.com/";
+pub const STREAM_BASE: &str = "wss://dstream.binance.com/stream";
+
+pub const API_BASE_TESTNET: &str = "https://testnet.binancefuture.com/";
+pub const STREAM_BASE_TESTNET: &str = "wss://dstream.binancefuture.com/stream";
+
+pub const RL_WEIGHT_PER_MINUTE: &str = "weight_per_minute";
+pub const RL_ORDERS_PER_MINUTE: &str = "orders_per_minute";
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    /// COIN-M (inverse) futures API.
+    #[derive(Clone)]
+    pub struct CmApi<S>
+    where
+        S: BinanceSigner,
+    {
+        pub(crate) client: RestClient<S>,
+        pub(crate) rate_limiter: RateLimiter,
+    }
+
+    impl<S> CmApi<S>
+    where
+        S: BinanceSigner,
+    {
+        pub fn new(signer: S, testnet: bool, proxy: Option<Proxy>) -> Self {
+            let (api_base, stream_base) = if testnet {
+                (
+                    Url::parse(API_BASE_TESTNET).unwrap(),
+                    Url::parse(STREAM_BASE_TESTNET).unwrap(),
+                )
+            } else {
+                (
+                    Url::parse(API_BASE).unwrap(),
+                    Url::parse(STREAM_BASE).unwrap(),
+                )
+            };
+            CmApi::with_config(Config::new(signer, api_base, stream_base, proxy))
+        }
+
+        /// Reads config from env vars with names like:
+        /// "CCX_BINANCE_API_KEY", "CCX_BINANCE_API_SECRET", and "CCX_BINANCE_API_TESTNET"
+        pub fn from_env() -> CmApi<ApiCred> {
+            let testnet = Config::<S>::env_var("TESTNET").as_deref() == Some("1");
+            let proxy = Proxy::from_env_with_prefix(CCX_BINANCE_API_PREFIX);
+            CmApi::new(
+                ApiCred::from_env_with_prefix(CCX_BINANCE_API_PREFIX),
+                testnet,
+                proxy,
+            )
+        }
+
+        /// Reads config from env vars with names like:
+        /// "${prefix}_KEY", "${prefix}_SECRET", and "${prefix}_TESTNET"
+        pub fn from_env_with_prefix(prefix: &str) -> CmApi<ApiCred> {
+            let testnet = env_var_with_prefix(prefix, "TESTNET").as_deref() == Some("1");
+            let proxy = Proxy::from_env_with_prefix(prefix);
+            CmApi::new(ApiCred::from_env_with_prefix(prefix), testnet, proxy)
+        }
+
+        pub fn with_config(config: Config<S>) -> Self {
+            let client = RestClient::new(config);
+            let rate_limiter = RateLimiterBuilder::default()
+                .synced_bucket(
+                    RL_WEIGHT_PER_MINUTE,
+                    RateLimitCounter::Weight,
+                    RateLimiterBucket::default()
+                        .interval(Duration::from_secs(60))
+                        .limit(2_400),
+                )
+                .synced_bucket(
+                    RL_ORDERS_PER_MINUTE,
+                    RateLimitCounter::OrderCount,
+                    RateLimiterBucket::default()
+                        .interval(Duration::from_secs(60))
+                        .limit(1_200),
+                )
+                .start();
+            CmApi {
+                client,
+                rate_limiter,
+            }
+        }
+
+        /// Creates multiplexed websocket stream.
+        pub async fn ws(&self) -> BinanceResult<WebsocketStream> {
+            self.client.web_socket().await
+        }
+
+        /// Creates multiplexed websocket stream which reconnects and resubscribes
+        /// after disconnections.
+        pub async fn ws_reconnecting(&self) -> BinanceResult<ReconnectingWebsocketStream>
+        where
+            S: 'static,
+        {
+            ReconnectingWebsocketStream::connect(self.client.clone()).await
+        }
+    }
+}
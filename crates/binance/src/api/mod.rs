@@ -1,3 +1,4 @@
+pub mod cm;
 pub mod spot;
 pub mod um;
 
@@ -1,3 +1,6 @@
+use super::ContingencyType;
+use super::ListOrderStatus;
+use super::OrderListOrder;
 use super::RL_ORDERS_PER_DAY;
 use super::RL_ORDERS_PER_SECOND;
 use super::RL_WEIGHT_PER_MINUTE;
@@ -8,12 +11,10 @@ use crate::client::Task;
 
 pub const API_V3_ORDER_TEST: &str = "/api/v3/order/test";
 pub const API_V3_ORDER: &str = "/api/v3/order";
-// TODO pub const API_V3_ORDER_OCO: &str = "/api/v3/order/oco";
-// TODO pub const API_V3_ORDER_LIST: &str = "/api/v3/orderList";
+pub const API_V3_ORDER_CANCEL_REPLACE: &str = "/api/v3/order/cancelReplace";
+pub const API_V3_ORDER_AMEND_KEEP_PRIORITY: &str = "/api/v3/order/amend/keepPriority";
 pub const API_V3_OPEN_ORDERS: &str = "/api/v3/openOrders";
 pub const API_V3_ALL_ORDERS: &str = "/api/v3/allOrders";
-// TODO pub const API_V3_ALL_ORDER_LIST: &str = "/api/v3/allOrderList";
-// TODO pub const API_V3_OPEN_ORDER_LIST: &str = "/api/v3/openOrderList";
 pub const API_V3_ACCOUNT: &str = "/api/v3/account";
 pub const API_V3_MY_TRADES: &str = "/api/v3/myTrades";
 
@@ -72,6 +73,21 @@ pub enum OrderResponseType {
     Full,
 }
 
+/// Prevents orders of the same account (or trade group) from matching against each other.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SelfTradePreventionMode {
+    #[serde(rename = "NONE")]
+    None,
+    #[serde(rename = "EXPIRE_TAKER")]
+    ExpireTaker,
+    #[serde(rename = "EXPIRE_MAKER")]
+    ExpireMaker,
+    #[serde(rename = "EXPIRE_BOTH")]
+    ExpireBoth,
+    #[serde(rename = "DECREMENT")]
+    Decrement,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct NewTestOrder {}
 
@@ -131,6 +147,42 @@ pub struct NewOrderFull {
     pub fills: Vec<OrderFill>,
 }
 
+/// A new order in any response type.
+///
+/// Only the identifiers are present for the `ACK` response type, and the fills only for `FULL`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrderReport {
+    pub symbol: Atom,
+    pub order_id: u64,
+    // FIXME make None when -1.
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    pub transact_time: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orig_qty: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub executed_qty: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cummulative_quote_qty: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrderStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<OrderType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub side: Option<OrderSide>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub working_time: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fills: Vec<OrderFill>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderFill {
@@ -142,6 +194,10 @@ pub struct OrderFill {
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum OrderStatus {
+    /// The order is the pending part of an order list and will be placed once the working
+    /// order is filled.
+    #[serde(rename = "PENDING_NEW")]
+    PendingNew,
     /// The order has been accepted by the engine.
     #[serde(rename = "NEW")]
     New,
@@ -165,6 +221,9 @@ pub enum OrderStatus {
     /// canceled during liquidation, orders canceled during maintenance).
     #[serde(rename = "EXPIRED")]
     Expired,
+    /// The order was expired by the exchange due to self-trade prevention.
+    #[serde(rename = "EXPIRED_IN_MATCH")]
+    ExpiredInMatch,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -187,6 +246,279 @@ pub struct CancelledOrder {
     pub side: OrderSide,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum CancelReplaceMode {
+    /// If the cancel request fails, the new order placement will not be attempted.
+    #[serde(rename = "STOP_ON_FAILURE")]
+    StopOnFailure,
+    /// New order placement will be attempted even if the cancel request fails.
+    #[serde(rename = "ALLOW_FAILURE")]
+    AllowFailure,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum CancelRestrictions {
+    /// Cancel will succeed only if the order status is `NEW`.
+    #[serde(rename = "ONLY_NEW")]
+    OnlyNew,
+    /// Cancel will succeed only if the order status is `PARTIALLY_FILLED`.
+    #[serde(rename = "ONLY_PARTIALLY_FILLED")]
+    OnlyPartiallyFilled,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum OrderRateLimitExceededMode {
+    /// The cancel is attempted only if the unfilled order count has not been exceeded (default).
+    #[serde(rename = "DO_NOTHING")]
+    DoNothing,
+    /// The cancel is always attempted, even if the unfilled order count has been exceeded.
+    #[serde(rename = "CANCEL_ONLY")]
+    CancelOnly,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum CancelReplaceResult {
+    #[serde(rename = "SUCCESS")]
+    Success,
+    #[serde(rename = "FAILURE")]
+    Failure,
+    #[serde(rename = "NOT_ATTEMPTED")]
+    NotAttempted,
+}
+
+/// Cancel an existing order and send a new order on the same symbol.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NewCancelReplaceOrder {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub r#type: OrderType,
+    pub cancel_replace_mode: CancelReplaceMode,
+    pub cancel_order_id: Option<u64>,
+    pub cancel_orig_client_order_id: Option<String>,
+    /// Used to uniquely identify this cancel. Automatically generated by default.
+    pub cancel_new_client_order_id: Option<String>,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: Option<Decimal>,
+    pub quote_order_qty: Option<Decimal>,
+    pub price: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
+    pub trailing_delta: Option<u64>,
+    pub iceberg_qty: Option<Decimal>,
+    pub new_client_order_id: Option<String>,
+    pub strategy_id: Option<u64>,
+    /// Values smaller than 1000000 are reserved and cannot be used.
+    pub strategy_type: Option<u32>,
+    pub new_order_resp_type: Option<OrderResponseType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub cancel_restrictions: Option<CancelRestrictions>,
+    pub order_rate_limit_exceeded_mode: Option<OrderRateLimitExceededMode>,
+}
+
+impl NewCancelReplaceOrder {
+    /// Either `cancel_order_id` or `cancel_orig_client_order_id` must be set.
+    pub fn new(
+        symbol: impl Into<String>,
+        side: OrderSide,
+        r#type: OrderType,
+        cancel_replace_mode: CancelReplaceMode,
+    ) -> Self {
+        NewCancelReplaceOrder {
+            symbol: symbol.into(),
+            side,
+            r#type,
+            cancel_replace_mode,
+            cancel_order_id: None,
+            cancel_orig_client_order_id: None,
+            cancel_new_client_order_id: None,
+            time_in_force: None,
+            quantity: None,
+            quote_order_qty: None,
+            price: None,
+            stop_price: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_client_order_id: None,
+            strategy_id: None,
+            strategy_type: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+            cancel_restrictions: None,
+            order_rate_limit_exceeded_mode: None,
+        }
+    }
+
+    pub fn cancel_order_id(mut self, cancel_order_id: u64) -> Self {
+        self.cancel_order_id = Some(cancel_order_id);
+        self
+    }
+
+    pub fn cancel_orig_client_order_id(
+        mut self,
+        cancel_orig_client_order_id: impl Into<String>,
+    ) -> Self {
+        self.cancel_orig_client_order_id = Some(cancel_orig_client_order_id.into());
+        self
+    }
+
+    pub fn cancel_new_client_order_id(
+        mut self,
+        cancel_new_client_order_id: impl Into<String>,
+    ) -> Self {
+        self.cancel_new_client_order_id = Some(cancel_new_client_order_id.into());
+        self
+    }
+
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn quantity(mut self, quantity: Decimal) -> Self {
+        self.quantity = Some(quantity);
+        self
+    }
+
+    pub fn quote_order_qty(mut self, quote_order_qty: Decimal) -> Self {
+        self.quote_order_qty = Some(quote_order_qty);
+        self
+    }
+
+    pub fn price(mut self, price: Decimal) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn stop_price(mut self, stop_price: Decimal) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    pub fn trailing_delta(mut self, trailing_delta: u64) -> Self {
+        self.trailing_delta = Some(trailing_delta);
+        self
+    }
+
+    pub fn iceberg_qty(mut self, iceberg_qty: Decimal) -> Self {
+        self.iceberg_qty = Some(iceberg_qty);
+        self
+    }
+
+    pub fn new_client_order_id(mut self, new_client_order_id: impl Into<String>) -> Self {
+        self.new_client_order_id = Some(new_client_order_id.into());
+        self
+    }
+
+    pub fn strategy_id(mut self, strategy_id: u64) -> Self {
+        self.strategy_id = Some(strategy_id);
+        self
+    }
+
+    pub fn strategy_type(mut self, strategy_type: u32) -> Self {
+        self.strategy_type = Some(strategy_type);
+        self
+    }
+
+    pub fn new_order_resp_type(mut self, new_order_resp_type: OrderResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    pub fn cancel_restrictions(mut self, cancel_restrictions: CancelRestrictions) -> Self {
+        self.cancel_restrictions = Some(cancel_restrictions);
+        self
+    }
+
+    pub fn order_rate_limit_exceeded_mode(mut self, mode: OrderRateLimitExceededMode) -> Self {
+        self.order_rate_limit_exceeded_mode = Some(mode);
+        self
+    }
+
+    fn check(&self) -> BinanceResult<()> {
+        if self.cancel_order_id.is_none() && self.cancel_orig_client_order_id.is_none() {
+            Err(ApiError::mandatory_field_omitted(
+                "cancel_order_id or cancel_orig_client_order_id",
+            ))?
+        }
+        check_order_fields(
+            self.r#type,
+            self.time_in_force,
+            self.quantity,
+            self.quote_order_qty,
+            self.price,
+            self.stop_price.is_some() || self.trailing_delta.is_some(),
+        )
+    }
+}
+
+/// Result of a cancel-replace request.
+///
+/// The same shape is returned inside [`ApiError::CancelReplaceFailed`] when either
+/// the cancel or the new order placement fails.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceOrder {
+    pub cancel_result: CancelReplaceResult,
+    pub new_order_result: CancelReplaceResult,
+    pub cancel_response: CancelReplaceResponse<CancelledOrder>,
+    /// `None` when the new order placement was not attempted.
+    #[serde(default)]
+    pub new_order_response: Option<CancelReplaceResponse<NewOrderReport>>,
+}
+
+/// Either part of a cancel-replace request.
+pub type CancelReplaceResponse<T> = SuccessOrFailure<T>;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendedOrder {
+    pub transact_time: u64,
+    pub execution_id: u64,
+    pub amended_order: AmendedOrderDetails,
+    /// Present only when the amended order is part of an order list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub list_status: Option<AmendedOrderListStatus>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendedOrderDetails {
+    pub symbol: Atom,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub orig_client_order_id: String,
+    pub client_order_id: String,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub executed_qty: Decimal,
+    pub prevented_qty: Decimal,
+    pub quote_order_qty: Decimal,
+    pub cumulative_quote_qty: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub side: OrderSide,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub working_time: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendedOrderListStatus {
+    pub order_list_id: u64,
+    pub contingency_type: ContingencyType,
+    pub list_order_status: ListOrderStatus,
+    pub list_client_order_id: String,
+    pub symbol: Atom,
+    pub orders: Vec<OrderListOrder>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Order {
@@ -297,6 +629,53 @@ impl NewOrder {
     }
 }
 
+/// Checks that the parameters mandatory for the order type are present.
+///
+/// `has_trigger` is set when either `stopPrice` or `trailingDelta` is provided.
+pub(super) fn check_order_fields(
+    r#type: OrderType,
+    time_in_force: Option<TimeInForce>,
+    quantity: Option<Decimal>,
+    quote_order_qty: Option<Decimal>,
+    price: Option<Decimal>,
+    has_trigger: bool,
+) -> BinanceResult<()> {
+    match r#type {
+        OrderType::Limit => {
+            if time_in_force.is_none() || quantity.is_none() || price.is_none() {
+                Err(ApiError::mandatory_field_omitted(
+                    "time_in_force, quantity, price",
+                ))?
+            }
+        }
+        OrderType::Market => {
+            if quantity.is_none() && quote_order_qty.is_none() {
+                Err(ApiError::mandatory_field_omitted(
+                    "quantity or quote_order_qty",
+                ))?
+            }
+        }
+        OrderType::StopLoss | OrderType::TakeProfit => {
+            if quantity.is_none() || !has_trigger {
+                Err(ApiError::mandatory_field_omitted("quantity, stop_price"))?
+            }
+        }
+        OrderType::StopLossLimit | OrderType::TakeProfitLimit => {
+            if time_in_force.is_none() || quantity.is_none() || price.is_none() || !has_trigger {
+                Err(ApiError::mandatory_field_omitted(
+                    "time_in_force, quantity, price, stop_price",
+                ))?
+            }
+        }
+        OrderType::LimitMaker => {
+            if quantity.is_none() || price.is_none() {
+                Err(ApiError::mandatory_field_omitted("quantity, price"))?
+            }
+        }
+    };
+    Ok(())
+}
+
 #[cfg(feature = "with_network")]
 pub use with_network::*;
 
@@ -435,59 +814,14 @@ mod with_network {
             } else {
                 API_V3_ORDER
             };
-            match r#type {
-                OrderType::Limit => {
-                    if time_in_force.is_none() || quantity.is_none() || price.is_none() {
-                        Err(ApiError::mandatory_field_omitted(
-                            "time_in_force, quantity, price",
-                        ))?
-                    }
-                }
-                OrderType::Market => {
-                    if quantity.is_none() && quote_order_qty.is_none() {
-                        Err(ApiError::mandatory_field_omitted(
-                            "quantity or quote_order_qty",
-                        ))?
-                    }
-                }
-                OrderType::StopLoss => {
-                    if quantity.is_none() || stop_price.is_none() {
-                        Err(ApiError::mandatory_field_omitted("quantity, stop_price"))?
-                    }
-                }
-                OrderType::StopLossLimit => {
-                    if time_in_force.is_none()
-                        || quantity.is_none()
-                        || price.is_none()
-                        || stop_price.is_none()
-                    {
-                        Err(ApiError::mandatory_field_omitted(
-                            "time_in_force, quantity, price, stop_price",
-                        ))?
-                    }
-                }
-                OrderType::TakeProfit => {
-                    if quantity.is_none() || stop_price.is_none() {
-                        Err(ApiError::mandatory_field_omitted("quantity, stop_price"))?
-                    }
-                }
-                OrderType::TakeProfitLimit => {
-                    if time_in_force.is_none()
-                        || quantity.is_none()
-                        || price.is_none()
-                        || stop_price.is_none()
-                    {
-                        Err(ApiError::mandatory_field_omitted(
-                            "time_in_force, quantity, price, stop_price",
-                        ))?
-                    }
-                }
-                OrderType::LimitMaker => {
-                    if quantity.is_none() || price.is_none() {
-                        Err(ApiError::mandatory_field_omitted("quantity, price"))?
-                    }
-                }
-            };
+            check_order_fields(
+                r#type,
+                time_in_force,
+                quantity,
+                quote_order_qty,
+                price,
+                stop_price.is_some(),
+            )?;
             let request = self
                 .client
                 .post(endpoint)?
@@ -545,6 +879,108 @@ mod with_network {
                 .send())
         }
 
+        /// Cancel an Existing Order and Send a New Order (TRADE)
+        ///
+        /// Cancels an existing order and places a new order on the same symbol.
+        ///
+        /// Weight(IP): 1
+        ///
+        /// Unfilled Order Count: 1
+        ///
+        /// When either the cancel or the new order placement fails the request is rejected
+        /// with [`ApiError::CancelReplaceFailed`] carrying the responses of both operations.
+        pub fn cancel_replace_order(
+            &self,
+            order: NewCancelReplaceOrder,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<CancelReplaceOrder>> {
+            order.check()?;
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_V3_ORDER_CANCEL_REPLACE)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &order.symbol)?
+                        .query_arg("side", &order.side)?
+                        .query_arg("type", &order.r#type)?
+                        .query_arg("cancelReplaceMode", &order.cancel_replace_mode)?
+                        .try_query_arg("timeInForce", &order.time_in_force)?
+                        .try_query_arg("quantity", &order.quantity)?
+                        .try_query_arg("quoteOrderQty", &order.quote_order_qty)?
+                        .try_query_arg("price", &order.price)?
+                        .try_query_arg("cancelNewClientOrderId", &order.cancel_new_client_order_id)?
+                        .try_query_arg(
+                            "cancelOrigClientOrderId",
+                            &order.cancel_orig_client_order_id,
+                        )?
+                        .try_query_arg("cancelOrderId", &order.cancel_order_id)?
+                        .try_query_arg("newClientOrderId", &order.new_client_order_id)?
+                        .try_query_arg("strategyId", &order.strategy_id)?
+                        .try_query_arg("strategyType", &order.strategy_type)?
+                        .try_query_arg("stopPrice", &order.stop_price)?
+                        .try_query_arg("trailingDelta", &order.trailing_delta)?
+                        .try_query_arg("icebergQty", &order.iceberg_qty)?
+                        .try_query_arg("newOrderRespType", &order.new_order_resp_type)?
+                        .try_query_arg(
+                            "selfTradePreventionMode",
+                            &order.self_trade_prevention_mode,
+                        )?
+                        .try_query_arg("cancelRestrictions", &order.cancel_restrictions)?
+                        .try_query_arg(
+                            "orderRateLimitExceededMode",
+                            &order.order_rate_limit_exceeded_mode,
+                        )?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .cost(RL_ORDERS_PER_SECOND, 1)
+                .cost(RL_ORDERS_PER_DAY, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Order Amend Keep Priority (TRADE)
+        ///
+        /// Reduce the quantity of an existing open order while keeping its priority
+        /// in the order book.
+        ///
+        /// Weight(IP): 4
+        ///
+        /// Unfilled Order Count: 0
+        ///
+        /// Either orderId or origClientOrderId must be sent.
+        /// new_qty must be greater than 0 and less than the order's quantity.
+        pub fn amend_order_keep_priority(
+            &self,
+            symbol: impl Serialize,
+            order_id: Option<u64>,
+            orig_client_order_id: Option<impl Serialize>,
+            new_client_order_id: Option<impl Serialize>,
+            new_qty: Decimal,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<AmendedOrder>> {
+            if order_id.is_none() && orig_client_order_id.is_none() {
+                Err(ApiError::mandatory_field_omitted(
+                    "order_id or orig_client_order_id",
+                ))?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .put(API_V3_ORDER_AMEND_KEEP_PRIORITY)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .try_query_arg("orderId", &order_id)?
+                        .try_query_arg("origClientOrderId", &orig_client_order_id)?
+                        .try_query_arg("newClientOrderId", &new_client_order_id)?
+                        .query_arg("newQty", &new_qty)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 4)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
         /// Cancel all Open Orders on a Symbol (TRADE)
         ///
         /// Cancels all active orders on a symbol.
@@ -667,12 +1103,6 @@ mod with_network {
                 .send())
         }
 
-        // TODO create_order_list
-        // TODO cancel_order_list
-        // TODO get_order_list
-        // TODO all_order_list
-        // TODO open_order_list
-
         /// Account Information (USER_DATA).
         ///
         /// Get current account information.
@@ -725,3 +1155,123 @@ mod with_network {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_replace_check() {
+        let order = NewCancelReplaceOrder::new(
+            "BTCUSDT",
+            OrderSide::Buy,
+            OrderType::Limit,
+            CancelReplaceMode::StopOnFailure,
+        )
+        .price(Decimal::ONE)
+        .quantity(Decimal::ONE)
+        .time_in_force(TimeInForce::Gtc);
+        assert!(order.check().is_err());
+        assert!(order.cancel_order_id(1).check().is_ok());
+    }
+
+    #[test]
+    fn test_deserialize_cancel_replace_success() {
+        let json = r#"{
+            "cancelResult": "SUCCESS",
+            "newOrderResult": "SUCCESS",
+            "cancelResponse": {
+                "symbol": "BTCUSDT",
+                "origClientOrderId": "DnLo3vTAQcjha43lAZhZ0y",
+                "orderId": 9,
+                "orderListId": -1,
+                "clientOrderId": "osxN3JXAtJvKvCqGeMWMVR",
+                "transactTime": 1684804350068,
+                "price": "0.01000010",
+                "origQty": "0.000100",
+                "executedQty": "0.00000000",
+                "cummulativeQuoteQty": "0.00000000",
+                "status": "CANCELED",
+                "timeInForce": "GTC",
+                "type": "LIMIT",
+                "side": "SELL",
+                "selfTradePreventionMode": "NONE"
+            },
+            "newOrderResponse": {
+                "symbol": "BTCUSDT",
+                "orderId": 10,
+                "orderListId": -1,
+                "clientOrderId": "wOceeeOzNORyLiQfw7jd8S",
+                "transactTime": 1652928801803,
+                "price": "0.00020000",
+                "origQty": "0.00100000",
+                "executedQty": "0.00000000",
+                "cummulativeQuoteQty": "0.00000000",
+                "status": "NEW",
+                "timeInForce": "GTC",
+                "type": "LIMIT",
+                "side": "BUY",
+                "workingTime": 1669277163808,
+                "fills": [],
+                "selfTradePreventionMode": "NONE"
+            }
+        }"#;
+        let res: CancelReplaceOrder = serde_json::from_str(json).unwrap();
+        assert_eq!(res.cancel_result, CancelReplaceResult::Success);
+        assert!(res.cancel_response.is_success());
+        let new_order = res.new_order_response.unwrap().success().unwrap();
+        assert_eq!(new_order.order_id, 10);
+        assert_eq!(new_order.status, Some(OrderStatus::New));
+        assert_eq!(new_order.working_time, Some(1669277163808));
+    }
+
+    #[test]
+    fn test_deserialize_cancel_replace_partial_failure() {
+        let json = r#"{
+            "cancelResult": "FAILURE",
+            "newOrderResult": "NOT_ATTEMPTED",
+            "cancelResponse": {
+                "code": -2011,
+                "msg": "Unknown order sent."
+            },
+            "newOrderResponse": null
+        }"#;
+        let res: CancelReplaceOrder = serde_json::from_str(json).unwrap();
+        assert_eq!(res.new_order_result, CancelReplaceResult::NotAttempted);
+        assert!(matches!(
+            res.cancel_response,
+            CancelReplaceResponse::Failure { code: -2011, .. }
+        ));
+        assert!(res.new_order_response.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_amended_order() {
+        let json = r#"{
+            "transactTime": 1741926410255,
+            "executionId": 75,
+            "amendedOrder": {
+                "symbol": "BTCUSDT",
+                "orderId": 33,
+                "orderListId": -1,
+                "origClientOrderId": "5xrgbMyg6z36NzBn2pbT8H",
+                "clientOrderId": "PFaq6hIHxqFENGfdtn4J6Q",
+                "price": "6.00000000",
+                "qty": "5.00000000",
+                "executedQty": "0.00000000",
+                "preventedQty": "0.00000000",
+                "quoteOrderQty": "0.00000000",
+                "cumulativeQuoteQty": "0.00000000",
+                "status": "NEW",
+                "timeInForce": "GTC",
+                "type": "LIMIT",
+                "side": "SELL",
+                "workingTime": 1741926410242,
+                "selfTradePreventionMode": "NONE"
+            }
+        }"#;
+        let res: AmendedOrder = serde_json::from_str(json).unwrap();
+        assert_eq!(res.amended_order.order_id, 33);
+        assert!(res.list_status.is_none());
+    }
+}
@@ -1,3 +1,1064 @@
-// use super::prelude::*;
+use super::CancelledOrder;
+use super::OrderFill;
+use super::OrderResponseType;
+use super::OrderSide;
+use super::OrderStatus;
+use super::OrderType;
+use super::RL_SAPI_UID_WEIGHT_PER_MINUTE;
+use super::RL_WEIGHT_PER_MINUTE;
+use super::RlPriorityLevel;
+use super::SelfTradePreventionMode;
+use super::TimeInForce;
+use super::account::check_order_fields;
+use super::prelude::*;
+use crate::client::Task;
 
-// pub const SAPI_V1_MARGIN_TRANSFER: &str = "/sapi/v1/margin/transfer";
+pub const SAPI_V1_MARGIN_ACCOUNT: &str = "/sapi/v1/margin/account";
+pub const SAPI_V1_MARGIN_ISOLATED_ACCOUNT: &str = "/sapi/v1/margin/isolated/account";
+pub const SAPI_V1_MARGIN_BORROW_REPAY: &str = "/sapi/v1/margin/borrow-repay";
+pub const SAPI_V1_MARGIN_MAX_BORROWABLE: &str = "/sapi/v1/margin/maxBorrowable";
+pub const SAPI_V1_MARGIN_ORDER: &str = "/sapi/v1/margin/order";
+pub const SAPI_V1_MARGIN_OPEN_ORDERS: &str = "/sapi/v1/margin/openOrders";
+pub const SAPI_V1_MARGIN_ALL_ORDERS: &str = "/sapi/v1/margin/allOrders";
+pub const SAPI_V1_MARGIN_INTEREST_HISTORY: &str = "/sapi/v1/margin/interestHistory";
+pub const SAPI_V1_MARGIN_TRANSFER: &str = "/sapi/v1/margin/transfer";
+pub const SAPI_V1_MARGIN_UNIVERSAL_TRANSFER: &str = "/sapi/v1/asset/transfer";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MarginBorrowRepayType {
+    #[serde(rename = "BORROW")]
+    Borrow,
+    #[serde(rename = "REPAY")]
+    Repay,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MarginSideEffectType {
+    /// A normal trade order.
+    #[serde(rename = "NO_SIDE_EFFECT")]
+    NoSideEffect,
+    /// Borrow the missing amount before placing the order.
+    #[serde(rename = "MARGIN_BUY")]
+    MarginBuy,
+    /// Repay the debt with the proceeds of the order.
+    #[serde(rename = "AUTO_REPAY")]
+    AutoRepay,
+    /// Borrow before placing the order and repay with the proceeds once it is filled.
+    #[serde(rename = "AUTO_BORROW_REPAY")]
+    AutoBorrowRepay,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MarginLevelStatus {
+    #[serde(rename = "EXCESSIVE")]
+    Excessive,
+    #[serde(rename = "NORMAL")]
+    Normal,
+    #[serde(rename = "MARGIN_CALL")]
+    MarginCall,
+    #[serde(rename = "PRE_LIQUIDATION")]
+    PreLiquidation,
+    #[serde(rename = "FORCE_LIQUIDATION")]
+    ForceLiquidation,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MarginTransactionStatus {
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "CONFIRMED")]
+    Confirmed,
+    #[serde(rename = "FAILED")]
+    Failed,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MarginInterestType {
+    /// Interest charged per hour.
+    #[serde(rename = "PERIODIC")]
+    Periodic,
+    /// First interest charged on borrow.
+    #[serde(rename = "ON_BORROW")]
+    OnBorrow,
+    /// Interest charged per hour converted into BNB.
+    #[serde(rename = "PERIODIC_CONVERTED")]
+    PeriodicConverted,
+    /// First interest charged on borrow converted into BNB.
+    #[serde(rename = "ON_BORROW_CONVERTED")]
+    OnBorrowConverted,
+    /// Portfolio margin negative balance daily interest.
+    #[serde(rename = "PORTFOLIO")]
+    Portfolio,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MarginTransferDirection {
+    #[serde(rename = "ROLL_IN")]
+    RollIn,
+    #[serde(rename = "ROLL_OUT")]
+    RollOut,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAccount {
+    pub borrow_enabled: bool,
+    pub margin_level: Decimal,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collateral_margin_level: Option<Decimal>,
+    pub total_asset_of_btc: Decimal,
+    pub total_liability_of_btc: Decimal,
+    pub total_net_asset_of_btc: Decimal,
+    #[serde(
+        rename = "TotalCollateralValueInUSDT",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub total_collateral_value_in_usdt: Option<Decimal>,
+    #[serde(
+        rename = "totalOpenOrderLossInUSDT",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub total_open_order_loss_in_usdt: Option<Decimal>,
+    pub trade_enabled: bool,
+    pub transfer_in_enabled: bool,
+    pub transfer_out_enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<String>,
+    pub user_assets: Vec<MarginAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAsset {
+    pub asset: Atom,
+    pub borrowed: Decimal,
+    pub free: Decimal,
+    pub interest: Decimal,
+    pub locked: Decimal,
+    pub net_asset: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAccount {
+    pub assets: Vec<IsolatedMarginPair>,
+    /// Omitted when the request is limited to specific symbols.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_asset_of_btc: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_liability_of_btc: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_net_asset_of_btc: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginPair {
+    pub symbol: Atom,
+    pub base_asset: IsolatedMarginAsset,
+    pub quote_asset: IsolatedMarginAsset,
+    pub isolated_created: bool,
+    pub enabled: bool,
+    pub margin_level: Decimal,
+    pub margin_level_status: MarginLevelStatus,
+    pub margin_ratio: Decimal,
+    pub index_price: Decimal,
+    pub liquidate_price: Decimal,
+    pub liquidate_rate: Decimal,
+    pub trade_enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAsset {
+    pub asset: Atom,
+    pub borrow_enabled: bool,
+    pub borrowed: Decimal,
+    pub free: Decimal,
+    pub interest: Decimal,
+    pub locked: Decimal,
+    pub net_asset: Decimal,
+    pub net_asset_of_btc: Decimal,
+    pub repay_enabled: bool,
+    pub total_asset: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTransaction {
+    /// Transaction id.
+    pub tran_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginMaxBorrowable {
+    pub amount: Decimal,
+    /// Max borrowable amount limited by the account level.
+    pub borrow_limit: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginRecords<T> {
+    #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+    pub rows: Vec<T>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginBorrowRepayRecord {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Atom>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub isolated_symbol: Option<Atom>,
+    pub amount: Decimal,
+    pub asset: Atom,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interest: Option<Decimal>,
+    pub principal: Decimal,
+    pub status: MarginTransactionStatus,
+    pub timestamp: u64,
+    pub tx_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginInterest {
+    pub tx_id: u64,
+    pub interest_accured_time: u64,
+    pub asset: Atom,
+    /// Present only when the interest was converted into BNB.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_asset: Option<Atom>,
+    pub principal: Decimal,
+    pub interest: Decimal,
+    pub interest_rate: Decimal,
+    pub r#type: MarginInterestType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub isolated_symbol: Option<Atom>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTransfer {
+    pub amount: Decimal,
+    pub asset: Atom,
+    pub status: MarginTransactionStatus,
+    pub timestamp: u64,
+    pub tx_id: u64,
+    pub r#type: MarginTransferDirection,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trans_from: Option<Atom>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trans_to: Option<Atom>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_symbol: Option<Atom>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_symbol: Option<Atom>,
+}
+
+/// New margin order.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NewMarginOrder {
+    pub symbol: String,
+    /// Isolated margin order when set, cross margin order otherwise.
+    pub is_isolated: bool,
+    pub side: OrderSide,
+    pub r#type: OrderType,
+    pub quantity: Option<Decimal>,
+    pub quote_order_qty: Option<Decimal>,
+    pub price: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
+    pub new_client_order_id: Option<String>,
+    pub iceberg_qty: Option<Decimal>,
+    pub new_order_resp_type: Option<OrderResponseType>,
+    pub side_effect_type: Option<MarginSideEffectType>,
+    pub time_in_force: Option<TimeInForce>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    /// Only for `AUTO_REPAY` and `AUTO_BORROW_REPAY`: whether the borrowed amount is repaid
+    /// when the order is canceled. Default true.
+    pub auto_repay_at_cancel: Option<bool>,
+}
+
+impl NewMarginOrder {
+    pub fn new(symbol: impl Into<String>, side: OrderSide, r#type: OrderType) -> Self {
+        NewMarginOrder {
+            symbol: symbol.into(),
+            is_isolated: false,
+            side,
+            r#type,
+            quantity: None,
+            quote_order_qty: None,
+            price: None,
+            stop_price: None,
+            new_client_order_id: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            side_effect_type: None,
+            time_in_force: None,
+            self_trade_prevention_mode: None,
+            auto_repay_at_cancel: None,
+        }
+    }
+
+    pub fn isolated(mut self) -> Self {
+        self.is_isolated = true;
+        self
+    }
+
+    pub fn quantity(mut self, quantity: Decimal) -> Self {
+        self.quantity = Some(quantity);
+        self
+    }
+
+    pub fn quote_order_qty(mut self, quote_order_qty: Decimal) -> Self {
+        self.quote_order_qty = Some(quote_order_qty);
+        self
+    }
+
+    pub fn price(mut self, price: Decimal) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn stop_price(mut self, stop_price: Decimal) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    pub fn new_client_order_id(mut self, new_client_order_id: impl Into<String>) -> Self {
+        self.new_client_order_id = Some(new_client_order_id.into());
+        self
+    }
+
+    pub fn iceberg_qty(mut self, iceberg_qty: Decimal) -> Self {
+        self.iceberg_qty = Some(iceberg_qty);
+        self
+    }
+
+    pub fn new_order_resp_type(mut self, new_order_resp_type: OrderResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn side_effect_type(mut self, side_effect_type: MarginSideEffectType) -> Self {
+        self.side_effect_type = Some(side_effect_type);
+        self
+    }
+
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    pub fn auto_repay_at_cancel(mut self, auto_repay_at_cancel: bool) -> Self {
+        self.auto_repay_at_cancel = Some(auto_repay_at_cancel);
+        self
+    }
+
+    fn check(&self) -> BinanceResult<()> {
+        check_order_fields(
+            self.r#type,
+            self.time_in_force,
+            self.quantity,
+            self.quote_order_qty,
+            self.price,
+            self.stop_price.is_some(),
+        )
+    }
+}
+
+/// Margin order as returned by the placement endpoint.
+///
+/// Only the identifiers are present for the `ACK` response type.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOrderReport {
+    pub symbol: Atom,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub is_isolated: bool,
+    pub transact_time: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orig_qty: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub executed_qty: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cummulative_quote_qty: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrderStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<OrderType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub side: Option<OrderSide>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    /// Present only for `MARGIN_BUY` and `AUTO_BORROW_REPAY` orders that borrowed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_buy_borrow_amount: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_buy_borrow_asset: Option<Atom>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fills: Vec<OrderFill>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginCancelledOrder {
+    pub symbol: Atom,
+    pub is_isolated: bool,
+    /// Sent as a string by this endpoint.
+    #[serde(deserialize_with = "u64_from_str_or_num")]
+    pub order_id: u64,
+    pub orig_client_order_id: String,
+    pub client_order_id: String,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub side: OrderSide,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOrder {
+    pub symbol: Atom,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub is_isolated: bool,
+    pub price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    pub cummulative_quote_qty: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub side: OrderSide,
+    pub stop_price: Decimal,
+    pub iceberg_qty: Decimal,
+    pub time: u64,
+    pub update_time: u64,
+    pub is_working: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+fn u64_from_str_or_num<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => s.parse().map_err(D::Error::custom),
+        v => serde_json::from_value(v).map_err(D::Error::custom),
+    }
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> SpotApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Query Cross Margin Account Details (USER_DATA)
+        ///
+        /// Weight(IP): 10
+        pub fn margin_account(
+            &self,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<MarginAccount>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V1_MARGIN_ACCOUNT)?
+                        .signed(time_window)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 10)
+                .send())
+        }
+
+        /// Query Isolated Margin Account Info (USER_DATA)
+        ///
+        /// Weight(IP): 10
+        ///
+        /// * symbols - Max 5 symbols can be sent; separated by ",".
+        ///
+        /// If "symbols" is not sent, all isolated assets will be returned.
+        pub fn isolated_margin_account(
+            &self,
+            symbols: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<IsolatedMarginAccount>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V1_MARGIN_ISOLATED_ACCOUNT)?
+                        .signed(time_window)?
+                        .try_query_arg("symbols", &symbols)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 10)
+                .send())
+        }
+
+        /// Margin Account Borrow/Repay (MARGIN)
+        ///
+        /// Margin account borrow/repay.
+        ///
+        /// Weight(UID): 1500
+        ///
+        /// * isolated_symbol - Borrow/repay on the isolated margin account of the symbol
+        ///   when set, on the cross margin account otherwise.
+        pub fn margin_borrow_repay(
+            &self,
+            r#type: MarginBorrowRepayType,
+            asset: impl Serialize,
+            amount: Decimal,
+            isolated_symbol: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<MarginTransaction>> {
+            let is_isolated = isolated_symbol.is_some();
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(SAPI_V1_MARGIN_BORROW_REPAY)?
+                        .signed(time_window)?
+                        .query_arg("asset", &asset)?
+                        .query_arg("isIsolated", &is_isolated_arg(is_isolated))?
+                        .try_query_arg("symbol", &isolated_symbol)?
+                        .query_arg("amount", &amount)?
+                        .query_arg("type", &r#type)?,
+                )
+                .cost(RL_SAPI_UID_WEIGHT_PER_MINUTE, 1500)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Margin Account Borrow (MARGIN)
+        ///
+        /// Shortcut for [`SpotApi::margin_borrow_repay`] with `BORROW` type.
+        pub fn margin_borrow(
+            &self,
+            asset: impl Serialize,
+            amount: Decimal,
+            isolated_symbol: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<MarginTransaction>> {
+            self.margin_borrow_repay(
+                MarginBorrowRepayType::Borrow,
+                asset,
+                amount,
+                isolated_symbol,
+                time_window,
+            )
+        }
+
+        /// Margin Account Repay (MARGIN)
+        ///
+        /// Shortcut for [`SpotApi::margin_borrow_repay`] with `REPAY` type.
+        pub fn margin_repay(
+            &self,
+            asset: impl Serialize,
+            amount: Decimal,
+            isolated_symbol: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<MarginTransaction>> {
+            self.margin_borrow_repay(
+                MarginBorrowRepayType::Repay,
+                asset,
+                amount,
+                isolated_symbol,
+                time_window,
+            )
+        }
+
+        /// Query borrow/repay records in Margin account (USER_DATA)
+        ///
+        /// Weight(IP): 10
+        ///
+        /// * tx_id - the tranId in `POST /sapi/v1/margin/borrow-repay`.
+        /// * current - Currently querying page. Start from 1. Default: 1.
+        /// * size - Default: 10 Max: 100.
+        ///
+        /// The max interval between startTime and endTime is 30 days.
+        /// Only data of the last 6 months can be queried.
+        #[allow(clippy::too_many_arguments)]
+        pub fn margin_borrow_repay_history(
+            &self,
+            r#type: MarginBorrowRepayType,
+            asset: Option<impl Serialize>,
+            isolated_symbol: Option<impl Serialize>,
+            tx_id: Option<u64>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            current: Option<u64>,
+            size: Option<u64>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<MarginRecords<MarginBorrowRepayRecord>>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V1_MARGIN_BORROW_REPAY)?
+                        .signed(time_window)?
+                        .try_query_arg("asset", &asset)?
+                        .try_query_arg("isolatedSymbol", &isolated_symbol)?
+                        .try_query_arg("txId", &tx_id)?
+                        .try_query_arg("startTime", &start_time)?
+                        .try_query_arg("endTime", &end_time)?
+                        .try_query_arg("current", &current)?
+                        .try_query_arg("size", &size)?
+                        .query_arg("type", &r#type)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 10)
+                .send())
+        }
+
+        /// Query Max Borrow (USER_DATA)
+        ///
+        /// Weight(IP): 50
+        ///
+        /// If isolated_symbol is not sent, crossed margin data will be sent.
+        pub fn margin_max_borrowable(
+            &self,
+            asset: impl Serialize,
+            isolated_symbol: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<MarginMaxBorrowable>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V1_MARGIN_MAX_BORROWABLE)?
+                        .signed(time_window)?
+                        .query_arg("asset", &asset)?
+                        .try_query_arg("isolatedSymbol", &isolated_symbol)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 50)
+                .send())
+        }
+
+        /// Margin Account New Order (TRADE)
+        ///
+        /// Post a new order for margin account.
+        ///
+        /// Weight(UID): 6
+        pub fn margin_create_order(
+            &self,
+            order: NewMarginOrder,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<MarginOrderReport>> {
+            order.check()?;
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(SAPI_V1_MARGIN_ORDER)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &order.symbol)?
+                        .query_arg("isIsolated", &is_isolated_arg(order.is_isolated))?
+                        .query_arg("side", &order.side)?
+                        .query_arg("type", &order.r#type)?
+                        .try_query_arg("quantity", &order.quantity)?
+                        .try_query_arg("quoteOrderQty", &order.quote_order_qty)?
+                        .try_query_arg("price", &order.price)?
+                        .try_query_arg("stopPrice", &order.stop_price)?
+                        .try_query_arg("newClientOrderId", &order.new_client_order_id)?
+                        .try_query_arg("icebergQty", &order.iceberg_qty)?
+                        .try_query_arg("newOrderRespType", &order.new_order_resp_type)?
+                        .try_query_arg("sideEffectType", &order.side_effect_type)?
+                        .try_query_arg("timeInForce", &order.time_in_force)?
+                        .try_query_arg(
+                            "selfTradePreventionMode",
+                            &order.self_trade_prevention_mode,
+                        )?
+                        .try_query_arg("autoRepayAtCancel", &order.auto_repay_at_cancel)?,
+                )
+                .cost(RL_SAPI_UID_WEIGHT_PER_MINUTE, 6)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Margin Account Cancel Order (TRADE)
+        ///
+        /// Cancel an active order for margin account.
+        ///
+        /// Weight(IP): 10
+        ///
+        /// Either orderId or origClientOrderId must be sent.
+        pub fn margin_cancel_order(
+            &self,
+            symbol: impl Serialize,
+            is_isolated: bool,
+            order_id: Option<u64>,
+            orig_client_order_id: Option<impl Serialize>,
+            new_client_order_id: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<MarginCancelledOrder>> {
+            if order_id.is_none() && orig_client_order_id.is_none() {
+                Err(ApiError::mandatory_field_omitted(
+                    "order_id or orig_client_order_id",
+                ))?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .delete(SAPI_V1_MARGIN_ORDER)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .query_arg("isIsolated", &is_isolated_arg(is_isolated))?
+                        .try_query_arg("orderId", &order_id)?
+                        .try_query_arg("origClientOrderId", &orig_client_order_id)?
+                        .try_query_arg("newClientOrderId", &new_client_order_id)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 10)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Margin Account Cancel all Open Orders on a Symbol (TRADE)
+        ///
+        /// Cancels all active orders on a symbol for margin account.
+        ///
+        /// Weight(IP): 1
+        pub fn margin_cancel_all_orders(
+            &self,
+            symbol: impl Serialize,
+            is_isolated: bool,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<CancelledOrder>>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .delete(SAPI_V1_MARGIN_OPEN_ORDERS)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .query_arg("isIsolated", &is_isolated_arg(is_isolated))?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Query Margin Account's Order (USER_DATA)
+        ///
+        /// Weight(IP): 10
+        ///
+        /// Either orderId or origClientOrderId must be sent.
+        /// For some historical orders cummulativeQuoteQty will be < 0,
+        ///   meaning the data is not available at this time.
+        pub fn margin_get_order(
+            &self,
+            symbol: impl Serialize,
+            is_isolated: bool,
+            order_id: Option<u64>,
+            orig_client_order_id: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<MarginOrder>> {
+            if order_id.is_none() && orig_client_order_id.is_none() {
+                Err(ApiError::mandatory_field_omitted(
+                    "order_id or orig_client_order_id",
+                ))?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V1_MARGIN_ORDER)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .query_arg("isIsolated", &is_isolated_arg(is_isolated))?
+                        .try_query_arg("orderId", &order_id)?
+                        .try_query_arg("origClientOrderId", &orig_client_order_id)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 10)
+                .send())
+        }
+
+        /// Query Margin Account's Open Orders (USER_DATA)
+        ///
+        /// Weight(IP): 10
+        ///
+        /// If the symbol is not sent, orders for all symbols will be returned in an array.
+        /// When all symbols are returned, the number of requests counted against the rate
+        ///   limiter is equal to the number of symbols currently trading on the exchange.
+        /// If isIsolated = true, symbol must be sent.
+        pub fn margin_open_orders(
+            &self,
+            symbol: Option<impl Serialize>,
+            is_isolated: bool,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<MarginOrder>>> {
+            if is_isolated && symbol.is_none() {
+                Err(ApiError::mandatory_field_omitted("symbol"))?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V1_MARGIN_OPEN_ORDERS)?
+                        .signed(time_window)?
+                        .try_query_arg("symbol", &symbol)?
+                        .query_arg("isIsolated", &is_isolated_arg(is_isolated))?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 10)
+                .send())
+        }
+
+        /// Query Margin Account's All Orders (USER_DATA)
+        ///
+        /// Weight(IP): 200
+        ///
+        /// * limit: Default 500; max 500.
+        ///
+        /// If orderId is set, it will get orders >= that orderId. Otherwise most recent orders
+        ///   are returned.
+        /// For some historical orders cummulativeQuoteQty will be < 0, meaning the data
+        ///   is not available at this time.
+        #[allow(clippy::too_many_arguments)]
+        pub fn margin_all_orders(
+            &self,
+            symbol: impl Serialize,
+            is_isolated: bool,
+            order_id: Option<u64>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u64>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<MarginOrder>>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V1_MARGIN_ALL_ORDERS)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .query_arg("isIsolated", &is_isolated_arg(is_isolated))?
+                        .try_query_arg("orderId", &order_id)?
+                        .try_query_arg("startTime", &start_time)?
+                        .try_query_arg("endTime", &end_time)?
+                        .try_query_arg("limit", &limit)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 200)
+                .send())
+        }
+
+        /// Get Interest History (USER_DATA)
+        ///
+        /// Weight(IP): 1
+        ///
+        /// * current - Currently querying page. Start from 1. Default: 1.
+        /// * size - Default: 10 Max: 100.
+        ///
+        /// Response in descending order.
+        /// The max interval between startTime and endTime is 30 days.
+        #[allow(clippy::too_many_arguments)]
+        pub fn margin_interest_history(
+            &self,
+            asset: Option<impl Serialize>,
+            isolated_symbol: Option<impl Serialize>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            current: Option<u64>,
+            size: Option<u64>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<MarginRecords<MarginInterest>>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V1_MARGIN_INTEREST_HISTORY)?
+                        .signed(time_window)?
+                        .try_query_arg("asset", &asset)?
+                        .try_query_arg("isolatedSymbol", &isolated_symbol)?
+                        .try_query_arg("startTime", &start_time)?
+                        .try_query_arg("endTime", &end_time)?
+                        .try_query_arg("current", &current)?
+                        .try_query_arg("size", &size)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Margin Account Transfer (USER_DATA)
+        ///
+        /// Transfer funds into or out of the cross or isolated margin accounts
+        /// via the universal transfer endpoint.
+        ///
+        /// Weight(UID): 900
+        ///
+        /// * from_symbol - Must be sent when transfer_type is `ISOLATEDMARGIN_MARGIN`
+        ///   or `ISOLATEDMARGIN_ISOLATEDMARGIN`.
+        /// * to_symbol - Must be sent when transfer_type is `MARGIN_ISOLATEDMARGIN`
+        ///   or `ISOLATEDMARGIN_ISOLATEDMARGIN`.
+        pub fn margin_transfer(
+            &self,
+            transfer_type: TransferKind,
+            asset: impl Serialize,
+            amount: Decimal,
+            from_symbol: Option<impl Serialize>,
+            to_symbol: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<MarginTransaction>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(SAPI_V1_MARGIN_UNIVERSAL_TRANSFER)?
+                        .signed(time_window)?
+                        .query_arg("type", &transfer_type)?
+                        .query_arg("asset", &asset)?
+                        .query_arg("amount", &amount)?
+                        .try_query_arg("fromSymbol", &from_symbol)?
+                        .try_query_arg("toSymbol", &to_symbol)?,
+                )
+                .cost(RL_SAPI_UID_WEIGHT_PER_MINUTE, 900)
+                .send())
+        }
+
+        /// Get Cross Margin Transfer History (USER_DATA)
+        ///
+        /// Weight(IP): 1
+        ///
+        /// * current - Currently querying page. Start from 1. Default: 1.
+        /// * size - Default: 10 Max: 100.
+        /// * isolated_symbol - Symbol in Isolated Margin.
+        ///
+        /// Response in descending order.
+        /// The max interval between startTime and endTime is 30 days.
+        #[allow(clippy::too_many_arguments)]
+        pub fn margin_transfer_history(
+            &self,
+            asset: Option<impl Serialize>,
+            r#type: Option<MarginTransferDirection>,
+            isolated_symbol: Option<impl Serialize>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            current: Option<u64>,
+            size: Option<u64>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<MarginRecords<MarginTransfer>>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V1_MARGIN_TRANSFER)?
+                        .signed(time_window)?
+                        .try_query_arg("asset", &asset)?
+                        .try_query_arg("type", &r#type)?
+                        .try_query_arg("isolatedSymbol", &isolated_symbol)?
+                        .try_query_arg("startTime", &start_time)?
+                        .try_query_arg("endTime", &end_time)?
+                        .try_query_arg("current", &current)?
+                        .try_query_arg("size", &size)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+    }
+
+    fn is_isolated_arg(is_isolated: bool) -> &'static str {
+        if is_isolated { "TRUE" } else { "FALSE" }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_isolated_margin_account() {
+        let json = r#"{
+            "assets": [{
+                "baseAsset": {
+                    "asset": "BTC",
+                    "borrowEnabled": true,
+                    "borrowed": "0.00000000",
+                    "free": "0.00000000",
+                    "interest": "0.00000000",
+                    "locked": "0.00000000",
+                    "netAsset": "0.00000000",
+                    "netAssetOfBtc": "0.00000000",
+                    "repayEnabled": true,
+                    "totalAsset": "0.00000000"
+                },
+                "quoteAsset": {
+                    "asset": "USDT",
+                    "borrowEnabled": true,
+                    "borrowed": "0.00000000",
+                    "free": "0.00000000",
+                    "interest": "0.00000000",
+                    "locked": "0.00000000",
+                    "netAsset": "0.00000000",
+                    "netAssetOfBtc": "0.00000000",
+                    "repayEnabled": true,
+                    "totalAsset": "0.00000000"
+                },
+                "symbol": "BTCUSDT",
+                "isolatedCreated": true,
+                "enabled": true,
+                "marginLevel": "0.00000000",
+                "marginLevelStatus": "EXCESSIVE",
+                "marginRatio": "0.00000000",
+                "indexPrice": "10000.00000000",
+                "liquidatePrice": "1000.00000000",
+                "liquidateRate": "1.00000000",
+                "tradeEnabled": true
+            }]
+        }"#;
+        let res: IsolatedMarginAccount = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            res.assets[0].margin_level_status,
+            MarginLevelStatus::Excessive
+        );
+        assert!(res.total_asset_of_btc.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_margin_cancelled_order() {
+        let json = r#"{
+            "symbol": "LTCBTC",
+            "isIsolated": true,
+            "orderId": "28",
+            "origClientOrderId": "myOrder1",
+            "clientOrderId": "cancelMyOrder1",
+            "price": "1.00000000",
+            "origQty": "10.00000000",
+            "executedQty": "8.00000000",
+            "cummulativeQuoteQty": "8.00000000",
+            "status": "CANCELED",
+            "timeInForce": "GTC",
+            "type": "LIMIT",
+            "side": "SELL"
+        }"#;
+        let res: MarginCancelledOrder = serde_json::from_str(json).unwrap();
+        assert_eq!(res.order_id, 28);
+    }
+}
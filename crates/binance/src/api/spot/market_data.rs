@@ -1,3 +1,7 @@
+use std::time::Duration;
+
+use ccx_api_lib::Instrument;
+use ccx_api_lib::InstrumentMap;
 use serde::de::Deserialize;
 use serde::de::Deserializer;
 use serde::de::{self};
@@ -8,6 +12,7 @@ use super::OrderType;
 use super::RL_WEIGHT_PER_MINUTE;
 use super::RlPriorityLevel;
 use super::prelude::*;
+use crate::client::RateLimitCounter;
 use crate::client::Task;
 use crate::util::Ask;
 use crate::util::Bid;
@@ -44,6 +49,16 @@ pub struct ExchangeInformation {
     pub symbols: Vec<Symbol>,
 }
 
+impl ExchangeInformation {
+    /// Maps the exchange-neutral instruments to the symbols and back.
+    pub fn instrument_map(&self) -> InstrumentMap {
+        self.symbols
+            .iter()
+            .map(|symbol| (symbol.instrument(), symbol.symbol.clone()))
+            .collect()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
 #[serde(rename_all = "camelCase")]
 pub struct RateLimit {
@@ -53,6 +68,27 @@ pub struct RateLimit {
     pub limit: u32,
 }
 
+impl RateLimit {
+    /// Length of the interval the limit applies to.
+    pub fn period(&self) -> Duration {
+        let secs = match self.interval {
+            RateLimitInterval::Second => 1,
+            RateLimitInterval::Minute => 60,
+            RateLimitInterval::Day => 86_400,
+        };
+        Duration::from_secs(secs * u64::from(self.interval_num))
+    }
+
+    /// Rate limiter counter the limit applies to; `None` for raw requests.
+    pub fn counter(&self) -> Option<RateLimitCounter> {
+        match self.rate_limit_type {
+            RateLimitType::RequestWeight => Some(RateLimitCounter::Weight),
+            RateLimitType::Orders => Some(RateLimitCounter::OrderCount),
+            RateLimitType::RawRequests => None,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum RateLimitType {
     #[serde(rename = "REQUEST_WEIGHT")]
@@ -95,6 +131,12 @@ pub struct Symbol {
     pub permissions: Vec<SymbolPermission>,
 }
 
+impl Symbol {
+    pub fn instrument(&self) -> Instrument {
+        Instrument::new(&self.base_asset, &self.quote_asset)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum SymbolStatus {
     #[serde(rename = "PRE_TRADING")]
@@ -261,6 +303,12 @@ pub struct NotionalFilter {
     pub max_notional: Decimal,
     #[serde(default)]
     pub apply_to_market: bool,
+    /// Whether `min_notional` is applied to MARKET orders.
+    #[serde(default)]
+    pub apply_min_to_market: bool,
+    /// Whether `max_notional` is applied to MARKET orders.
+    #[serde(default)]
+    pub apply_max_to_market: bool,
     #[serde(default)]
     pub avg_price_mins: u64,
 }
@@ -564,6 +612,7 @@ pub use with_network::*;
 #[cfg(feature = "with_network")]
 mod with_network {
     use super::*;
+    use crate::client::RateLimiter;
 
     impl<S> SpotApi<S>
     where
@@ -595,13 +644,19 @@ mod with_network {
 
         /// Current exchange trading rules and symbol information.
         ///
+        /// The `rateLimits` of the response are applied to the rate limiter.
+        ///
         /// Weight: 1
         pub fn exchange_info(&self) -> BinanceResult<Task<ExchangeInformation>> {
+            let rate_limiter = self.rate_limiter.clone();
             Ok(self
                 .rate_limiter
                 .task(self.client.get(API_V3_EXCHANGE_INFO)?)
                 .cost(RL_WEIGHT_PER_MINUTE, 1)
-                .send())
+                .send()
+                .inspect_ok(move |info: &ExchangeInformation| {
+                    apply_rate_limits(&rate_limiter, &info.rate_limits)
+                }))
         }
 
         /// Order book.
@@ -916,4 +971,13 @@ mod with_network {
                 .send())
         }
     }
+
+    /// Applies `rateLimits` of the exchange information to the rate limiter.
+    fn apply_rate_limits(rate_limiter: &RateLimiter, rate_limits: &[RateLimit]) {
+        for rate_limit in rate_limits {
+            if let Some(counter) = rate_limit.counter() {
+                rate_limiter.set_limit(counter, rate_limit.period(), rate_limit.limit);
+            }
+        }
+    }
 }
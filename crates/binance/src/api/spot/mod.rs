@@ -5,8 +5,10 @@ use crate::client::ApiCred;
 use crate::client::CCX_BINANCE_API_PREFIX;
 use crate::client::Config;
 use crate::client::Proxy;
+use crate::client::RateLimitCounter;
 use crate::client::RateLimiterBucket;
 use crate::client::RateLimiterBuilder;
+use crate::client::ReconnectingWebsocketStream;
 use crate::client::RestClient;
 use crate::client::WebsocketStream;
 use crate::error::*;
@@ -15,6 +17,7 @@ mod account;
 mod broker;
 mod margin;
 mod market_data;
+mod order_list;
 mod user_data_stream;
 // TODO mod error;
 // TODO mod savings;
@@ -26,6 +29,7 @@ mod clearjunction;
 mod subaccount;
 pub mod util;
 mod wallet;
+mod websocket_api;
 mod websocket_market;
 
 pub use self::account::*;
@@ -40,9 +44,11 @@ pub use self::clearjunction::*;
 pub use self::futures::*;
 pub use self::margin::*;
 pub use self::market_data::*;
+pub use self::order_list::*;
 pub use self::subaccount::*;
 pub use self::user_data_stream::*;
 pub use self::wallet::*;
+pub use self::websocket_api::*;
 pub use self::websocket_market::*;
 use crate::client::BinanceSigner;
 
@@ -52,9 +58,14 @@ pub const STREAM_BASE: &str = "wss://stream.binance.com/stream";
 pub const API_BASE_TESTNET: &str = "https://testnet.binance.vision/";
 pub const STREAM_BASE_TESTNET: &str = "wss://testnet.binance.vision/stream";
 
+pub const WS_API_BASE: &str = "wss://ws-api.binance.com:443/ws-api/v3";
+pub const WS_API_BASE_TESTNET: &str = "wss://ws-api.testnet.binance.vision/ws-api/v3";
+
 pub const RL_WEIGHT_PER_MINUTE: &str = "weight_per_minute";
 pub const RL_ORDERS_PER_SECOND: &str = "orders_per_second";
 pub const RL_ORDERS_PER_DAY: &str = "orders_per_day";
+/// The `/sapi` endpoints weighted per account rather than per IP.
+pub const RL_SAPI_UID_WEIGHT_PER_MINUTE: &str = "sapi_uid_weight_per_minute";
 
 pub enum RlPriorityLevel {
     Normal = 1,
@@ -89,18 +100,22 @@ mod with_network {
         S: BinanceSigner,
     {
         pub fn new(signer: S, testnet: bool, proxy: Option<Proxy>) -> Self {
-            let (api_base, stream_base) = if testnet {
+            let (api_base, stream_base, ws_api_base) = if testnet {
                 (
                     Url::parse(API_BASE_TESTNET).unwrap(),
                     Url::parse(STREAM_BASE_TESTNET).unwrap(),
+                    Url::parse(WS_API_BASE_TESTNET).unwrap(),
                 )
             } else {
                 (
                     Url::parse(API_BASE).unwrap(),
                     Url::parse(STREAM_BASE).unwrap(),
+                    Url::parse(WS_API_BASE).unwrap(),
                 )
             };
-            SpotApi::with_config(Config::new(signer, api_base, stream_base, proxy))
+            SpotApi::with_config(
+                Config::new(signer, api_base, stream_base, proxy).with_ws_api_base(ws_api_base),
+            )
         }
 
         /// Reads config from env vars with names like:
@@ -128,25 +143,34 @@ mod with_network {
 
             let client = RestClient::new(config);
             let rate_limiter = RateLimiterBuilder::default()
-                .bucket(
+                .synced_bucket(
                     RL_WEIGHT_PER_MINUTE,
+                    RateLimitCounter::Weight,
                     RateLimiterBucket::default()
                         .interval(Duration::from_secs(60))
                         .limit(1_200),
                 )
-                .bucket(
+                .synced_bucket(
                     RL_ORDERS_PER_SECOND,
+                    RateLimitCounter::OrderCount,
                     RateLimiterBucket::default()
                         .delay(Duration::from_secs(1))
                         .interval(Duration::from_secs(1))
                         .limit(10),
                 )
-                .bucket(
+                .synced_bucket(
                     RL_ORDERS_PER_DAY,
+                    RateLimitCounter::OrderCount,
                     RateLimiterBucket::default()
                         .interval(Duration::from_secs(86_400))
                         .limit(200_000),
                 )
+                .bucket(
+                    RL_SAPI_UID_WEIGHT_PER_MINUTE,
+                    RateLimiterBucket::default()
+                        .interval(Duration::from_secs(60))
+                        .limit(180_000),
+                )
                 .start();
 
             SpotApi {
@@ -159,5 +183,14 @@ mod with_network {
         pub async fn ws(&self) -> BinanceResult<WebsocketStream> {
             self.client.web_socket().await
         }
+
+        /// Creates multiplexed websocket stream which reconnects and resubscribes
+        /// after disconnections.
+        pub async fn ws_reconnecting(&self) -> BinanceResult<ReconnectingWebsocketStream>
+        where
+            S: 'static,
+        {
+            ReconnectingWebsocketStream::connect(self.client.clone()).await
+        }
     }
 }
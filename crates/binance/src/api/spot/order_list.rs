@@ -0,0 +1,897 @@
+use super::OrderFill;
+use super::OrderResponseType;
+use super::OrderSide;
+use super::OrderStatus;
+use super::OrderType;
+use super::RL_ORDERS_PER_DAY;
+use super::RL_ORDERS_PER_SECOND;
+use super::RL_WEIGHT_PER_MINUTE;
+use super::RlPriorityLevel;
+use super::SelfTradePreventionMode;
+use super::TimeInForce;
+use super::prelude::*;
+use crate::client::Task;
+
+pub const API_V3_ORDER_LIST_OCO: &str = "/api/v3/orderList/oco";
+pub const API_V3_ORDER_LIST_OTO: &str = "/api/v3/orderList/oto";
+pub const API_V3_ORDER_LIST_OTOCO: &str = "/api/v3/orderList/otoco";
+pub const API_V3_ORDER_LIST: &str = "/api/v3/orderList";
+pub const API_V3_ALL_ORDER_LIST: &str = "/api/v3/allOrderList";
+pub const API_V3_OPEN_ORDER_LIST: &str = "/api/v3/openOrderList";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ContingencyType {
+    #[serde(rename = "OCO")]
+    Oco,
+    #[serde(rename = "OTO")]
+    Oto,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ListStatusType {
+    /// This is used when the ListStatus is responding to a failed action.
+    /// (E.g. order list placement or cancellation)
+    #[serde(rename = "RESPONSE")]
+    Response,
+    /// The order list has been placed or there is an update to the order list status.
+    #[serde(rename = "EXEC_STARTED")]
+    ExecStarted,
+    /// The order list has finished executing and thus is no longer active.
+    #[serde(rename = "ALL_DONE")]
+    AllDone,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ListOrderStatus {
+    /// Either an order list has been placed or there is an update to the status of the list.
+    #[serde(rename = "EXECUTING")]
+    Executing,
+    /// An order list has completed execution and thus no longer active.
+    #[serde(rename = "ALL_DONE")]
+    AllDone,
+    /// The List Status is responding to a failed action either during order placement
+    /// or order canceled.
+    #[serde(rename = "REJECT")]
+    Reject,
+}
+
+/// A single order of an order list.
+///
+/// Used for the `above`/`below` legs of an OCO and for the `working`/`pending` legs of
+/// OTO and OTOCO lists. `side` and `quantity` are only sent for the legs that carry their own
+/// (the working and pending legs of an OTO, and the working leg of an OTOCO).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OrderListLeg {
+    pub r#type: OrderType,
+    pub side: Option<OrderSide>,
+    pub quantity: Option<Decimal>,
+    pub price: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
+    pub trailing_delta: Option<u64>,
+    pub iceberg_qty: Option<Decimal>,
+    pub time_in_force: Option<TimeInForce>,
+    pub client_order_id: Option<String>,
+    pub strategy_id: Option<u64>,
+    /// Values smaller than 1000000 are reserved and cannot be used.
+    pub strategy_type: Option<u32>,
+}
+
+impl OrderListLeg {
+    pub fn new(r#type: OrderType) -> Self {
+        OrderListLeg {
+            r#type,
+            side: None,
+            quantity: None,
+            price: None,
+            stop_price: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            time_in_force: None,
+            client_order_id: None,
+            strategy_id: None,
+            strategy_type: None,
+        }
+    }
+
+    pub fn limit(price: Decimal, time_in_force: TimeInForce) -> Self {
+        Self::new(OrderType::Limit)
+            .price(price)
+            .time_in_force(time_in_force)
+    }
+
+    pub fn limit_maker(price: Decimal) -> Self {
+        Self::new(OrderType::LimitMaker).price(price)
+    }
+
+    pub fn market() -> Self {
+        Self::new(OrderType::Market)
+    }
+
+    pub fn stop_loss(stop_price: Decimal) -> Self {
+        Self::new(OrderType::StopLoss).stop_price(stop_price)
+    }
+
+    pub fn stop_loss_limit(
+        stop_price: Decimal,
+        price: Decimal,
+        time_in_force: TimeInForce,
+    ) -> Self {
+        Self::new(OrderType::StopLossLimit)
+            .stop_price(stop_price)
+            .price(price)
+            .time_in_force(time_in_force)
+    }
+
+    pub fn take_profit(stop_price: Decimal) -> Self {
+        Self::new(OrderType::TakeProfit).stop_price(stop_price)
+    }
+
+    pub fn take_profit_limit(
+        stop_price: Decimal,
+        price: Decimal,
+        time_in_force: TimeInForce,
+    ) -> Self {
+        Self::new(OrderType::TakeProfitLimit)
+            .stop_price(stop_price)
+            .price(price)
+            .time_in_force(time_in_force)
+    }
+
+    pub fn side(mut self, side: OrderSide) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    pub fn quantity(mut self, quantity: Decimal) -> Self {
+        self.quantity = Some(quantity);
+        self
+    }
+
+    pub fn price(mut self, price: Decimal) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn stop_price(mut self, stop_price: Decimal) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    pub fn trailing_delta(mut self, trailing_delta: u64) -> Self {
+        self.trailing_delta = Some(trailing_delta);
+        self
+    }
+
+    pub fn iceberg_qty(mut self, iceberg_qty: Decimal) -> Self {
+        self.iceberg_qty = Some(iceberg_qty);
+        self
+    }
+
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn client_order_id(mut self, client_order_id: impl Into<String>) -> Self {
+        self.client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    pub fn strategy_id(mut self, strategy_id: u64) -> Self {
+        self.strategy_id = Some(strategy_id);
+        self
+    }
+
+    pub fn strategy_type(mut self, strategy_type: u32) -> Self {
+        self.strategy_type = Some(strategy_type);
+        self
+    }
+
+    /// Checks that the parameters mandatory for the leg's order type are present.
+    fn check(&self, prefix: &'static str) -> BinanceResult<()> {
+        let has_trigger = self.stop_price.is_some() || self.trailing_delta.is_some();
+        let omitted = match self.r#type {
+            OrderType::Limit => self.price.is_none() || self.time_in_force.is_none(),
+            OrderType::Market => false,
+            OrderType::LimitMaker => self.price.is_none(),
+            OrderType::StopLoss | OrderType::TakeProfit => !has_trigger,
+            OrderType::StopLossLimit | OrderType::TakeProfitLimit => {
+                !has_trigger || self.price.is_none() || self.time_in_force.is_none()
+            }
+        };
+        if omitted {
+            Err(ApiError::mandatory_field_omitted(format!(
+                "{prefix}: mandatory fields for {:?}",
+                self.r#type
+            )))?
+        }
+        Ok(())
+    }
+
+    fn check_side_and_quantity(&self, prefix: &'static str) -> BinanceResult<()> {
+        if self.side.is_none() || self.quantity.is_none() {
+            Err(ApiError::mandatory_field_omitted(format!(
+                "{prefix}: side, quantity"
+            )))?
+        }
+        Ok(())
+    }
+
+    /// The legs of an OCO pair take `side` and `quantity` from the list.
+    fn check_no_side_and_quantity(&self, prefix: &'static str) -> BinanceResult<()> {
+        if self.side.is_some() || self.quantity.is_some() {
+            Err(ApiError::field_not_required(format!(
+                "{prefix}: side, quantity"
+            )))?
+        }
+        Ok(())
+    }
+}
+
+/// New One-Cancels-the-Other order list.
+///
+/// An OCO has 2 orders called the above order and below order.
+/// One of the orders must be a `LIMIT_MAKER`/`TAKE_PROFIT`/`TAKE_PROFIT_LIMIT` order
+/// and the other must be a `STOP_LOSS` or `STOP_LOSS_LIMIT` order.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NewOcoOrderList {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub quantity: Decimal,
+    pub above: OrderListLeg,
+    pub below: OrderListLeg,
+    pub list_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<OrderResponseType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+impl NewOcoOrderList {
+    pub fn new(
+        symbol: impl Into<String>,
+        side: OrderSide,
+        quantity: Decimal,
+        above: OrderListLeg,
+        below: OrderListLeg,
+    ) -> Self {
+        NewOcoOrderList {
+            symbol: symbol.into(),
+            side,
+            quantity,
+            above,
+            below,
+            list_client_order_id: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        }
+    }
+
+    pub fn list_client_order_id(mut self, list_client_order_id: impl Into<String>) -> Self {
+        self.list_client_order_id = Some(list_client_order_id.into());
+        self
+    }
+
+    pub fn new_order_resp_type(mut self, new_order_resp_type: OrderResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    fn check(&self) -> BinanceResult<()> {
+        self.above.check("above")?;
+        self.above.check_no_side_and_quantity("above")?;
+        self.below.check("below")?;
+        self.below.check_no_side_and_quantity("below")
+    }
+}
+
+/// New One-Triggers-the-Other order list.
+///
+/// The working order must be `LIMIT` or `LIMIT_MAKER`. The pending order is placed only
+/// once the working order is fully filled.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NewOtoOrderList {
+    pub symbol: String,
+    pub working: OrderListLeg,
+    pub pending: OrderListLeg,
+    pub list_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<OrderResponseType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+impl NewOtoOrderList {
+    /// Both legs must have `side` and `quantity` set.
+    pub fn new(symbol: impl Into<String>, working: OrderListLeg, pending: OrderListLeg) -> Self {
+        NewOtoOrderList {
+            symbol: symbol.into(),
+            working,
+            pending,
+            list_client_order_id: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        }
+    }
+
+    pub fn list_client_order_id(mut self, list_client_order_id: impl Into<String>) -> Self {
+        self.list_client_order_id = Some(list_client_order_id.into());
+        self
+    }
+
+    pub fn new_order_resp_type(mut self, new_order_resp_type: OrderResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    fn check(&self) -> BinanceResult<()> {
+        self.working.check("working")?;
+        self.working.check_side_and_quantity("working")?;
+        self.pending.check("pending")?;
+        self.pending.check_side_and_quantity("pending")
+    }
+}
+
+/// New One-Triggers-a-One-Cancels-the-Other order list.
+///
+/// The working order must be `LIMIT` or `LIMIT_MAKER`. Once it is fully filled,
+/// the pending OCO pair (`pending_above` and optional `pending_below`) is placed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NewOtocoOrderList {
+    pub symbol: String,
+    pub working: OrderListLeg,
+    pub pending_side: OrderSide,
+    pub pending_quantity: Decimal,
+    pub pending_above: OrderListLeg,
+    pub pending_below: Option<OrderListLeg>,
+    pub list_client_order_id: Option<String>,
+    pub new_order_resp_type: Option<OrderResponseType>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+impl NewOtocoOrderList {
+    /// The working leg must have `side` and `quantity` set.
+    pub fn new(
+        symbol: impl Into<String>,
+        working: OrderListLeg,
+        pending_side: OrderSide,
+        pending_quantity: Decimal,
+        pending_above: OrderListLeg,
+    ) -> Self {
+        NewOtocoOrderList {
+            symbol: symbol.into(),
+            working,
+            pending_side,
+            pending_quantity,
+            pending_above,
+            pending_below: None,
+            list_client_order_id: None,
+            new_order_resp_type: None,
+            self_trade_prevention_mode: None,
+        }
+    }
+
+    pub fn pending_below(mut self, pending_below: OrderListLeg) -> Self {
+        self.pending_below = Some(pending_below);
+        self
+    }
+
+    pub fn list_client_order_id(mut self, list_client_order_id: impl Into<String>) -> Self {
+        self.list_client_order_id = Some(list_client_order_id.into());
+        self
+    }
+
+    pub fn new_order_resp_type(mut self, new_order_resp_type: OrderResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    fn check(&self) -> BinanceResult<()> {
+        self.working.check("working")?;
+        self.working.check_side_and_quantity("working")?;
+        self.pending_above.check("pendingAbove")?;
+        self.pending_above
+            .check_no_side_and_quantity("pendingAbove")?;
+        if let Some(pending_below) = &self.pending_below {
+            pending_below.check("pendingBelow")?;
+            pending_below.check_no_side_and_quantity("pendingBelow")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListOrder {
+    pub symbol: Atom,
+    pub order_id: u64,
+    pub client_order_id: String,
+}
+
+/// Order list as returned by the query endpoints.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderList {
+    pub order_list_id: u64,
+    pub contingency_type: ContingencyType,
+    pub list_status_type: ListStatusType,
+    pub list_order_status: ListOrderStatus,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: Atom,
+    pub orders: Vec<OrderListOrder>,
+}
+
+/// Order list as returned by the placement and cancellation endpoints.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListResult {
+    pub order_list_id: u64,
+    pub contingency_type: ContingencyType,
+    pub list_status_type: ListStatusType,
+    pub list_order_status: ListOrderStatus,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: Atom,
+    pub orders: Vec<OrderListOrder>,
+    pub order_reports: Vec<OrderListReport>,
+}
+
+/// State of a single order of an order list.
+///
+/// Only the identifiers are present for the `ACK` response type.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListReport {
+    pub symbol: Atom,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transact_time: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orig_qty: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub executed_qty: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cummulative_quote_qty: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrderStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<OrderType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub side: Option<OrderSide>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<Decimal>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fills: Vec<OrderFill>,
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+    use crate::client::BinanceSigner;
+    use crate::client::RequestBuilder;
+
+    impl<S> SpotApi<S>
+    where
+        S: BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// New Order list - OCO (TRADE)
+        ///
+        /// Send in an one-cancels-the-other (OCO) pair, where activation of one order
+        /// immediately cancels the other.
+        ///
+        /// Weight: 1
+        ///
+        /// Unfilled Order Count: 2
+        pub fn create_oco_order_list(
+            &self,
+            order_list: NewOcoOrderList,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<OrderListResult>> {
+            order_list.check()?;
+            let request = self
+                .client
+                .post(API_V3_ORDER_LIST_OCO)?
+                .signed(time_window)?
+                .query_arg("symbol", &order_list.symbol)?
+                .try_query_arg("listClientOrderId", &order_list.list_client_order_id)?
+                .query_arg("side", &order_list.side)?
+                .query_arg("quantity", &order_list.quantity)?;
+            let request = leg_query_args(request, "above", &order_list.above)?;
+            let request = leg_query_args(request, "below", &order_list.below)?
+                .try_query_arg("newOrderRespType", &order_list.new_order_resp_type)?
+                .try_query_arg(
+                    "selfTradePreventionMode",
+                    &order_list.self_trade_prevention_mode,
+                )?;
+
+            Ok(self.order_list_task(request, 2))
+        }
+
+        /// New Order list - OTO (TRADE)
+        ///
+        /// Places an OTO. An OTO (One-Triggers-the-Other) is an order list comprised
+        /// of 2 orders: the working order is placed immediately, the pending order is placed
+        /// when the working order is fully filled.
+        ///
+        /// Weight: 1
+        ///
+        /// Unfilled Order Count: 2
+        pub fn create_oto_order_list(
+            &self,
+            order_list: NewOtoOrderList,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<OrderListResult>> {
+            order_list.check()?;
+            let request = self
+                .client
+                .post(API_V3_ORDER_LIST_OTO)?
+                .signed(time_window)?
+                .query_arg("symbol", &order_list.symbol)?
+                .try_query_arg("listClientOrderId", &order_list.list_client_order_id)?
+                .try_query_arg("newOrderRespType", &order_list.new_order_resp_type)?
+                .try_query_arg(
+                    "selfTradePreventionMode",
+                    &order_list.self_trade_prevention_mode,
+                )?;
+            let request = leg_query_args(request, "working", &order_list.working)?;
+            let request = leg_query_args(request, "pending", &order_list.pending)?;
+
+            Ok(self.order_list_task(request, 2))
+        }
+
+        /// New Order list - OTOCO (TRADE)
+        ///
+        /// Place an OTOCO. An OTOCO (One-Triggers-One-Cancels-the-Other) is an order list
+        /// comprised of 3 orders: the working order is placed immediately, the pending OCO
+        /// pair is placed when the working order is fully filled.
+        ///
+        /// Weight: 1
+        ///
+        /// Unfilled Order Count: 3
+        pub fn create_otoco_order_list(
+            &self,
+            order_list: NewOtocoOrderList,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<OrderListResult>> {
+            order_list.check()?;
+            let request = self
+                .client
+                .post(API_V3_ORDER_LIST_OTOCO)?
+                .signed(time_window)?
+                .query_arg("symbol", &order_list.symbol)?
+                .try_query_arg("listClientOrderId", &order_list.list_client_order_id)?
+                .try_query_arg("newOrderRespType", &order_list.new_order_resp_type)?
+                .try_query_arg(
+                    "selfTradePreventionMode",
+                    &order_list.self_trade_prevention_mode,
+                )?;
+            let request = leg_query_args(request, "working", &order_list.working)?
+                .query_arg("pendingSide", &order_list.pending_side)?
+                .query_arg("pendingQuantity", &order_list.pending_quantity)?;
+            let mut request = leg_query_args(request, "pendingAbove", &order_list.pending_above)?;
+            if let Some(pending_below) = &order_list.pending_below {
+                request = leg_query_args(request, "pendingBelow", pending_below)?;
+            }
+
+            Ok(self.order_list_task(request, 3))
+        }
+
+        /// Cancel Order list (TRADE)
+        ///
+        /// Cancel an entire Order list.
+        ///
+        /// Weight(IP): 1
+        ///
+        /// Either orderListId or listClientOrderId must be provided.
+        /// Canceling an individual order from an order list will cancel the entire list.
+        pub fn cancel_order_list(
+            &self,
+            symbol: impl Serialize,
+            order_list_id: Option<u64>,
+            list_client_order_id: Option<impl Serialize>,
+            new_client_order_id: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<OrderListResult>> {
+            if order_list_id.is_none() && list_client_order_id.is_none() {
+                Err(ApiError::mandatory_field_omitted(
+                    "order_list_id or list_client_order_id",
+                ))?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .delete(API_V3_ORDER_LIST)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .try_query_arg("orderListId", &order_list_id)?
+                        .try_query_arg("listClientOrderId", &list_client_order_id)?
+                        .try_query_arg("newClientOrderId", &new_client_order_id)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Query Order list (USER_DATA)
+        ///
+        /// Retrieves a specific order list based on provided optional parameters.
+        ///
+        /// Weight(IP): 4
+        ///
+        /// Either orderListId or origClientOrderId must be provided.
+        pub fn get_order_list(
+            &self,
+            order_list_id: Option<u64>,
+            orig_client_order_id: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<OrderList>> {
+            if order_list_id.is_none() && orig_client_order_id.is_none() {
+                Err(ApiError::mandatory_field_omitted(
+                    "order_list_id or orig_client_order_id",
+                ))?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(API_V3_ORDER_LIST)?
+                        .signed(time_window)?
+                        .try_query_arg("orderListId", &order_list_id)?
+                        .try_query_arg("origClientOrderId", &orig_client_order_id)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 4)
+                .send())
+        }
+
+        /// Query all Order lists (USER_DATA)
+        ///
+        /// Retrieves all order lists based on provided optional parameters.
+        ///
+        /// Weight(IP): 20
+        ///
+        /// * limit: Default 500; max 1000.
+        ///
+        /// If fromId is supplied, neither startTime or endTime can be provided.
+        /// The time between startTime and endTime can't be longer than 24 hours.
+        pub fn all_order_lists(
+            &self,
+            from_id: Option<u64>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u64>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<OrderList>>> {
+            if from_id.is_some() && (start_time.is_some() || end_time.is_some()) {
+                Err(ApiError::OutOfBounds)?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(API_V3_ALL_ORDER_LIST)?
+                        .signed(time_window)?
+                        .try_query_arg("fromId", &from_id)?
+                        .try_query_arg("startTime", &start_time)?
+                        .try_query_arg("endTime", &end_time)?
+                        .try_query_arg("limit", &limit)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 20)
+                .send())
+        }
+
+        /// Query Open Order lists (USER_DATA)
+        ///
+        /// Weight(IP): 6
+        pub fn open_order_lists(
+            &self,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<OrderList>>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(API_V3_OPEN_ORDER_LIST)?
+                        .signed(time_window)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 6)
+                .send())
+        }
+
+        fn order_list_task(
+            &self,
+            request: RequestBuilder<S>,
+            order_count: u32,
+        ) -> Task<OrderListResult> {
+            self.rate_limiter
+                .task(request)
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .cost(RL_ORDERS_PER_SECOND, order_count)
+                .cost(RL_ORDERS_PER_DAY, order_count)
+                .priority(RlPriorityLevel::High as u8)
+                .send()
+        }
+    }
+
+    /// Appends the parameters of a single leg with the given prefix
+    /// (`above`, `below`, `working`, `pending`, `pendingAbove` or `pendingBelow`).
+    fn leg_query_args<S>(
+        request: RequestBuilder<S>,
+        prefix: &str,
+        leg: &OrderListLeg,
+    ) -> BinanceResult<RequestBuilder<S>>
+    where
+        S: BinanceSigner,
+    {
+        request
+            .query_arg(format!("{prefix}Type"), &leg.r#type)?
+            .try_query_arg(format!("{prefix}Side"), &leg.side)?
+            .try_query_arg(format!("{prefix}ClientOrderId"), &leg.client_order_id)?
+            .try_query_arg(format!("{prefix}Quantity"), &leg.quantity)?
+            .try_query_arg(format!("{prefix}Price"), &leg.price)?
+            .try_query_arg(format!("{prefix}StopPrice"), &leg.stop_price)?
+            .try_query_arg(format!("{prefix}TrailingDelta"), &leg.trailing_delta)?
+            .try_query_arg(format!("{prefix}IcebergQty"), &leg.iceberg_qty)?
+            .try_query_arg(format!("{prefix}TimeInForce"), &leg.time_in_force)?
+            .try_query_arg(format!("{prefix}StrategyId"), &leg.strategy_id)?
+            .try_query_arg(format!("{prefix}StrategyType"), &leg.strategy_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_oco_leg_check() {
+        let oco = NewOcoOrderList::new(
+            "LTCBTC",
+            OrderSide::Sell,
+            dec!(1),
+            OrderListLeg::limit_maker(dec!(1.5)),
+            OrderListLeg::new(OrderType::StopLossLimit).price(dec!(0.9)),
+        );
+        assert!(oco.check().is_err());
+
+        let oco = oco.clone();
+        let oco = NewOcoOrderList {
+            below: oco
+                .below
+                .stop_price(dec!(1))
+                .time_in_force(TimeInForce::Gtc),
+            ..oco
+        };
+        assert!(oco.check().is_ok());
+    }
+
+    #[test]
+    fn test_oto_requires_side_and_quantity() {
+        let oto = NewOtoOrderList::new(
+            "LTCBTC",
+            OrderListLeg::limit(dec!(1), TimeInForce::Gtc).side(OrderSide::Buy),
+            OrderListLeg::limit_maker(dec!(1.5))
+                .side(OrderSide::Sell)
+                .quantity(dec!(1)),
+        );
+        assert!(oto.check().is_err());
+    }
+
+    #[test]
+    fn test_oco_legs_reject_side_and_quantity() {
+        let oco = NewOcoOrderList::new(
+            "LTCBTC",
+            OrderSide::Sell,
+            dec!(1),
+            OrderListLeg::limit_maker(dec!(1.5)).quantity(dec!(1)),
+            OrderListLeg::stop_loss(dec!(0.9)),
+        );
+        assert!(matches!(
+            oco.check(),
+            Err(LibError::ApiError(ApiError::FieldNotRequired(_)))
+        ));
+
+        let otoco = NewOtocoOrderList::new(
+            "LTCBTC",
+            OrderListLeg::limit(dec!(1), TimeInForce::Gtc)
+                .side(OrderSide::Buy)
+                .quantity(dec!(1)),
+            OrderSide::Sell,
+            dec!(1),
+            OrderListLeg::limit_maker(dec!(1.5)),
+        )
+        .pending_below(OrderListLeg::stop_loss(dec!(0.9)).side(OrderSide::Sell));
+        assert!(matches!(
+            otoco.check(),
+            Err(LibError::ApiError(ApiError::FieldNotRequired(_)))
+        ));
+    }
+
+    #[test]
+    fn test_deserialize_order_list_result() {
+        let json = r#"{
+            "orderListId": 1,
+            "contingencyType": "OCO",
+            "listStatusType": "EXEC_STARTED",
+            "listOrderStatus": "EXECUTING",
+            "listClientOrderId": "lH1YDkuQKWiXVXHPSKYEIp",
+            "transactionTime": 1710485608839,
+            "symbol": "LTCBTC",
+            "orders": [
+                {"symbol": "LTCBTC", "orderId": 10, "clientOrderId": "44nZvqpemY7sVYgPYbvPih"},
+                {"symbol": "LTCBTC", "orderId": 11, "clientOrderId": "NuMp0nVYnciDiFmVqfpBqK"}
+            ],
+            "orderReports": [
+                {
+                    "symbol": "LTCBTC",
+                    "orderId": 10,
+                    "orderListId": 1,
+                    "clientOrderId": "44nZvqpemY7sVYgPYbvPih",
+                    "transactTime": 1710485608839,
+                    "price": "1.00000000",
+                    "origQty": "5.00000000",
+                    "executedQty": "0.00000000",
+                    "origQuoteOrderQty": "0.000000",
+                    "cummulativeQuoteQty": "0.00000000",
+                    "status": "NEW",
+                    "timeInForce": "GTC",
+                    "type": "STOP_LOSS_LIMIT",
+                    "side": "SELL",
+                    "stopPrice": "1.00000000",
+                    "workingTime": -1,
+                    "selfTradePreventionMode": "NONE"
+                },
+                {
+                    "symbol": "LTCBTC",
+                    "orderId": 11,
+                    "orderListId": 1,
+                    "clientOrderId": "NuMp0nVYnciDiFmVqfpBqK",
+                    "transactTime": 1710485608839,
+                    "price": "3.00000000",
+                    "origQty": "5.00000000",
+                    "executedQty": "0.00000000",
+                    "origQuoteOrderQty": "0.000000",
+                    "cummulativeQuoteQty": "0.00000000",
+                    "status": "NEW",
+                    "timeInForce": "GTC",
+                    "type": "LIMIT_MAKER",
+                    "side": "SELL",
+                    "workingTime": 1710485608839,
+                    "selfTradePreventionMode": "NONE"
+                }
+            ]
+        }"#;
+        let res: OrderListResult = serde_json::from_str(json).unwrap();
+        assert_eq!(res.contingency_type, ContingencyType::Oco);
+        assert_eq!(res.order_reports.len(), 2);
+        assert_eq!(res.order_reports[1].r#type, Some(OrderType::LimitMaker));
+        assert_eq!(res.order_reports[0].stop_price, Some(dec!(1)));
+    }
+}
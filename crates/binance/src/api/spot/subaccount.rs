@@ -1 +1,519 @@
-// use super::prelude::*;
+use super::Deposit;
+use super::DepositAddress;
+use super::DepositStatus;
+use super::RL_WEIGHT_PER_MINUTE;
+use super::prelude::*;
+use crate::client::Task;
+
+pub const SAPI_V1_SUB_ACCOUNT_LIST: &str = "/sapi/v1/sub-account/list";
+pub const SAPI_V1_SUB_ACCOUNT_VIRTUAL_SUB_ACCOUNT: &str = "/sapi/v1/sub-account/virtualSubAccount";
+pub const SAPI_V1_SUB_ACCOUNT_SPOT_SUMMARY: &str = "/sapi/v1/sub-account/spotSummary";
+pub const SAPI_V2_SUB_ACCOUNT_FUTURES_ACCOUNT_SUMMARY: &str =
+    "/sapi/v2/sub-account/futures/accountSummary";
+pub const SAPI_V1_SUB_ACCOUNT_UNIVERSAL_TRANSFER: &str = "/sapi/v1/sub-account/universalTransfer";
+pub const SAPI_V1_CAPITAL_DEPOSIT_SUB_ADDRESS: &str = "/sapi/v1/capital/deposit/subAddress";
+pub const SAPI_V1_CAPITAL_DEPOSIT_SUB_HISTORY: &str = "/sapi/v1/capital/deposit/subHisrec";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountList {
+    pub sub_accounts: Vec<Subaccount>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Subaccount {
+    pub email: String,
+    pub is_freeze: bool,
+    pub create_time: u64,
+    #[serde(default)]
+    pub is_managed_sub_account: bool,
+    #[serde(default)]
+    pub is_asset_management_sub_account: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountCreated {
+    pub email: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountSpotSummary {
+    pub total_count: u64,
+    pub master_account_total_asset: Decimal,
+    pub spot_sub_user_asset_btc_vo_list: Vec<SubaccountSpotAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountSpotAsset {
+    pub email: String,
+    /// Total asset in BTC.
+    pub total_asset: Decimal,
+}
+
+#[derive(
+    Clone, Copy, Debug, Serialize_repr, Deserialize_repr, Eq, Ord, PartialOrd, PartialEq, Hash,
+)]
+#[repr(u32)]
+pub enum SubaccountFuturesType {
+    UsdMargined = 1,
+    CoinMargined = 2,
+}
+
+/// Only the summary of the requested futures type is present.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountFuturesSummary {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub future_account_summary_resp: Option<SubaccountUsdMarginedSummary>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivery_account_summary_resp: Option<SubaccountCoinMarginedSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountUsdMarginedSummary {
+    pub total_initial_margin: Decimal,
+    pub total_maintenance_margin: Decimal,
+    pub total_margin_balance: Decimal,
+    pub total_open_order_initial_margin: Decimal,
+    pub total_position_initial_margin: Decimal,
+    pub total_unrealized_profit: Decimal,
+    pub total_wallet_balance: Decimal,
+    pub asset: Atom,
+    pub sub_account_list: Vec<SubaccountUsdMarginedAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountUsdMarginedAsset {
+    pub email: String,
+    pub total_initial_margin: Decimal,
+    pub total_maintenance_margin: Decimal,
+    pub total_margin_balance: Decimal,
+    pub total_open_order_initial_margin: Decimal,
+    pub total_position_initial_margin: Decimal,
+    pub total_unrealized_profit: Decimal,
+    pub total_wallet_balance: Decimal,
+    pub asset: Atom,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountCoinMarginedSummary {
+    #[serde(rename = "totalMarginBalanceOfBTC")]
+    pub total_margin_balance_of_btc: Decimal,
+    #[serde(rename = "totalUnrealizedProfitOfBTC")]
+    pub total_unrealized_profit_of_btc: Decimal,
+    #[serde(rename = "totalWalletBalanceOfBTC")]
+    pub total_wallet_balance_of_btc: Decimal,
+    pub asset: Atom,
+    pub sub_account_list: Vec<SubaccountCoinMarginedAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountCoinMarginedAsset {
+    pub email: String,
+    pub total_margin_balance: Decimal,
+    pub total_unrealized_profit: Decimal,
+    pub total_wallet_balance: Decimal,
+    pub asset: Atom,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SubaccountAccountType {
+    #[serde(rename = "SPOT")]
+    Spot,
+    #[serde(rename = "USDT_FUTURE")]
+    UsdtFuture,
+    #[serde(rename = "COIN_FUTURE")]
+    CoinFuture,
+    #[serde(rename = "MARGIN")]
+    Margin,
+    #[serde(rename = "ISOLATED_MARGIN")]
+    IsolatedMargin,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountTransferCreated {
+    pub tran_id: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_tran_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountTransferList {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub result: Vec<SubaccountTransfer>,
+    pub total_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubaccountTransfer {
+    pub tran_id: u64,
+    pub from_email: String,
+    pub to_email: String,
+    pub asset: Atom,
+    pub amount: Decimal,
+    pub create_time_stamp: u64,
+    pub from_account_type: SubaccountAccountType,
+    pub to_account_type: SubaccountAccountType,
+    pub status: SubaccountTransferStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_tran_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SubaccountTransferStatus {
+    #[serde(rename = "PROCESS")]
+    Process,
+    #[serde(rename = "SUCCESS")]
+    Success,
+    #[serde(rename = "FAILURE")]
+    Failure,
+}
+
+impl SubaccountTransferStatus {
+    pub fn is_finished(&self) -> bool {
+        use SubaccountTransferStatus as S;
+        matches!(self, S::Success | S::Failure)
+    }
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> SpotApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Query Sub-account List (For Master Account) (USER_DATA)
+        ///
+        /// Weight(IP): 1
+        ///
+        /// * email - Sub-account email.
+        /// * page - Default value: 1.
+        /// * limit - Default value: 1, Max value: 200.
+        pub fn subaccount_list(
+            &self,
+            email: Option<impl Serialize>,
+            is_freeze: Option<bool>,
+            page: Option<u32>,
+            limit: Option<u32>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<SubaccountList>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V1_SUB_ACCOUNT_LIST)?
+                        .signed(time_window)?
+                        .try_query_arg("email", &email)?
+                        .try_query_arg("isFreeze", &is_freeze)?
+                        .try_query_arg("page", &page)?
+                        .try_query_arg("limit", &limit)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Create a Virtual Sub-account (For Master Account) (USER_DATA)
+        ///
+        /// Weight(IP): 1
+        ///
+        /// * sub_account_string - Please input a string. We will create a virtual email
+        ///   using that string for you to register.
+        ///
+        /// This request will generate a virtual sub account under your master account.
+        /// You need to enable "trade" option for the api key which requests this endpoint.
+        pub fn subaccount_create_virtual(
+            &self,
+            sub_account_string: impl Serialize,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<SubaccountCreated>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(SAPI_V1_SUB_ACCOUNT_VIRTUAL_SUB_ACCOUNT)?
+                        .signed(time_window)?
+                        .query_arg("subAccountString", &sub_account_string)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Query Sub-account Spot Assets Summary (For Master Account) (USER_DATA)
+        ///
+        /// Get BTC valued asset summary of subaccounts.
+        ///
+        /// Weight(IP): 1
+        ///
+        /// * email - Sub account email.
+        /// * page - Default value: 1.
+        /// * size - Default value: 10, Max value: 20.
+        pub fn subaccount_spot_summary(
+            &self,
+            email: Option<impl Serialize>,
+            page: Option<u32>,
+            size: Option<u32>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<SubaccountSpotSummary>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V1_SUB_ACCOUNT_SPOT_SUMMARY)?
+                        .signed(time_window)?
+                        .try_query_arg("email", &email)?
+                        .try_query_arg("page", &page)?
+                        .try_query_arg("size", &size)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Get Summary of Sub-account's Futures Account V2 (For Master Account) (USER_DATA)
+        ///
+        /// Weight(IP): 10
+        ///
+        /// * page - Default value: 1.
+        /// * limit - Default value: 10, Max value: 20.
+        pub fn subaccount_futures_summary(
+            &self,
+            futures_type: SubaccountFuturesType,
+            page: Option<u32>,
+            limit: Option<u32>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<SubaccountFuturesSummary>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V2_SUB_ACCOUNT_FUTURES_ACCOUNT_SUMMARY)?
+                        .signed(time_window)?
+                        .query_arg("futuresType", &futures_type)?
+                        .try_query_arg("page", &page)?
+                        .try_query_arg("limit", &limit)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 10)
+                .send())
+        }
+
+        /// Universal Transfer (For Master Account)
+        ///
+        /// Weight(IP): 360
+        ///
+        /// * from_email, to_email - If not sent, the master account is used.
+        /// * client_tran_id - Must be unique.
+        /// * symbol - Only supported under ISOLATED_MARGIN type.
+        ///
+        /// You need to enable "internal transfer" option for the api key which requests
+        ///   this endpoint.
+        /// Transfer between futures accounts is not supported.
+        #[allow(clippy::too_many_arguments)]
+        pub fn subaccount_universal_transfer(
+            &self,
+            from_email: Option<impl Serialize>,
+            to_email: Option<impl Serialize>,
+            from_account_type: SubaccountAccountType,
+            to_account_type: SubaccountAccountType,
+            client_tran_id: Option<impl Serialize>,
+            symbol: Option<impl Serialize>,
+            asset: impl Serialize,
+            amount: Decimal,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<SubaccountTransferCreated>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(SAPI_V1_SUB_ACCOUNT_UNIVERSAL_TRANSFER)?
+                        .signed(time_window)?
+                        .try_query_arg("fromEmail", &from_email)?
+                        .try_query_arg("toEmail", &to_email)?
+                        .query_arg("fromAccountType", &from_account_type)?
+                        .query_arg("toAccountType", &to_account_type)?
+                        .try_query_arg("clientTranId", &client_tran_id)?
+                        .try_query_arg("symbol", &symbol)?
+                        .query_arg("asset", &asset)?
+                        .query_arg("amount", &amount)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 360)
+                .send())
+        }
+
+        /// Query Universal Transfer History (For Master Account)
+        ///
+        /// Weight(IP): 1
+        ///
+        /// * start_time - Default: 30 days from current timestamp.
+        /// * page - Default value: 1.
+        /// * limit - Default value: 500, Max value: 500.
+        ///
+        /// fromEmail and toEmail cannot be sent at the same time.
+        /// Return fromEmail equal master account email by default.
+        /// The query time period must be less than 7 days.
+        #[allow(clippy::too_many_arguments)]
+        pub fn subaccount_universal_transfer_history(
+            &self,
+            from_email: Option<impl Serialize>,
+            to_email: Option<impl Serialize>,
+            client_tran_id: Option<impl Serialize>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            page: Option<u32>,
+            limit: Option<u32>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<SubaccountTransferList>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V1_SUB_ACCOUNT_UNIVERSAL_TRANSFER)?
+                        .signed(time_window)?
+                        .try_query_arg("fromEmail", &from_email)?
+                        .try_query_arg("toEmail", &to_email)?
+                        .try_query_arg("clientTranId", &client_tran_id)?
+                        .try_query_arg("startTime", &start_time)?
+                        .try_query_arg("endTime", &end_time)?
+                        .try_query_arg("page", &page)?
+                        .try_query_arg("limit", &limit)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Get Sub-account Deposit Address (For Master Account) (USER_DATA)
+        ///
+        /// Fetch sub-account deposit address.
+        ///
+        /// Weight(IP): 1
+        ///
+        /// If network is not send, return with default network of the coin.
+        pub fn subaccount_deposit_address(
+            &self,
+            email: impl Serialize,
+            coin: impl Serialize,
+            network: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<DepositAddress>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V1_CAPITAL_DEPOSIT_SUB_ADDRESS)?
+                        .signed(time_window)?
+                        .query_arg("email", &email)?
+                        .query_arg("coin", &coin)?
+                        .try_query_arg("network", &network)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Get Sub-account Deposit History (For Master Account) (USER_DATA)
+        ///
+        /// Fetch sub-account deposit history.
+        ///
+        /// Weight(IP): 1
+        ///
+        /// * start_time - Default: 7 days from current timestamp
+        /// * end_time - Default: present timestamp
+        /// * limit - Default: 500.
+        /// * offset - Default: 0.
+        ///
+        /// Please notice the default startTime and endTime to make sure that time interval
+        ///   is within 0-7 days.
+        #[allow(clippy::too_many_arguments)]
+        pub fn subaccount_deposit_history(
+            &self,
+            email: impl Serialize,
+            coin: Option<impl Serialize>,
+            status: Option<DepositStatus>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u16>,
+            offset: Option<u16>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<Deposit>>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(SAPI_V1_CAPITAL_DEPOSIT_SUB_HISTORY)?
+                        .signed(time_window)?
+                        .query_arg("email", &email)?
+                        .try_query_arg("coin", &coin)?
+                        .try_query_arg("status", &status)?
+                        .try_query_arg("startTime", &start_time)?
+                        .try_query_arg("endTime", &end_time)?
+                        .try_query_arg("limit", &limit)?
+                        .try_query_arg("offset", &offset)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_futures_summary() {
+        let json = r#"{
+            "deliveryAccountSummaryResp": {
+                "totalMarginBalanceOfBTC": "25.03221121",
+                "totalUnrealizedProfitOfBTC": "0.12233454",
+                "totalWalletBalanceOfBTC": "22.15879444",
+                "asset": "BTC",
+                "subAccountList": [{
+                    "email": "123@test.com",
+                    "totalMarginBalance": "22.12659734",
+                    "totalUnrealizedProfit": "0.00000000",
+                    "totalWalletBalance": "22.12659734",
+                    "asset": "BTC"
+                }]
+            }
+        }"#;
+        let res: SubaccountFuturesSummary = serde_json::from_str(json).unwrap();
+        assert!(res.future_account_summary_resp.is_none());
+        let summary = res.delivery_account_summary_resp.unwrap();
+        assert_eq!(summary.sub_account_list.len(), 1);
+    }
+
+    #[test]
+    fn test_deserialize_transfer_list() {
+        let json = r#"{
+            "result": [{
+                "tranId": 11945860693,
+                "fromEmail": "master@test.com",
+                "toEmail": "subaccount1@test.com",
+                "asset": "BTC",
+                "amount": "0.1",
+                "createTimeStamp": 1544433325000,
+                "fromAccountType": "SPOT",
+                "toAccountType": "SPOT",
+                "status": "SUCCESS",
+                "clientTranId": "test"
+            }],
+            "totalCount": 1
+        }"#;
+        let res: SubaccountTransferList = serde_json::from_str(json).unwrap();
+        assert!(res.result[0].status.is_finished());
+    }
+}
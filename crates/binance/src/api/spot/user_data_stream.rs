@@ -1,21 +1,34 @@
+use std::time::Duration;
+
 use super::RL_WEIGHT_PER_MINUTE;
 use super::prelude::*;
 use crate::client::Task;
 
 pub const V1_USER_DATA_STREAM: &str = "/api/v1/userDataStream";
 
+/// How often the listen key of a connected user data stream is kept alive.
+///
+/// The stream closes after 60 minutes without a keepalive; Binance recommends sending one
+/// every 30 minutes.
+pub const USER_DATA_STREAM_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
 #[serde(rename_all = "camelCase")]
 pub struct ListenKey {
     pub listen_key: String,
 }
 
+/// Empty response of the listen key keepalive and close requests.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ListenKeyAck {}
+
 #[cfg(feature = "with_network")]
 pub use with_network::*;
 
 #[cfg(feature = "with_network")]
 mod with_network {
     use super::*;
+    use crate::client::UserDataStream;
 
     impl<S> SpotApi<S>
     where
@@ -35,5 +48,78 @@ mod with_network {
                 .cost(RL_WEIGHT_PER_MINUTE, 1)
                 .send())
         }
+
+        /// Ping/Keep-alive a listenKey.
+        ///
+        /// Keepalive a user data stream to prevent a time out. User data streams will close
+        /// after 60 minutes. It's recommended to send a ping about every 30 minutes.
+        ///
+        /// Weight: 1
+        pub fn user_data_stream_keep_alive(
+            &self,
+            listen_key: &str,
+        ) -> BinanceResult<Task<ListenKeyAck>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .put(V1_USER_DATA_STREAM)?
+                        .auth_header()?
+                        .query_arg("listenKey", listen_key)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Close a listenKey.
+        ///
+        /// Close out a user data stream.
+        ///
+        /// Weight: 1
+        pub fn user_data_stream_close(
+            &self,
+            listen_key: &str,
+        ) -> BinanceResult<Task<ListenKeyAck>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .delete(V1_USER_DATA_STREAM)?
+                        .auth_header()?
+                        .query_arg("listenKey", listen_key)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Creates a listen key and connects to its user data stream.
+        ///
+        /// The listen key is kept alive every [`USER_DATA_STREAM_KEEP_ALIVE_INTERVAL`]
+        /// until the returned stream is dropped.
+        pub async fn user_data_ws(&self) -> BinanceResult<UserDataStream> {
+            let ListenKey { listen_key } = self.user_data_stream()?.await?;
+            let url = self.client.user_data_stream_url(&listen_key)?;
+            let api = SpotApi {
+                client: self.client.clone(),
+                rate_limiter: self.rate_limiter.clone(),
+            };
+            let keep_alive = crate::client::spawn(async move {
+                let mut interval = tokio::time::interval_at(
+                    tokio::time::Instant::now() + USER_DATA_STREAM_KEEP_ALIVE_INTERVAL,
+                    USER_DATA_STREAM_KEEP_ALIVE_INTERVAL,
+                );
+                loop {
+                    interval.tick().await;
+                    let res = match api.user_data_stream_keep_alive(&listen_key) {
+                        Ok(task) => task.await.map(|_| ()),
+                        Err(e) => Err(e),
+                    };
+                    if let Err(e) = res {
+                        log::warn!("Failed to keep the user data stream alive: {:?}", e);
+                    }
+                }
+            });
+            UserDataStream::connect(self.client.clone(), url, Some(keep_alive)).await
+        }
     }
 }
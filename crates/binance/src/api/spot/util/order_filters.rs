@@ -40,3 +40,368 @@ impl OrderFilters {
         this
     }
 }
+
+/// An order to be checked against the symbol filters before it is sent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProposedOrder {
+    pub side: OrderSide,
+    pub r#type: OrderType,
+    /// Limit price; `None` for `MARKET`, `STOP_LOSS` and `TAKE_PROFIT` orders.
+    pub price: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
+    pub quantity: Decimal,
+    pub iceberg_qty: Option<Decimal>,
+    /// Average or last price of the symbol.
+    ///
+    /// The filters relative to the market price (PERCENT_PRICE, PERCENT_PRICE_BY_SIDE) and
+    /// the notional of orders without a limit price are checked only when it is provided.
+    pub reference_price: Option<Decimal>,
+}
+
+/// A symbol filter the order doesn't pass.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum FilterViolation {
+    /// `price` or `stop_price` is out of `[min_price, max_price]`.
+    #[error("PRICE_FILTER: price {price} is out of [{min}, {max}]")]
+    Price {
+        price: Decimal,
+        min: Decimal,
+        max: Decimal,
+    },
+    #[error("PERCENT_PRICE: price {price} is out of [{min}, {max}]")]
+    PercentPrice {
+        price: Decimal,
+        min: Decimal,
+        max: Decimal,
+    },
+    #[error("PERCENT_PRICE_BY_SIDE: price {price} is out of [{min}, {max}]")]
+    PercentPriceBySide {
+        price: Decimal,
+        min: Decimal,
+        max: Decimal,
+    },
+    /// `quantity` or `iceberg_qty` is out of `[min_qty, max_qty]`.
+    #[error("LOT_SIZE: quantity {qty} is out of [{min}, {max}]")]
+    LotSize {
+        qty: Decimal,
+        min: Decimal,
+        max: Decimal,
+    },
+    #[error("MARKET_LOT_SIZE: quantity {qty} is out of [{min}, {max}]")]
+    MarketLotSize {
+        qty: Decimal,
+        min: Decimal,
+        max: Decimal,
+    },
+    #[error("MIN_NOTIONAL: notional {notional} is below {min}")]
+    MinNotional { notional: Decimal, min: Decimal },
+    #[error("NOTIONAL: notional {notional} is out of [{min}, {max}]")]
+    Notional {
+        notional: Decimal,
+        min: Decimal,
+        max: Decimal,
+    },
+    #[error("ICEBERG_PARTS: {parts} parts exceed the limit of {limit}")]
+    IcebergParts { parts: u64, limit: u64 },
+}
+
+impl OrderFilters {
+    /// Rounds the price to the tick size; buy prices are rounded down and sell prices up,
+    /// so that the order is never worse than requested.
+    pub fn round_price(&self, side: OrderSide, price: Decimal) -> Decimal {
+        let Some(filter) = self.price else {
+            return price;
+        };
+        let rounding = match side {
+            OrderSide::Buy => Rounding::Down,
+            OrderSide::Sell => Rounding::Up,
+        };
+        round_to_step(price, filter.min_price, filter.tick_size, rounding)
+    }
+
+    /// Rounds the quantity down to the step size.
+    pub fn round_qty(&self, r#type: OrderType, qty: Decimal) -> Decimal {
+        let market_step = self
+            .market_lot_size
+            .filter(|f| r#type == OrderType::Market && !f.step_size.is_zero());
+        match (market_step, self.lot_size) {
+            (Some(f), _) => round_to_step(qty, f.min_qty, f.step_size, Rounding::Down),
+            (None, Some(f)) => round_to_step(qty, f.min_qty, f.step_size, Rounding::Down),
+            (None, None) => qty,
+        }
+    }
+
+    /// Rounds the order prices and quantities to the tick and step sizes and checks it
+    /// against the filters.
+    ///
+    /// Returns the rounded order, or every filter it doesn't pass. The filters that depend
+    /// on the account state (MAX_NUM_ORDERS, MAX_POSITION, etc.) are not checked.
+    pub fn check_order(&self, order: ProposedOrder) -> Result<ProposedOrder, Vec<FilterViolation>> {
+        let order = ProposedOrder {
+            price: order.price.map(|p| self.round_price(order.side, p)),
+            stop_price: order.stop_price.map(|p| self.round_price(order.side, p)),
+            quantity: self.round_qty(order.r#type, order.quantity),
+            iceberg_qty: order.iceberg_qty.map(|q| self.round_qty(order.r#type, q)),
+            ..order
+        };
+        let mut violations = Vec::new();
+        self.check_prices(&order, &mut violations);
+        self.check_quantities(&order, &mut violations);
+        self.check_notional(&order, &mut violations);
+        if violations.is_empty() {
+            Ok(order)
+        } else {
+            Err(violations)
+        }
+    }
+
+    fn check_prices(&self, order: &ProposedOrder, violations: &mut Vec<FilterViolation>) {
+        if let Some(f) = self.price {
+            for price in order.price.into_iter().chain(order.stop_price) {
+                let too_low = !f.min_price.is_zero() && price < f.min_price;
+                let too_high = !f.max_price.is_zero() && price > f.max_price;
+                if too_low || too_high {
+                    violations.push(FilterViolation::Price {
+                        price,
+                        min: f.min_price,
+                        max: f.max_price,
+                    });
+                }
+            }
+        }
+
+        let (Some(price), Some(reference)) = (order.price, order.reference_price) else {
+            return;
+        };
+        if let Some(f) = self.percent_price {
+            let min = reference * f.multiplier_down;
+            let max = reference * f.multiplier_up;
+            if price < min || price > max {
+                violations.push(FilterViolation::PercentPrice { price, min, max });
+            }
+        }
+        if let Some(f) = self.percent_price_by_side {
+            let (down, up) = match order.side {
+                OrderSide::Buy => (f.bid_multiplier_down, f.bid_multiplier_up),
+                OrderSide::Sell => (f.ask_multiplier_down, f.ask_multiplier_up),
+            };
+            let (min, max) = (reference * down, reference * up);
+            if price < min || price > max {
+                violations.push(FilterViolation::PercentPriceBySide { price, min, max });
+            }
+        }
+    }
+
+    fn check_quantities(&self, order: &ProposedOrder, violations: &mut Vec<FilterViolation>) {
+        for qty in Some(order.quantity).into_iter().chain(order.iceberg_qty) {
+            if let Some(f) = self.lot_size {
+                if qty < f.min_qty || qty > f.max_qty {
+                    violations.push(FilterViolation::LotSize {
+                        qty,
+                        min: f.min_qty,
+                        max: f.max_qty,
+                    });
+                }
+            }
+        }
+
+        if order.r#type == OrderType::Market {
+            if let Some(f) = self.market_lot_size {
+                let qty = order.quantity;
+                let too_low = qty < f.min_qty;
+                let too_high = !f.max_qty.is_zero() && qty > f.max_qty;
+                if too_low || too_high {
+                    violations.push(FilterViolation::MarketLotSize {
+                        qty,
+                        min: f.min_qty,
+                        max: f.max_qty,
+                    });
+                }
+            }
+        }
+
+        if let (Some(iceberg_qty), Some(f)) = (order.iceberg_qty, self.icebert_parts) {
+            if !iceberg_qty.is_zero() {
+                let parts = (order.quantity / iceberg_qty).ceil();
+                let parts = u64::try_from(parts).unwrap_or(u64::MAX);
+                if parts > f.limit {
+                    violations.push(FilterViolation::IcebergParts {
+                        parts,
+                        limit: f.limit,
+                    });
+                }
+            }
+        }
+    }
+
+    fn check_notional(&self, order: &ProposedOrder, violations: &mut Vec<FilterViolation>) {
+        let is_market = order.price.is_none();
+        let Some(price) = order.price.or(order.reference_price) else {
+            return;
+        };
+        let notional = price * order.quantity;
+
+        if let Some(f) = self.min_notional {
+            if (!is_market || f.apply_to_market) && notional < f.min_notional {
+                violations.push(FilterViolation::MinNotional {
+                    notional,
+                    min: f.min_notional,
+                });
+            }
+        }
+        if let Some(f) = self.notional {
+            let too_low = (!is_market || f.apply_min_to_market) && notional < f.min_notional;
+            let too_high = (!is_market || f.apply_max_to_market) && notional > f.max_notional;
+            if too_low || too_high {
+                violations.push(FilterViolation::Notional {
+                    notional,
+                    min: f.min_notional,
+                    max: f.max_notional,
+                });
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Rounding {
+    Down,
+    Up,
+}
+
+/// Rounds `value` to `min + n * step`; a zero step disables the rounding.
+fn round_to_step(value: Decimal, min: Decimal, step: Decimal, rounding: Rounding) -> Decimal {
+    if step.is_zero() || value < min {
+        return value;
+    }
+    let steps = (value - min) / step;
+    let steps = match rounding {
+        Rounding::Down => steps.floor(),
+        Rounding::Up => steps.ceil(),
+    };
+    (min + steps * step).normalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn filters() -> OrderFilters {
+        let filters: Vec<Filter> = serde_json::from_str(
+            r#"[
+                {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000.00", "tickSize": "0.01"},
+                {"filterType": "LOT_SIZE", "minQty": "0.00001", "maxQty": "9000.00000", "stepSize": "0.00001"},
+                {"filterType": "ICEBERG_PARTS", "limit": 10},
+                {"filterType": "MARKET_LOT_SIZE", "minQty": "0.00000", "maxQty": "110.0", "stepSize": "0.00000"},
+                {"filterType": "PERCENT_PRICE_BY_SIDE", "bidMultiplierUp": "5", "bidMultiplierDown": "0.2", "askMultiplierUp": "5", "askMultiplierDown": "0.2", "avgPriceMins": 5},
+                {"filterType": "NOTIONAL", "minNotional": "5.00", "applyMinToMarket": true, "maxNotional": "9000000.00", "applyMaxToMarket": false, "avgPriceMins": 5}
+            ]"#,
+        )
+        .unwrap();
+        OrderFilters::from_filters(&filters)
+    }
+
+    fn limit(side: OrderSide, price: Decimal, quantity: Decimal) -> ProposedOrder {
+        ProposedOrder {
+            side,
+            r#type: OrderType::Limit,
+            price: Some(price),
+            stop_price: None,
+            quantity,
+            iceberg_qty: None,
+            reference_price: Some(dec!(60000)),
+        }
+    }
+
+    #[test]
+    fn test_rounds_to_tick_and_step() {
+        let filters = filters();
+        let order = filters
+            .check_order(limit(OrderSide::Buy, dec!(60000.019), dec!(0.0012345)))
+            .unwrap();
+        assert_eq!(order.price, Some(dec!(60000.01)));
+        assert_eq!(order.quantity, dec!(0.00123));
+
+        let order = filters
+            .check_order(limit(OrderSide::Sell, dec!(60000.011), dec!(0.001)))
+            .unwrap();
+        assert_eq!(order.price, Some(dec!(60000.02)));
+    }
+
+    #[test]
+    fn test_violations() {
+        let filters = filters();
+        let res = filters.check_order(limit(OrderSide::Buy, dec!(400000), dec!(0.0001)));
+        assert_eq!(
+            res.unwrap_err(),
+            vec![FilterViolation::PercentPriceBySide {
+                price: dec!(400000),
+                min: dec!(12000.0),
+                max: dec!(300000),
+            }]
+        );
+
+        let res = filters.check_order(limit(OrderSide::Buy, dec!(60000), dec!(0.00005)));
+        assert!(matches!(
+            res.unwrap_err()[..],
+            [FilterViolation::Notional { notional, .. }] if notional == dec!(3)
+        ));
+
+        let order = ProposedOrder {
+            iceberg_qty: Some(dec!(0.0001)),
+            ..limit(OrderSide::Sell, dec!(60000), dec!(0.002))
+        };
+        assert_eq!(
+            filters.check_order(order).unwrap_err(),
+            vec![FilterViolation::IcebergParts {
+                parts: 20,
+                limit: 10
+            }]
+        );
+    }
+
+    #[test]
+    fn test_market_order() {
+        let filters = filters();
+        let order = ProposedOrder {
+            r#type: OrderType::Market,
+            price: None,
+            quantity: dec!(120),
+            ..limit(OrderSide::Buy, dec!(0), dec!(0))
+        };
+        assert!(matches!(
+            filters.check_order(order).unwrap_err()[..],
+            [FilterViolation::MarketLotSize { .. }]
+        ));
+    }
+
+    #[test]
+    fn test_market_order_notional() {
+        let filters: Vec<Filter> = serde_json::from_str(
+            r#"[
+                {"filterType": "NOTIONAL", "minNotional": "5.00", "applyMinToMarket": true, "maxNotional": "1000.00", "applyMaxToMarket": false, "avgPriceMins": 5}
+            ]"#,
+        )
+        .unwrap();
+        let filters = OrderFilters::from_filters(&filters);
+        let market = |quantity| ProposedOrder {
+            r#type: OrderType::Market,
+            price: None,
+            ..limit(OrderSide::Buy, dec!(0), quantity)
+        };
+
+        assert!(filters.check_order(market(dec!(0.1))).is_ok());
+        assert!(matches!(
+            filters.check_order(market(dec!(0.00005))).unwrap_err()[..],
+            [FilterViolation::Notional { notional, .. }] if notional == dec!(3)
+        ));
+        assert!(matches!(
+            filters
+                .check_order(limit(OrderSide::Buy, dec!(60000), dec!(0.1)))
+                .unwrap_err()[..],
+            [FilterViolation::Notional { notional, .. }] if notional == dec!(6000)
+        ));
+    }
+}
@@ -0,0 +1,290 @@
+use super::AccountInformation;
+use super::CancelledOrder;
+use super::NewOrderAck;
+use super::NewOrderFull;
+use super::NewOrderResult;
+use super::NewTestOrder;
+use super::Order;
+use super::OrderResponseType;
+use super::OrderSide;
+use super::OrderType;
+use super::Pong;
+use super::ServerTime;
+use super::TimeInForce;
+use super::check_order_fields;
+use super::prelude::*;
+
+/// Response of the `order.place` WebSocket API request.
+///
+/// The variant depends on the requested [`OrderResponseType`].
+#[derive(Debug, Clone)]
+pub enum WsNewOrder {
+    Ack(NewOrderAck),
+    Result(NewOrderResult),
+    Full(Box<NewOrderFull>),
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+    use crate::client::WsApiClient;
+    use crate::client::WsApiParams;
+
+    impl<S> SpotApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Connects to the WebSocket API.
+        ///
+        /// Requests sent over the WebSocket API are counted against the same rate limits
+        /// as the REST ones but are not tracked by the local rate limiter.
+        pub async fn ws_api(&self) -> BinanceResult<WsApiClient<S>> {
+            self.client.web_socket_api().await
+        }
+    }
+
+    impl<S> WsApiClient<S>
+    where
+        S: crate::client::BinanceSigner,
+    {
+        /// Test connectivity
+        ///
+        /// Weight: 1
+        pub async fn ping(&self) -> BinanceResult<Pong> {
+            self.request("ping", WsApiParams::new()).await
+        }
+
+        /// Check server time
+        ///
+        /// Weight: 1
+        pub async fn time(&self) -> BinanceResult<ServerTime> {
+            self.request("time", WsApiParams::new()).await
+        }
+
+        /// Account information (USER_DATA)
+        ///
+        /// Query information about your account.
+        ///
+        /// Weight: 20
+        pub async fn account_status(
+            &self,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<AccountInformation> {
+            self.signed_request("account.status", WsApiParams::new(), time_window)
+                .await
+        }
+
+        /// Place new order (TRADE)
+        ///
+        /// Send in a new order.
+        ///
+        /// Weight: 1
+        ///
+        /// Same parameters as [`SpotApi::create_order`]. When `new_order_resp_type` is
+        ///   omitted `MARKET` and `LIMIT` orders default to `FULL`, all others to `ACK`.
+        #[allow(clippy::too_many_arguments)]
+        pub async fn order_place(
+            &self,
+            symbol: impl Serialize,
+            side: OrderSide,
+            r#type: OrderType,
+            time_in_force: Option<TimeInForce>,
+            quantity: Option<Decimal>,
+            quote_order_qty: Option<Decimal>,
+            iceberg_qty: Option<Decimal>,
+            price: Option<Decimal>,
+            stop_price: Option<Decimal>,
+            new_client_order_id: Option<impl Serialize>,
+            new_order_resp_type: Option<OrderResponseType>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<WsNewOrder> {
+            let new_order_resp_type = new_order_resp_type.unwrap_or(match r#type {
+                OrderType::Limit | OrderType::Market => OrderResponseType::Full,
+                _ => OrderResponseType::Ack,
+            });
+            let params = order_params(
+                symbol,
+                side,
+                r#type,
+                time_in_force,
+                quantity,
+                quote_order_qty,
+                iceberg_qty,
+                price,
+                stop_price,
+                new_client_order_id,
+                Some(new_order_resp_type),
+            )?;
+            Ok(match new_order_resp_type {
+                OrderResponseType::Ack => WsNewOrder::Ack(
+                    self.signed_request("order.place", params, time_window)
+                        .await?,
+                ),
+                OrderResponseType::Result => WsNewOrder::Result(
+                    self.signed_request("order.place", params, time_window)
+                        .await?,
+                ),
+                OrderResponseType::Full => WsNewOrder::Full(
+                    self.signed_request("order.place", params, time_window)
+                        .await?,
+                ),
+            })
+        }
+
+        /// Test new order (TRADE)
+        ///
+        /// Test order placement. Validates the new order but does not send it into the
+        /// matching engine.
+        ///
+        /// Weight: 1
+        ///
+        /// Same parameters as [`WsApiClient::order_place`].
+        #[allow(clippy::too_many_arguments)]
+        pub async fn order_test(
+            &self,
+            symbol: impl Serialize,
+            side: OrderSide,
+            r#type: OrderType,
+            time_in_force: Option<TimeInForce>,
+            quantity: Option<Decimal>,
+            quote_order_qty: Option<Decimal>,
+            iceberg_qty: Option<Decimal>,
+            price: Option<Decimal>,
+            stop_price: Option<Decimal>,
+            new_client_order_id: Option<impl Serialize>,
+            new_order_resp_type: Option<OrderResponseType>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<NewTestOrder> {
+            let params = order_params(
+                symbol,
+                side,
+                r#type,
+                time_in_force,
+                quantity,
+                quote_order_qty,
+                iceberg_qty,
+                price,
+                stop_price,
+                new_client_order_id,
+                new_order_resp_type,
+            )?;
+            self.signed_request("order.test", params, time_window).await
+        }
+
+        /// Query order (USER_DATA)
+        ///
+        /// Check execution status of an order.
+        ///
+        /// Weight: 4
+        ///
+        /// Either orderId or origClientOrderId must be sent.
+        pub async fn order_status(
+            &self,
+            symbol: impl Serialize,
+            order_id: Option<u64>,
+            orig_client_order_id: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Order> {
+            if order_id.is_none() && orig_client_order_id.is_none() {
+                Err(ApiError::mandatory_field_omitted(
+                    "order_id or orig_client_order_id",
+                ))?
+            }
+            let params = WsApiParams::new()
+                .arg("symbol", &symbol)?
+                .try_arg("orderId", &order_id)?
+                .try_arg("origClientOrderId", &orig_client_order_id)?;
+            self.signed_request("order.status", params, time_window)
+                .await
+        }
+
+        /// Cancel order (TRADE)
+        ///
+        /// Cancel an active order.
+        ///
+        /// Weight: 1
+        ///
+        /// * newClientOrderId Used to uniquely identify this cancel. Automatically generated by default.
+        ///
+        /// Either orderId or origClientOrderId must be sent.
+        pub async fn order_cancel(
+            &self,
+            symbol: impl Serialize,
+            order_id: Option<u64>,
+            orig_client_order_id: Option<impl Serialize>,
+            new_client_order_id: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<CancelledOrder> {
+            if order_id.is_none() && orig_client_order_id.is_none() {
+                Err(ApiError::mandatory_field_omitted(
+                    "order_id or orig_client_order_id",
+                ))?
+            }
+            let params = WsApiParams::new()
+                .arg("symbol", &symbol)?
+                .try_arg("orderId", &order_id)?
+                .try_arg("origClientOrderId", &orig_client_order_id)?
+                .try_arg("newClientOrderId", &new_client_order_id)?;
+            self.signed_request("order.cancel", params, time_window)
+                .await
+        }
+
+        /// Current open orders (USER_DATA)
+        ///
+        /// Query execution status of all open orders.
+        ///
+        /// Weight: 6 for a single symbol; 80 when the symbol parameter is omitted
+        ///
+        /// If you need to continuously monitor order status updates, please consider using
+        ///   the user data stream.
+        pub async fn open_orders_status(
+            &self,
+            symbol: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Vec<Order>> {
+            let params = WsApiParams::new().try_arg("symbol", &symbol)?;
+            self.signed_request("openOrders.status", params, time_window)
+                .await
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn order_params(
+        symbol: impl Serialize,
+        side: OrderSide,
+        r#type: OrderType,
+        time_in_force: Option<TimeInForce>,
+        quantity: Option<Decimal>,
+        quote_order_qty: Option<Decimal>,
+        iceberg_qty: Option<Decimal>,
+        price: Option<Decimal>,
+        stop_price: Option<Decimal>,
+        new_client_order_id: Option<impl Serialize>,
+        new_order_resp_type: Option<OrderResponseType>,
+    ) -> BinanceResult<WsApiParams> {
+        check_order_fields(
+            r#type,
+            time_in_force,
+            quantity,
+            quote_order_qty,
+            price,
+            stop_price.is_some(),
+        )?;
+        WsApiParams::new()
+            .arg("symbol", &symbol)?
+            .arg("side", &side)?
+            .arg("type", &r#type)?
+            .try_arg("timeInForce", &time_in_force)?
+            .try_arg("quantity", &quantity)?
+            .try_arg("quoteOrderQty", &quote_order_qty)?
+            .try_arg("icebergQty", &iceberg_qty)?
+            .try_arg("price", &price)?
+            .try_arg("stopPrice", &stop_price)?
+            .try_arg("newClientOrderId", &new_client_order_id)?
+            .try_arg("newOrderRespType", &new_order_resp_type)
+    }
+}
@@ -0,0 +1,170 @@
+use super::NewOrder;
+use super::Order;
+use crate::api::spot::RlPriorityLevel;
+use crate::api::um::RL_ORDERS_PER_10_SECONDS;
+use crate::api::um::RL_ORDERS_PER_MINUTE;
+use crate::api::um::RL_WEIGHT_PER_MINUTE;
+use crate::api::um::prelude::*;
+use crate::client::Task;
+
+pub const FAPI_V1_BATCH_ORDERS: &str = "/fapi/v1/batchOrders";
+
+/// Max number of orders in a single batch request.
+pub const BATCH_ORDERS_MAX: usize = 5;
+
+/// Every order of a batch request.
+pub type BatchOrderResponse<T> = SuccessOrFailure<T>;
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> UmApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Place Multiple Orders (TRADE)
+        ///
+        /// Weight: 5 on 10s order rate limit(X-MBX-ORDER-COUNT-10S);
+        ///   1 on 1min order rate limit(X-MBX-ORDER-COUNT-1M); 5 on IP rate limit(x-mbx-used-weight-1m)
+        ///
+        /// * orders - max 5 orders.
+        ///
+        /// The batch orders are processed concurrently, and the order of matching is not
+        ///   guaranteed. The order of returned contents is the same as the order of the
+        ///   order list.
+        pub fn create_batch_orders(
+            &self,
+            orders: Vec<NewOrder>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<BatchOrderResponse<Order>>>> {
+            if orders.is_empty() {
+                Err(ApiError::mandatory_field_omitted("orders"))?
+            }
+            if orders.len() > BATCH_ORDERS_MAX {
+                Err(ApiError::OutOfBounds)?
+            }
+            for order in &orders {
+                order.check()?;
+            }
+            let batch_orders = serde_json::to_string(&orders)?;
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(FAPI_V1_BATCH_ORDERS)?
+                        .signed(time_window)?
+                        .query_arg("batchOrders", &batch_orders)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 5)
+                .cost(RL_ORDERS_PER_10_SECONDS, 5)
+                .cost(RL_ORDERS_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Cancel Multiple Orders (TRADE)
+        ///
+        /// Weight: 1
+        ///
+        /// * order_id_list - max length 10.
+        /// * orig_client_order_id_list - max length 10.
+        ///
+        /// Either orderIdList or origClientOrderIdList must be sent.
+        pub fn cancel_batch_orders(
+            &self,
+            symbol: impl Serialize,
+            order_id_list: Option<&[u64]>,
+            orig_client_order_id_list: Option<&[String]>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<BatchOrderResponse<Order>>>> {
+            if order_id_list.is_none() && orig_client_order_id_list.is_none() {
+                Err(ApiError::mandatory_field_omitted(
+                    "order_id_list or orig_client_order_id_list",
+                ))?
+            }
+            let order_id_list = order_id_list.map(serde_json::to_string).transpose()?;
+            let orig_client_order_id_list = orig_client_order_id_list
+                .map(serde_json::to_string)
+                .transpose()?;
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .delete(FAPI_V1_BATCH_ORDERS)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .try_query_arg("orderIdList", &order_id_list)?
+                        .try_query_arg("origClientOrderIdList", &orig_client_order_id_list)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::api::um::OrderSide;
+    use crate::api::um::TimeInForce;
+
+    #[test]
+    fn test_serialize_batch_orders() {
+        let orders = vec![
+            NewOrder::limit(
+                "BTCUSDT",
+                OrderSide::Buy,
+                dec!(0.01),
+                dec!(25000.5),
+                TimeInForce::GoodTilCanceled,
+            )
+            .reduce_only(true),
+        ];
+        let json = serde_json::to_string(&orders).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"symbol":"BTCUSDT","side":"BUY","type":"LIMIT","timeInForce":"GTC","quantity":"0.01","reduceOnly":"true","price":"25000.5"}]"#
+        );
+    }
+
+    #[test]
+    fn test_deserialize_batch_response() {
+        let json = r#"[
+            {"code": -2022, "msg": "ReduceOnly Order is rejected."},
+            {
+                "clientOrderId": "testOrder",
+                "cumQty": "0",
+                "cumQuote": "0",
+                "executedQty": "0",
+                "orderId": 22542179,
+                "avgPrice": "0.00000",
+                "origQty": "10",
+                "price": "0",
+                "reduceOnly": false,
+                "side": "BUY",
+                "positionSide": "SHORT",
+                "status": "NEW",
+                "stopPrice": "9300",
+                "closePosition": false,
+                "symbol": "BTCUSDT",
+                "timeInForce": "GTC",
+                "type": "STOP_MARKET",
+                "origType": "STOP_MARKET",
+                "updateTime": 1566818724722,
+                "workingType": "CONTRACT_PRICE",
+                "priceProtect": false
+            }
+        ]"#;
+        let res: Vec<BatchOrderResponse<Order>> = serde_json::from_str(json).unwrap();
+        assert!(!res[0].is_success());
+        assert_eq!(res[1].clone().success().unwrap().order_id, 22542179);
+    }
+}
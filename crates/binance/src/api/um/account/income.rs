@@ -0,0 +1,154 @@
+use crate::api::um::RL_WEIGHT_PER_MINUTE;
+use crate::api::um::prelude::*;
+use crate::client::Task;
+
+pub const FAPI_V1_INCOME: &str = "/fapi/v1/income";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum IncomeType {
+    #[serde(rename = "TRANSFER")]
+    Transfer,
+    #[serde(rename = "WELCOME_BONUS")]
+    WelcomeBonus,
+    #[serde(rename = "REALIZED_PNL")]
+    RealizedPnl,
+    #[serde(rename = "FUNDING_FEE")]
+    FundingFee,
+    #[serde(rename = "COMMISSION")]
+    Commission,
+    #[serde(rename = "INSURANCE_CLEAR")]
+    InsuranceClear,
+    #[serde(rename = "REFERRAL_KICKBACK")]
+    ReferralKickback,
+    #[serde(rename = "COMMISSION_REBATE")]
+    CommissionRebate,
+    #[serde(rename = "API_REBATE")]
+    ApiRebate,
+    #[serde(rename = "CONTEST_REWARD")]
+    ContestReward,
+    #[serde(rename = "CROSS_COLLATERAL_TRANSFER")]
+    CrossCollateralTransfer,
+    #[serde(rename = "OPTIONS_PREMIUM_FEE")]
+    OptionsPremiumFee,
+    #[serde(rename = "OPTIONS_SETTLE_PROFIT")]
+    OptionsSettleProfit,
+    #[serde(rename = "INTERNAL_TRANSFER")]
+    InternalTransfer,
+    #[serde(rename = "AUTO_EXCHANGE")]
+    AutoExchange,
+    #[serde(rename = "DELIVERED_SETTELMENT")]
+    DeliveredSettelment,
+    #[serde(rename = "COIN_SWAP_DEPOSIT")]
+    CoinSwapDeposit,
+    #[serde(rename = "COIN_SWAP_WITHDRAW")]
+    CoinSwapWithdraw,
+    #[serde(rename = "POSITION_LIMIT_INCREASE_FEE")]
+    PositionLimitIncreaseFee,
+    /// Income types introduced after this client was released.
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Income {
+    /// Empty for the income types not bound to a symbol.
+    pub symbol: Atom,
+    pub income_type: IncomeType,
+    pub income: Decimal,
+    pub asset: Atom,
+    pub info: String,
+    pub time: u64,
+    pub tran_id: u64,
+    pub trade_id: String,
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> UmApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Get Income History (USER_DATA)
+        ///
+        /// Query income history.
+        ///
+        /// Weight: 30
+        ///
+        /// * income_type - all types are returned if omitted.
+        /// * limit - default 100; max 1000.
+        ///
+        /// If neither startTime nor endTime is sent, the recent 7-day data will be returned.
+        /// If incomeType is not sent, all kinds of flow will be returned.
+        /// The interval between startTime and endTime can not be longer than 1 year.
+        pub fn income_history(
+            &self,
+            symbol: Option<impl Serialize>,
+            income_type: Option<IncomeType>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            page: Option<u32>,
+            limit: Option<u16>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<Income>>> {
+            if income_type == Some(IncomeType::Other) {
+                Err(ApiError::OutOfBounds)?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(FAPI_V1_INCOME)?
+                        .signed(time_window)?
+                        .try_query_arg("symbol", &symbol)?
+                        .try_query_arg("incomeType", &income_type)?
+                        .try_query_arg("startTime", &start_time)?
+                        .try_query_arg("endTime", &end_time)?
+                        .try_query_arg("page", &page)?
+                        .try_query_arg("limit", &limit)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 30)
+                .send())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_income() {
+        let json = r#"[
+            {
+                "symbol": "",
+                "incomeType": "TRANSFER",
+                "income": "-0.37500000",
+                "asset": "USDT",
+                "info": "TRANSFER",
+                "time": 1570608000000,
+                "tranId": 9689322392,
+                "tradeId": ""
+            },
+            {
+                "symbol": "BTCUSDT",
+                "incomeType": "STRATEGY_UMFUTURES_TRANSFER",
+                "income": "1.00000000",
+                "asset": "USDT",
+                "info": "",
+                "time": 1570636800000,
+                "tranId": 9689322393,
+                "tradeId": ""
+            }
+        ]"#;
+        let res: Vec<Income> = serde_json::from_str(json).unwrap();
+        assert_eq!(res[0].income_type, IncomeType::Transfer);
+        assert_eq!(res[1].income_type, IncomeType::Other);
+    }
+}
@@ -0,0 +1,122 @@
+use super::PositionSide;
+use crate::api::um::RL_WEIGHT_PER_MINUTE;
+use crate::api::um::prelude::*;
+use crate::client::Task;
+
+pub const FAPI_V3_ACCOUNT: &str = "/fapi/v3/account";
+pub const FAPI_V3_BALANCE: &str = "/fapi/v3/balance";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    pub total_initial_margin: Decimal,
+    pub total_maint_margin: Decimal,
+    pub total_wallet_balance: Decimal,
+    pub total_unrealized_profit: Decimal,
+    pub total_margin_balance: Decimal,
+    pub total_position_initial_margin: Decimal,
+    pub total_open_order_initial_margin: Decimal,
+    pub total_cross_wallet_balance: Decimal,
+    pub total_cross_un_pnl: Decimal,
+    pub available_balance: Decimal,
+    pub max_withdraw_amount: Decimal,
+    pub assets: Vec<AccountAsset>,
+    /// Positions of all symbols user had position / open orders are returned.
+    pub positions: Vec<AccountPosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    pub asset: Atom,
+    pub wallet_balance: Decimal,
+    pub unrealized_profit: Decimal,
+    pub margin_balance: Decimal,
+    pub maint_margin: Decimal,
+    pub initial_margin: Decimal,
+    pub position_initial_margin: Decimal,
+    pub open_order_initial_margin: Decimal,
+    pub cross_wallet_balance: Decimal,
+    pub cross_un_pnl: Decimal,
+    pub available_balance: Decimal,
+    pub max_withdraw_amount: Decimal,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPosition {
+    pub symbol: Atom,
+    pub position_side: PositionSide,
+    pub position_amt: Decimal,
+    pub unrealized_profit: Decimal,
+    pub isolated_margin: Decimal,
+    pub notional: Decimal,
+    pub isolated_wallet: Decimal,
+    pub initial_margin: Decimal,
+    pub maint_margin: Decimal,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalance {
+    /// Unique account code.
+    pub account_alias: String,
+    pub asset: Atom,
+    pub balance: Decimal,
+    pub cross_wallet_balance: Decimal,
+    pub cross_un_pnl: Decimal,
+    pub available_balance: Decimal,
+    pub max_withdraw_amount: Decimal,
+    /// Whether the asset can be used as margin in Multi-Assets mode.
+    pub margin_available: bool,
+    pub update_time: u64,
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> UmApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Account Information V3 (USER_DATA)
+        ///
+        /// Get current account information. User in single-asset/ multi-assets mode will see
+        ///   different value.
+        ///
+        /// Weight: 5
+        pub fn account(
+            &self,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<AccountInformation>> {
+            Ok(self
+                .rate_limiter
+                .task(self.client.get(FAPI_V3_ACCOUNT)?.signed(time_window)?)
+                .cost(RL_WEIGHT_PER_MINUTE, 5)
+                .send())
+        }
+
+        /// Futures Account Balance V3 (USER_DATA)
+        ///
+        /// Query account balance info.
+        ///
+        /// Weight: 5
+        pub fn balance(
+            &self,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<AccountBalance>>> {
+            Ok(self
+                .rate_limiter
+                .task(self.client.get(FAPI_V3_BALANCE)?.signed(time_window)?)
+                .cost(RL_WEIGHT_PER_MINUTE, 5)
+                .send())
+        }
+    }
+}
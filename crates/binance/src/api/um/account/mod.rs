@@ -0,0 +1,20 @@
+use crate::api::um::prelude::*;
+
+mod batch_order;
+mod income;
+mod information;
+mod order;
+mod position;
+
+pub use self::batch_order::*;
+pub use self::income::*;
+pub use self::information::*;
+pub use self::order::*;
+pub use self::position::*;
+
+/// Generic `{code, msg}` response of the endpoints that don't return any data.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StatusResponse {
+    pub code: i64,
+    pub msg: String,
+}
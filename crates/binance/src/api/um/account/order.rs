@@ -0,0 +1,677 @@
+use super::StatusResponse;
+use crate::api::spot::RlPriorityLevel;
+use crate::api::um::OrderType;
+use crate::api::um::RL_ORDERS_PER_10_SECONDS;
+use crate::api::um::RL_ORDERS_PER_MINUTE;
+use crate::api::um::RL_WEIGHT_PER_MINUTE;
+use crate::api::um::TimeInForce;
+use crate::api::um::prelude::*;
+use crate::client::Task;
+
+pub const FAPI_V1_ORDER: &str = "/fapi/v1/order";
+pub const FAPI_V1_ORDER_TEST: &str = "/fapi/v1/order/test";
+pub const FAPI_V1_ALL_OPEN_ORDERS: &str = "/fapi/v1/allOpenOrders";
+pub const FAPI_V1_OPEN_ORDERS: &str = "/fapi/v1/openOrders";
+pub const FAPI_V1_ALL_ORDERS: &str = "/fapi/v1/allOrders";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum OrderSide {
+    #[serde(rename = "BUY")]
+    Buy,
+    #[serde(rename = "SELL")]
+    Sell,
+}
+
+/// Default `BOTH` for One-way Mode; `LONG` or `SHORT` for Hedge Mode.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum PositionSide {
+    #[serde(rename = "BOTH")]
+    Both,
+    #[serde(rename = "LONG")]
+    Long,
+    #[serde(rename = "SHORT")]
+    Short,
+}
+
+/// Price used to trigger the stop orders.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum WorkingType {
+    #[serde(rename = "MARK_PRICE")]
+    MarkPrice,
+    #[serde(rename = "CONTRACT_PRICE")]
+    ContractPrice,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum OrderStatus {
+    #[serde(rename = "NEW")]
+    New,
+    #[serde(rename = "PARTIALLY_FILLED")]
+    PartiallyFilled,
+    #[serde(rename = "FILLED")]
+    Filled,
+    #[serde(rename = "CANCELED")]
+    Canceled,
+    #[serde(rename = "REJECTED")]
+    Rejected,
+    #[serde(rename = "EXPIRED")]
+    Expired,
+    /// The order was expired by the exchange due to self-trade prevention.
+    #[serde(rename = "EXPIRED_IN_MATCH")]
+    ExpiredInMatch,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum OrderResponseType {
+    #[serde(rename = "ACK")]
+    Ack,
+    #[serde(rename = "RESULT")]
+    Result,
+}
+
+/// Price match mode. Can't be passed together with `price`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum PriceMatch {
+    #[serde(rename = "NONE")]
+    None,
+    #[serde(rename = "OPPONENT")]
+    Opponent,
+    #[serde(rename = "OPPONENT_5")]
+    Opponent5,
+    #[serde(rename = "OPPONENT_10")]
+    Opponent10,
+    #[serde(rename = "OPPONENT_20")]
+    Opponent20,
+    #[serde(rename = "QUEUE")]
+    Queue,
+    #[serde(rename = "QUEUE_5")]
+    Queue5,
+    #[serde(rename = "QUEUE_10")]
+    Queue10,
+    #[serde(rename = "QUEUE_20")]
+    Queue20,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SelfTradePreventionMode {
+    #[serde(rename = "NONE")]
+    None,
+    #[serde(rename = "EXPIRE_TAKER")]
+    ExpireTaker,
+    #[serde(rename = "EXPIRE_MAKER")]
+    ExpireMaker,
+    #[serde(rename = "EXPIRE_BOTH")]
+    ExpireBoth,
+}
+
+/// New futures order.
+///
+/// Serializes into the `batchOrders` entry format: camelCase keys, decimals and booleans
+/// as strings.
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOrder {
+    pub symbol: String,
+    pub side: OrderSide,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_side: Option<PositionSide>,
+    pub r#type: OrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    /// Cannot be sent with `close_position`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<Decimal>,
+    /// Cannot be sent in Hedge Mode; cannot be sent with `close_position`.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_bool_as_str"
+    )]
+    pub reduce_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_client_order_id: Option<String>,
+    /// Used with `STOP`/`STOP_MARKET` or `TAKE_PROFIT`/`TAKE_PROFIT_MARKET` orders.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<Decimal>,
+    /// Close-All, used with `STOP_MARKET` or `TAKE_PROFIT_MARKET` orders.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_bool_as_str"
+    )]
+    pub close_position: Option<bool>,
+    /// Used with `TRAILING_STOP_MARKET` orders, default as the latest price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activation_price: Option<Decimal>,
+    /// Used with `TRAILING_STOP_MARKET` orders, min 0.1, max 10 where 1 for 1%.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_rate: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_type: Option<WorkingType>,
+    /// Used with `STOP`/`STOP_MARKET` or `TAKE_PROFIT`/`TAKE_PROFIT_MARKET` orders.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_opt_bool_as_str"
+    )]
+    pub price_protect: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<OrderResponseType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_match: Option<PriceMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    /// Order cancel time for timeInForce `GTD`, mandatory when `timeInforce` set to `GTD`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub good_till_date: Option<u64>,
+}
+
+impl NewOrder {
+    pub fn new(symbol: impl Into<String>, side: OrderSide, r#type: OrderType) -> Self {
+        NewOrder {
+            symbol: symbol.into(),
+            side,
+            position_side: None,
+            r#type,
+            time_in_force: None,
+            quantity: None,
+            reduce_only: None,
+            price: None,
+            new_client_order_id: None,
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+            new_order_resp_type: None,
+            price_match: None,
+            self_trade_prevention_mode: None,
+            good_till_date: None,
+        }
+    }
+
+    pub fn limit(
+        symbol: impl Into<String>,
+        side: OrderSide,
+        quantity: Decimal,
+        price: Decimal,
+        time_in_force: TimeInForce,
+    ) -> Self {
+        Self::new(symbol, side, OrderType::Limit)
+            .quantity(quantity)
+            .price(price)
+            .time_in_force(time_in_force)
+    }
+
+    pub fn market(symbol: impl Into<String>, side: OrderSide, quantity: Decimal) -> Self {
+        Self::new(symbol, side, OrderType::Market).quantity(quantity)
+    }
+
+    pub fn position_side(mut self, position_side: PositionSide) -> Self {
+        self.position_side = Some(position_side);
+        self
+    }
+
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn quantity(mut self, quantity: Decimal) -> Self {
+        self.quantity = Some(quantity);
+        self
+    }
+
+    pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = Some(reduce_only);
+        self
+    }
+
+    pub fn price(mut self, price: Decimal) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn new_client_order_id(mut self, new_client_order_id: impl Into<String>) -> Self {
+        self.new_client_order_id = Some(new_client_order_id.into());
+        self
+    }
+
+    pub fn stop_price(mut self, stop_price: Decimal) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    pub fn close_position(mut self, close_position: bool) -> Self {
+        self.close_position = Some(close_position);
+        self
+    }
+
+    pub fn activation_price(mut self, activation_price: Decimal) -> Self {
+        self.activation_price = Some(activation_price);
+        self
+    }
+
+    pub fn callback_rate(mut self, callback_rate: Decimal) -> Self {
+        self.callback_rate = Some(callback_rate);
+        self
+    }
+
+    pub fn working_type(mut self, working_type: WorkingType) -> Self {
+        self.working_type = Some(working_type);
+        self
+    }
+
+    pub fn price_protect(mut self, price_protect: bool) -> Self {
+        self.price_protect = Some(price_protect);
+        self
+    }
+
+    pub fn new_order_resp_type(mut self, new_order_resp_type: OrderResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn price_match(mut self, price_match: PriceMatch) -> Self {
+        self.price_match = Some(price_match);
+        self
+    }
+
+    pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    pub fn good_till_date(mut self, good_till_date: u64) -> Self {
+        self.good_till_date = Some(good_till_date);
+        self
+    }
+
+    /// Checks that the parameters mandatory for the order type are present.
+    pub(crate) fn check(&self) -> BinanceResult<()> {
+        let has_price = self.price.is_some() || self.price_match.is_some();
+        let has_quantity = self.quantity.is_some();
+        match self.r#type {
+            OrderType::Limit => {
+                if self.time_in_force.is_none() || !has_quantity || !has_price {
+                    Err(ApiError::mandatory_field_omitted(
+                        "time_in_force, quantity, price",
+                    ))?
+                }
+            }
+            OrderType::Market => {
+                if !has_quantity {
+                    Err(ApiError::mandatory_field_omitted("quantity"))?
+                }
+            }
+            OrderType::Stop | OrderType::TakeProfit => {
+                if !has_quantity || !has_price || self.stop_price.is_none() {
+                    Err(ApiError::mandatory_field_omitted(
+                        "quantity, price, stop_price",
+                    ))?
+                }
+            }
+            OrderType::StopMarket | OrderType::TakeProfitMarket => {
+                if self.stop_price.is_none() {
+                    Err(ApiError::mandatory_field_omitted("stop_price"))?
+                }
+            }
+            OrderType::TrailingStopMarket => {
+                if self.callback_rate.is_none() {
+                    Err(ApiError::mandatory_field_omitted("callback_rate"))?
+                }
+            }
+        };
+        Ok(())
+    }
+}
+
+fn serialize_opt_bool_as_str<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match value {
+        Some(true) => serializer.serialize_str("true"),
+        Some(false) => serializer.serialize_str("false"),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Futures order as returned by the trade endpoints.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub symbol: Atom,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub price: Decimal,
+    pub avg_price: Decimal,
+    pub orig_qty: Decimal,
+    pub executed_qty: Decimal,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cum_qty: Option<Decimal>,
+    pub cum_quote: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    pub r#type: OrderType,
+    pub orig_type: OrderType,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub stop_price: Decimal,
+    pub close_position: bool,
+    pub reduce_only: bool,
+    /// Present only for `TRAILING_STOP_MARKET` orders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activate_price: Option<Decimal>,
+    /// Present only for `TRAILING_STOP_MARKET` orders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price_rate: Option<Decimal>,
+    pub working_type: WorkingType,
+    pub price_protect: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price_match: Option<PriceMatch>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub good_till_date: Option<u64>,
+    /// Present only in the query endpoints.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<u64>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct NewTestOrder {}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+    use crate::client::RequestBuilder;
+
+    impl<S> UmApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// New Order (TRADE)
+        ///
+        /// Send in a new order.
+        ///
+        /// Weight: 1 on 10s order rate limit(X-MBX-ORDER-COUNT-10S);
+        ///   1 on 1min order rate limit(X-MBX-ORDER-COUNT-1M); 0 on IP rate limit(x-mbx-used-weight-1m)
+        pub fn create_order(
+            &self,
+            order: NewOrder,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Order>> {
+            order.check()?;
+            Ok(self
+                .rate_limiter
+                .task(order_query_args(
+                    self.client.post(FAPI_V1_ORDER)?.signed(time_window)?,
+                    &order,
+                )?)
+                .cost(RL_ORDERS_PER_10_SECONDS, 1)
+                .cost(RL_ORDERS_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Test Order (TRADE)
+        ///
+        /// Testing order request, this order will not be submitted to matching engine.
+        ///
+        /// Weight: 0
+        pub fn create_order_test(
+            &self,
+            order: NewOrder,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<NewTestOrder>> {
+            order.check()?;
+            Ok(self
+                .rate_limiter
+                .task(order_query_args(
+                    self.client.post(FAPI_V1_ORDER_TEST)?.signed(time_window)?,
+                    &order,
+                )?)
+                .send())
+        }
+
+        /// Cancel Order (TRADE)
+        ///
+        /// Cancel an active order.
+        ///
+        /// Weight: 1
+        ///
+        /// Either orderId or origClientOrderId must be sent.
+        pub fn cancel_order(
+            &self,
+            symbol: impl Serialize,
+            order_id: Option<u64>,
+            orig_client_order_id: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Order>> {
+            if order_id.is_none() && orig_client_order_id.is_none() {
+                Err(ApiError::mandatory_field_omitted(
+                    "order_id or orig_client_order_id",
+                ))?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .delete(FAPI_V1_ORDER)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .try_query_arg("orderId", &order_id)?
+                        .try_query_arg("origClientOrderId", &orig_client_order_id)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Cancel All Open Orders (TRADE)
+        ///
+        /// Weight: 1
+        pub fn cancel_all_orders(
+            &self,
+            symbol: impl Serialize,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<StatusResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .delete(FAPI_V1_ALL_OPEN_ORDERS)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Query Order (USER_DATA)
+        ///
+        /// Check an order's status.
+        ///
+        /// Weight: 1
+        ///
+        /// Either orderId or origClientOrderId must be sent.
+        /// These orders will not be found:
+        ///   order status is CANCELED or EXPIRED AND order has NO filled trade
+        ///   AND created time + 3 days < current time;
+        ///   order create time + 90 days < current time.
+        pub fn get_order(
+            &self,
+            symbol: impl Serialize,
+            order_id: Option<u64>,
+            orig_client_order_id: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Order>> {
+            if order_id.is_none() && orig_client_order_id.is_none() {
+                Err(ApiError::mandatory_field_omitted(
+                    "order_id or orig_client_order_id",
+                ))?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(FAPI_V1_ORDER)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .try_query_arg("orderId", &order_id)?
+                        .try_query_arg("origClientOrderId", &orig_client_order_id)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Current All Open Orders (USER_DATA)
+        ///
+        /// Get all open orders on a symbol. Careful when accessing this with no symbol.
+        ///
+        /// Weight: 1 for a single symbol; 40 when the symbol parameter is omitted
+        pub fn open_orders(
+            &self,
+            symbol: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<Order>>> {
+            let weight: u32 = if symbol.is_some() { 1 } else { 40 };
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(FAPI_V1_OPEN_ORDERS)?
+                        .signed(time_window)?
+                        .try_query_arg("symbol", &symbol)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, weight)
+                .send())
+        }
+
+        /// All Orders (USER_DATA)
+        ///
+        /// Get all account orders; active, canceled, or filled.
+        ///
+        /// Weight: 5
+        ///
+        /// * limit: Default 500; max 1000.
+        ///
+        /// If orderId is set, it will get orders >= that orderId. Otherwise most recent orders
+        ///   are returned.
+        /// The query time period must be less than 7 days (default as the recent 7 days).
+        pub fn all_orders(
+            &self,
+            symbol: impl Serialize,
+            order_id: Option<u64>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u16>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<Order>>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(FAPI_V1_ALL_ORDERS)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .try_query_arg("orderId", &order_id)?
+                        .try_query_arg("startTime", &start_time)?
+                        .try_query_arg("endTime", &end_time)?
+                        .try_query_arg("limit", &limit)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 5)
+                .send())
+        }
+    }
+
+    pub(crate) fn order_query_args<S>(
+        request: RequestBuilder<S>,
+        order: &NewOrder,
+    ) -> BinanceResult<RequestBuilder<S>>
+    where
+        S: crate::client::BinanceSigner,
+    {
+        request
+            .query_arg("symbol", &order.symbol)?
+            .query_arg("side", &order.side)?
+            .try_query_arg("positionSide", &order.position_side)?
+            .query_arg("type", &order.r#type)?
+            .try_query_arg("timeInForce", &order.time_in_force)?
+            .try_query_arg("quantity", &order.quantity)?
+            .try_query_arg("reduceOnly", &order.reduce_only)?
+            .try_query_arg("price", &order.price)?
+            .try_query_arg("newClientOrderId", &order.new_client_order_id)?
+            .try_query_arg("stopPrice", &order.stop_price)?
+            .try_query_arg("closePosition", &order.close_position)?
+            .try_query_arg("activationPrice", &order.activation_price)?
+            .try_query_arg("callbackRate", &order.callback_rate)?
+            .try_query_arg("workingType", &order.working_type)?
+            .try_query_arg("priceProtect", &order.price_protect)?
+            .try_query_arg("newOrderRespType", &order.new_order_resp_type)?
+            .try_query_arg("priceMatch", &order.price_match)?
+            .try_query_arg("selfTradePreventionMode", &order.self_trade_prevention_mode)?
+            .try_query_arg("goodTillDate", &order.good_till_date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_new_order_check() {
+        let order = NewOrder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit).quantity(dec!(1));
+        assert!(order.check().is_err());
+        let order = order
+            .price_match(PriceMatch::Queue)
+            .time_in_force(TimeInForce::GoodTilCanceled);
+        assert!(order.check().is_ok());
+        let order = NewOrder::new("BTCUSDT", OrderSide::Sell, OrderType::TrailingStopMarket);
+        assert!(order.check().is_err());
+    }
+
+    #[test]
+    fn test_deserialize_order() {
+        let json = r#"{
+            "clientOrderId": "testOrder",
+            "cumQty": "0",
+            "cumQuote": "0",
+            "executedQty": "0",
+            "orderId": 22542179,
+            "avgPrice": "0.00000",
+            "origQty": "10",
+            "price": "0",
+            "reduceOnly": false,
+            "side": "BUY",
+            "positionSide": "SHORT",
+            "status": "NEW",
+            "stopPrice": "9300",
+            "closePosition": false,
+            "symbol": "BTCUSDT",
+            "timeInForce": "GTD",
+            "type": "TRAILING_STOP_MARKET",
+            "origType": "TRAILING_STOP_MARKET",
+            "activatePrice": "9020",
+            "priceRate": "0.3",
+            "updateTime": 1566818724722,
+            "workingType": "CONTRACT_PRICE",
+            "priceProtect": false,
+            "priceMatch": "NONE",
+            "selfTradePreventionMode": "NONE",
+            "goodTillDate": 1693207680000
+        }"#;
+        let res: Order = serde_json::from_str(json).unwrap();
+        assert_eq!(res.time_in_force, TimeInForce::GoodTilDate);
+        assert_eq!(res.price_rate, Some(dec!(0.3)));
+    }
+}
@@ -0,0 +1,226 @@
+use super::PositionSide;
+use super::StatusResponse;
+use crate::api::spot::RlPriorityLevel;
+use crate::api::um::RL_WEIGHT_PER_MINUTE;
+use crate::api::um::prelude::*;
+use crate::client::Task;
+
+pub const FAPI_V2_POSITION_RISK: &str = "/fapi/v2/positionRisk";
+pub const FAPI_V1_LEVERAGE: &str = "/fapi/v1/leverage";
+pub const FAPI_V1_MARGIN_TYPE: &str = "/fapi/v1/marginType";
+pub const FAPI_V1_POSITION_SIDE_DUAL: &str = "/fapi/v1/positionSide/dual";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MarginType {
+    #[serde(rename = "ISOLATED", alias = "isolated")]
+    Isolated,
+    #[serde(rename = "CROSSED", alias = "cross")]
+    Crossed,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRisk {
+    pub symbol: Atom,
+    pub position_amt: Decimal,
+    pub entry_price: Decimal,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub break_even_price: Option<Decimal>,
+    pub mark_price: Decimal,
+    pub un_realized_profit: Decimal,
+    pub liquidation_price: Decimal,
+    pub leverage: Decimal,
+    pub max_notional_value: Decimal,
+    /// `"isolated"` or `"cross"`.
+    pub margin_type: MarginType,
+    pub isolated_margin: Decimal,
+    /// `"true"` or `"false"`.
+    pub is_auto_add_margin: String,
+    pub position_side: PositionSide,
+    pub notional: Decimal,
+    pub isolated_wallet: Decimal,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageChanged {
+    pub leverage: u32,
+    pub max_notional_value: Decimal,
+    pub symbol: Atom,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMode {
+    /// `true`: Hedge Mode; `false`: One-way Mode.
+    pub dual_side_position: bool,
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> UmApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Position Information V2 (USER_DATA)
+        ///
+        /// Get current position information.
+        ///
+        /// Weight: 5
+        pub fn position_risk(
+            &self,
+            symbol: Option<impl Serialize>,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<Vec<PositionRisk>>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(FAPI_V2_POSITION_RISK)?
+                        .signed(time_window)?
+                        .try_query_arg("symbol", &symbol)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 5)
+                .send())
+        }
+
+        /// Change Initial Leverage (TRADE)
+        ///
+        /// Change user's initial leverage of specific symbol market.
+        ///
+        /// Weight: 1
+        ///
+        /// * leverage - target initial leverage: int from 1 to 125.
+        pub fn change_leverage(
+            &self,
+            symbol: impl Serialize,
+            leverage: u32,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<LeverageChanged>> {
+            if !(1..=125).contains(&leverage) {
+                Err(ApiError::OutOfBounds)?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(FAPI_V1_LEVERAGE)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .query_arg("leverage", &leverage)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Change Margin Type (TRADE)
+        ///
+        /// Change symbol level margin type.
+        ///
+        /// Weight: 1
+        pub fn change_margin_type(
+            &self,
+            symbol: impl Serialize,
+            margin_type: MarginType,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<StatusResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(FAPI_V1_MARGIN_TYPE)?
+                        .signed(time_window)?
+                        .query_arg("symbol", &symbol)?
+                        .query_arg("marginType", &margin_type)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Change Position Mode (TRADE)
+        ///
+        /// Change user's position mode (Hedge Mode or One-way Mode) on EVERY symbol.
+        ///
+        /// Weight: 1
+        ///
+        /// * dual_side_position - `true`: Hedge Mode; `false`: One-way Mode.
+        pub fn change_position_mode(
+            &self,
+            dual_side_position: bool,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<StatusResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(FAPI_V1_POSITION_SIDE_DUAL)?
+                        .signed(time_window)?
+                        .query_arg("dualSidePosition", &dual_side_position)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Get Current Position Mode (USER_DATA)
+        ///
+        /// Get user's position mode (Hedge Mode or One-way Mode) on EVERY symbol.
+        ///
+        /// Weight: 30
+        pub fn position_mode(
+            &self,
+            time_window: impl Into<TimeWindow>,
+        ) -> BinanceResult<Task<PositionMode>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(FAPI_V1_POSITION_SIDE_DUAL)?
+                        .signed(time_window)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 30)
+                .send())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize_position_risk() {
+        let json = r#"[{
+            "entryPrice": "0.00000",
+            "breakEvenPrice": "0.0",
+            "marginType": "isolated",
+            "isAutoAddMargin": "false",
+            "isolatedMargin": "0.00000000",
+            "leverage": "10",
+            "liquidationPrice": "0",
+            "markPrice": "6679.50671178",
+            "maxNotionalValue": "20000000",
+            "positionAmt": "0.000",
+            "notional": "0",
+            "isolatedWallet": "0",
+            "symbol": "BTCUSDT",
+            "unRealizedProfit": "0.00000000",
+            "positionSide": "BOTH",
+            "updateTime": 0
+        }]"#;
+        let res: Vec<PositionRisk> = serde_json::from_str(json).unwrap();
+        assert_eq!(res[0].margin_type, MarginType::Isolated);
+        assert_eq!(res[0].leverage, dec!(10));
+    }
+}
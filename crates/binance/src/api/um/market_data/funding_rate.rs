@@ -0,0 +1,91 @@
+use ccx_api_lib::serde_util::none_as_empty_str;
+
+use super::super::RL_WEIGHT_PER_MINUTE;
+use super::prelude::*;
+use crate::client::Task;
+
+pub const FAPI_V1_FUNDING_RATE: &str = "/fapi/v1/fundingRate";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: Atom,
+    pub funding_rate: Decimal,
+    pub funding_time: u64,
+    /// Empty for the oldest records.
+    #[serde(with = "none_as_empty_str", default)]
+    pub mark_price: Option<Decimal>,
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> UmApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Get Funding Rate History.
+        ///
+        /// Weight: shares 500/5min/IP rate limit with the funding info endpoint.
+        ///
+        /// * start_time - Timestamp in ms to get funding rate from INCLUSIVE.
+        /// * end_time - Timestamp in ms to get funding rate until INCLUSIVE.
+        /// * limit - Default 100; max 1000.
+        ///
+        /// If startTime and endTime are not sent, the most recent limit datas are returned.
+        /// If the number of data between startTime and endTime is larger than limit,
+        ///   return as startTime + limit.
+        /// In ascending order.
+        pub fn funding_rate(
+            &self,
+            symbol: Option<impl Serialize>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+            limit: Option<u16>,
+        ) -> BinanceResult<Task<Vec<FundingRate>>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(FAPI_V1_FUNDING_RATE)?
+                        .try_query_arg("symbol", &symbol)?
+                        .try_query_arg("startTime", &start_time)?
+                        .try_query_arg("endTime", &end_time)?
+                        .try_query_arg("limit", &limit)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_funding_rate() {
+        let json = r#"[
+            {
+                "symbol": "BTCUSDT",
+                "fundingRate": "-0.03750000",
+                "fundingTime": 1570608000000,
+                "markPrice": "34287.54619963"
+            },
+            {
+                "symbol": "BTCUSDT",
+                "fundingRate": "0.00010000",
+                "fundingTime": 1570636800000,
+                "markPrice": ""
+            }
+        ]"#;
+        let res: Vec<FundingRate> = serde_json::from_str(json).unwrap();
+        assert!(res[0].mark_price.is_some());
+        assert!(res[1].mark_price.is_none());
+    }
+}
@@ -44,6 +44,7 @@ pub use with_network::*;
 #[cfg(feature = "with_network")]
 mod with_network {
     use super::*;
+    use crate::client::RateLimiter;
 
     impl<S> UmApi<S>
     where
@@ -52,13 +53,26 @@ mod with_network {
     {
         /// Current exchange trading rules and symbol information.
         ///
+        /// The `rateLimits` of the response are applied to the rate limiter.
+        ///
         /// Weight: 1
         pub fn exchange_info(&self) -> BinanceResult<Task<ExchangeInformation>> {
+            let rate_limiter = self.rate_limiter.clone();
             Ok(self
                 .rate_limiter
                 .task(self.client.get(FAPI_V1_EXCHANGE_INFO)?)
                 .cost(RL_WEIGHT_PER_MINUTE, 1)
-                .send())
+                .send()
+                .inspect_ok(move |info: &ExchangeInformation| {
+                    apply_rate_limits(&rate_limiter, &info.rate_limits)
+                }))
+        }
+    }
+
+    /// Applies `rateLimits` of the exchange information to the rate limiter.
+    fn apply_rate_limits(rate_limiter: &RateLimiter, rate_limits: &[RateLimit]) {
+        for rate_limit in rate_limits {
+            rate_limiter.set_limit(rate_limit.counter(), rate_limit.period(), rate_limit.limit);
         }
     }
 }
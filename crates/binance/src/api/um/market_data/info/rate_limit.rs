@@ -1,4 +1,7 @@
+use std::time::Duration;
+
 use crate::api::um::prelude::*;
+use crate::client::RateLimitCounter;
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
 #[serde(rename_all = "camelCase")]
@@ -9,6 +12,26 @@ pub struct RateLimit {
     pub rate_limit_type: RateLimitType,
 }
 
+impl RateLimit {
+    /// Length of the interval the limit applies to.
+    pub fn period(&self) -> Duration {
+        let secs = match self.interval {
+            RateLimitInterval::Second => 1,
+            RateLimitInterval::Minute => 60,
+            RateLimitInterval::Day => 86_400,
+        };
+        Duration::from_secs(secs * u64::from(self.interval_num))
+    }
+
+    /// Rate limiter counter the limit applies to.
+    pub fn counter(&self) -> RateLimitCounter {
+        match self.rate_limit_type {
+            RateLimitType::RequestWeight => RateLimitCounter::Weight,
+            RateLimitType::Orders => RateLimitCounter::OrderCount,
+        }
+    }
+}
+
 // TODO check variants
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum RateLimitInterval {
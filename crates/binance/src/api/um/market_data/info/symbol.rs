@@ -55,6 +55,9 @@ pub enum ContractType {
     /// 次季交割合约
     #[serde(rename = "NEXT_QUARTER")]
     NextQarter,
+    /// Perpetual contract pending delisting.
+    #[serde(rename = "PERPETUAL DELIVERING")]
+    PerpetualDelivering,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -122,4 +125,8 @@ pub enum TimeInForce {
     /// Good Till Crossing (Post Only)
     #[serde(rename = "GTX")]
     GoodTilCrossing,
+    /// Good Till Date
+    /// An order will be on the book until `goodTillDate`.
+    #[serde(rename = "GTD")]
+    GoodTilDate,
 }
@@ -0,0 +1,59 @@
+use super::super::RL_WEIGHT_PER_MINUTE;
+use super::prelude::*;
+use crate::client::Task;
+
+pub const FAPI_V1_PREMIUM_INDEX: &str = "/fapi/v1/premiumIndex";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: Atom,
+    pub mark_price: Decimal,
+    pub index_price: Decimal,
+    /// Only useful in the last hour before the settlement starts.
+    pub estimated_settle_price: Decimal,
+    pub last_funding_rate: Decimal,
+    pub interest_rate: Decimal,
+    pub next_funding_time: u64,
+    pub time: u64,
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> UmApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Mark Price and Funding Rate of a symbol.
+        ///
+        /// Weight: 1
+        pub fn mark_price(&self, symbol: impl Serialize) -> BinanceResult<Task<MarkPrice>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(FAPI_V1_PREMIUM_INDEX)?
+                        .query_arg("symbol", &symbol)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Mark Price and Funding Rate of all symbols.
+        ///
+        /// Weight: 1
+        pub fn mark_prices(&self) -> BinanceResult<Task<Vec<MarkPrice>>> {
+            Ok(self
+                .rate_limiter
+                .task(self.client.get(FAPI_V1_PREMIUM_INDEX)?)
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+    }
+}
@@ -1,8 +1,14 @@
+mod funding_rate;
 mod info;
+mod mark_price;
+mod open_interest;
 mod ping;
 mod time;
 
+pub use self::funding_rate::*;
 pub use self::info::*;
+pub use self::mark_price::*;
+pub use self::open_interest::*;
 pub use self::ping::*;
 pub use self::time::*;
 // use crate::util::{Ask, Bid};
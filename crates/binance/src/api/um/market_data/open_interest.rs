@@ -0,0 +1,106 @@
+use super::super::RL_WEIGHT_PER_MINUTE;
+use super::prelude::*;
+use crate::client::Task;
+
+pub const FAPI_V1_OPEN_INTEREST: &str = "/fapi/v1/openInterest";
+pub const FUTURES_DATA_OPEN_INTEREST_HIST: &str = "/futures/data/openInterestHist";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub open_interest: Decimal,
+    pub symbol: Atom,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestStats {
+    pub symbol: Atom,
+    /// Total open interest.
+    pub sum_open_interest: Decimal,
+    /// Total open interest value.
+    pub sum_open_interest_value: Decimal,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum StatsPeriod {
+    #[serde(rename = "5m")]
+    M5,
+    #[serde(rename = "15m")]
+    M15,
+    #[serde(rename = "30m")]
+    M30,
+    #[serde(rename = "1h")]
+    H1,
+    #[serde(rename = "2h")]
+    H2,
+    #[serde(rename = "4h")]
+    H4,
+    #[serde(rename = "6h")]
+    H6,
+    #[serde(rename = "12h")]
+    H12,
+    #[serde(rename = "1d")]
+    D1,
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> UmApi<S>
+    where
+        S: crate::client::BinanceSigner,
+        S: Unpin + 'static,
+    {
+        /// Get present open interest of a specific symbol.
+        ///
+        /// Weight: 1
+        pub fn open_interest(&self, symbol: impl Serialize) -> BinanceResult<Task<OpenInterest>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(FAPI_V1_OPEN_INTEREST)?
+                        .query_arg("symbol", &symbol)?,
+                )
+                .cost(RL_WEIGHT_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Open Interest Statistics.
+        ///
+        /// Weight: 0 (IP rate limit 1000 requests/5min).
+        ///
+        /// * limit - Default 30; max 500.
+        ///
+        /// If startTime and endTime are not sent, the most recent data is returned.
+        /// Only the data of the latest 1 month is available.
+        pub fn open_interest_history(
+            &self,
+            symbol: impl Serialize,
+            period: StatsPeriod,
+            limit: Option<u16>,
+            start_time: Option<u64>,
+            end_time: Option<u64>,
+        ) -> BinanceResult<Task<Vec<OpenInterestStats>>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(FUTURES_DATA_OPEN_INTEREST_HIST)?
+                        .query_arg("symbol", &symbol)?
+                        .query_arg("period", &period)?
+                        .try_query_arg("limit", &limit)?
+                        .try_query_arg("startTime", &start_time)?
+                        .try_query_arg("endTime", &end_time)?,
+                )
+                .send())
+        }
+    }
+}
@@ -8,22 +8,24 @@ use crate::client::BinanceSigner;
 use crate::client::CCX_BINANCE_API_PREFIX;
 use crate::client::Config;
 use crate::client::Proxy;
+use crate::client::RateLimitCounter;
 use crate::client::RateLimiter;
 use crate::client::RateLimiterBucket;
 use crate::client::RateLimiterBuilder;
+use crate::client::ReconnectingWebsocketStream;
 use crate::client::RestClient;
 use crate::client::WebsocketStream;
 use crate::error::*;
 
+mod account;
 mod market_data;
 // TODO mod websocket_market;
-// TODO mod account;
 // TODO mod user_data_stream;
 // TODO mod error;
 
+pub use self::account::*;
 pub use self::market_data::*;
 // pub use self::websocket_market::*;
-// pub use self::account::*;
 // pub use self::user_data_stream::*;
 
 mod prelude {
@@ -39,6 +41,8 @@ pub const API_BASE_TESTNET: &str = "https://testnet.binancefuture.com/";
 pub const STREAM_BASE_TESTNET: &str = "wss://stream.binancefuture.com/stream";
 
 pub const RL_WEIGHT_PER_MINUTE: &str = "weight_per_minute";
+pub const RL_ORDERS_PER_10_SECONDS: &str = "orders_per_10_seconds";
+pub const RL_ORDERS_PER_MINUTE: &str = "orders_per_minute";
 
 #[cfg(feature = "with_network")]
 pub use with_network::*;
@@ -98,8 +102,23 @@ mod with_network {
         pub fn with_config(config: Config<S>) -> Self {
             let client = RestClient::new(config);
             let rate_limiter = RateLimiterBuilder::default()
-                .bucket(
+                .synced_bucket(
                     RL_WEIGHT_PER_MINUTE,
+                    RateLimitCounter::Weight,
+                    RateLimiterBucket::default()
+                        .interval(Duration::from_secs(60))
+                        .limit(2_400),
+                )
+                .synced_bucket(
+                    RL_ORDERS_PER_10_SECONDS,
+                    RateLimitCounter::OrderCount,
+                    RateLimiterBucket::default()
+                        .interval(Duration::from_secs(10))
+                        .limit(300),
+                )
+                .synced_bucket(
+                    RL_ORDERS_PER_MINUTE,
+                    RateLimitCounter::OrderCount,
                     RateLimiterBucket::default()
                         .interval(Duration::from_secs(60))
                         .limit(1_200),
@@ -115,5 +134,14 @@ mod with_network {
         pub async fn ws(&self) -> BinanceResult<WebsocketStream> {
             self.client.web_socket().await
         }
+
+        /// Creates multiplexed websocket stream which reconnects and resubscribes
+        /// after disconnections.
+        pub async fn ws_reconnecting(&self) -> BinanceResult<ReconnectingWebsocketStream>
+        where
+            S: 'static,
+        {
+            ReconnectingWebsocketStream::connect(self.client.clone()).await
+        }
     }
 }
@@ -1,4 +1,7 @@
+use std::sync::Arc;
+
 pub use ccx_api_lib::ApiCred;
+pub use ccx_api_lib::HttpTransport;
 pub use ccx_api_lib::Proxy;
 use ccx_api_lib::env_var_with_prefix;
 use url::Url;
@@ -13,7 +16,11 @@ pub struct Config<S: BinanceSigner> {
     pub signer: S,
     pub api_base: Url,
     pub stream_base: Url,
+    /// Base url of the WebSocket API; `None` when the API has no WebSocket API endpoint.
+    pub ws_api_base: Option<Url>,
     pub proxy: Option<Proxy>,
+    /// Sends the REST requests; over the network when `None`.
+    pub transport: Option<Arc<dyn HttpTransport>>,
 }
 
 impl<S> Config<S>
@@ -25,10 +32,22 @@ where
             signer,
             api_base,
             stream_base,
+            ws_api_base: None,
             proxy,
+            transport: None,
         }
     }
 
+    pub fn with_ws_api_base(mut self, ws_api_base: Url) -> Self {
+        self.ws_api_base = Some(ws_api_base);
+        self
+    }
+
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
     pub fn env_var(postfix: &str) -> Option<String> {
         env_var_with_prefix(CCX_BINANCE_API_PREFIX, postfix)
     }
@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use actix_http::header::HeaderMap;
+use ccx_api_lib::http::HeaderMap;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -56,7 +56,7 @@ impl IntervalLetter {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct TimeSpan {
     pub interval: Duration,
 }
@@ -79,6 +79,15 @@ impl TimeSpan {
     }
 }
 
+/// Server-side counter a rate limiter bucket is kept in sync with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RateLimitCounter {
+    /// `X-MBX-USED-WEIGHT-*` headers; `REQUEST_WEIGHT` rate limits.
+    Weight,
+    /// `X-MBX-ORDER-COUNT-*` headers; `ORDERS` rate limits.
+    OrderCount,
+}
+
 #[derive(Default, Debug)]
 pub struct UsedRateLimits {
     /// The limits on the API are based on the IPs, not the API keys.
@@ -128,4 +137,56 @@ impl UsedRateLimits {
         }
         u
     }
+
+    /// Usage reported for the counter over the interval.
+    pub fn used(&self, counter: RateLimitCounter, interval: Duration) -> Option<u32> {
+        let used = match counter {
+            RateLimitCounter::Weight => &self.weight_per_ip,
+            RateLimitCounter::OrderCount => &self.order_count_per_account,
+        };
+        used.iter()
+            .find(|(time_span, _)| time_span.interval == interval)
+            .map(|(_, used)| *used)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ccx_api_lib::http::HeaderName;
+    use ccx_api_lib::http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn test_used_rate_limits_from_headers() {
+        let mut headers = HeaderMap::new();
+        for (name, value) in [
+            ("x-mbx-used-weight", "42"),
+            ("x-mbx-used-weight-1m", "42"),
+            ("x-mbx-order-count-10s", "3"),
+            ("x-mbx-order-count-1d", "17"),
+        ] {
+            headers.insert(
+                HeaderName::from_static(name),
+                HeaderValue::from_static(value),
+            );
+        }
+        let used = UsedRateLimits::from_headers(&headers);
+        assert_eq!(
+            used.used(RateLimitCounter::Weight, Duration::from_secs(60)),
+            Some(42)
+        );
+        assert_eq!(
+            used.used(RateLimitCounter::OrderCount, Duration::from_secs(10)),
+            Some(3)
+        );
+        assert_eq!(
+            used.used(RateLimitCounter::OrderCount, Duration::from_secs(86_400)),
+            Some(17)
+        );
+        assert_eq!(
+            used.used(RateLimitCounter::Weight, Duration::from_secs(1)),
+            None
+        );
+    }
 }
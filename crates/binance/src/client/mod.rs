@@ -3,7 +3,14 @@ mod limits;
 mod rate_limiter;
 mod rest;
 mod signer;
+#[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
 mod websocket;
+mod websocket_api;
+mod websocket_reconnecting;
+#[cfg(feature = "with_tokio")]
+mod websocket_tokio;
+use std::future::Future;
+
 use serde::Deserialize;
 
 pub use self::config::*;
@@ -11,12 +18,36 @@ pub use self::limits::*;
 pub use self::rate_limiter::*;
 pub use self::rest::*;
 pub use self::signer::*;
+#[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
 pub use self::websocket::*;
+pub use self::websocket_api::*;
+pub use self::websocket_reconnecting::*;
+#[cfg(feature = "with_tokio")]
+pub use self::websocket_tokio::*;
+use crate::error::BinanceErrorCode;
 
+/// Error body of a rejected request.
 #[derive(Debug, Deserialize)]
 struct BinanceContentError {
-    #[allow(dead_code)]
-    pub code: i16,
-    #[allow(dead_code)]
+    pub code: BinanceErrorCode,
     pub msg: String,
 }
+
+/// Spawns a background task on the runtime of the WebSocket backend.
+#[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
+pub(crate) fn spawn<F>(future: F) -> tokio::task::JoinHandle<F::Output>
+where
+    F: Future + 'static,
+{
+    actix_rt::spawn(future)
+}
+
+/// Spawns a background task on the runtime of the WebSocket backend.
+#[cfg(feature = "with_tokio")]
+pub(crate) fn spawn<F>(future: F) -> tokio::task::JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    tokio::spawn(future)
+}
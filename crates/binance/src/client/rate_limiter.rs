@@ -1,74 +1,60 @@
-use std::borrow::Cow;
 use std::collections::HashMap;
-use std::collections::VecDeque;
-use std::collections::hash_map::Entry;
 use std::fmt::Debug;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
-use std::time::Instant;
 
-use actix::clock::sleep;
-use futures::channel::mpsc;
-use futures::channel::oneshot;
-use futures::lock::Mutex;
+use ccx_api_lib::BucketName;
+pub(crate) use ccx_api_lib::RateLimiterBucket;
+use ccx_api_lib::TaskCosts;
+pub use ccx_api_lib::TaskMetadata;
 use futures::prelude::*;
 use futures::task::Context;
 use futures::task::Poll;
 
 use super::BinanceSigner;
+use super::RateLimitCounter;
 use super::RequestBuilder;
+use super::UsedRateLimits;
 use crate::BinanceResult;
 use crate::LibError;
 
-type BucketName = Cow<'static, str>;
-type TaskCosts = HashMap<BucketName, u32>;
-type TaskMessageResult = BinanceResult<()>;
-
-struct TaskMessage {
-    priority: u8,
-    costs: TaskCosts,
-    tx: oneshot::Sender<TaskMessageResult>,
-}
-
 #[derive(Default)]
 pub(crate) struct RateLimiterBuilder {
-    buckets: HashMap<BucketName, RateLimiterBucket>,
+    inner: ccx_api_lib::RateLimiterBuilder,
+    counters: HashMap<BucketName, RateLimitCounter>,
 }
 
 impl RateLimiterBuilder {
     pub fn bucket(mut self, key: impl Into<BucketName>, bucket: RateLimiterBucket) -> Self {
-        match self.buckets.entry(key.into()) {
-            Entry::Occupied(mut e) => *e.get_mut() = bucket,
-            Entry::Vacant(e) => {
-                e.insert(bucket);
-            }
-        }
+        self.inner = self.inner.bucket(key, bucket);
         self
     }
 
+    /// Adds a bucket kept in sync with the server-side counter of the same interval.
+    pub fn synced_bucket(
+        mut self,
+        key: impl Into<BucketName>,
+        counter: RateLimitCounter,
+        bucket: RateLimiterBucket,
+    ) -> Self {
+        let key = key.into();
+        self.counters.insert(key.clone(), counter);
+        self.bucket(key, bucket)
+    }
+
     pub fn start(self) -> RateLimiter {
-        let (tasks_tx, tasks_rx) = mpsc::unbounded::<TaskMessage>();
-        let buckets = self
-            .buckets
-            .into_iter()
-            .map(|(k, v)| (k, Mutex::new(v)))
-            .collect();
-        let rate_limiter = RateLimiter {
-            buckets: Arc::new(buckets),
-            tasks_tx,
-            queue: Arc::new(Mutex::new(Queue::new())),
-        };
-        rate_limiter.recv(tasks_rx);
-        rate_limiter
+        RateLimiter {
+            inner: self.inner.start(),
+            counters: Arc::new(self.counters),
+        }
     }
 }
 
 #[derive(Clone)]
 pub(crate) struct RateLimiter {
-    buckets: Arc<HashMap<BucketName, Mutex<RateLimiterBucket>>>,
-    tasks_tx: mpsc::UnboundedSender<TaskMessage>,
-    queue: Arc<Mutex<Queue>>,
+    inner: ccx_api_lib::RateLimiter,
+    counters: Arc<HashMap<BucketName, RateLimitCounter>>,
 }
 
 impl RateLimiter {
@@ -80,221 +66,38 @@ impl RateLimiter {
             priority: 0,
             costs: TaskCosts::new(),
             req_builder: builder,
-            tasks_tx: self.tasks_tx.clone(),
+            rate_limiter: self.clone(),
         }
     }
 
-    fn recv(&self, mut rx: mpsc::UnboundedReceiver<TaskMessage>) {
-        let buckets = self.buckets.clone();
-        let queue = self.queue.clone();
-        actix_rt::spawn(async move {
-            while let Some(task_message) = rx.next().await {
-                let is_first_task = queue.lock().await.add(task_message).is_first();
-                if is_first_task {
-                    Self::handler(buckets.clone(), queue.clone()).await;
-                }
+    /// Updates the limits of the buckets synced with the counter over the interval,
+    /// e.g. from `rateLimits` of the exchange information.
+    pub fn set_limit(&self, counter: RateLimitCounter, interval: Duration, limit: u32) {
+        self.inner.update_buckets(|name, bucket| {
+            if self.counters.get(name) == Some(&counter) && bucket.get_interval() == interval {
+                log::debug!("RateLimiter: bucket {} :: New limit {}", name, limit);
+                bucket.set_limit(limit);
             }
         });
     }
 
-    async fn handler(
-        buckets: Arc<HashMap<BucketName, Mutex<RateLimiterBucket>>>,
-        queue: Arc<Mutex<Queue>>,
-    ) {
-        actix_rt::spawn(async move {
-            loop {
-                let TaskMessage {
-                    priority,
-                    costs,
-                    tx,
-                } = match queue.lock().await.next() {
-                    Some(task) => task,
-                    None => {
-                        log::debug!("RateLimiter: stop queue handler (queue is empty)");
-                        break;
-                    }
-                };
-                log::debug!("RateLimiter: received task with priority {}", priority);
-
-                let buckets = buckets.clone();
-                let res = async move {
-                    if let Some(dur) = Self::timeout(buckets.clone(), &costs).await? {
-                        log::debug!("RateLimiter: sleep for {:?}", dur);
-                        sleep(dur).await;
-                    }
-                    Self::set_costs(buckets, &costs).await?;
-                    Ok(())
-                }
-                .await;
-
-                log::debug!("RateLimiter: completed task with priority {}", priority);
-                let _ = tx.send(res);
-            }
-        });
-    }
-
-    async fn timeout(
-        buckets: Arc<HashMap<BucketName, Mutex<RateLimiterBucket>>>,
-        costs: &TaskCosts,
-    ) -> BinanceResult<Option<Duration>> {
-        let mut timeout = Duration::default();
-
-        for (name, cost) in costs {
-            let mut bucket = match buckets.get(name) {
-                Some(bucket) => bucket.lock().await,
-                None => Err(LibError::other(format!(
-                    "RateLimiter: undefined bucket {}",
-                    name
-                )))?,
+    /// Reconciles the buckets with the usage reported by the server, which also counts
+    /// the requests sent by other clients sharing the IP or the account.
+    fn sync_used(&self, used_rate_limits: &UsedRateLimits) {
+        self.inner.update_buckets(|name, bucket| {
+            let Some(counter) = self.counters.get(name) else {
+                return;
             };
-
-            let delay = bucket.delay.duration_since(Instant::now());
-            if !delay.is_zero() {
-                log::debug!("RateLimiter: bucket {} :: Delayed start {:?}", name, delay);
-                timeout = delay;
-                continue;
-            }
-
-            bucket.update_state();
-            let new_amount = bucket.amount + cost;
-            log::debug!(
-                "RateLimiter: bucket {} :: Task cost {}; prev amount {}; bucket limit: {};",
-                name,
-                cost,
-                bucket.amount,
-                bucket.limit
-            );
-
-            if new_amount > bucket.limit {
-                let bucket_timeout = bucket.get_timeout();
-                log::debug!("RateLimiter: bucket {} :: Limit has been reached", name);
-
-                if bucket_timeout > timeout {
-                    log::debug!(
-                        "RateLimiter: bucket {} :: Need sleep {:?}.",
-                        name,
-                        bucket_timeout
-                    );
-                    timeout = bucket_timeout;
-                }
+            if let Some(used) = used_rate_limits.used(*counter, bucket.get_interval()) {
+                bucket.sync_used(used);
+                log::debug!(
+                    "RateLimiter: bucket {} :: Synced amount {}; bucket limit: {}",
+                    name,
+                    bucket.amount(),
+                    bucket.get_limit()
+                );
             }
-        }
-
-        Ok((!timeout.is_zero()).then_some(timeout))
-    }
-
-    async fn set_costs(
-        buckets: Arc<HashMap<BucketName, Mutex<RateLimiterBucket>>>,
-        costs: &TaskCosts,
-    ) -> BinanceResult<()> {
-        for (name, cost) in costs {
-            let mut bucket = match buckets.get(name) {
-                Some(bucket) => bucket.lock().await,
-                None => Err(LibError::other(format!(
-                    "RateLimiter: undefined bucket {}",
-                    name
-                )))?,
-            };
-
-            bucket.update_state();
-            bucket.amount += cost;
-
-            log::debug!(
-                "RateLimiter: bucket {} :: New amount {}; bucket limit: {}",
-                name,
-                bucket.amount,
-                bucket.limit
-            );
-        }
-
-        Ok(())
-    }
-}
-
-pub(crate) struct RateLimiterBucket {
-    time_instant: Instant,
-    delay: Instant,
-    interval: Duration,
-    limit: u32,
-    amount: u32,
-}
-
-impl Default for RateLimiterBucket {
-    fn default() -> Self {
-        Self {
-            time_instant: Instant::now(),
-            delay: Instant::now(),
-            interval: Duration::default(),
-            limit: 0,
-            amount: 0,
-        }
-    }
-}
-
-impl RateLimiterBucket {
-    pub fn delay(mut self, delay: Duration) -> Self {
-        self.delay = Instant::now() + delay;
-        self
-    }
-
-    pub fn interval(mut self, interval: Duration) -> Self {
-        self.interval = interval;
-        self
-    }
-
-    pub fn limit(mut self, limit: u32) -> Self {
-        self.limit = limit;
-        self
-    }
-
-    fn update_state(&mut self) {
-        let elapsed = Instant::now().duration_since(self.time_instant);
-        if elapsed > self.interval {
-            self.time_instant = Instant::now();
-            self.amount = 0;
-        }
-    }
-
-    fn get_timeout(&self) -> Duration {
-        let elapsed = Instant::now().duration_since(self.time_instant);
-        self.interval - elapsed
-    }
-}
-
-struct Queue {
-    inner: VecDeque<TaskMessage>,
-}
-
-impl Queue {
-    fn new() -> Self {
-        Self {
-            inner: VecDeque::new(),
-        }
-    }
-
-    fn add(&mut self, msg: TaskMessage) -> &Self {
-        let priority = msg.priority;
-        self.inner.push_back(msg);
-
-        if priority > 0 {
-            self.inner
-                .make_contiguous()
-                .sort_by(|a, b| b.priority.cmp(&a.priority));
-        }
-
-        self
-    }
-
-    fn is_first(&self) -> bool {
-        self.inner.len() == 1
-    }
-}
-
-impl Iterator for Queue {
-    type Item = TaskMessage;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.inner.pop_front()
+        });
     }
 }
 
@@ -305,7 +108,7 @@ where
     priority: u8,
     costs: TaskCosts,
     req_builder: RequestBuilder<S>,
-    tasks_tx: mpsc::UnboundedSender<TaskMessage>,
+    rate_limiter: RateLimiter,
 }
 
 impl<S> TaskBuilder<S>
@@ -318,10 +121,7 @@ where
     }
 
     pub fn cost(mut self, key: impl Into<BucketName>, weight: u32) -> Self {
-        self.costs
-            .entry(key.into())
-            .and_modify(|e| *e = weight)
-            .or_insert(weight);
+        self.costs.insert(key.into(), weight);
         self
     }
 
@@ -332,30 +132,29 @@ where
         let priority = self.priority;
         let costs = self.costs.clone();
         let req_builder = self.req_builder;
-        let mut tasks_tx = self.tasks_tx.clone();
+        let rate_limiter = self.rate_limiter;
 
         let fut = async move {
-            let (tx, rx) = oneshot::channel::<TaskMessageResult>();
-            tasks_tx
-                .send(TaskMessage {
-                    priority,
-                    costs,
-                    tx,
-                })
+            rate_limiter
+                .inner
+                .acquire(&costs, priority)
                 .await
-                .map_err(|_| LibError::other("RateLimiter: task channel was dropped"))?;
-            rx.await
-                .map_err(|_| LibError::other("RateLimiter: task channel was dropped"))?
-                .map_err(|e| {
-                    log::error!("RateLimiter: task err. {:?}", e);
-                    e
-                })?;
-
-            req_builder.send::<V>().await
+                .inspect_err(|e| log::error!("RateLimiter: task err. {:?}", e))?;
+
+            let res = req_builder.execute().await?;
+            rate_limiter.sync_used(&res.used_rate_limits);
+            let res = res.json::<V>();
+            if let Err(LibError::ApiError(e)) = &res {
+                if let Some(retry_after) = e.retry_after() {
+                    log::warn!("RateLimiter: backing off for {:?}", retry_after);
+                    rate_limiter.inner.delay_all(retry_after);
+                }
+            }
+            res
         };
 
         Task {
-            fut: fut.boxed_local(),
+            fut: fut.boxed(),
             costs: self.costs,
         }
     }
@@ -365,7 +164,7 @@ pub struct Task<V>
 where
     V: serde::de::DeserializeOwned + Debug,
 {
-    fut: Pin<Box<dyn Future<Output = BinanceResult<V>>>>,
+    fut: Pin<Box<dyn Future<Output = BinanceResult<V>> + Send>>,
     costs: TaskCosts,
 }
 
@@ -378,6 +177,17 @@ where
             costs: self.costs.clone(),
         }
     }
+
+    /// Runs `f` on a successful response before the task resolves with it.
+    pub(crate) fn inspect_ok(self, f: impl FnOnce(&V) + Send + 'static) -> Self
+    where
+        V: 'static,
+    {
+        Task {
+            fut: self.fut.inspect_ok(f).boxed(),
+            costs: self.costs,
+        }
+    }
 }
 
 impl<V> Future for Task<V>
@@ -391,15 +201,13 @@ where
     }
 }
 
-#[derive(Debug)]
-pub struct TaskMetadata {
-    pub costs: TaskCosts,
-}
-
 #[cfg(test)]
 mod tests {
     use std::sync::atomic::AtomicU8;
     use std::sync::atomic::Ordering;
+    use std::time::Instant;
+
+    use tokio::time::sleep;
 
     use super::*;
     use crate::ApiCred;
@@ -571,7 +379,7 @@ mod tests {
             let position = position.clone();
             let rate_limiter = rate_limiter.clone();
             let spot_api = spot_api.clone();
-            actix::spawn(async move {
+            actix_rt::spawn(async move {
                 while counter.load(Ordering::SeqCst) < 6 {
                     sleep(Duration::from_millis(10)).await;
                 }
@@ -599,7 +407,7 @@ mod tests {
             let counter = counter.clone();
             let rate_limiter = rate_limiter.clone();
             let spot_api = spot_api.clone();
-            actix::spawn(async move {
+            actix_rt::spawn(async move {
                 let _task_res = rate_limiter
                     .task(spot_api.client.get("/api/v3/time").unwrap())
                     .cost("interval_3__limit_5", 1)
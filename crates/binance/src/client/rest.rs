@@ -1,19 +1,19 @@
 use std::sync::Arc;
+use std::time::Duration;
 use std::time::Instant;
 
-use actix_http::BoxedPayloadStream;
-use actix_http::Method;
-use actix_http::Payload;
-use actix_http::StatusCode;
-use actix_http::Uri;
-use actix_http::encoding::Decoder;
-use ccx_api_lib::awc::Client;
-use ccx_api_lib::awc::ClientRequest;
-use ccx_api_lib::awc::ClientResponse;
-use ccx_api_lib::awc::make_client;
+use bytes::Bytes;
+use ccx_api_lib::http::HeaderMap;
+use ccx_api_lib::reqwest::Client;
+use ccx_api_lib::reqwest::ClientRequest;
+use ccx_api_lib::reqwest::Method;
+use ccx_api_lib::reqwest::StatusCode;
+use ccx_api_lib::reqwest::make_client_with;
 use serde::Serialize;
+use url::Url;
 
 use super::*;
+use crate::api::spot::CancelReplaceOrder;
 use crate::client::WebsocketStream;
 use crate::client::limits::UsedRateLimits;
 use crate::error::*;
@@ -43,6 +43,7 @@ where
     S: BinanceSigner,
 {
     config: Config<S>,
+    client: Client,
 }
 
 pub struct RequestBuilder<S>
@@ -59,20 +60,24 @@ where
     S: BinanceSigner,
 {
     pub fn new(config: Config<S>) -> Self {
-        let inner = Arc::new(ClientInner { config });
+        let client = make_client_with(config.transport.as_ref(), false, config.proxy.as_ref());
+        let inner = Arc::new(ClientInner { config, client });
         RestClient { inner }
     }
 
-    fn client_(&self, h1_only: bool) -> Client {
-        make_client(h1_only, self.inner.config.proxy.as_ref())
+    /// REST client sending through the configured transport.
+    pub(super) fn client(&self) -> &Client {
+        &self.inner.client
     }
 
-    pub(super) fn client(&self) -> Client {
-        self.client_(false)
+    /// Client for the actix WebSocket connections.
+    #[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
+    pub(super) fn ws_client(&self) -> ccx_api_lib::awc::Client {
+        ccx_api_lib::awc::make_client(true, self.inner.config.proxy.as_ref())
     }
 
-    pub(super) fn client_h1(&self) -> Client {
-        self.client_(true)
+    pub(super) fn config(&self) -> &Config<S> {
+        &self.inner.config
     }
 
     pub fn request(&self, method: Method, endpoint: &str) -> BinanceResult<RequestBuilder<S>> {
@@ -107,6 +112,27 @@ where
         let url = self.inner.config.stream_base.clone();
         WebsocketStream::connect(self.clone(), url).await
     }
+
+    /// Connects to the WebSocket API.
+    pub async fn web_socket_api(&self) -> BinanceResult<WsApiClient<S>> {
+        let url = self
+            .inner
+            .config
+            .ws_api_base
+            .clone()
+            .ok_or_else(|| ApiError::mandatory_field_omitted("ws_api_base"))?;
+        WsApiClient::connect(self.clone(), url).await
+    }
+
+    /// Raw stream url of the user data stream, e.g. `wss://stream.binance.com/ws/<listenKey>`.
+    pub fn user_data_stream_url(&self, listen_key: &str) -> BinanceResult<Url> {
+        Ok(self
+            .inner
+            .config
+            .stream_base
+            .join("ws/")?
+            .join(listen_key)?)
+    }
 }
 
 impl<S> RequestBuilder<S>
@@ -114,39 +140,27 @@ where
     S: BinanceSigner,
 {
     pub fn uri(&self) -> String {
-        self.request.get_uri().to_string()
+        let query = self.request.query_params();
+        if query.is_empty() {
+            self.request.url().to_string()
+        } else {
+            format!("{}?{}", self.request.url(), query)
+        }
     }
 
     pub fn query_args<T: Serialize>(mut self, query: &T) -> BinanceResult<Self> {
-        self.request = self.request.query(query)?;
+        // Surface the encoding errors the client would drop.
+        serde_urlencoded::to_string(query)?;
+        self.request = self.request.query(query);
         Ok(self)
     }
 
     pub fn query_arg<Name: AsRef<str>, T: Serialize + ?Sized>(
-        mut self,
+        self,
         name: Name,
         query: &T,
     ) -> BinanceResult<Self> {
-        let mut parts = self.request.get_uri().clone().into_parts();
-
-        if let Some(path_and_query) = parts.path_and_query {
-            let mut buf = path_and_query.path().to_string();
-            buf.push('?');
-            match path_and_query.query().unwrap_or("") {
-                "" => {}
-                old_query => {
-                    buf.push_str(old_query);
-                    buf.push('&');
-                }
-            }
-            buf.push_str(&serde_urlencoded::to_string([(name.as_ref(), query)])?);
-            parts.path_and_query = buf.parse().ok();
-            let uri =
-                Uri::from_parts(parts).map_err(|e| BinanceError::other(format!("{:?}", e)))?;
-            self.request = self.request.uri(uri);
-        }
-
-        Ok(self)
+        self.query_args(&[(name.as_ref(), query)])
     }
 
     pub fn try_query_arg<Name: AsRef<str>, T: Serialize>(
@@ -163,7 +177,7 @@ where
     pub fn auth_header(mut self) -> BinanceResult<Self> {
         self.request = self
             .request
-            .append_header(("X-MBX-APIKEY", self.api_client.inner.config.api_key()));
+            .header("X-MBX-APIKEY", self.api_client.inner.config.api_key());
         Ok(self)
     }
 
@@ -172,10 +186,15 @@ where
         self.auth_header()
     }
 
-    pub async fn send<V>(mut self) -> BinanceResult<V>
+    pub async fn send<V>(self) -> BinanceResult<V>
     where
         V: serde::de::DeserializeOwned,
     {
+        self.execute().await?.json()
+    }
+
+    /// Sends the request and reads the response without checking its status.
+    pub(crate) async fn execute(mut self) -> BinanceResult<RawResponse> {
         self = if let Some(sign) = self.sign {
             self = self.query_arg("timestamp", &sign.timestamp())?;
             let recv_window = sign.recv_window();
@@ -186,34 +205,29 @@ where
         } else {
             self
         };
-        log::debug!("{}  {}", self.request.get_method(), self.request.get_uri(),);
+        log::debug!("{}  {}", self.request.method(), self.uri());
 
         let tm = Instant::now();
-        let mut res = self.request.send().await?;
+        let res = self.request.send().await?;
         let d1 = tm.elapsed();
-        let resp = res.body().limit(16 * 1024 * 1024).await?;
+        let status = res.status();
+        let headers = res.headers().clone();
+        let body = res.bytes().await?;
         let d2 = tm.elapsed() - d1;
         log::debug!(
             "Request time elapsed:  {:0.1}ms + {:0.1}ms",
             d1.as_secs_f64() * 1000.0,
             d2.as_secs_f64() * 1000.0,
         );
-        log::debug!(
-            "Response: {} «{}»",
-            res.status(),
-            String::from_utf8_lossy(&resp)
-        );
-        if let Err(err) = check_response(res) {
-            // log::debug!("Response: {}", String::from_utf8_lossy(&resp));
-            Err(err)?
-        };
-        match serde_json::from_slice(&resp) {
-            Ok(json) => Ok(json),
-            Err(err) => {
-                // log::debug!("Response: {}", String::from_utf8_lossy(&resp));
-                Err(err)?
-            }
-        }
+        log::debug!("Response: {} «{}»", status, String::from_utf8_lossy(&body));
+        let used_rate_limits = UsedRateLimits::from_headers(&headers);
+        log::debug!("  used_rate_limits:  {:?}", used_rate_limits);
+        Ok(RawResponse {
+            status,
+            headers,
+            body,
+            used_rate_limits,
+        })
     }
 
     // pub async fn send_no_response(mut self) -> BinanceResult<()> {
@@ -251,7 +265,7 @@ where
     // }
 
     async fn sign(self) -> BinanceResult<Self> {
-        let query = self.request.get_uri().query().unwrap_or("");
+        let query = self.request.query_params();
         let signature = self
             .api_client
             .inner
@@ -263,40 +277,152 @@ where
     }
 }
 
-type AwcClientResponse = ClientResponse<Decoder<Payload<BoxedPayloadStream>>>;
-
-fn check_response(res: AwcClientResponse) -> BinanceResult<AwcClientResponse> {
-    let used_rate_limits = UsedRateLimits::from_headers(res.headers());
+/// Response that has been read but not checked yet.
+pub(crate) struct RawResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+    /// Usage of the rate limits reported by the server.
+    pub used_rate_limits: UsedRateLimits,
+}
 
-    log::debug!("  used_rate_limits:  {:?}", used_rate_limits);
+impl RawResponse {
+    pub fn json<V>(self) -> BinanceResult<V>
+    where
+        V: serde::de::DeserializeOwned,
+    {
+        check_response(self.status, &self.headers, &self.body)?;
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+}
 
-    match res.status() {
-        StatusCode::OK => Ok(res),
+fn check_response(status: StatusCode, headers: &HeaderMap, body: &[u8]) -> BinanceResult<()> {
+    match status {
+        StatusCode::OK => Ok(()),
         StatusCode::INTERNAL_SERVER_ERROR => Err(ApiServiceError::ServerError)?,
         StatusCode::SERVICE_UNAVAILABLE => Err(ApiServiceError::ServiceUnavailable)?,
         StatusCode::UNAUTHORIZED => Err(ApiError::Unauthorized)?,
-        // StatusCode::BAD_REQUEST => {
-        //     let error_json: BinanceContentError = response.json()?;
-        //
-        //     Err(ErrorKind::BinanceError(error_json.code, error_json.msg, response).into())
-        // }
-        s => Err(BinanceError::UnknownStatus(s))?,
+        s @ (StatusCode::BAD_REQUEST | StatusCode::CONFLICT) => {
+            if let Ok(e) = serde_json::from_slice::<CancelReplaceError>(body) {
+                Err(ApiError::CancelReplaceFailed {
+                    code: e.code,
+                    msg: e.msg,
+                    data: Box::new(e.data),
+                })?
+            }
+            Err(rejected(s, body, None))?
+        }
+        s @ StatusCode::FORBIDDEN => Err(rejected(s, body, None))?,
+        // 418 is returned to IPs banned for continuing to send requests after a 429.
+        s @ (StatusCode::IM_A_TEAPOT | StatusCode::TOO_MANY_REQUESTS) => {
+            let retry_after = headers
+                .get("retry-after")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse().ok())
+                .map(Duration::from_secs);
+            Err(rejected(s, body, retry_after))?
+        }
+        s => Err(BinanceError::UnknownStatusReqwest(s))?,
+    }
+}
+
+/// Parses the error body of a rejected request.
+///
+/// Bodies without an error code (e.g. the WAF limit violation pages) are reported
+/// as unknown status.
+fn rejected(status: StatusCode, body: &[u8], retry_after: Option<Duration>) -> BinanceError {
+    match serde_json::from_slice::<BinanceContentError>(body) {
+        Ok(e) => ApiError::Rejected {
+            status: status.as_u16(),
+            code: e.code,
+            msg: e.msg,
+            retry_after,
+        }
+        .into(),
+        Err(_) => BinanceError::UnknownStatusReqwest(status),
     }
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use super::*;
-//
-//     #[test]
-//     fn it_should_sign() {
-//         let query = "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&\
-//                     recvWindow=5000&timestamp=1499827319559";
-//         let key = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j";
-//         let res = sign(query, key.as_bytes());
-//         assert_eq!(
-//             res,
-//             "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
-//         )
-//     }
-// }
+/// Error body of a cancel-replace request where the cancel and/or the new order failed.
+#[derive(Debug, serde::Deserialize)]
+struct CancelReplaceError {
+    code: BinanceErrorCode,
+    msg: String,
+    data: CancelReplaceOrder,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::spot::CancelReplaceResponse;
+
+    #[test]
+    fn test_rejected_error_code() {
+        let body = br#"{"code":-1013,"msg":"Filter failure: LOT_SIZE"}"#;
+        let err = rejected(StatusCode::BAD_REQUEST, body, None);
+        let BinanceError::ApiError(err) = err else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert_eq!(err.error_code(), Some(BinanceErrorCode::FilterFailure));
+
+        let body = br#"{"code":-1003,"msg":"Too many requests."}"#;
+        let err = rejected(
+            StatusCode::TOO_MANY_REQUESTS,
+            body,
+            Some(Duration::from_secs(7)),
+        );
+        let BinanceError::ApiError(err) = err else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert_eq!(err.error_code(), Some(BinanceErrorCode::TooManyRequests));
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(7)));
+
+        let err = rejected(StatusCode::FORBIDDEN, b"<html></html>", None);
+        assert!(matches!(
+            err,
+            BinanceError::UnknownStatusReqwest(StatusCode::FORBIDDEN)
+        ));
+    }
+
+    #[test]
+    fn test_cancel_replace_failed() {
+        let body = br#"{
+            "code": -2021,
+            "msg": "Order cancel-replace partially failed.",
+            "data": {
+                "cancelResult": "SUCCESS",
+                "newOrderResult": "FAILURE",
+                "cancelResponse": {
+                    "symbol": "BTCUSDT",
+                    "origClientOrderId": "86M8erehfExV8z2RC8Zo8k",
+                    "orderId": 3,
+                    "orderListId": -1,
+                    "clientOrderId": "G1kLo6aDv2KGNTFcjfTSFq",
+                    "price": "0.006123",
+                    "origQty": "10000.000000",
+                    "executedQty": "0.000000",
+                    "cummulativeQuoteQty": "0.000000",
+                    "status": "CANCELED",
+                    "timeInForce": "GTC",
+                    "type": "LIMIT_MAKER",
+                    "side": "SELL",
+                    "selfTradePreventionMode": "NONE"
+                },
+                "newOrderResponse": {
+                    "code": -2010,
+                    "msg": "Order would immediately match and take."
+                }
+            }
+        }"#;
+        let err = check_response(StatusCode::BAD_REQUEST, &HeaderMap::new(), body).unwrap_err();
+        let BinanceError::ApiError(ApiError::CancelReplaceFailed { code, data, .. }) = err else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert_eq!(code, BinanceErrorCode::Other(-2021));
+        assert!(data.cancel_response.is_success());
+        assert!(matches!(
+            data.new_order_response,
+            Some(CancelReplaceResponse::Failure { code: -2010, .. })
+        ));
+    }
+}
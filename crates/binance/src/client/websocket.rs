@@ -13,6 +13,7 @@ use futures::channel::mpsc;
 use futures::stream::SplitSink;
 use serde::Deserialize;
 use serde::Serialize;
+use serde::de::DeserializeOwned;
 use url::Url;
 
 use crate::client::RestClient;
@@ -20,6 +21,7 @@ use crate::error::BinanceError;
 use crate::error::BinanceResult;
 use crate::ws_stream::UpstreamApiRequest;
 use crate::ws_stream::UpstreamWebsocketMessage;
+use crate::ws_stream::UserDataEvent;
 use crate::ws_stream::WsCommand;
 use crate::ws_stream::WsEvent;
 use crate::ws_stream::WsSubscription;
@@ -33,6 +35,11 @@ const CLIENT_TIMEOUT: Duration = Duration::from_secs(30);
 #[rtype(result = "()")]
 struct M<T>(pub T);
 
+/// Closes the connection.
+#[derive(actix::Message)]
+#[rtype(result = "()")]
+struct Close;
+
 pub struct WebsocketStream {
     tx: WebsocketStreamTx,
     rx: mpsc::UnboundedReceiver<UpstreamWebsocketMessage<WsEvent>>,
@@ -42,14 +49,18 @@ pub struct WebsocketStreamTx {
     addr: Addr<Websocket>,
 }
 
-pub struct Websocket {
+/// Websocket connection actor forwarding the decoded server messages of type `T` downstream.
+pub struct Websocket<T = UpstreamWebsocketMessage<WsEvent>> {
     sink: SinkWrite<ws::Message, SplitSink<Framed<BoxedSocket, Codec>, ws::Message>>,
-    tx: mpsc::UnboundedSender<UpstreamWebsocketMessage<WsEvent>>,
+    tx: mpsc::UnboundedSender<T>,
     hb: Instant,
     id_seq: Seq<u64>,
 }
 
-impl Actor for Websocket {
+impl<T> Actor for Websocket<T>
+where
+    T: DeserializeOwned + Unpin + 'static,
+{
     type Context = Context<Self>;
 
     fn started(&mut self, ctx: &mut Self::Context) {
@@ -58,7 +69,10 @@ impl Actor for Websocket {
 }
 
 /// Handler for `ws::Message`.
-impl StreamHandler<Result<ws::Frame, ws::ProtocolError>> for Websocket {
+impl<T> StreamHandler<Result<ws::Frame, ws::ProtocolError>> for Websocket<T>
+where
+    T: DeserializeOwned + Unpin + 'static,
+{
     fn handle(&mut self, msg: Result<ws::Frame, ws::ProtocolError>, ctx: &mut Self::Context) {
         let msg = match msg {
             Ok(msg) => msg,
@@ -114,9 +128,15 @@ impl StreamHandler<Result<ws::Frame, ws::ProtocolError>> for Websocket {
     }
 }
 
-impl actix::io::WriteHandler<ws::ProtocolError> for Websocket {}
+impl<T> actix::io::WriteHandler<ws::ProtocolError> for Websocket<T> where
+    T: DeserializeOwned + Unpin + 'static
+{
+}
 
-impl Handler<M<WsCommand>> for Websocket {
+impl<T> Handler<M<WsCommand>> for Websocket<T>
+where
+    T: DeserializeOwned + Unpin + 'static,
+{
     type Result = ();
 
     fn handle(&mut self, M(cmd): M<WsCommand>, ctx: &mut Self::Context) {
@@ -132,11 +152,26 @@ impl Handler<M<WsCommand>> for Websocket {
     }
 }
 
-impl Websocket {
+impl<T> Handler<Close> for Websocket<T>
+where
+    T: DeserializeOwned + Unpin + 'static,
+{
+    type Result = ();
+
+    fn handle(&mut self, _msg: Close, ctx: &mut Self::Context) {
+        let _ = self.sink.write(ws::Message::Close(None));
+        ctx.stop();
+    }
+}
+
+impl<T> Websocket<T>
+where
+    T: DeserializeOwned + Unpin + 'static,
+{
     #[rustfmt::skip]
     pub(crate) fn new(
         sink: SinkWrite<ws::Message, SplitSink<Framed<BoxedSocket, Codec>, ws::Message>>,
-        tx: mpsc::UnboundedSender<UpstreamWebsocketMessage<WsEvent>>,
+        tx: mpsc::UnboundedSender<T>,
     ) -> Self {
         let hb = Instant::now();
         let id_seq = Seq::new();
@@ -159,18 +194,16 @@ impl Websocket {
             };
         });
     }
-}
 
-impl WebsocketStream {
-    pub async fn connect<S: crate::client::BinanceSigner>(
+    async fn start<S: crate::client::BinanceSigner>(
         api_client: RestClient<S>,
         url: Url,
-    ) -> BinanceResult<Self> {
+    ) -> BinanceResult<(Addr<Self>, mpsc::UnboundedReceiver<T>)> {
         use futures::StreamExt;
 
         log::debug!("Connecting WS: {}", url.as_str());
 
-        let (response, connection) = api_client.client_h1().ws(url.as_str()).connect().await?;
+        let (response, connection) = api_client.ws_client().ws(url.as_str()).connect().await?;
         log::debug!("{:?}", response);
 
         let (sink, stream) = connection.split();
@@ -179,7 +212,16 @@ impl WebsocketStream {
             Websocket::add_stream(stream, ctx);
             Websocket::new(SinkWrite::new(sink, ctx), tx)
         });
+        Ok((addr, rx))
+    }
+}
 
+impl WebsocketStream {
+    pub async fn connect<S: crate::client::BinanceSigner>(
+        api_client: RestClient<S>,
+        url: Url,
+    ) -> BinanceResult<Self> {
+        let (addr, rx) = Websocket::start(api_client, url).await?;
         let tx = WebsocketStreamTx { addr };
         Ok(WebsocketStream { tx, rx })
     }
@@ -221,4 +263,74 @@ impl WebsocketStreamTx {
             .await
             .map_err(|_e| BinanceError::IoError(io::ErrorKind::ConnectionAborted.into()))
     }
+
+    pub async fn unsubscribe_list(
+        &self,
+        subscriptions: Box<[WsSubscription]>,
+    ) -> BinanceResult<()> {
+        let cmd = WsCommand::Unsubscribe(subscriptions);
+        self.addr
+            .send(M(cmd))
+            .await
+            .map_err(|_e| BinanceError::IoError(io::ErrorKind::ConnectionAborted.into()))
+    }
+
+    /// Closes the connection; the receiving half of the stream ends.
+    pub fn close(&self) {
+        self.addr.do_send(Close);
+    }
+}
+
+/// User data stream connected with a listen key.
+///
+/// Yields the account and order events. The listen key keep-alive task attached to
+/// the stream is aborted when the stream is dropped.
+pub struct UserDataStream {
+    _addr: Addr<Websocket<UserDataEvent>>,
+    rx: mpsc::UnboundedReceiver<UserDataEvent>,
+    keep_alive: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl UserDataStream {
+    pub async fn connect<S: crate::client::BinanceSigner>(
+        api_client: RestClient<S>,
+        url: Url,
+        keep_alive: Option<tokio::task::JoinHandle<()>>,
+    ) -> BinanceResult<Self> {
+        let (addr, rx) = match Websocket::start(api_client, url).await {
+            Ok(res) => res,
+            Err(e) => {
+                if let Some(keep_alive) = &keep_alive {
+                    keep_alive.abort();
+                }
+                Err(e)?
+            }
+        };
+        Ok(UserDataStream {
+            _addr: addr,
+            rx,
+            keep_alive,
+        })
+    }
+}
+
+impl futures::Stream for UserDataStream {
+    type Item = UserDataEvent;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use futures::StreamExt;
+
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
+impl Drop for UserDataStream {
+    fn drop(&mut self) {
+        if let Some(keep_alive) = self.keep_alive.take() {
+            keep_alive.abort();
+        }
+    }
 }
@@ -0,0 +1,770 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::io;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use futures::channel::oneshot;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use url::Url;
+
+use self::connection::Connection;
+use crate::client::BinanceSigner;
+use crate::client::RestClient;
+use crate::error::ApiError;
+use crate::error::BinanceError;
+use crate::error::BinanceErrorCode;
+use crate::error::BinanceResult;
+use crate::proto::TimeWindow;
+
+/// How often heartbeat pings are sent.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+/// How long before lack of server response causes a timeout.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(30);
+/// How long to wait for the response to a request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Parameters of a WebSocket API request.
+///
+/// Kept sorted by name as the signature payload requires.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(transparent)]
+pub struct WsApiParams(BTreeMap<String, Value>);
+
+impl WsApiParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn arg<T: Serialize + ?Sized>(
+        mut self,
+        name: impl Into<String>,
+        value: &T,
+    ) -> BinanceResult<Self> {
+        self.0.insert(name.into(), serde_json::to_value(value)?);
+        Ok(self)
+    }
+
+    pub fn try_arg<T: Serialize>(
+        self,
+        name: impl Into<String>,
+        value: &Option<T>,
+    ) -> BinanceResult<Self> {
+        match value {
+            Some(value) => self.arg(name, value),
+            None => Ok(self),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// `name=value` pairs joined with `&`, in alphabetical order of the names.
+    fn signature_payload(&self) -> String {
+        let mut payload = String::new();
+        for (name, value) in &self.0 {
+            if !payload.is_empty() {
+                payload.push('&');
+            }
+            payload.push_str(name);
+            payload.push('=');
+            match value {
+                Value::String(s) => payload.push_str(s),
+                value => payload.push_str(&value.to_string()),
+            }
+        }
+        payload
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct WsApiRequest<'a> {
+    id: String,
+    method: &'a str,
+    #[serde(skip_serializing_if = "WsApiParams::is_empty")]
+    params: WsApiParams,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsApiResponse {
+    /// `None` when the server can't attribute the error to a request.
+    pub id: Option<String>,
+    pub status: u16,
+    #[serde(default)]
+    pub result: Option<Value>,
+    #[serde(default)]
+    pub error: Option<WsApiError>,
+    /// Rate limits status after the request.
+    #[serde(default = "Vec::new")]
+    pub rate_limits: Vec<WsApiRateLimit>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WsApiError {
+    pub code: BinanceErrorCode,
+    pub msg: String,
+    /// Present when the IP is rate limited.
+    #[serde(default)]
+    pub data: Option<WsApiErrorData>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsApiErrorData {
+    pub server_time: Option<u64>,
+    /// Timestamp in milliseconds when the requests are accepted again.
+    pub retry_after: Option<u64>,
+}
+
+impl WsApiError {
+    fn retry_after(&self) -> Option<Duration> {
+        let data = self.data.as_ref()?;
+        let delay = data.retry_after?.saturating_sub(data.server_time?);
+        Some(Duration::from_millis(delay))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsApiRateLimit {
+    /// `REQUEST_WEIGHT` or `ORDERS`.
+    pub rate_limit_type: String,
+    /// `SECOND`, `MINUTE`, `HOUR` or `DAY`.
+    pub interval: String,
+    pub interval_num: u32,
+    pub limit: u32,
+    pub count: u32,
+}
+
+/// Status of the WebSocket API session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsApiSessionStatus {
+    /// The API key the session is authenticated with; `None` until logged on.
+    pub api_key: Option<String>,
+    pub authorized_since: Option<u64>,
+    pub connected_since: u64,
+    pub return_rate_limits: bool,
+    pub server_time: u64,
+}
+
+/// The connection on actix actors.
+#[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
+mod connection {
+    use actix::io::SinkWrite;
+    use actix::prelude::*;
+    use actix_codec::Framed;
+    use actix_http::ws::Codec;
+    use actix_web_actors::ws;
+    use awc::BoxedSocket;
+    use futures::stream::SplitSink;
+
+    use super::*;
+
+    #[derive(actix::Message)]
+    #[rtype(result = "()")]
+    struct WsApiCall {
+        id: String,
+        payload: String,
+        tx: oneshot::Sender<WsApiResponse>,
+    }
+
+    /// Connection actor correlating the responses to the pending requests by id.
+    struct WsApiConnection {
+        sink: SinkWrite<ws::Message, SplitSink<Framed<BoxedSocket, Codec>, ws::Message>>,
+        hb: Instant,
+        pending: HashMap<String, oneshot::Sender<WsApiResponse>>,
+    }
+
+    impl Actor for WsApiConnection {
+        type Context = Context<Self>;
+
+        fn started(&mut self, ctx: &mut Self::Context) {
+            self.hb(ctx);
+        }
+    }
+
+    impl StreamHandler<Result<ws::Frame, ws::ProtocolError>> for WsApiConnection {
+        fn handle(&mut self, msg: Result<ws::Frame, ws::ProtocolError>, ctx: &mut Self::Context) {
+            let msg = match msg {
+                Ok(msg) => msg,
+                Err(e) => {
+                    log::warn!("WebSocket API connection broken: {:?}", e);
+                    ctx.stop();
+                    return;
+                }
+            };
+
+            match msg {
+                ws::Frame::Ping(msg) => {
+                    self.hb = Instant::now();
+                    if let Err(_msg) = self.sink.write(ws::Message::Pong(msg)) {
+                        log::warn!("Failed to send Pong. Disconnecting.");
+                        ctx.stop()
+                    }
+                }
+                ws::Frame::Pong(_) => {
+                    self.hb = Instant::now();
+                }
+                ws::Frame::Binary(_bin) => {
+                    log::warn!("unexpected binary message (ignored)");
+                }
+                ws::Frame::Text(msg) => match serde_json::from_slice::<WsApiResponse>(&msg) {
+                    Err(e) => {
+                        log::error!(
+                            "Failed to deserialize server message: {:?}; {}",
+                            e,
+                            String::from_utf8_lossy(&msg)
+                        );
+                    }
+                    Ok(response) => {
+                        let tx = response.id.as_ref().and_then(|id| self.pending.remove(id));
+                        match tx {
+                            Some(tx) => {
+                                let _ = tx.send(response);
+                            }
+                            None => log::warn!("Unexpected WebSocket API response: {:?}", response),
+                        }
+                    }
+                },
+                ws::Frame::Close(_) => {
+                    ctx.stop();
+                }
+                ws::Frame::Continuation(_) => {
+                    ctx.stop();
+                }
+            }
+        }
+    }
+
+    impl actix::io::WriteHandler<ws::ProtocolError> for WsApiConnection {}
+
+    #[derive(actix::Message)]
+    #[rtype(result = "()")]
+    struct Close;
+
+    impl Handler<Close> for WsApiConnection {
+        type Result = ();
+
+        fn handle(&mut self, _: Close, ctx: &mut Self::Context) {
+            let _ = self.sink.write(ws::Message::Close(None));
+            self.sink.close();
+            ctx.stop();
+        }
+    }
+
+    impl Handler<WsApiCall> for WsApiConnection {
+        type Result = ();
+
+        fn handle(&mut self, WsApiCall { id, payload, tx }: WsApiCall, ctx: &mut Self::Context) {
+            log::debug!("Sending to server: `{}`", payload);
+            if let Err(_msg) = self.sink.write(ws::Message::Text(payload.into())) {
+                ctx.stop();
+                return;
+            }
+            // Drop the waiters of the requests that timed out.
+            self.pending.retain(|_, tx| !tx.is_canceled());
+            self.pending.insert(id, tx);
+        }
+    }
+
+    impl WsApiConnection {
+        /// helper method that sends ping to server every [`HEARTBEAT_INTERVAL`].
+        ///
+        /// also this method checks heartbeats from server
+        fn hb(&mut self, ctx: &mut <Self as Actor>::Context) {
+            ctx.run_interval(HEARTBEAT_INTERVAL, move |act, ctx| {
+                if Instant::now().duration_since(act.hb) > CLIENT_TIMEOUT {
+                    log::warn!("WebSocket API heartbeat failed, disconnecting!");
+                    ctx.stop();
+                    return;
+                }
+                if let Err(_msg) = act.sink.write(ws::Message::Ping("".into())) {
+                    log::warn!("WebSocket API failed to send ping, stopping!");
+                    ctx.stop()
+                };
+            });
+        }
+    }
+
+    /// Handle of the connection actor.
+    pub(super) struct Connection {
+        addr: Addr<WsApiConnection>,
+    }
+
+    impl Connection {
+        pub(super) async fn open<S: BinanceSigner>(
+            api_client: &RestClient<S>,
+            url: Url,
+        ) -> BinanceResult<Self> {
+            use futures::StreamExt;
+
+            let (response, connection) = api_client.ws_client().ws(url.as_str()).connect().await?;
+            log::debug!("{:?}", response);
+
+            let (sink, stream) = connection.split();
+            let addr = WsApiConnection::create(move |ctx| {
+                WsApiConnection::add_stream(stream, ctx);
+                WsApiConnection {
+                    sink: SinkWrite::new(sink, ctx),
+                    hb: Instant::now(),
+                    pending: HashMap::new(),
+                }
+            });
+            Ok(Connection { addr })
+        }
+
+        /// Sends the request; the response is delivered to `tx`.
+        pub(super) async fn send(
+            &self,
+            id: String,
+            payload: String,
+            tx: oneshot::Sender<WsApiResponse>,
+        ) -> BinanceResult<()> {
+            self.addr
+                .send(WsApiCall { id, payload, tx })
+                .await
+                .map_err(|_e| BinanceError::IoError(std::io::ErrorKind::ConnectionAborted.into()))
+        }
+
+        /// Closes the socket and stops the actor along with its heartbeat.
+        pub(super) fn close(&self) {
+            self.addr.do_send(Close);
+        }
+    }
+}
+
+/// The connection on tokio-tungstenite.
+#[cfg(feature = "with_tokio")]
+mod connection {
+    use futures::SinkExt;
+    use futures::StreamExt;
+    use futures::channel::mpsc;
+    use tokio::time::interval;
+    use tokio_tungstenite::tungstenite::Message;
+
+    use super::*;
+    use crate::client::websocket_tokio::Socket;
+    use crate::client::websocket_tokio::connect;
+
+    struct WsApiCall {
+        id: String,
+        payload: String,
+        tx: oneshot::Sender<WsApiResponse>,
+    }
+
+    /// Handle of the connection task.
+    pub(super) struct Connection {
+        call_tx: mpsc::UnboundedSender<WsApiCall>,
+    }
+
+    impl Connection {
+        pub(super) async fn open<S: BinanceSigner>(
+            api_client: &RestClient<S>,
+            url: Url,
+        ) -> BinanceResult<Self> {
+            let socket = connect(&url, api_client.config().proxy.as_ref()).await?;
+            let (call_tx, call_rx) = mpsc::unbounded();
+            tokio::spawn(run(socket, call_rx));
+            Ok(Connection { call_tx })
+        }
+
+        /// Sends the request; the response is delivered to `tx`.
+        pub(super) async fn send(
+            &self,
+            id: String,
+            payload: String,
+            tx: oneshot::Sender<WsApiResponse>,
+        ) -> BinanceResult<()> {
+            self.call_tx
+                .unbounded_send(WsApiCall { id, payload, tx })
+                .map_err(|_e| BinanceError::IoError(io::ErrorKind::ConnectionAborted.into()))
+        }
+
+        /// Closes the socket and ends the connection task along with its heartbeat.
+        pub(super) fn close(&self) {
+            self.call_tx.close_channel();
+        }
+    }
+
+    /// Correlates the responses to the pending requests by id.
+    async fn run(socket: Socket, mut call_rx: mpsc::UnboundedReceiver<WsApiCall>) {
+        let (mut sink, mut stream) = socket.split();
+        let mut heartbeat = interval(HEARTBEAT_INTERVAL);
+        let mut hb = Instant::now();
+        let mut pending: HashMap<String, oneshot::Sender<WsApiResponse>> = HashMap::new();
+
+        loop {
+            tokio::select! {
+                msg = stream.next() => match msg {
+                    Some(Ok(Message::Text(text))) => {
+                        match serde_json::from_str::<WsApiResponse>(&text) {
+                            Err(e) => {
+                                log::error!("Failed to deserialize server message: {:?}; {}", e, text);
+                            }
+                            Ok(response) => {
+                                let tx = response.id.as_ref().and_then(|id| pending.remove(id));
+                                match tx {
+                                    Some(tx) => {
+                                        let _ = tx.send(response);
+                                    }
+                                    None => log::warn!("Unexpected WebSocket API response: {:?}", response),
+                                }
+                            }
+                        }
+                    }
+                    Some(Ok(Message::Binary(_))) => {
+                        log::warn!("unexpected binary message (ignored)");
+                    }
+                    Some(Ok(Message::Ping(data))) => {
+                        hb = Instant::now();
+                        if sink.send(Message::Pong(data)).await.is_err() {
+                            log::warn!("Failed to send Pong. Disconnecting.");
+                            break;
+                        }
+                    }
+                    Some(Ok(Message::Pong(_))) => {
+                        hb = Instant::now();
+                    }
+                    Some(Ok(Message::Frame(_))) => {}
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Err(e)) => {
+                        log::warn!("WebSocket API connection broken: {:?}", e);
+                        break;
+                    }
+                },
+                call = call_rx.next() => match call {
+                    Some(WsApiCall { id, payload, tx }) => {
+                        log::debug!("Sending to server: `{}`", payload);
+                        if sink.send(Message::Text(payload.into())).await.is_err() {
+                            break;
+                        }
+                        // Drop the waiters of the requests that timed out.
+                        pending.retain(|_, tx| !tx.is_canceled());
+                        pending.insert(id, tx);
+                    }
+                    // The client has been closed or dropped.
+                    None => break,
+                },
+                _ = heartbeat.tick() => {
+                    if Instant::now().duration_since(hb) > CLIENT_TIMEOUT {
+                        log::warn!("WebSocket API heartbeat failed, disconnecting!");
+                        break;
+                    }
+                    if sink.send(Message::Ping(Default::default())).await.is_err() {
+                        log::warn!("WebSocket API failed to send ping, stopping!");
+                        break;
+                    }
+                }
+            }
+        }
+
+        let _ = sink.close().await;
+    }
+}
+
+/// WebSocket API client.
+///
+/// Requests are sent over a single persistent connection and resolved by the responses
+/// with the matching id. Signed requests are signed with the same [`BinanceSigner`] as
+/// the REST ones unless the session is logged on with [`WsApiClient::session_logon`].
+pub struct WsApiClient<S>
+where
+    S: BinanceSigner,
+{
+    api_client: RestClient<S>,
+    connection: Connection,
+    id_seq: AtomicU64,
+    logged_on: AtomicBool,
+}
+
+impl<S> WsApiClient<S>
+where
+    S: BinanceSigner,
+{
+    pub async fn connect(api_client: RestClient<S>, url: Url) -> BinanceResult<Self> {
+        log::debug!("Connecting WS API: {}", url.as_str());
+
+        let connection = Connection::open(&api_client, url).await?;
+        Ok(WsApiClient {
+            api_client,
+            connection,
+            id_seq: AtomicU64::new(0),
+            logged_on: AtomicBool::new(false),
+        })
+    }
+
+    /// Whether signed requests are authenticated by the session rather than signed
+    /// one by one.
+    pub fn is_logged_on(&self) -> bool {
+        self.logged_on.load(Ordering::Relaxed)
+    }
+
+    /// Sends a request that needs no authentication.
+    pub async fn request<T>(&self, method: &str, params: WsApiParams) -> BinanceResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self.call(method, params).await?;
+        match (response.result, response.error) {
+            (_, Some(error)) => Err(ApiError::Rejected {
+                status: response.status,
+                code: error.code,
+                retry_after: error.retry_after(),
+                msg: error.msg,
+            })?,
+            (Some(result), None) => Ok(serde_json::from_value(result)?),
+            (None, None) => Ok(serde_json::from_value(Value::Null)?),
+        }
+    }
+
+    /// Sends a request that needs a signature (TRADE and USER_DATA).
+    pub async fn signed_request<T>(
+        &self,
+        method: &str,
+        params: WsApiParams,
+        time_window: impl Into<TimeWindow>,
+    ) -> BinanceResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        let mut params = self.timed(params, time_window.into())?;
+        if !self.is_logged_on() {
+            params = self.sign(params).await?;
+        }
+        self.request(method, params).await
+    }
+
+    /// Log in with API key (SIGNED)
+    ///
+    /// Authenticates the connection, so that the subsequent signed requests don't need
+    /// the API key and signature. Supported for Ed25519 keys only.
+    ///
+    /// Weight: 2
+    pub async fn session_logon(
+        &self,
+        time_window: impl Into<TimeWindow>,
+    ) -> BinanceResult<WsApiSessionStatus> {
+        let params = self.timed(WsApiParams::new(), time_window.into())?;
+        let params = self.sign(params).await?;
+        let status = self.request("session.logon", params).await?;
+        self.logged_on.store(true, Ordering::Relaxed);
+        Ok(status)
+    }
+
+    /// Query session status
+    ///
+    /// Weight: 2
+    pub async fn session_status(&self) -> BinanceResult<WsApiSessionStatus> {
+        self.request("session.status", WsApiParams::new()).await
+    }
+
+    /// Log out of the session
+    ///
+    /// Forgets the API key previously authenticated; the connection stays open.
+    ///
+    /// Weight: 2
+    pub async fn session_logout(&self) -> BinanceResult<WsApiSessionStatus> {
+        let status = self.request("session.logout", WsApiParams::new()).await?;
+        self.logged_on.store(false, Ordering::Relaxed);
+        Ok(status)
+    }
+
+    fn timed(&self, params: WsApiParams, time_window: TimeWindow) -> BinanceResult<WsApiParams> {
+        let params = params.arg("timestamp", &time_window.timestamp())?;
+        let recv_window = time_window.recv_window();
+        if recv_window.is_default() {
+            Ok(params)
+        } else {
+            params.arg("recvWindow", &*recv_window)
+        }
+    }
+
+    async fn sign(&self, params: WsApiParams) -> BinanceResult<WsApiParams> {
+        let config = self.api_client.config();
+        let params = params.arg("apiKey", config.api_key())?;
+        let signature = config
+            .signer()
+            .sign_data(&params.signature_payload())
+            .await?;
+        params.arg("signature", &signature)
+    }
+
+    async fn call(&self, method: &str, params: WsApiParams) -> BinanceResult<WsApiResponse> {
+        let id = self.id_seq.fetch_add(1, Ordering::Relaxed).to_string();
+        let payload = serde_json::to_string(&WsApiRequest {
+            id: id.clone(),
+            method,
+            params,
+        })?;
+        let (tx, rx) = oneshot::channel();
+        self.connection.send(id, payload, tx).await?;
+        match tokio::time::timeout(REQUEST_TIMEOUT, rx).await {
+            Ok(Ok(response)) => Ok(response),
+            Ok(Err(_canceled)) => Err(BinanceError::IoError(
+                io::ErrorKind::ConnectionAborted.into(),
+            )),
+            Err(_elapsed) => Err(BinanceError::IoError(io::ErrorKind::TimedOut.into())),
+        }
+    }
+}
+
+impl<S> Drop for WsApiClient<S>
+where
+    S: BinanceSigner,
+{
+    fn drop(&mut self) {
+        self.connection.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::Decimal;
+
+    #[test]
+    fn test_signature_payload_is_sorted() {
+        let params = WsApiParams::new()
+            .arg("timestamp", &1655971564069_u64)
+            .unwrap()
+            .arg("symbol", "BTCUSDT")
+            .unwrap()
+            .arg("price", &dec!(23416.10))
+            .unwrap()
+            .try_arg("quantity", &Some(dec!(0.00847)))
+            .unwrap()
+            .try_arg("icebergQty", &None::<Decimal>)
+            .unwrap()
+            .arg(
+                "apiKey",
+                "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A",
+            )
+            .unwrap();
+        assert_eq!(
+            params.signature_payload(),
+            "apiKey=vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A\
+             &price=23416.10&quantity=0.00847&symbol=BTCUSDT&timestamp=1655971564069"
+        );
+    }
+
+    #[test]
+    fn test_decode_error_response() {
+        let json = r#"{
+            "id": "7",
+            "status": 400,
+            "error": {"code": -2010, "msg": "Account has insufficient balance for requested action."},
+            "rateLimits": [
+                {"rateLimitType": "ORDERS", "interval": "SECOND", "intervalNum": 10, "limit": 50, "count": 13}
+            ]
+        }"#;
+        let response: WsApiResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.id.as_deref(), Some("7"));
+        assert_eq!(
+            response.error.unwrap().code,
+            BinanceErrorCode::NewOrderRejected
+        );
+        assert_eq!(response.rate_limits[0].count, 13);
+    }
+
+    #[test]
+    fn test_decode_rate_limited_response() {
+        let json = r#"{
+            "id": "8",
+            "status": 418,
+            "error": {
+                "code": -1003,
+                "msg": "Way too much request weight used; IP banned until 1659146400000.",
+                "data": {"serverTime": 1659142907531, "retryAfter": 1659146400000}
+            }
+        }"#;
+        let response: WsApiResponse = serde_json::from_str(json).unwrap();
+        let error = response.error.unwrap();
+        assert_eq!(error.code, BinanceErrorCode::TooManyRequests);
+        assert_eq!(error.retry_after(), Some(Duration::from_millis(3492469)));
+    }
+
+    /// Answers a single request and reports whether the client closed the connection after.
+    async fn serve_once(listener: tokio::net::TcpListener, result: Value) -> bool {
+        use futures::SinkExt;
+        use futures::StreamExt;
+        use tokio_tungstenite::tungstenite::Message;
+
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+        let request = loop {
+            match ws.next().await.unwrap().unwrap() {
+                Message::Text(text) => break serde_json::from_str::<Value>(&text).unwrap(),
+                _ => continue,
+            }
+        };
+        let response = serde_json::json!({
+            "id": request["id"],
+            "status": 200,
+            "result": result,
+            "rateLimits": [],
+        });
+        ws.send(Message::Text(response.to_string().into()))
+            .await
+            .unwrap();
+        let closed = async {
+            while let Some(Ok(msg)) = ws.next().await {
+                if msg.is_close() {
+                    break;
+                }
+            }
+        };
+        tokio::time::timeout(Duration::from_secs(5), closed)
+            .await
+            .is_ok()
+    }
+
+    #[cfg_attr(feature = "with_tokio", tokio::test)]
+    #[cfg_attr(
+        all(feature = "with_actix", not(feature = "with_tokio")),
+        actix_rt::test
+    )]
+    async fn test_request_round_trip() {
+        use crate::client::ApiCred;
+        use crate::client::Config;
+
+        // Both rustls providers are enabled in the dependency graph, so the awc connector
+        // can't pick one on its own.
+        #[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
+        let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("ws://{}/", listener.local_addr().unwrap())).unwrap();
+        let server = tokio::spawn(serve_once(
+            listener,
+            serde_json::json!({
+                "apiKey": null,
+                "authorizedSince": null,
+                "connectedSince": 1684805401000_u64,
+                "returnRateLimits": true,
+                "serverTime": 1684805401500_u64,
+            }),
+        ));
+
+        let config = Config::new(ApiCred::default(), url.clone(), url.clone(), None);
+        let client = WsApiClient::connect(RestClient::new(config), url)
+            .await
+            .unwrap();
+        let status = client.session_status().await.unwrap();
+        assert_eq!(status.api_key, None);
+        assert_eq!(status.connected_since, 1684805401000);
+
+        drop(client);
+        assert!(server.await.unwrap(), "the socket is left open");
+    }
+}
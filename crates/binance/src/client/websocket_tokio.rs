@@ -0,0 +1,270 @@
+use std::io;
+use std::time::Duration;
+
+use ccx_api_lib::Seq;
+use futures::SinkExt;
+use futures::StreamExt;
+use futures::channel::mpsc;
+use serde::de::DeserializeOwned;
+use tokio::net::TcpStream;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tokio::time::interval;
+use tokio_tungstenite::MaybeTlsStream;
+use tokio_tungstenite::WebSocketStream as TungsteniteStream;
+use tokio_tungstenite::client_async_tls;
+use tokio_tungstenite::tungstenite::Message;
+use url::Url;
+
+use crate::client::Proxy;
+use crate::client::RestClient;
+use crate::error::BinanceError;
+use crate::error::BinanceResult;
+use crate::ws_stream::UpstreamApiRequest;
+use crate::ws_stream::UpstreamWebsocketMessage;
+use crate::ws_stream::UserDataEvent;
+use crate::ws_stream::WsCommand;
+use crate::ws_stream::WsEvent;
+use crate::ws_stream::WsSubscription;
+
+/// How often heartbeat pings are sent.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+/// How long before lack of client response causes a timeout.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub(crate) type Socket = TungsteniteStream<MaybeTlsStream<TcpStream>>;
+
+enum Command {
+    Ws(WsCommand),
+    Close,
+}
+
+pub struct WebsocketStream {
+    tx: WebsocketStreamTx,
+    rx: mpsc::UnboundedReceiver<UpstreamWebsocketMessage<WsEvent>>,
+}
+
+#[derive(Clone)]
+pub struct WebsocketStreamTx {
+    command_tx: mpsc::UnboundedSender<Command>,
+}
+
+pub(crate) async fn connect(url: &Url, proxy: Option<&Proxy>) -> BinanceResult<Socket> {
+    log::debug!("Connecting WS: {}", url.as_str());
+
+    let stream = ccx_api_lib::connect_tcp(url, proxy).await?;
+    let (socket, response) = client_async_tls(url.as_str(), stream)
+        .await
+        .map_err(|e| BinanceError::IoError(io::Error::other(e)))?;
+    log::debug!("{:?}", response);
+    Ok(socket)
+}
+
+/// Connects and spawns the task forwarding the decoded server messages of type `T`.
+async fn start<T>(
+    url: Url,
+    proxy: Option<&Proxy>,
+) -> BinanceResult<(mpsc::UnboundedSender<Command>, mpsc::UnboundedReceiver<T>)>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let socket = connect(&url, proxy).await?;
+    let (command_tx, command_rx) = mpsc::unbounded();
+    let (tx, rx) = mpsc::unbounded();
+    tokio::spawn(run(socket, command_rx, tx));
+    Ok((command_tx, rx))
+}
+
+impl WebsocketStream {
+    pub async fn connect<S: crate::client::BinanceSigner>(
+        api_client: RestClient<S>,
+        url: Url,
+    ) -> BinanceResult<Self> {
+        let (command_tx, rx) = start(url, api_client.config().proxy.as_ref()).await?;
+        let tx = WebsocketStreamTx { command_tx };
+        Ok(WebsocketStream { tx, rx })
+    }
+
+    pub fn split(
+        self,
+    ) -> (
+        WebsocketStreamTx,
+        mpsc::UnboundedReceiver<UpstreamWebsocketMessage<WsEvent>>,
+    ) {
+        (self.tx, self.rx)
+    }
+}
+
+impl std::ops::Deref for WebsocketStream {
+    type Target = WebsocketStreamTx;
+
+    fn deref(&self) -> &Self::Target {
+        &self.tx
+    }
+}
+
+impl WebsocketStreamTx {
+    pub async fn subscribe_one(
+        &self,
+        subscription: impl Into<WsSubscription>,
+    ) -> BinanceResult<()> {
+        self.send(WsCommand::Subscribe1([subscription.into()]))
+    }
+
+    pub async fn subscribe_list(&self, subscriptions: Box<[WsSubscription]>) -> BinanceResult<()> {
+        self.send(WsCommand::Subscribe(subscriptions))
+    }
+
+    pub async fn unsubscribe_list(
+        &self,
+        subscriptions: Box<[WsSubscription]>,
+    ) -> BinanceResult<()> {
+        self.send(WsCommand::Unsubscribe(subscriptions))
+    }
+
+    /// Closes the connection; the receiving half of the stream ends.
+    pub fn close(&self) {
+        let _ = self.command_tx.unbounded_send(Command::Close);
+    }
+
+    fn send(&self, cmd: WsCommand) -> BinanceResult<()> {
+        self.command_tx
+            .unbounded_send(Command::Ws(cmd))
+            .map_err(|_e| BinanceError::IoError(io::ErrorKind::ConnectionAborted.into()))
+    }
+}
+
+/// User data stream connected with a listen key.
+///
+/// Yields the account and order events. The listen key keep-alive task attached to
+/// the stream is aborted when the stream is dropped.
+pub struct UserDataStream {
+    command_tx: mpsc::UnboundedSender<Command>,
+    rx: mpsc::UnboundedReceiver<UserDataEvent>,
+    keep_alive: Option<JoinHandle<()>>,
+}
+
+impl UserDataStream {
+    pub async fn connect<S: crate::client::BinanceSigner>(
+        api_client: RestClient<S>,
+        url: Url,
+        keep_alive: Option<JoinHandle<()>>,
+    ) -> BinanceResult<Self> {
+        let (command_tx, rx) = match start(url, api_client.config().proxy.as_ref()).await {
+            Ok(res) => res,
+            Err(e) => {
+                if let Some(keep_alive) = &keep_alive {
+                    keep_alive.abort();
+                }
+                Err(e)?
+            }
+        };
+        Ok(UserDataStream {
+            command_tx,
+            rx,
+            keep_alive,
+        })
+    }
+}
+
+impl futures::Stream for UserDataStream {
+    type Item = UserDataEvent;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
+impl Drop for UserDataStream {
+    fn drop(&mut self) {
+        let _ = self.command_tx.unbounded_send(Command::Close);
+        if let Some(keep_alive) = self.keep_alive.take() {
+            keep_alive.abort();
+        }
+    }
+}
+
+async fn run<T>(
+    socket: Socket,
+    mut command_rx: mpsc::UnboundedReceiver<Command>,
+    tx: mpsc::UnboundedSender<T>,
+) where
+    T: DeserializeOwned,
+{
+    let (mut sink, mut stream) = socket.split();
+    let mut heartbeat = interval(HEARTBEAT_INTERVAL);
+    let mut hb = Instant::now();
+    let mut id_seq = Seq::<u64>::new();
+    // The connection outlives the senders as long as the events are received.
+    let mut commands_open = true;
+
+    loop {
+        tokio::select! {
+            msg = stream.next() => match msg {
+                Some(Ok(Message::Text(text))) => {
+                    match serde_json::from_str(&text) {
+                        Err(e) => {
+                            log::error!("json message from server: {}", text);
+                            log::error!("Failed to deserialize server message: {:?}", e);
+                        }
+                        Ok(msg) => {
+                            if let Err(e) = tx.unbounded_send(msg) {
+                                log::warn!("Failed to notify downstream: {:?}", e);
+                                break;
+                            }
+                        }
+                    }
+                }
+                Some(Ok(Message::Binary(_))) => {
+                    log::warn!("unexpected binary message (ignored)");
+                }
+                Some(Ok(Message::Ping(data))) => {
+                    hb = Instant::now();
+                    if sink.send(Message::Pong(data)).await.is_err() {
+                        log::warn!("Failed to send Pong. Disconnecting.");
+                        break;
+                    }
+                }
+                Some(Ok(Message::Pong(_))) => {
+                    hb = Instant::now();
+                }
+                Some(Ok(Message::Frame(_))) => {}
+                Some(Ok(Message::Close(_))) | None => break,
+                Some(Err(e)) => {
+                    log::warn!("WebSocket broken: {:?}", e);
+                    break;
+                }
+            },
+            cmd = command_rx.next(), if commands_open => match cmd {
+                Some(Command::Ws(cmd)) => {
+                    let msg = UpstreamApiRequest {
+                        id: id_seq.next(),
+                        payload: cmd,
+                    };
+                    let msg = serde_json::to_string(&msg).expect("json encode");
+                    log::debug!("Sending to server: `{}`", msg);
+                    if sink.send(Message::Text(msg.into())).await.is_err() {
+                        break;
+                    }
+                }
+                Some(Command::Close) => break,
+                None => commands_open = false,
+            },
+            _ = heartbeat.tick() => {
+                if Instant::now().duration_since(hb) > CLIENT_TIMEOUT {
+                    log::warn!("Websocket client heartbeat failed, disconnecting!");
+                    break;
+                }
+                if sink.send(Message::Ping(Default::default())).await.is_err() {
+                    log::warn!("Websocket client failed to send ping, stopping!");
+                    break;
+                }
+            }
+        }
+    }
+
+    let _ = sink.close().await;
+}
@@ -1,25 +1,323 @@
 use std::borrow::Cow;
+use std::fmt;
+use std::time::Duration;
 
 pub use ccx_api_lib::*;
+use serde::Deserialize;
+use serde::Serialize;
 use thiserror::Error;
 
+use crate::api::spot::CancelReplaceOrder;
+
 #[derive(Clone, Debug, Error)]
 pub enum ApiError {
     #[error("Unauthorized")]
     Unauthorized,
     #[error("Mandatory field(s) omitted: {0}")]
     MandatoryFieldOmitted(Cow<'static, str>),
+    #[error("Field(s) sent when not required: {0}")]
+    FieldNotRequired(Cow<'static, str>),
     #[error("Argument is out of bounds")]
     OutOfBounds,
+    /// The request was rejected by the exchange with an error code.
+    ///
+    /// `retry_after` is set when the IP is rate limited (418 and 429 responses).
+    #[error("Request rejected: {status} {code} {msg}")]
+    Rejected {
+        status: u16,
+        code: BinanceErrorCode,
+        msg: String,
+        retry_after: Option<Duration>,
+    },
+    /// An order book diff doesn't follow the previous update; the book needs a new snapshot.
+    #[error("Order book update out of sequence: expected {expected}, got {first_update_id}")]
+    OrderBookOutOfSequence { expected: u64, first_update_id: u64 },
+    #[error("Cancel-replace failed: {code} {msg}")]
+    CancelReplaceFailed {
+        code: BinanceErrorCode,
+        msg: String,
+        data: Box<CancelReplaceOrder>,
+    },
 }
 
 impl ApiError {
     pub fn mandatory_field_omitted(field: impl Into<Cow<'static, str>>) -> Self {
         ApiError::MandatoryFieldOmitted(field.into())
     }
+
+    pub fn field_not_required(field: impl Into<Cow<'static, str>>) -> Self {
+        ApiError::FieldNotRequired(field.into())
+    }
+
+    /// The error code the exchange rejected the request with.
+    pub fn error_code(&self) -> Option<BinanceErrorCode> {
+        match self {
+            ApiError::Rejected { code, .. } | ApiError::CancelReplaceFailed { code, .. } => {
+                Some(*code)
+            }
+            _ => None,
+        }
+    }
+
+    /// How long to back off before sending requests again.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ApiError::Rejected { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+/// Error codes returned by the exchange along with the error message.
+///
+/// More information: [Error Codes](https://developers.binance.com/docs/binance-spot-api-docs/errors)
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+#[serde(from = "i64", into = "i64")]
+pub enum BinanceErrorCode {
+    /// An unknown error occurred while processing the request.
+    Unknown,
+    /// Internal error; unable to process your request. Please try again.
+    Disconnected,
+    /// You are not authorized to execute this request.
+    Unauthorized,
+    /// Too many requests queued or the request weight limit is exceeded.
+    TooManyRequests,
+    /// An unexpected response was received from the message bus. Execution status unknown.
+    UnexpectedResponse,
+    /// Timeout waiting for response from backend server. Execution status unknown.
+    Timeout,
+    /// Server is currently overloaded with other requests. Please try again in a few minutes.
+    ServerBusy,
+    /// The request is invalid, e.g. it failed a symbol filter
+    /// (`Filter failure: PRICE_FILTER`, `Filter failure: LOT_SIZE`, etc.).
+    FilterFailure,
+    /// Unsupported order combination.
+    UnknownOrderComposition,
+    /// Too many new orders; the order rate limit is exceeded.
+    TooManyOrders,
+    /// This service is no longer available.
+    ServiceShuttingDown,
+    /// This operation is not supported.
+    UnsupportedOperation,
+    /// Timestamp for this request is outside of the recvWindow
+    /// or was 1000ms ahead of the server's time.
+    InvalidTimestamp,
+    /// Signature for this request is not valid.
+    InvalidSignature,
+    /// Illegal characters found in a parameter.
+    IllegalChars,
+    /// Too many parameters sent for this endpoint.
+    TooManyParameters,
+    /// A mandatory parameter was not sent, was empty/null, or malformed.
+    MandatoryParamEmptyOrMalformed,
+    /// An unknown parameter was sent.
+    UnknownParam,
+    /// Not all sent parameters were read.
+    UnreadParameters,
+    /// A parameter was empty.
+    ParamEmpty,
+    /// A parameter was sent when not required.
+    ParamNotRequired,
+    /// Precision is over the maximum defined for this asset.
+    BadPrecision,
+    /// No orders on book for symbol.
+    NoDepth,
+    /// `timeInForce` parameter sent when not required.
+    TifNotRequired,
+    /// Invalid `timeInForce`.
+    InvalidTif,
+    /// Invalid `orderType`.
+    InvalidOrderType,
+    /// Invalid `side`.
+    InvalidSide,
+    /// New client order ID was empty.
+    EmptyNewClientOrderId,
+    /// Original client order ID was empty.
+    EmptyOrigClientOrderId,
+    /// Invalid interval.
+    BadInterval,
+    /// Invalid symbol.
+    BadSymbol,
+    /// This listenKey does not exist.
+    InvalidListenKey,
+    /// Lookup interval is too big.
+    MoreThanXxHours,
+    /// Combination of optional parameters invalid.
+    OptionalParamsBadCombo,
+    /// Invalid data sent for a parameter.
+    InvalidParameter,
+    /// The new order was rejected, most commonly with
+    /// `Account has insufficient balance for requested action.`
+    NewOrderRejected,
+    /// The cancel request was rejected, e.g. the order is unknown or already filled.
+    CancelRejected,
+    /// Order does not exist.
+    NoSuchOrder,
+    /// API-key format invalid.
+    BadApiKeyFormat,
+    /// Invalid API-key, IP, or permissions for action.
+    RejectedApiKey,
+    /// No trading window could be found for the symbol.
+    NoTradingWindow,
+    /// Futures: margin is insufficient.
+    MarginInsufficient,
+    /// Futures: the reduce-only order was rejected.
+    ReduceOnlyRejected,
+    /// Order was canceled or expired with no executed qty over 90 days ago and has been archived.
+    OrderArchived,
+    /// A code without a typed representation.
+    Other(i64),
+}
+
+impl BinanceErrorCode {
+    pub fn code(self) -> i64 {
+        use BinanceErrorCode as C;
+
+        match self {
+            C::Unknown => -1000,
+            C::Disconnected => -1001,
+            C::Unauthorized => -1002,
+            C::TooManyRequests => -1003,
+            C::UnexpectedResponse => -1006,
+            C::Timeout => -1007,
+            C::ServerBusy => -1008,
+            C::FilterFailure => -1013,
+            C::UnknownOrderComposition => -1014,
+            C::TooManyOrders => -1015,
+            C::ServiceShuttingDown => -1016,
+            C::UnsupportedOperation => -1020,
+            C::InvalidTimestamp => -1021,
+            C::InvalidSignature => -1022,
+            C::IllegalChars => -1100,
+            C::TooManyParameters => -1101,
+            C::MandatoryParamEmptyOrMalformed => -1102,
+            C::UnknownParam => -1103,
+            C::UnreadParameters => -1104,
+            C::ParamEmpty => -1105,
+            C::ParamNotRequired => -1106,
+            C::BadPrecision => -1111,
+            C::NoDepth => -1112,
+            C::TifNotRequired => -1114,
+            C::InvalidTif => -1115,
+            C::InvalidOrderType => -1116,
+            C::InvalidSide => -1117,
+            C::EmptyNewClientOrderId => -1118,
+            C::EmptyOrigClientOrderId => -1119,
+            C::BadInterval => -1120,
+            C::BadSymbol => -1121,
+            C::InvalidListenKey => -1125,
+            C::MoreThanXxHours => -1127,
+            C::OptionalParamsBadCombo => -1128,
+            C::InvalidParameter => -1130,
+            C::NewOrderRejected => -2010,
+            C::CancelRejected => -2011,
+            C::NoSuchOrder => -2013,
+            C::BadApiKeyFormat => -2014,
+            C::RejectedApiKey => -2015,
+            C::NoTradingWindow => -2016,
+            C::MarginInsufficient => -2019,
+            C::ReduceOnlyRejected => -2022,
+            C::OrderArchived => -2026,
+            C::Other(code) => code,
+        }
+    }
+
+    /// Whether the request may have been executed despite the error.
+    pub fn is_execution_status_unknown(self) -> bool {
+        matches!(
+            self,
+            BinanceErrorCode::UnexpectedResponse | BinanceErrorCode::Timeout
+        )
+    }
+}
+
+impl From<i64> for BinanceErrorCode {
+    fn from(code: i64) -> Self {
+        use BinanceErrorCode as C;
+
+        match code {
+            -1000 => C::Unknown,
+            -1001 => C::Disconnected,
+            -1002 => C::Unauthorized,
+            -1003 => C::TooManyRequests,
+            -1006 => C::UnexpectedResponse,
+            -1007 => C::Timeout,
+            -1008 => C::ServerBusy,
+            -1013 => C::FilterFailure,
+            -1014 => C::UnknownOrderComposition,
+            -1015 => C::TooManyOrders,
+            -1016 => C::ServiceShuttingDown,
+            -1020 => C::UnsupportedOperation,
+            -1021 => C::InvalidTimestamp,
+            -1022 => C::InvalidSignature,
+            -1100 => C::IllegalChars,
+            -1101 => C::TooManyParameters,
+            -1102 => C::MandatoryParamEmptyOrMalformed,
+            -1103 => C::UnknownParam,
+            -1104 => C::UnreadParameters,
+            -1105 => C::ParamEmpty,
+            -1106 => C::ParamNotRequired,
+            -1111 => C::BadPrecision,
+            -1112 => C::NoDepth,
+            -1114 => C::TifNotRequired,
+            -1115 => C::InvalidTif,
+            -1116 => C::InvalidOrderType,
+            -1117 => C::InvalidSide,
+            -1118 => C::EmptyNewClientOrderId,
+            -1119 => C::EmptyOrigClientOrderId,
+            -1120 => C::BadInterval,
+            -1121 => C::BadSymbol,
+            -1125 => C::InvalidListenKey,
+            -1127 => C::MoreThanXxHours,
+            -1128 => C::OptionalParamsBadCombo,
+            -1130 => C::InvalidParameter,
+            -2010 => C::NewOrderRejected,
+            -2011 => C::CancelRejected,
+            -2013 => C::NoSuchOrder,
+            -2014 => C::BadApiKeyFormat,
+            -2015 => C::RejectedApiKey,
+            -2016 => C::NoTradingWindow,
+            -2019 => C::MarginInsufficient,
+            -2022 => C::ReduceOnlyRejected,
+            -2026 => C::OrderArchived,
+            code => C::Other(code),
+        }
+    }
+}
+
+impl From<BinanceErrorCode> for i64 {
+    fn from(code: BinanceErrorCode) -> Self {
+        code.code()
+    }
+}
+
+impl fmt::Display for BinanceErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
 }
 
 impl CcxApiError for ApiError {}
 
 pub type BinanceResult<T> = ccx_api_lib::LibResult<T, ApiError>;
 pub type BinanceError = ccx_api_lib::LibError<ApiError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_code_round_trip() {
+        for code in [-1013, -1021, -2010, -4164] {
+            assert_eq!(BinanceErrorCode::from(code).code(), code);
+        }
+        assert_eq!(
+            BinanceErrorCode::from(-1021),
+            BinanceErrorCode::InvalidTimestamp
+        );
+        assert_eq!(
+            BinanceErrorCode::from(-4164),
+            BinanceErrorCode::Other(-4164)
+        );
+    }
+}
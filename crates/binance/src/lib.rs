@@ -1,5 +1,11 @@
 #![allow(warnings)]
 
+#[cfg(all(
+    feature = "with_network",
+    not(any(feature = "with_actix", feature = "with_tokio"))
+))]
+compile_error!("`with_network` needs a WebSocket backend: enable `with_actix` or `with_tokio`");
+
 pub mod api;
 #[cfg(feature = "with_network")]
 pub mod client;
@@ -16,8 +22,11 @@ pub use self::with_network::*;
 
 #[cfg(feature = "with_network")]
 mod with_network {
+    pub use super::api::cm::CmApi;
     pub use super::api::spot::SpotApi;
     pub use super::api::um::UmApi;
+    pub use super::client::Ed25519ApiCred;
+    pub use super::client::RsaApiCred;
 }
 
 pub use ccx_api_lib;
@@ -56,6 +56,29 @@ impl ChartInterval {
             Month1 => "1M",
         }
     }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        use ChartInterval::*;
+        Some(match s {
+            "1m" => Minute1,
+            "3m" => Minute3,
+            "5m" => Minute5,
+            "15m" => Minute15,
+            "30m" => Minute30,
+            "1h" => Hour1,
+            "2h" => Hour2,
+            "4h" => Hour4,
+            "6h" => Hour6,
+            "8h" => Hour8,
+            "12h" => Hour12,
+            "1d" => Day1,
+            "3d" => Day3,
+            "1w" => Week1,
+            "1M" => Month1,
+            _ => None?,
+        })
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
@@ -80,6 +103,47 @@ impl OrderBookStreamLimit {
             N20 => "20",
         }
     }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        use OrderBookStreamLimit::*;
+        Some(match s {
+            "5" => N5,
+            "10" => N10,
+            "20" => N20,
+            _ => None?,
+        })
+    }
+}
+
+/// Window size of the rolling window ticker streams.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum TickerWindow {
+    Hour1,
+    Hour4,
+    Day1,
+}
+
+impl TickerWindow {
+    pub fn as_str(self) -> &'static str {
+        use TickerWindow::*;
+        match self {
+            Hour1 => "1h",
+            Hour4 => "4h",
+            Day1 => "1d",
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        use TickerWindow::*;
+        Some(match s {
+            "1h" => Hour1,
+            "4h" => Hour4,
+            "1d" => Day1,
+            _ => None?,
+        })
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
@@ -122,6 +186,13 @@ pub enum TransferKind {
     #[serde(rename = "MARGIN_UMFUTURE")]
     MarginUmFuture, // Margin（cross）account transfer to USDⓈ-M Futures
 
+    #[serde(rename = "MARGIN_ISOLATEDMARGIN")]
+    MarginIsolatedMargin, // Margin（cross）account transfer to Isolated margin account
+    #[serde(rename = "ISOLATEDMARGIN_MARGIN")]
+    IsolatedMarginMargin, // Isolated margin account transfer to Margin（cross）account
+    #[serde(rename = "ISOLATEDMARGIN_ISOLATEDMARGIN")]
+    IsolatedMarginIsolatedMargin, // Isolated margin account transfer to Isolated margin account
+
     #[serde(rename = "MINING_MAIN")]
     MiningMain, // Mining account transfer to Spot account
     #[serde(rename = "MINING_UMFUTURE")]
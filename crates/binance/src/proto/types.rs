@@ -1,6 +1,9 @@
 use std::ops;
 
+use ccx_api_lib::serde_util::value_buffered;
 use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::ApiError;
 use crate::BinanceResult;
@@ -64,3 +67,49 @@ impl RecvWindow {
         self == Self::DEFAULT
     }
 }
+
+/// A part of a compound request reported either as the regular response on success
+/// or as a `{code, msg}` error object on failure.
+#[derive(Debug, Serialize, Clone)]
+#[serde(untagged)]
+pub enum SuccessOrFailure<T> {
+    Success(T),
+    Failure { code: i64, msg: String },
+}
+
+impl<T> SuccessOrFailure<T> {
+    pub fn is_success(&self) -> bool {
+        matches!(self, SuccessOrFailure::Success(_))
+    }
+
+    pub fn success(self) -> Option<T> {
+        match self {
+            SuccessOrFailure::Success(v) => Some(v),
+            SuccessOrFailure::Failure { .. } => None,
+        }
+    }
+}
+
+impl<'de, T> Deserialize<'de> for SuccessOrFailure<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Failure {
+            code: i64,
+            msg: String,
+        }
+
+        let value = value_buffered::buffer(deserializer)?;
+        if value.get("code").is_some() {
+            let Failure { code, msg } = value_buffered::from_value(value)?;
+            Ok(SuccessOrFailure::Failure { code, msg })
+        } else {
+            value_buffered::from_value(value).map(SuccessOrFailure::Success)
+        }
+    }
+}
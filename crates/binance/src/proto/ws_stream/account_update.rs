@@ -1,18 +1,38 @@
-//#[derive(Debug, Serialize, Deserialize, Clone)]
-//#[serde(rename_all = "camelCase")]
-//pub struct AccountUpdateEvent {
-//    #[serde(rename = "e")] pub event_type: String,
-//
-//    #[serde(rename = "E")] pub event_time: u64,
-//
-//    m: u64,
-//    t: u64,
-//    b: u64,
-//    s: u64,
-//
-//    #[serde(rename = "T")] t_ignore: bool,
-//    #[serde(rename = "W")] w_ignore: bool,
-//    #[serde(rename = "D")] d_ignore: bool,
-//
-//    #[serde(rename = "B")] pub balance: Vec<EventBalance>,
-//}
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Atom;
+use crate::Decimal;
+
+/// `outboundAccountPosition` event.
+///
+/// Sent any time an account balance has changed and contains the assets that were
+/// possibly changed by the event that generated the balance change.
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+pub struct OutboundAccountPositionEvent {
+    /// Event type.
+    #[serde(skip, rename = "e")]
+    pub event_type: (),
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// Time of last account update.
+    #[serde(rename = "u")]
+    pub last_update_time: u64,
+    /// Balances array.
+    #[serde(rename = "B")]
+    pub balances: Vec<AccountPositionBalance>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+pub struct AccountPositionBalance {
+    /// Asset.
+    #[serde(rename = "a")]
+    pub asset: Atom,
+    /// Free.
+    #[serde(rename = "f")]
+    pub free: Decimal,
+    /// Locked.
+    #[serde(rename = "l")]
+    pub locked: Decimal,
+}
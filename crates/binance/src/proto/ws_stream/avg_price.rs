@@ -0,0 +1,25 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Atom;
+use crate::Decimal;
+
+/// Average price changes over a fixed time interval.
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+pub struct AvgPriceEvent {
+    #[serde(skip, rename = "e")]
+    pub event_type: (),
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: Atom,
+    /// Average price interval, e.g. `5m`.
+    #[serde(rename = "i")]
+    pub interval: String,
+    /// Average price.
+    #[serde(rename = "w")]
+    pub price: Decimal,
+    /// Last trade time.
+    #[serde(rename = "T")]
+    pub last_trade_time: u64,
+}
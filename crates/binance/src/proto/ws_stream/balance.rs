@@ -1,8 +1,28 @@
-//
-//#[derive(Debug, Serialize, Deserialize, Clone)]
-//#[serde(rename_all = "camelCase")]
-//pub struct EventBalance {
-//    #[serde(rename = "a")] pub asset: String,
-//    #[serde(rename = "f")] pub free: String,
-//    #[serde(rename = "l")] pub locked: String,
-//}
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Atom;
+use crate::Decimal;
+
+/// `balanceUpdate` event.
+///
+/// Occurs during deposits or withdrawals from the account and transfers of funds between
+/// accounts (e.g. spot to margin).
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+pub struct BalanceUpdateEvent {
+    /// Event type.
+    #[serde(skip, rename = "e")]
+    pub event_type: (),
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// Asset.
+    #[serde(rename = "a")]
+    pub asset: Atom,
+    /// Balance delta.
+    #[serde(rename = "d")]
+    pub delta: Decimal,
+    /// Clear time.
+    #[serde(rename = "T")]
+    pub clear_time: u64,
+}
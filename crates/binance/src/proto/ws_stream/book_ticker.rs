@@ -0,0 +1,23 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Atom;
+use crate::Decimal;
+
+/// Any update to the best bid or ask's price or quantity in real-time for a specified symbol.
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+pub struct BookTickerEvent {
+    /// Order book updateId.
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    #[serde(rename = "s")]
+    pub symbol: Atom,
+    #[serde(rename = "b")]
+    pub best_bid_price: Decimal,
+    #[serde(rename = "B")]
+    pub best_bid_qty: Decimal,
+    #[serde(rename = "a")]
+    pub best_ask_price: Decimal,
+    #[serde(rename = "A")]
+    pub best_ask_qty: Decimal,
+}
@@ -1,37 +1,59 @@
+use std::fmt;
+
 use serde::Deserialize;
 use serde::Serialize;
 use string_cache::DefaultAtom as Atom;
 
+use crate::ChartInterval;
+use crate::OrderBookStreamLimit;
+use crate::TickerWindow;
+
 mod account_update;
 mod agg_trade;
+mod avg_price;
 mod balance;
+mod book_ticker;
 mod day_ticker;
 mod kline;
 mod mini_ticker;
 mod order_trade;
 mod orderbook_diff;
+mod partial_depth;
 mod ticker;
 mod trade;
+mod user_data;
 mod ws_kline;
 
 pub use account_update::*;
 pub use agg_trade::*;
+pub use avg_price::*;
 pub use balance::*;
+pub use book_ticker::*;
 pub use day_ticker::*;
 pub use kline::*;
 pub use mini_ticker::*;
 pub use order_trade::*;
 pub use orderbook_diff::*;
+pub use partial_depth::*;
 pub use ticker::*;
 pub use trade::*;
+pub use user_data::*;
 pub use ws_kline::*;
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum WsEvent {
     AggTrade(AggTradeEvent),
     OrderBookDiff(OrderBookDiffEvent),
-    Kline(KlineEvent),
+    PartialDepth(PartialDepthEvent),
+    Kline(Box<KlineEvent>),
     Trade(TradeEvent),
+    MiniTicker(MiniTickerEvent),
+    AllMiniTickers(Vec<MiniTickerEvent>),
+    Ticker(Box<TickerEvent>),
+    RollingWindowTicker(Box<RollingWindowTickerEvent>),
+    AllRollingWindowTickers(Vec<RollingWindowTickerEvent>),
+    BookTicker(BookTickerEvent),
+    AvgPrice(AvgPriceEvent),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
@@ -56,6 +78,19 @@ impl WsSubscription {
         let market = market.into();
         WsSubscription { market, stream }
     }
+
+    /// Subscription to an all-markets stream like [`WsStream::AllMiniTickers`].
+    pub fn all_markets(stream: WsStream) -> Self {
+        WsSubscription::new("", stream)
+    }
+
+    pub fn market(&self) -> &Atom {
+        &self.market
+    }
+
+    pub fn stream(&self) -> WsStream {
+        self.stream
+    }
 }
 
 impl<A> From<(A, WsStream)> for WsSubscription
@@ -69,35 +104,132 @@ where
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum WsStream {
+    /// `<symbol>@aggTrade`
+    AggTrade,
+    /// `<symbol>@trade`
+    Trade,
+    /// `<symbol>@kline_<interval>`
+    Kline(ChartInterval),
+    /// `<symbol>@miniTicker`
+    MiniTicker,
+    /// `!miniTicker@arr`; not bound to a market.
+    AllMiniTickers,
+    /// `<symbol>@ticker`
+    Ticker,
+    /// `<symbol>@ticker_<window>`
+    RollingWindowTicker(TickerWindow),
+    /// `!ticker_<window>@arr`; not bound to a market.
+    AllRollingWindowTickers(TickerWindow),
+    /// `<symbol>@bookTicker`
+    BookTicker,
+    /// `<symbol>@depth`
     Depth,
+    /// `<symbol>@depth@100ms`
     Depth100ms,
-    Trade,
+    /// `<symbol>@depth<levels>`
+    PartialDepth(OrderBookStreamLimit),
+    /// `<symbol>@depth<levels>@100ms`
+    PartialDepth100ms(OrderBookStreamLimit),
+    /// `<symbol>@avgPrice`
+    AvgPrice,
 }
 
 impl WsStream {
+    const AGG_TRADE: &'static str = "aggTrade";
+    const TRADE: &'static str = "trade";
+    const KLINE_PREFIX: &'static str = "kline_";
+    const MINI_TICKER: &'static str = "miniTicker";
+    const ALL_MINI_TICKERS: &'static str = "!miniTicker@arr";
+    const TICKER: &'static str = "ticker";
+    const TICKER_PREFIX: &'static str = "ticker_";
+    const ALL_TICKERS_PREFIX: &'static str = "!ticker_";
+    const ALL_SUFFIX: &'static str = "@arr";
+    const BOOK_TICKER: &'static str = "bookTicker";
     const DEPTH: &'static str = "depth";
     const DEPTH_100MS: &'static str = "depth@100ms";
-    const TRADE: &'static str = "trade";
-
-    pub fn as_str(self) -> &'static str {
-        match self {
-            WsStream::Depth => Self::DEPTH,
-            WsStream::Depth100ms => Self::DEPTH_100MS,
-            WsStream::Trade => Self::TRADE,
-        }
+    const SUFFIX_100MS: &'static str = "@100ms";
+    const AVG_PRICE: &'static str = "avgPrice";
+
+    /// Whether the stream covers all markets and is subscribed without a market prefix.
+    pub fn is_all_markets(self) -> bool {
+        matches!(
+            self,
+            WsStream::AllMiniTickers | WsStream::AllRollingWindowTickers(_)
+        )
     }
 
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         Some(match s {
+            Self::AGG_TRADE => Self::AggTrade,
+            Self::TRADE => Self::Trade,
+            Self::MINI_TICKER => Self::MiniTicker,
+            Self::ALL_MINI_TICKERS => Self::AllMiniTickers,
+            Self::TICKER => Self::Ticker,
+            Self::BOOK_TICKER => Self::BookTicker,
             Self::DEPTH => Self::Depth,
             Self::DEPTH_100MS => Self::Depth100ms,
-            Self::TRADE => Self::Trade,
-            _ => None?,
+            Self::AVG_PRICE => Self::AvgPrice,
+            _ => {
+                if let Some(interval) = s.strip_prefix(Self::KLINE_PREFIX) {
+                    Self::Kline(ChartInterval::from_str(interval)?)
+                } else if let Some(window) = s.strip_prefix(Self::TICKER_PREFIX) {
+                    Self::RollingWindowTicker(TickerWindow::from_str(window)?)
+                } else if let Some(window) = s.strip_prefix(Self::ALL_TICKERS_PREFIX) {
+                    let window = window.strip_suffix(Self::ALL_SUFFIX)?;
+                    Self::AllRollingWindowTickers(TickerWindow::from_str(window)?)
+                } else if let Some(levels) = s.strip_prefix(Self::DEPTH) {
+                    match levels.strip_suffix(Self::SUFFIX_100MS) {
+                        Some(levels) => {
+                            Self::PartialDepth100ms(OrderBookStreamLimit::from_str(levels)?)
+                        }
+                        None => Self::PartialDepth(OrderBookStreamLimit::from_str(levels)?),
+                    }
+                } else {
+                    None?
+                }
+            }
         })
     }
 }
 
+impl fmt::Display for WsStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            WsStream::AggTrade => f.write_str(Self::AGG_TRADE),
+            WsStream::Trade => f.write_str(Self::TRADE),
+            WsStream::Kline(interval) => {
+                write!(f, "{}{}", Self::KLINE_PREFIX, interval.as_str())
+            }
+            WsStream::MiniTicker => f.write_str(Self::MINI_TICKER),
+            WsStream::AllMiniTickers => f.write_str(Self::ALL_MINI_TICKERS),
+            WsStream::Ticker => f.write_str(Self::TICKER),
+            WsStream::RollingWindowTicker(window) => {
+                write!(f, "{}{}", Self::TICKER_PREFIX, window.as_str())
+            }
+            WsStream::AllRollingWindowTickers(window) => write!(
+                f,
+                "{}{}{}",
+                Self::ALL_TICKERS_PREFIX,
+                window.as_str(),
+                Self::ALL_SUFFIX
+            ),
+            WsStream::BookTicker => f.write_str(Self::BOOK_TICKER),
+            WsStream::Depth => f.write_str(Self::DEPTH),
+            WsStream::Depth100ms => f.write_str(Self::DEPTH_100MS),
+            WsStream::PartialDepth(levels) => write!(f, "{}{}", Self::DEPTH, levels.as_str()),
+            WsStream::PartialDepth100ms(levels) => write!(
+                f,
+                "{}{}{}",
+                Self::DEPTH,
+                levels.as_str(),
+                Self::SUFFIX_100MS
+            ),
+            WsStream::AvgPrice => f.write_str(Self::AVG_PRICE),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct UpstreamApiRequest<T> {
     pub id: u64,
@@ -155,6 +287,7 @@ mod deser {
     use serde::de::Visitor;
     use serde::de::{self};
 
+    use super::PartialDepthEvent;
     use super::WsEvent;
     use super::WsStream;
     use super::WsSubscription;
@@ -164,10 +297,14 @@ mod deser {
         where
             S: Serializer,
         {
+            use std::fmt::Write;
+
             let mut buffer = String::with_capacity(32);
-            buffer.push_str(&self.market);
-            buffer.push('@');
-            buffer.push_str(self.stream.as_str());
+            if !self.stream.is_all_markets() {
+                buffer.push_str(&self.market);
+                buffer.push('@');
+            }
+            write!(buffer, "{}", self.stream).map_err(serde::ser::Error::custom)?;
             serializer.serialize_str(&buffer)
         }
     }
@@ -195,6 +332,10 @@ mod deser {
             E: de::Error,
         {
             let parse = |s: &str| -> Option<Self::Value> {
+                if s.starts_with('!') {
+                    let stream = WsStream::from_str(s)?;
+                    return Some(WsSubscription::all_markets(stream));
+                }
                 let n = s.find('@')?;
                 let stream = WsStream::from_str(&s[n + 1..])?;
                 let market = s[..n].into();
@@ -275,7 +416,7 @@ mod deser {
                             return Err(de::Error::duplicate_field(WsEventField::STREAM));
                         }
                         let x: WsSubscription = map.next_value()?;
-                        stream = Some(x.stream);
+                        stream = Some(x);
                     }
                     WsEventField::Data => {
                         if result.is_some() {
@@ -284,11 +425,29 @@ mod deser {
                         let stream = stream
                             .as_ref()
                             .ok_or_else(|| de::Error::missing_field(WsEventField::STREAM))?;
-                        result = Some(match stream {
+                        result = Some(match stream.stream {
                             WsStream::Depth | WsStream::Depth100ms => {
                                 WsEvent::OrderBookDiff(map.next_value()?)
                             }
+                            WsStream::PartialDepth(_) | WsStream::PartialDepth100ms(_) => {
+                                let mut event: PartialDepthEvent = map.next_value()?;
+                                event.market = stream.market.clone();
+                                WsEvent::PartialDepth(event)
+                            }
+                            WsStream::AggTrade => WsEvent::AggTrade(map.next_value()?),
                             WsStream::Trade => WsEvent::Trade(map.next_value()?),
+                            WsStream::Kline(_) => WsEvent::Kline(map.next_value()?),
+                            WsStream::MiniTicker => WsEvent::MiniTicker(map.next_value()?),
+                            WsStream::AllMiniTickers => WsEvent::AllMiniTickers(map.next_value()?),
+                            WsStream::Ticker => WsEvent::Ticker(map.next_value()?),
+                            WsStream::RollingWindowTicker(_) => {
+                                WsEvent::RollingWindowTicker(map.next_value()?)
+                            }
+                            WsStream::AllRollingWindowTickers(_) => {
+                                WsEvent::AllRollingWindowTickers(map.next_value()?)
+                            }
+                            WsStream::BookTicker => WsEvent::BookTicker(map.next_value()?),
+                            WsStream::AvgPrice => WsEvent::AvgPrice(map.next_value()?),
                         });
                     }
                 }
@@ -362,4 +521,147 @@ mod tests {
 
         let _res = serde_json::from_str::<UpstreamWebsocketMessage<WsEvent>>(input).unwrap();
     }
+
+    #[test]
+    fn subscription_names_round_trip() {
+        let cases = [
+            (
+                WsSubscription::new("bnbbtc", WsStream::AggTrade),
+                "bnbbtc@aggTrade",
+            ),
+            (
+                WsSubscription::new("bnbbtc", WsStream::Kline(ChartInterval::Minute15)),
+                "bnbbtc@kline_15m",
+            ),
+            (
+                WsSubscription::all_markets(WsStream::AllMiniTickers),
+                "!miniTicker@arr",
+            ),
+            (
+                WsSubscription::new("bnbbtc", WsStream::RollingWindowTicker(TickerWindow::Hour4)),
+                "bnbbtc@ticker_4h",
+            ),
+            (
+                WsSubscription::all_markets(WsStream::AllRollingWindowTickers(TickerWindow::Day1)),
+                "!ticker_1d@arr",
+            ),
+            (
+                WsSubscription::new("bnbbtc", WsStream::BookTicker),
+                "bnbbtc@bookTicker",
+            ),
+            (
+                WsSubscription::new("bnbbtc", WsStream::PartialDepth(OrderBookStreamLimit::N10)),
+                "bnbbtc@depth10",
+            ),
+            (
+                WsSubscription::new(
+                    "bnbbtc",
+                    WsStream::PartialDepth100ms(OrderBookStreamLimit::N20),
+                ),
+                "bnbbtc@depth20@100ms",
+            ),
+            (
+                WsSubscription::new("bnbbtc", WsStream::Depth100ms),
+                "bnbbtc@depth@100ms",
+            ),
+            (
+                WsSubscription::new("bnbbtc", WsStream::AvgPrice),
+                "bnbbtc@avgPrice",
+            ),
+        ];
+        for (subscription, name) in cases {
+            let json = serde_json::to_string(&subscription).unwrap();
+            assert_eq!(json, format!("\"{name}\""));
+            let decoded: WsSubscription = serde_json::from_str(&json).unwrap();
+            assert_eq!(decoded, subscription);
+        }
+    }
+
+    #[test]
+    fn decode_partial_depth_event() {
+        let input = r#"{
+            "stream": "bnbbtc@depth5@100ms",
+            "data": {
+                "lastUpdateId": 160,
+                "bids": [["0.0024", "10"]],
+                "asks": [["0.0026", "100"]]
+            }
+        }"#;
+        let res = serde_json::from_str::<WsEvent>(input).unwrap();
+        let WsEvent::PartialDepth(event) = res else {
+            panic!("unexpected event: {res:?}");
+        };
+        assert_eq!(event.market, Atom::from("bnbbtc"));
+        assert_eq!(event.last_update_id, 160);
+    }
+
+    #[test]
+    fn decode_all_mini_tickers_event() {
+        let input = r#"{
+            "stream": "!miniTicker@arr",
+            "data": [{
+                "e": "24hrMiniTicker",
+                "E": 1672515782136,
+                "s": "BNBBTC",
+                "c": "0.0025",
+                "o": "0.0010",
+                "h": "0.0025",
+                "l": "0.0010",
+                "v": "10000",
+                "q": "18"
+            }]
+        }"#;
+        let res = serde_json::from_str::<WsEvent>(input).unwrap();
+        assert!(matches!(res, WsEvent::AllMiniTickers(v) if v.len() == 1));
+    }
+
+    #[test]
+    fn decode_kline_event() {
+        let input = r#"{
+            "stream": "bnbbtc@kline_1m",
+            "data": {
+                "e": "kline",
+                "E": 1672515782136,
+                "s": "BNBBTC",
+                "k": {
+                    "t": 1672515780000,
+                    "T": 1672515839999,
+                    "s": "BNBBTC",
+                    "i": "1m",
+                    "f": 3717726327,
+                    "L": 3717726400,
+                    "o": "0.0010",
+                    "c": "0.0020",
+                    "h": "0.0025",
+                    "l": "0.0015",
+                    "v": "1000",
+                    "n": 100,
+                    "x": false,
+                    "q": "1.0000",
+                    "V": "500",
+                    "Q": "0.500",
+                    "B": "123456"
+                }
+            }
+        }"#;
+        let res = serde_json::from_str::<WsEvent>(input).unwrap();
+        assert!(matches!(res, WsEvent::Kline(e) if e.kline.first_trade_id == 3717726327));
+    }
+
+    #[test]
+    fn decode_book_ticker_event() {
+        let input = r#"{
+            "stream": "bnbusdt@bookTicker",
+            "data": {
+                "u": 400900217,
+                "s": "BNBUSDT",
+                "b": "25.35190000",
+                "B": "31.21000000",
+                "a": "25.36520000",
+                "A": "40.66000000"
+            }
+        }"#;
+        let res = serde_json::from_str::<WsEvent>(input).unwrap();
+        assert!(matches!(res, WsEvent::BookTicker(e) if e.update_id == 400900217));
+    }
 }
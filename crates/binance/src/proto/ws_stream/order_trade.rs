@@ -1,61 +1,195 @@
-//
-//#[derive(Debug, Serialize, Deserialize, Clone)]
-//#[serde(rename_all = "camelCase")]
-//pub struct OrderTradeEvent {
-//    #[serde(rename = "e")] pub event_type: String,
-//
-//    #[serde(rename = "E")] pub event_time: u64,
-//
-//    #[serde(rename = "s")] pub symbol: String,
-//
-//    #[serde(rename = "c")] pub new_client_order_id: String,
-//
-//    #[serde(rename = "S")] pub side: String,
-//
-//    #[serde(rename = "o")] pub order_type: String,
-//
-//    #[serde(rename = "f")] pub time_in_force: String,
-//
-//    #[serde(rename = "q")] pub qty: String,
-//
-//    #[serde(rename = "p")] pub price: String,
-//
-//    #[serde(skip_serializing, rename = "P")] pub p_ignore: String,
-//
-//    #[serde(skip_serializing, rename = "F")] pub f_ignore: String,
-//
-//    #[serde(skip_serializing)] pub g: i32,
-//
-//    #[serde(skip_serializing, rename = "C")] pub c_ignore: Option<String>,
-//
-//    #[serde(rename = "x")] pub execution_type: String,
-//
-//    #[serde(rename = "X")] pub order_status: String,
-//
-//    #[serde(rename = "r")] pub order_reject_reason: String,
-//
-//    #[serde(rename = "i")] pub order_id: u64,
-//
-//    #[serde(rename = "l")] pub qty_last_filled_trade: String,
-//
-//    #[serde(rename = "z")] pub accumulated_qty_filled_trades: String,
-//
-//    #[serde(rename = "L")] pub price_last_filled_trade: String,
-//
-//    #[serde(rename = "n")] pub commission: String,
-//
-//    #[serde(skip_serializing, rename = "N")] pub asset_commisioned: Option<String>,
-//
-//    #[serde(rename = "T")] pub trade_order_time: u64,
-//
-//    #[serde(rename = "t")] pub trade_id: i64,
-//
-//    #[serde(skip_serializing, rename = "I")] pub i_ignore: u64,
-//
-//    #[serde(skip_serializing)] pub w: bool,
-//
-//    #[serde(rename = "m")] pub is_buyer_maker: bool,
-//
-//    #[serde(skip_serializing, rename = "M")] pub m_ignore: bool,
-//}
-//
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Atom;
+use crate::Decimal;
+use crate::api::spot::ContingencyType;
+use crate::api::spot::ListOrderStatus;
+use crate::api::spot::ListStatusType;
+use crate::api::spot::OrderSide;
+use crate::api::spot::OrderStatus;
+use crate::api::spot::OrderType;
+use crate::api::spot::SelfTradePreventionMode;
+use crate::api::spot::TimeInForce;
+
+/// The reason an `executionReport` event was sent.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ExecutionType {
+    /// The order has been accepted into the engine.
+    #[serde(rename = "NEW")]
+    New,
+    /// The order has been canceled by the user.
+    #[serde(rename = "CANCELED")]
+    Canceled,
+    /// The order has been amended.
+    #[serde(rename = "REPLACED")]
+    Replaced,
+    /// The order has been rejected and was not processed.
+    #[serde(rename = "REJECTED")]
+    Rejected,
+    /// Part of the order or all of the order's quantity has filled.
+    #[serde(rename = "TRADE")]
+    Trade,
+    /// The order was canceled according to the order type's rules or by the exchange.
+    #[serde(rename = "EXPIRED")]
+    Expired,
+    /// The order has expired due to self-trade prevention.
+    #[serde(rename = "TRADE_PREVENTION")]
+    TradePrevention,
+}
+
+/// `executionReport` event: an order update.
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+pub struct ExecutionReportEvent {
+    /// Event type.
+    #[serde(skip, rename = "e")]
+    pub event_type: (),
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// Symbol.
+    #[serde(rename = "s")]
+    pub symbol: Atom,
+    /// Client order ID.
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    /// Side.
+    #[serde(rename = "S")]
+    pub side: OrderSide,
+    /// Order type.
+    #[serde(rename = "o")]
+    pub order_type: OrderType,
+    /// Time in force.
+    #[serde(rename = "f")]
+    pub time_in_force: TimeInForce,
+    /// Order quantity.
+    #[serde(rename = "q")]
+    pub qty: Decimal,
+    /// Order price.
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    /// Stop price.
+    #[serde(rename = "P")]
+    pub stop_price: Decimal,
+    /// Iceberg quantity.
+    #[serde(rename = "F")]
+    pub iceberg_qty: Decimal,
+    /// Order list ID; `-1` when the order is not a part of an order list.
+    #[serde(rename = "g")]
+    pub order_list_id: i64,
+    /// Original client order ID; This is the ID of the order being canceled.
+    #[serde(rename = "C")]
+    pub orig_client_order_id: String,
+    /// Current execution type.
+    #[serde(rename = "x")]
+    pub execution_type: ExecutionType,
+    /// Current order status.
+    #[serde(rename = "X")]
+    pub order_status: OrderStatus,
+    /// Order reject reason; `NONE` if the order was not rejected.
+    #[serde(rename = "r")]
+    pub order_reject_reason: String,
+    /// Order ID.
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    /// Last executed quantity.
+    #[serde(rename = "l")]
+    pub last_executed_qty: Decimal,
+    /// Cumulative filled quantity.
+    #[serde(rename = "z")]
+    pub cumulative_filled_qty: Decimal,
+    /// Last executed price.
+    #[serde(rename = "L")]
+    pub last_executed_price: Decimal,
+    /// Commission amount.
+    #[serde(rename = "n")]
+    pub commission: Decimal,
+    /// Commission asset; `None` unless the event is a trade.
+    #[serde(rename = "N")]
+    pub commission_asset: Option<Atom>,
+    /// Transaction time.
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    /// Trade ID; `-1` unless the event is a trade.
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+    /// Prevented match ID; present only when the order expired due to self-trade prevention.
+    #[serde(rename = "v", default, skip_serializing_if = "Option::is_none")]
+    pub prevented_match_id: Option<u64>,
+    /// Is the order on the book?
+    #[serde(rename = "w")]
+    pub is_on_book: bool,
+    /// Is this trade the maker side?
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    /// Order creation time.
+    #[serde(rename = "O")]
+    pub creation_time: u64,
+    /// Cumulative quote asset transacted quantity.
+    #[serde(rename = "Z")]
+    pub cumulative_quote_qty: Decimal,
+    /// Last quote asset transacted quantity (i.e. `last_executed_price * last_executed_qty`).
+    #[serde(rename = "Y")]
+    pub last_quote_qty: Decimal,
+    /// Quote order quantity.
+    #[serde(rename = "Q")]
+    pub quote_order_qty: Decimal,
+    /// Working time; present only when the order is on the book.
+    #[serde(rename = "W", default, skip_serializing_if = "Option::is_none")]
+    pub working_time: Option<u64>,
+    /// Self-trade prevention mode.
+    #[serde(rename = "V")]
+    pub self_trade_prevention_mode: SelfTradePreventionMode,
+}
+
+/// `listStatus` event: an order list update, sent in addition to the `executionReport`
+/// events of its orders.
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+pub struct ListStatusEvent {
+    /// Event type.
+    #[serde(skip, rename = "e")]
+    pub event_type: (),
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// Symbol.
+    #[serde(rename = "s")]
+    pub symbol: Atom,
+    /// Order list ID.
+    #[serde(rename = "g")]
+    pub order_list_id: i64,
+    /// Contingency type.
+    #[serde(rename = "c")]
+    pub contingency_type: ContingencyType,
+    /// List status type.
+    #[serde(rename = "l")]
+    pub list_status_type: ListStatusType,
+    /// List order status.
+    #[serde(rename = "L")]
+    pub list_order_status: ListOrderStatus,
+    /// List reject reason; `NONE` if the list was not rejected.
+    #[serde(rename = "r")]
+    pub list_reject_reason: String,
+    /// List client order ID.
+    #[serde(rename = "C")]
+    pub list_client_order_id: String,
+    /// Transaction time.
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    /// Orders of the list.
+    #[serde(rename = "O")]
+    pub orders: Vec<ListStatusOrder>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+pub struct ListStatusOrder {
+    /// Symbol.
+    #[serde(rename = "s")]
+    pub symbol: Atom,
+    /// Order ID.
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    /// Client order ID.
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Atom;
+use crate::util::Ask;
+use crate::util::Bid;
+
+/// Top bids and asks of a partial book depth stream.
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialDepthEvent {
+    /// The payload doesn't carry the symbol; this is the market of the stream name
+    /// (i.e. the lowercase symbol).
+    #[serde(skip)]
+    pub market: Atom,
+    pub last_update_id: u64,
+    pub bids: Vec<Bid>,
+    pub asks: Vec<Ask>,
+}
@@ -53,3 +53,43 @@ pub struct TickerEvent {
     #[serde(rename = "n")]
     pub number_of_trades: u64,
 }
+
+/// Rolling window ticker statistics for a single symbol, computed over multiple windows.
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, Hash)]
+pub struct RollingWindowTickerEvent {
+    /// Event type, e.g. `1hTicker`.
+    #[serde(skip, rename = "e")]
+    pub event_type: (),
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: Atom,
+    #[serde(rename = "p")]
+    pub price_change: Decimal,
+    #[serde(rename = "P")]
+    pub price_change_percent: Decimal,
+    #[serde(rename = "o")]
+    pub open: Decimal,
+    #[serde(rename = "h")]
+    pub high: Decimal,
+    #[serde(rename = "l")]
+    pub low: Decimal,
+    #[serde(rename = "c")]
+    pub last_price: Decimal,
+    #[serde(rename = "w")]
+    pub weighted_avg_price: Decimal,
+    #[serde(rename = "v")]
+    pub base_volume: Decimal,
+    #[serde(rename = "q")]
+    pub quote_volume: Decimal,
+    #[serde(rename = "O")]
+    pub stats_open_time: u64,
+    #[serde(rename = "C")]
+    pub stats_close_time: u64,
+    #[serde(rename = "F")]
+    pub first_trade_id: u64,
+    #[serde(rename = "L")]
+    pub last_trade_id: u64,
+    #[serde(rename = "n")]
+    pub number_of_trades: u64,
+}
@@ -0,0 +1,163 @@
+use ccx_api_lib::serde_util::value_buffered;
+use serde::Deserialize;
+
+use super::BalanceUpdateEvent;
+use super::ExecutionReportEvent;
+use super::ListStatusEvent;
+use super::OutboundAccountPositionEvent;
+
+/// An event of the user data stream.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum UserDataEvent {
+    AccountPosition(OutboundAccountPositionEvent),
+    BalanceUpdate(BalanceUpdateEvent),
+    ExecutionReport(Box<ExecutionReportEvent>),
+    ListStatus(ListStatusEvent),
+    /// Events that don't have a typed representation yet
+    /// (e.g. `eventStreamTerminated` or `externalLockUpdate`).
+    Other(serde_json::Value),
+}
+
+impl UserDataEvent {
+    const ACCOUNT_POSITION: &'static str = "outboundAccountPosition";
+    const BALANCE_UPDATE: &'static str = "balanceUpdate";
+    const EXECUTION_REPORT: &'static str = "executionReport";
+    const LIST_STATUS: &'static str = "listStatus";
+}
+
+impl<'de> Deserialize<'de> for UserDataEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let value = value_buffered::buffer(deserializer)?;
+        let event_type = value
+            .get("e")
+            .and_then(|e| e.as_str())
+            .ok_or_else(|| D::Error::missing_field("e"))?;
+        match event_type {
+            Self::ACCOUNT_POSITION => value_buffered::from_value(value).map(Self::AccountPosition),
+            Self::BALANCE_UPDATE => value_buffered::from_value(value).map(Self::BalanceUpdate),
+            Self::EXECUTION_REPORT => value_buffered::from_value(value).map(Self::ExecutionReport),
+            Self::LIST_STATUS => value_buffered::from_value(value).map(Self::ListStatus),
+            _ => Ok(Self::Other(value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::api::spot::OrderStatus;
+    use crate::ws_stream::ExecutionType;
+
+    #[test]
+    fn decode_account_position() {
+        let input = r#"{
+            "e": "outboundAccountPosition",
+            "E": 1564034571105,
+            "u": 1564034571073,
+            "B": [{"a": "ETH", "f": "10000.000000", "l": "0.000000"}]
+        }"#;
+        let res: UserDataEvent = serde_json::from_str(input).unwrap();
+        let UserDataEvent::AccountPosition(event) = res else {
+            panic!("unexpected event: {res:?}");
+        };
+        assert_eq!(event.balances[0].free, dec!(10000));
+    }
+
+    #[test]
+    fn decode_balance_update() {
+        let input = r#"{
+            "e": "balanceUpdate",
+            "E": 1573200697110,
+            "a": "BTC",
+            "d": "100.00000000",
+            "T": 1573200697068
+        }"#;
+        let res: UserDataEvent = serde_json::from_str(input).unwrap();
+        assert!(matches!(res, UserDataEvent::BalanceUpdate(e) if e.delta == dec!(100)));
+    }
+
+    #[test]
+    fn decode_execution_report() {
+        let input = r#"{
+            "e": "executionReport",
+            "E": 1499405658658,
+            "s": "ETHBTC",
+            "c": "mUvoqJxFIILMdfAW5iGSOW",
+            "S": "BUY",
+            "o": "LIMIT",
+            "f": "GTC",
+            "q": "1.00000000",
+            "p": "0.10264410",
+            "P": "0.00000000",
+            "F": "0.00000000",
+            "g": -1,
+            "C": "",
+            "x": "NEW",
+            "X": "NEW",
+            "r": "NONE",
+            "i": 4293153,
+            "l": "0.00000000",
+            "z": "0.00000000",
+            "L": "0.00000000",
+            "n": "0",
+            "N": null,
+            "T": 1499405658657,
+            "t": -1,
+            "v": 3,
+            "I": 8641984,
+            "w": true,
+            "m": false,
+            "M": false,
+            "O": 1499405658657,
+            "Z": "0.00000000",
+            "Y": "0.00000000",
+            "Q": "0.00000000",
+            "W": 1499405658657,
+            "V": "NONE"
+        }"#;
+        let res: UserDataEvent = serde_json::from_str(input).unwrap();
+        let UserDataEvent::ExecutionReport(event) = res else {
+            panic!("unexpected event: {res:?}");
+        };
+        assert_eq!(event.execution_type, ExecutionType::New);
+        assert_eq!(event.order_status, OrderStatus::New);
+        assert_eq!(event.commission_asset, None);
+        assert_eq!(event.prevented_match_id, Some(3));
+    }
+
+    #[test]
+    fn decode_list_status() {
+        let input = r#"{
+            "e": "listStatus",
+            "E": 1564035303637,
+            "s": "ETHBTC",
+            "g": 2,
+            "c": "OCO",
+            "l": "EXEC_STARTED",
+            "L": "EXECUTING",
+            "r": "NONE",
+            "C": "F4QN4G8DlFATFlIUQ0cjdD",
+            "T": 1564035303625,
+            "O": [
+                {"s": "ETHBTC", "i": 17, "c": "AJYsMjErWJesZvqlJCTUgL"},
+                {"s": "ETHBTC", "i": 18, "c": "bfYPSQdLoqAJeNrOr9adzq"}
+            ]
+        }"#;
+        let res: UserDataEvent = serde_json::from_str(input).unwrap();
+        assert!(matches!(res, UserDataEvent::ListStatus(e) if e.orders.len() == 2));
+    }
+
+    #[test]
+    fn decode_other_event() {
+        let input = r#"{"e": "eventStreamTerminated", "E": 1728973001334}"#;
+        let res: UserDataEvent = serde_json::from_str(input).unwrap();
+        assert!(matches!(res, UserDataEvent::Other(_)));
+    }
+}
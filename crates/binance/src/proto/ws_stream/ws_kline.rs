@@ -16,9 +16,9 @@ pub struct WSKline {
     #[serde(rename = "i")]
     pub interval: ChartInterval,
     #[serde(rename = "f")]
-    pub first_trade_id: i32,
+    pub first_trade_id: i64,
     #[serde(rename = "L")]
-    pub last_trade_id: i32,
+    pub last_trade_id: i64,
     #[serde(rename = "o")]
     pub open: Decimal,
     #[serde(rename = "c")]
@@ -1,3 +1,7 @@
 mod order_book;
+#[cfg(feature = "with_network")]
+mod order_book_manager;
 
 pub use self::order_book::*;
+#[cfg(feature = "with_network")]
+pub use self::order_book_manager::*;
@@ -5,7 +5,7 @@ use rust_decimal::prelude::Zero;
 use serde::Deserialize;
 use serde::Serialize;
 
-use crate::BinanceError;
+use crate::ApiError;
 use crate::BinanceResult;
 use crate::ws_stream::OrderBookDiffEvent;
 
@@ -14,6 +14,7 @@ pub enum OrderBookUpdater {
     Ready { state: OrderBookState },
 }
 
+#[derive(Clone, Debug)]
 pub struct OrderBookState {
     last_update_id: u64,
     dirty: bool,
@@ -51,6 +52,11 @@ impl OrderBookUpdater {
         OrderBookUpdater::Preparing { buffer: vec![] }
     }
 
+    /// Drops the book and starts buffering the diffs for a new snapshot.
+    pub fn reset(&mut self) {
+        *self = OrderBookUpdater::new();
+    }
+
     pub fn state(&self) -> Option<&OrderBookState> {
         match self {
             OrderBookUpdater::Preparing { .. } => None,
@@ -225,18 +231,18 @@ impl OrderBookState {
                 return Ok(());
             }
             if diff.first_update_id > next_id {
-                Err(BinanceError::other(format!(
-                    "first_update_id > next_id:   {};   {}",
-                    diff.first_update_id, next_id
-                )))?
+                Err(ApiError::OrderBookOutOfSequence {
+                    expected: next_id,
+                    first_update_id: diff.first_update_id,
+                })?
             }
             // ^^ ensures diff.first_update_id <= next_id && diff.final_update_id > next_id
             self.dirty = false;
         } else if diff.first_update_id != next_id {
-            Err(BinanceError::other(format!(
-                "first_update_id != next_id:   {};   {}",
-                diff.first_update_id, next_id
-            )))?
+            Err(ApiError::OrderBookOutOfSequence {
+                expected: next_id,
+                first_update_id: diff.first_update_id,
+            })?
         }
 
         self.last_update_id = diff.final_update_id;
@@ -258,3 +264,54 @@ impl OrderBookState {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::LibError;
+
+    fn diff(first_update_id: u64, final_update_id: u64) -> OrderBookDiffEvent {
+        OrderBookDiffEvent {
+            event_type: (),
+            event_time: 0,
+            symbol: "BTCUSDT".into(),
+            first_update_id,
+            final_update_id,
+            bids: vec![Bid {
+                price: dec!(100),
+                qty: Decimal::from(final_update_id),
+            }],
+            asks: vec![],
+        }
+    }
+
+    #[test]
+    fn test_updater_detects_gap() {
+        let mut updater = OrderBookUpdater::new();
+        updater.push_diff(diff(8, 10)).unwrap();
+        updater.push_diff(diff(11, 12)).unwrap();
+        updater
+            .init(OrderBook {
+                last_update_id: 9,
+                bids: Box::new([]),
+                asks: Box::new([]),
+            })
+            .unwrap();
+        let state = updater.state().unwrap();
+        assert_eq!(state.next_bid(), Some((&dec!(100), &dec!(12))));
+
+        let res = updater.push_diff(diff(14, 15));
+        assert!(matches!(
+            res,
+            Err(LibError::ApiError(ApiError::OrderBookOutOfSequence {
+                expected: 13,
+                first_update_id: 14,
+            }))
+        ));
+
+        updater.reset();
+        assert!(updater.state().is_none());
+    }
+}
@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use futures::StreamExt;
+use futures::channel::mpsc;
+use futures::stream;
+
+use super::OrderBookState;
+use super::OrderBookUpdater;
+use crate::ApiError;
+use crate::Atom;
+use crate::BinanceError;
+use crate::BinanceResult;
+use crate::LibError;
+use crate::api::spot::OrderBookLimit;
+use crate::api::spot::SpotApi;
+use crate::client::BinanceSigner;
+use crate::ws_stream::UpstreamWebsocketMessage;
+use crate::ws_stream::WsEvent;
+use crate::ws_stream::WsStream;
+
+/// How long to wait before fetching a snapshot again after a failure.
+const SNAPSHOT_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// A change of a managed order book.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OrderBookEvent {
+    /// The book has been (re)built from a snapshot and the buffered diffs.
+    Synced(Atom),
+    /// A diff has been applied to the book.
+    Updated(Atom),
+    /// A gap in the diffs has been detected; the book is unavailable until it is synced
+    /// again.
+    Desynced(Atom),
+}
+
+type Books = Arc<RwLock<HashMap<Atom, OrderBookUpdater>>>;
+
+/// Local order books of several symbols kept in sync with the diff depth stream.
+///
+/// Each book is built from the REST `depth` snapshot and the diffs buffered while it is
+/// fetched; a gap in the diffs triggers a new snapshot. The manager yields
+/// [`OrderBookEvent`]s as a stream and ends when the websocket connection is closed.
+/// The background task is aborted when the manager is dropped.
+pub struct OrderBookManager {
+    books: Books,
+    rx: mpsc::UnboundedReceiver<OrderBookEvent>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+enum Input {
+    Ws(UpstreamWebsocketMessage<WsEvent>),
+    Snapshot(Atom, BinanceResult<super::OrderBook>),
+}
+
+impl OrderBookManager {
+    /// Subscribes to the 100ms diff depth stream of the symbols and starts building
+    /// the books with snapshots of the `limit` depth.
+    pub async fn start<S>(
+        api: &SpotApi<S>,
+        symbols: impl IntoIterator<Item = impl Into<Atom>>,
+        limit: OrderBookLimit,
+    ) -> BinanceResult<Self>
+    where
+        S: BinanceSigner + Unpin + 'static,
+    {
+        let symbols: Vec<Atom> = symbols
+            .into_iter()
+            .map(|s| Atom::from(s.into().to_uppercase()))
+            .collect();
+        let (sink, ws_rx) = api.ws().await?.split();
+        let subscriptions = symbols
+            .iter()
+            .map(|s| (s.to_lowercase(), WsStream::Depth100ms).into())
+            .collect();
+        sink.subscribe_list(subscriptions).await?;
+
+        let books: Books = Arc::new(RwLock::new(
+            symbols
+                .iter()
+                .map(|s| (s.clone(), OrderBookUpdater::new()))
+                .collect(),
+        ));
+        let (tx, rx) = mpsc::unbounded();
+        let syncer = Syncer {
+            api: SpotApi {
+                client: api.client.clone(),
+                rate_limiter: api.rate_limiter.clone(),
+            },
+            limit,
+            books: books.clone(),
+            tx,
+            pending: HashSet::new(),
+        };
+        let task = crate::client::spawn(syncer.run(symbols, sink, ws_rx));
+        Ok(OrderBookManager { books, rx, task })
+    }
+
+    /// Symbols of the managed books.
+    pub fn symbols(&self) -> Vec<Atom> {
+        self.books
+            .read()
+            .map(|books| books.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Calls `f` with the current book of the symbol; `None` while the book is syncing.
+    pub fn with_book<R>(&self, symbol: &str, f: impl FnOnce(&OrderBookState) -> R) -> Option<R> {
+        let books = self.books.read().ok()?;
+        books.get(&Atom::from(symbol))?.state().map(f)
+    }
+
+    /// A copy of the current book of the symbol; `None` while the book is syncing.
+    pub fn book(&self, symbol: &str) -> Option<OrderBookState> {
+        self.with_book(symbol, OrderBookState::clone)
+    }
+}
+
+impl futures::Stream for OrderBookManager {
+    type Item = OrderBookEvent;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
+impl Drop for OrderBookManager {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+struct Syncer<S>
+where
+    S: BinanceSigner,
+{
+    api: SpotApi<S>,
+    limit: OrderBookLimit,
+    books: Books,
+    tx: mpsc::UnboundedSender<OrderBookEvent>,
+    /// Symbols with a snapshot being fetched.
+    pending: HashSet<Atom>,
+}
+
+impl<S> Syncer<S>
+where
+    S: BinanceSigner + Unpin + 'static,
+{
+    async fn run(
+        mut self,
+        symbols: Vec<Atom>,
+        // Keeps the connection open.
+        _sink: crate::client::WebsocketStreamTx,
+        ws_rx: mpsc::UnboundedReceiver<UpstreamWebsocketMessage<WsEvent>>,
+    ) {
+        let (snapshot_tx, snapshot_rx) = mpsc::unbounded();
+        for symbol in symbols {
+            self.fetch_snapshot(symbol, Duration::ZERO, &snapshot_tx);
+        }
+
+        let mut inputs = stream::select(
+            ws_rx.map(Input::Ws),
+            snapshot_rx.map(|(symbol, res)| Input::Snapshot(symbol, res)),
+        );
+        while let Some(input) = inputs.next().await {
+            match input {
+                Input::Ws(UpstreamWebsocketMessage::Event(WsEvent::OrderBookDiff(diff))) => {
+                    let symbol = diff.symbol.clone();
+                    let res = self.update(&symbol, |book| book.push_diff(diff));
+                    match res {
+                        Some(Ok(true)) => self.notify(OrderBookEvent::Updated(symbol)),
+                        Some(Ok(false)) | None => {}
+                        Some(Err(e)) => self.resync(symbol, e, &snapshot_tx),
+                    }
+                }
+                Input::Ws(UpstreamWebsocketMessage::Event(_)) => {}
+                Input::Ws(UpstreamWebsocketMessage::Response(res)) => {
+                    log::debug!("OrderBookManager: {:?}", res);
+                }
+                Input::Snapshot(symbol, Ok(snapshot)) => {
+                    self.pending.remove(&symbol);
+                    match self.update(&symbol, |book| book.init(snapshot)) {
+                        Some(Ok(_)) => self.notify(OrderBookEvent::Synced(symbol)),
+                        Some(Err(e)) => self.resync(symbol, e, &snapshot_tx),
+                        None => {}
+                    }
+                }
+                Input::Snapshot(symbol, Err(e)) => {
+                    log::warn!("OrderBookManager: {} snapshot failed: {:?}", symbol, e);
+                    self.pending.remove(&symbol);
+                    self.fetch_snapshot(symbol, SNAPSHOT_RETRY_DELAY, &snapshot_tx);
+                }
+            }
+        }
+        log::warn!("OrderBookManager: depth stream closed");
+    }
+
+    /// Applies `f` to the book of the symbol; returns whether the book is ready after it.
+    fn update(
+        &self,
+        symbol: &Atom,
+        f: impl FnOnce(&mut OrderBookUpdater) -> BinanceResult<()>,
+    ) -> Option<BinanceResult<bool>> {
+        let mut books = self.books.write().ok()?;
+        let book = books.get_mut(symbol)?;
+        Some(f(book).map(|_| book.state().is_some()))
+    }
+
+    fn resync(
+        &mut self,
+        symbol: Atom,
+        e: BinanceError,
+        snapshot_tx: &mpsc::UnboundedSender<(Atom, BinanceResult<super::OrderBook>)>,
+    ) {
+        match &e {
+            LibError::ApiError(ApiError::OrderBookOutOfSequence { .. }) => {
+                log::debug!("OrderBookManager: {} resync: {}", symbol, e);
+            }
+            _ => log::warn!("OrderBookManager: {} resync: {:?}", symbol, e),
+        }
+        if let Ok(mut books) = self.books.write() {
+            if let Some(book) = books.get_mut(&symbol) {
+                book.reset();
+            }
+        }
+        self.notify(OrderBookEvent::Desynced(symbol.clone()));
+        self.fetch_snapshot(symbol, Duration::ZERO, snapshot_tx);
+    }
+
+    fn fetch_snapshot(
+        &mut self,
+        symbol: Atom,
+        delay: Duration,
+        snapshot_tx: &mpsc::UnboundedSender<(Atom, BinanceResult<super::OrderBook>)>,
+    ) {
+        if !self.pending.insert(symbol.clone()) {
+            return;
+        }
+        let task = self.api.depth(&symbol, self.limit);
+        let snapshot_tx = snapshot_tx.clone();
+        crate::client::spawn(async move {
+            if !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+            let res = match task {
+                Ok(task) => task.await.map(Into::into),
+                Err(e) => Err(e),
+            };
+            let _ = snapshot_tx.unbounded_send((symbol, res));
+        });
+    }
+
+    fn notify(&self, event: OrderBookEvent) {
+        let _ = self.tx.unbounded_send(event);
+    }
+}
@@ -1,3 +1,4 @@
+use ccx_api_lib::Instrument;
 use serde::Deserialize;
 
 use crate::Atom;
@@ -17,6 +18,13 @@ pub struct TradingPairInfo {
     pub description: Atom,
 }
 
+impl TradingPairInfo {
+    /// Parsed from the pair name, e.g. `BTC/USD`.
+    pub fn instrument(&self) -> Option<Instrument> {
+        self.name.parse().ok()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct MinimumOrder {
     pub currency: Atom,
@@ -2,10 +2,16 @@ mod config;
 mod rate_limiter;
 mod rest;
 mod signer;
+#[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
 mod websocket;
+#[cfg(feature = "with_tokio")]
+mod websocket_tokio;
 
 pub use config::*;
 pub use rate_limiter::*;
 pub use rest::*;
 pub use signer::*;
+#[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
 pub use websocket::*;
+#[cfg(feature = "with_tokio")]
+pub use websocket_tokio::*;
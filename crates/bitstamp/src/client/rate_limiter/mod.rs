@@ -1,32 +1,20 @@
-mod bucket;
-mod bucket_mode;
 mod limiter;
 mod limiter_builder;
-mod queue;
 mod task;
 mod task_builder;
-mod task_message;
-mod task_metadata;
 
-use std::borrow::Cow;
-
-#[allow(unused_imports)]
-pub use bucket::*;
-#[allow(unused_imports)]
-pub use bucket_mode::*;
+use ccx_api_lib::BucketName;
+pub(crate) use ccx_api_lib::RateLimiterBucket;
+pub(crate) use ccx_api_lib::RateLimiterBucketMode;
+use ccx_api_lib::TaskCosts;
+pub use ccx_api_lib::TaskMetadata;
 #[allow(unused_imports)]
 pub use limiter::*;
 #[allow(unused_imports)]
 pub use limiter_builder::*;
-use queue::*;
 #[allow(unused_imports)]
 pub use task::*;
 use task_builder::*;
-use task_message::*;
-#[allow(unused_imports)]
-pub use task_metadata::*;
-
-type BucketName = Cow<'static, str>;
 
 // #[cfg(test)]
 // mod tests {
@@ -1,16 +1,11 @@
 use std::sync::Arc;
 use std::time::Instant;
 
-use ::awc::http::Method;
-use ::awc::http::StatusCode;
-use actix_http::BoxedPayloadStream;
-use actix_http::Payload;
-use actix_http::Uri;
-use actix_http::encoding::Decoder;
-use ccx_api_lib::awc::Client;
-use ccx_api_lib::awc::ClientRequest;
-use ccx_api_lib::awc::ClientResponse;
-use ccx_api_lib::awc::make_client;
+use ccx_api_lib::reqwest::Client;
+use ccx_api_lib::reqwest::ClientRequest;
+use ccx_api_lib::reqwest::Method;
+use ccx_api_lib::reqwest::StatusCode;
+use ccx_api_lib::reqwest::make_client_with;
 use chrono::Utc;
 use serde::Deserialize;
 use serde::Serialize;
@@ -46,6 +41,7 @@ where
     S: BitstampSigner,
 {
     config: Config<S>,
+    client: Client,
 }
 
 pub struct RequestBuilder<S>
@@ -58,6 +54,7 @@ where
     body: String,
 }
 
+#[allow(dead_code)]
 #[derive(Serialize, Deserialize)]
 struct BitstampApiAnswer<T> {
     result: T,
@@ -69,12 +66,26 @@ where
     S: BitstampSigner,
 {
     pub fn new(config: Config<S>) -> Self {
-        let inner = Arc::new(ClientInner { config });
+        let client = make_client_with(config.transport.as_ref(), false, config.proxy.as_ref());
+        let inner = Arc::new(ClientInner { config, client });
         RestClient { inner }
     }
 
-    pub(super) fn client(&self) -> Client {
-        make_client(false, self.inner.config.proxy.as_ref())
+    /// REST client sending through the configured transport.
+    pub(super) fn client(&self) -> &Client {
+        &self.inner.client
+    }
+
+    /// Client for the actix WebSocket connections.
+    #[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
+    pub(super) fn ws_client(&self) -> ccx_api_lib::awc::Client {
+        ccx_api_lib::awc::make_client(false, self.inner.config.proxy.as_ref())
+    }
+
+    /// Proxy for the tokio WebSocket connections.
+    #[cfg(feature = "with_tokio")]
+    pub(super) fn proxy(&self) -> Option<&Proxy> {
+        self.inner.config.proxy.as_ref()
     }
 
     pub fn request(&self, method: Method, endpoint: &str) -> BitstampResult<RequestBuilder<S>> {
@@ -108,7 +119,7 @@ where
 
     pub async fn web_socket(&self) -> BitstampResult<WebsocketStream> {
         let url = self.inner.config.stream_base.clone();
-        Ok(WebsocketStream::connect(self.clone(), url).await?)
+        WebsocketStream::connect(self.clone(), url).await
     }
 }
 
@@ -117,7 +128,12 @@ where
     S: BitstampSigner,
 {
     pub fn uri(&self) -> String {
-        self.request.get_uri().to_string()
+        let query = self.request.query_params();
+        if query.is_empty() {
+            self.request.url().to_string()
+        } else {
+            format!("{}?{}", self.request.url(), query)
+        }
     }
 
     pub fn query_arg<Name: AsRef<str>, T: Serialize + ?Sized>(
@@ -125,25 +141,10 @@ where
         name: Name,
         query: &T,
     ) -> BitstampResult<Self> {
-        let mut parts = self.request.get_uri().clone().into_parts();
-
-        if let Some(path_and_query) = parts.path_and_query {
-            let mut buf = path_and_query.path().to_string();
-            buf.push('?');
-            match path_and_query.query().unwrap_or("") {
-                "" => {}
-                old_query => {
-                    buf.push_str(old_query);
-                    buf.push('&');
-                }
-            }
-            buf.push_str(&serde_urlencoded::to_string([(name.as_ref(), query)])?);
-            parts.path_and_query = buf.parse().ok();
-            let uri =
-                Uri::from_parts(parts).map_err(|e| BitstampError::other(format!("{:?}", e)))?;
-            self.request = self.request.uri(uri);
-        }
-
+        let pair = [(name.as_ref(), query)];
+        // Surface the encoding errors the client would drop.
+        serde_urlencoded::to_string(pair)?;
+        self.request = self.request.query(&pair);
         Ok(self)
     }
 
@@ -164,7 +165,7 @@ where
         if !self.body.is_empty() {
             self.request = self
                 .request
-                .content_type("application/x-www-form-urlencoded");
+                .header("Content-Type", "application/x-www-form-urlencoded");
         }
 
         Ok(self)
@@ -190,15 +191,16 @@ where
 
         log::debug!(
             "[{request_id}]  Request: {} {}",
-            self.request.get_method(),
-            self.request.get_uri()
+            self.request.method(),
+            self.uri()
         );
         log::debug!("[{request_id}]  Request body: {:?}", self.body);
 
         let tm = Instant::now();
-        let mut res = self.request.send_body(self.body).await?;
+        let res = self.request.body(self.body).send().await?;
         let d1 = tm.elapsed();
-        let resp = res.body().limit(16 * 1024 * 1024).await?;
+        let code = res.status();
+        let resp = res.bytes().await?;
         let d2 = tm.elapsed() - d1;
 
         log::debug!(
@@ -206,14 +208,13 @@ where
             d1.as_secs_f64() * 1000.0,
             d2.as_secs_f64() * 1000.0,
         );
-        let code = res.status();
         log::debug!(
             "[{request_id}]  Response: {} «{}»",
             code,
             String::from_utf8_lossy(&resp)
         );
 
-        if let Err(err) = check_response(res) {
+        if let Err(err) = check_response(code) {
             // log::debug!("Response: {}", String::from_utf8_lossy(&resp));
             Err(err)?
         };
@@ -225,21 +226,20 @@ where
         if let Some((nonce, timestamp)) = self.sign {
             let req_host = self
                 .request
-                .get_uri()
-                .host()
+                .url()
+                .host_str()
                 .ok_or_else(|| BitstampApiError::lib_error(&"Missing Host"))?;
 
             let req_content_type = self
                 .request
                 .headers()
-                .get(actix_http::header::CONTENT_TYPE)
-                .map(|h| h.to_str().ok())
-                .unwrap_or_default()
+                .get("Content-Type")
+                .map(String::as_str)
                 .unwrap_or_default();
 
-            let req_path = self.request.get_uri().path();
-            let req_query = self.request.get_uri().query().unwrap_or_default();
-            let req_method = self.request.get_method();
+            let req_path = self.request.url().path();
+            let req_query = self.request.query_params();
+            let req_method = self.request.method();
 
             // path should be like /api/v2/balance/
             let api_version = req_path.split('/').nth(2).unwrap_or_default().to_string();
@@ -269,45 +269,41 @@ where
 
             self.request = self
                 .request
-                .append_header(("X-Auth", auth_key))
-                .append_header(("X-Auth-Signature", signature))
-                .append_header(("X-Auth-Nonce", nonce.to_string()))
-                .append_header(("X-Auth-Timestamp", timestamp.to_string()))
-                .append_header(("X-Auth-Version", api_version));
+                .header("X-Auth", auth_key)
+                .header("X-Auth-Signature", signature)
+                .header("X-Auth-Nonce", nonce.to_string())
+                .header("X-Auth-Timestamp", timestamp.to_string())
+                .header("X-Auth-Version", api_version);
         };
 
         self.request = self
             .request
-            .append_header(("Accept", "application/json"))
-            .append_header(("User-Agent", "ccx-api/0.4 (lib; Rust)"));
+            .header("Accept", "application/json")
+            .header("User-Agent", "ccx-api/0.4 (lib; Rust)");
 
         Ok(self)
     }
 }
 
-/// Return base64 encoded api sign.
-
-type AwcClientResponse = ClientResponse<Decoder<Payload<BoxedPayloadStream>>>;
-
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct ApiErrorMessage {
     reason: String,
 }
 
-fn check_response(res: AwcClientResponse) -> BitstampApiResult<AwcClientResponse> {
+fn check_response(status: StatusCode) -> BitstampApiResult<()> {
     // let used_rate_limits = UsedRateLimits::from_headers(res.headers());
     //
     // log::debug!("  used_rate_limits:  {:?}", used_rate_limits);
 
-    match res.status() {
-        StatusCode::OK => Ok(res),
+    match status {
+        StatusCode::OK => Ok(()),
         // TODO check for rate limit error
         StatusCode::TOO_MANY_REQUESTS => Err(ApiServiceError::RateLimitExceeded)?,
         StatusCode::INTERNAL_SERVER_ERROR => Err(ApiServiceError::ServerError)?,
         StatusCode::BAD_GATEWAY => Err(ApiServiceError::ServiceUnavailable)?,
         StatusCode::SERVICE_UNAVAILABLE => Err(ApiServiceError::ServiceUnavailable)?,
         StatusCode::GATEWAY_TIMEOUT => Err(ApiServiceError::ServiceUnavailable)?,
-        _ => Ok(res),
+        _ => Ok(()),
     }
 }
 
@@ -123,7 +123,12 @@ impl Handler<M<WsCommand>> for Websocket {
     fn handle(&mut self, M(cmd): M<WsCommand>, ctx: &mut Self::Context) {
         let msg = serde_json::to_string(&cmd).expect("json encode");
         log::debug!("Sending to server: `{}`", msg);
-        if let Err(_) = self.inner_mut().sink.write(ws::Message::Text(msg.into())) {
+        if self
+            .inner_mut()
+            .sink
+            .write(ws::Message::Text(msg.into()))
+            .is_err()
+        {
             ctx.stop();
         }
 
@@ -261,12 +266,9 @@ impl Websocket {
     /// Handles raw message bytes serializing them to [`Event`].
     /// [`Event::Client`] will be passed down
     fn handle_raw_message(&mut self, msg: &[u8], ctx: &mut Context<Self>) {
-        let res = serde_json::from_slice(&msg);
+        let res = serde_json::from_slice(msg);
         if res.is_err() {
-            log::error!(
-                "json message from server: {}",
-                String::from_utf8_lossy(&msg)
-            );
+            log::error!("json message from server: {}", String::from_utf8_lossy(msg));
         }
 
         let event = match res {
@@ -316,7 +318,7 @@ impl WebsocketStream {
     ) -> BitstampResult<Self> {
         log::debug!("Connecting WS: {}", url.as_str());
 
-        let client = api_client.client();
+        let client = api_client.ws_client();
 
         let (tx, rx) = mpsc::unbounded();
 
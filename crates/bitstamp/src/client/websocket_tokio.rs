@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+use std::io;
+use std::time::Duration;
+
+use futures::SinkExt;
+use futures::StreamExt;
+use futures::channel::mpsc;
+use tokio::net::TcpStream;
+use tokio::time::Instant;
+use tokio::time::interval;
+use tokio::time::sleep;
+use tokio_tungstenite::MaybeTlsStream;
+use tokio_tungstenite::WebSocketStream as TungsteniteStream;
+use tokio_tungstenite::client_async_tls;
+use tokio_tungstenite::tungstenite::Message;
+use url::Url;
+
+use crate::client::Proxy;
+use crate::client::RestClient;
+use crate::error::BitstampError;
+use crate::error::BitstampResult;
+use crate::ws_stream::Event;
+use crate::ws_stream::SystemEvent;
+use crate::ws_stream::WsCommand;
+use crate::ws_stream::WsEvent;
+use crate::ws_stream::WsSubscription;
+
+/// How often heartbeat pings are sent.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+/// How long before lack of client response causes a timeout.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(30);
+/// Interval between automatic reconnections.
+///
+/// According to documentation every connection older than 90 days will be
+/// automatically dropped.
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+type Socket = TungsteniteStream<MaybeTlsStream<TcpStream>>;
+
+pub struct WebsocketStream {
+    tx: WebsocketStreamTx,
+    rx: mpsc::UnboundedReceiver<WsEvent>,
+}
+
+#[derive(Clone)]
+pub struct WebsocketStreamTx {
+    command_tx: mpsc::UnboundedSender<WsCommand>,
+}
+
+/// Why a connection has been left.
+enum Exit {
+    Reconnect,
+    Stop,
+}
+
+struct Websocket {
+    ws_url: Url,
+    proxy: Option<Proxy>,
+    tx: mpsc::UnboundedSender<WsEvent>,
+    command_rx: mpsc::UnboundedReceiver<WsCommand>,
+    /// The connection outlives the senders as long as the events are received.
+    commands_open: bool,
+    channels: HashMap<WsSubscription, bool>,
+}
+
+impl WebsocketStream {
+    pub async fn connect<S: crate::client::BitstampSigner>(
+        api_client: RestClient<S>,
+        url: Url,
+    ) -> BitstampResult<Self> {
+        log::debug!("Connecting WS: {}", url.as_str());
+
+        let proxy = api_client.proxy().cloned();
+        let socket = connect(&url, proxy.as_ref()).await?;
+
+        let (command_tx, command_rx) = mpsc::unbounded();
+        let (tx, rx) = mpsc::unbounded();
+        let websocket = Websocket {
+            ws_url: url,
+            proxy,
+            tx,
+            command_rx,
+            commands_open: true,
+            channels: HashMap::new(),
+        };
+        tokio::spawn(websocket.run(socket));
+
+        let tx = WebsocketStreamTx { command_tx };
+        Ok(WebsocketStream { tx, rx })
+    }
+
+    pub fn split(self) -> (WebsocketStreamTx, mpsc::UnboundedReceiver<WsEvent>) {
+        (self.tx, self.rx)
+    }
+}
+
+impl std::ops::Deref for WebsocketStream {
+    type Target = WebsocketStreamTx;
+
+    fn deref(&self) -> &Self::Target {
+        &self.tx
+    }
+}
+
+impl WebsocketStreamTx {
+    pub async fn subscribe_one(
+        &self,
+        subscription: impl Into<WsSubscription>,
+    ) -> BitstampResult<()> {
+        let cmd = WsCommand::Subscribe(subscription.into());
+        self.command_tx
+            .unbounded_send(cmd)
+            .map_err(|_e| BitstampError::IoError(io::ErrorKind::ConnectionAborted.into()))
+    }
+}
+
+async fn connect(url: &Url, proxy: Option<&Proxy>) -> BitstampResult<Socket> {
+    let stream = ccx_api_lib::connect_tcp(url, proxy).await?;
+    let (socket, response) = client_async_tls(url.as_str(), stream)
+        .await
+        .map_err(|e| BitstampError::IoError(io::Error::other(e)))?;
+    log::debug!("Websocket response: {:?}", response.status());
+    Ok(socket)
+}
+
+impl Websocket {
+    async fn run(mut self, socket: Socket) {
+        let mut socket = socket;
+        loop {
+            match self.session(socket).await {
+                Exit::Stop => return,
+                Exit::Reconnect => {}
+            }
+            socket = match connect(&self.ws_url, self.proxy.as_ref()).await {
+                Ok(socket) => socket,
+                Err(err) => {
+                    log::error!("Socket connection was not initialized: {}", err);
+                    return;
+                }
+            };
+        }
+    }
+
+    /// Serves one connection, resubscribing to the channels of the previous ones.
+    async fn session(&mut self, socket: Socket) -> Exit {
+        let (mut sink, mut stream) = socket.split();
+
+        let old_subscriptions = std::mem::take(&mut self.channels);
+        for (subscription, _) in old_subscriptions {
+            if self
+                .send(&mut sink, WsCommand::Subscribe(subscription))
+                .await
+                .is_err()
+            {
+                return Exit::Stop;
+            }
+        }
+
+        let mut heartbeat = interval(HEARTBEAT_INTERVAL);
+        let mut hb = Instant::now();
+        let reconnect = sleep(RECONNECT_INTERVAL);
+        tokio::pin!(reconnect);
+
+        let exit = loop {
+            tokio::select! {
+                msg = stream.next() => match msg {
+                    Some(Ok(Message::Text(text))) => {
+                        if let Some(exit) = self.handle_raw_message(text.as_bytes()) {
+                            break exit;
+                        }
+                    }
+                    Some(Ok(Message::Binary(_))) => {
+                        log::warn!("unexpected binary message (ignored)");
+                    }
+                    Some(Ok(Message::Ping(data))) => {
+                        hb = Instant::now();
+                        if sink.send(Message::Pong(data)).await.is_err() {
+                            log::warn!("Failed to send Pong. Disconnecting.");
+                            break Exit::Stop;
+                        }
+                    }
+                    Some(Ok(Message::Pong(_))) => {
+                        hb = Instant::now();
+                    }
+                    Some(Ok(Message::Frame(_))) => {}
+                    Some(Ok(Message::Close(_))) | None => break Exit::Stop,
+                    Some(Err(e)) => {
+                        log::warn!("WebSocket broken: {:?}", e);
+                        break Exit::Stop;
+                    }
+                },
+                cmd = self.command_rx.next(), if self.commands_open => match cmd {
+                    Some(cmd) => {
+                        if self.send(&mut sink, cmd).await.is_err() {
+                            break Exit::Stop;
+                        }
+                    }
+                    None => self.commands_open = false,
+                },
+                _ = heartbeat.tick() => {
+                    if Instant::now().duration_since(hb) > CLIENT_TIMEOUT {
+                        log::warn!("Websocket client heartbeat failed, disconnecting!");
+                        break Exit::Stop;
+                    }
+                    if sink.send(Message::Ping(Default::default())).await.is_err() {
+                        log::warn!("Websocket client failed to send ping, stopping!");
+                        break Exit::Stop;
+                    }
+                }
+                _ = &mut reconnect => break Exit::Reconnect,
+            }
+        };
+
+        let _ = sink.close().await;
+        exit
+    }
+
+    async fn send<W>(&mut self, sink: &mut W, cmd: WsCommand) -> Result<(), W::Error>
+    where
+        W: futures::Sink<Message> + Unpin,
+    {
+        let msg = serde_json::to_string(&cmd).expect("json encode");
+        log::debug!("Sending to server: `{}`", msg);
+        sink.send(Message::Text(msg.into())).await?;
+
+        match cmd {
+            WsCommand::Subscribe(cmd) => {
+                self.channels.entry(cmd).or_default();
+            }
+            WsCommand::Unsubscribe(cmd) => {
+                self.channels.remove(&cmd);
+            }
+        };
+        Ok(())
+    }
+
+    /// Handles raw message bytes serializing them to [`Event`].
+    /// [`Event::Client`] will be passed down
+    fn handle_raw_message(&mut self, msg: &[u8]) -> Option<Exit> {
+        let event = match serde_json::from_slice(msg) {
+            Err(e) => {
+                log::error!("json message from server: {}", String::from_utf8_lossy(msg));
+                log::error!("Failed to deserialize server message: {:?}", e);
+                return None;
+            }
+            Ok(msg) => msg,
+        };
+
+        match event {
+            Event::Client(ev) => {
+                if let Err(e) = self.tx.unbounded_send(ev) {
+                    log::warn!("Failed to notify downstream: {:?}", e);
+                    return Some(Exit::Stop);
+                }
+            }
+            Event::System(ev) => match ev {
+                SystemEvent::ReconnectRequest => {
+                    log::debug!("Reconnect request received");
+                    return Some(Exit::Reconnect);
+                }
+                SystemEvent::SubscriptionSucceeded { channel } => {
+                    let subscription = channel.into();
+                    if !self.channels.contains_key(&subscription) {
+                        log::warn!(
+                            "Successfully subscribed to {:?}. But it was \
+                             not found in list of active subscriptions",
+                            subscription,
+                        );
+                    }
+                    self.channels.insert(subscription, true);
+                }
+                SystemEvent::Error { channel, data } => {
+                    log::error!("Websocket Channel({}) returned error: {:?}", channel, data);
+                }
+                SystemEvent::Heartbeat => {}
+            },
+        }
+        None
+    }
+}
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 use std::fmt;
 
-use ccx_api_lib::awc::StatusCode;
+pub use ccx_api_lib::http::StatusCode;
 pub use ccx_api_lib::*;
 use thiserror::Error;
 
@@ -34,7 +34,7 @@ pub struct LiveOrderEvent {
 }
 
 /// Event types related to orders.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub enum LiveOrderEventType {
     #[serde(rename = "order_created")]
     OrderCreated,
@@ -44,15 +44,10 @@ pub enum LiveOrderEventType {
     OrderDeleted,
     /// Fallback event type used in cases of new/unknown events.
     #[serde(other)]
+    #[default]
     Unknown,
 }
 
-impl Default for LiveOrderEventType {
-    fn default() -> Self {
-        Self::Unknown
-    }
-}
-
 #[cfg(test)]
 mod tests {
 
@@ -98,8 +98,8 @@ impl WsEvent {
         channel: String,
         data: serde_json::Value,
     ) -> Result<Self, DeserializeError> {
-        let (stream, pair) = channel_from_raw(&channel)
-            .ok_or_else(|| DeserializeError::InvalidChannelName(channel))?;
+        let (stream, pair) =
+            channel_from_raw(&channel).ok_or(DeserializeError::InvalidChannelName(channel))?;
         let event = match (ev, stream) {
             (ClientEventType::Data, WsStream::OrderBook) => WsEvent::OrderBook {
                 pair,
@@ -113,6 +113,10 @@ impl WsEvent {
                 pair,
                 data: serde_json::from_value(data)?,
             },
+            (ClientEventType::Trade, WsStream::LiveTrades) => WsEvent::LiveTrade {
+                pair,
+                data: serde_json::from_value(data)?,
+            },
             (ClientEventType::OrderCreated, WsStream::LiveOrders) => {
                 let mut event: LiveOrderEvent = serde_json::from_value(data)?;
                 event.event_type = LiveOrderEventType::OrderCreated;
@@ -168,7 +172,7 @@ impl SystemEvent {
             SystemEventType::ReconnectRequest => Self::ReconnectRequest,
             SystemEventType::SubscriptionSucceeded => {
                 let channel = channel_from_raw(&channel)
-                    .ok_or_else(|| DeserializeError::InvalidChannelName(channel))?;
+                    .ok_or(DeserializeError::InvalidChannelName(channel))?;
                 Self::SubscriptionSucceeded { channel }
             }
             SystemEventType::Error => Self::Error {
@@ -1,40 +1,97 @@
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
-pub use reqwest::Error as SendRequestError;
+use bytes::Bytes;
+use futures::FutureExt;
+use futures::future::BoxFuture;
 pub use reqwest::Method;
 pub use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use reqwest::header::HeaderName;
+use reqwest::header::HeaderValue;
 
+use crate::HttpRequest;
+use crate::HttpResponse;
+use crate::HttpTransport;
 pub use crate::Proxy;
+pub use crate::TransportError as SendRequestError;
 
 const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
 const CLIENT_TIMEOUT: Duration = Duration::from_secs(60);
 
-// Wrapper around reqwest::Client to provide awc-compatible interface
+/// Sends the requests over the network with `reqwest`.
+#[derive(Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        ReqwestTransport { client }
+    }
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'static, Result<HttpResponse, SendRequestError>> {
+        let request = self
+            .client
+            .request(request.method, request.url)
+            .headers(request.headers)
+            .body(request.body);
+        async move {
+            let response = request.send().await.map_err(request_error)?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await.map_err(request_error)?;
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        }
+        .boxed()
+    }
+}
+
+fn request_error(e: reqwest::Error) -> SendRequestError {
+    SendRequestError::Request(e.to_string())
+}
+
+// Provides awc-compatible interface on top of an `HttpTransport`
 #[derive(Clone)]
 pub struct Client {
-    inner: reqwest::Client,
+    transport: Arc<dyn HttpTransport>,
 }
 
 impl Client {
+    pub fn with_transport(transport: Arc<dyn HttpTransport>) -> Self {
+        Client { transport }
+    }
+
     pub fn request(&self, method: Method, url: &str) -> ClientRequest {
         let url_parsed = url::Url::parse(url).expect("Invalid URL");
         ClientRequest {
-            inner: self.inner.request(method.clone(), url_parsed.clone()),
+            transport: self.transport.clone(),
             url: url_parsed,
             method,
-            headers: std::collections::HashMap::new(),
+            headers: HashMap::new(),
             query_params: String::new(),
+            body: Bytes::new(),
         }
     }
 }
 
-// Wrapper around reqwest::RequestBuilder to provide awc-compatible interface
 pub struct ClientRequest {
-    inner: reqwest::RequestBuilder,
+    transport: Arc<dyn HttpTransport>,
     url: url::Url,
     method: Method,
-    headers: std::collections::HashMap<String, String>,
+    headers: HashMap<String, String>,
     query_params: String,
+    body: Bytes,
 }
 
 impl ClientRequest {
@@ -53,13 +110,7 @@ impl ClientRequest {
     {
         self.headers
             .insert(key.as_ref().to_string(), value.as_ref().to_string());
-        Self {
-            inner: self.inner.header(key.as_ref(), value.as_ref()),
-            url: self.url,
-            method: self.method,
-            headers: self.headers,
-            query_params: self.query_params,
-        }
+        self
     }
 
     pub fn query<T: serde::Serialize + ?Sized>(mut self, query: &T) -> Self {
@@ -73,27 +124,15 @@ impl ClientRequest {
             }
             self.query_params.push_str(&serialized);
         }
-
-        Self {
-            inner: self.inner.query(query),
-            url: self.url,
-            method: self.method,
-            headers: self.headers,
-            query_params: self.query_params,
-        }
+        self
     }
 
-    pub fn body<T: Into<reqwest::Body>>(self, body: T) -> Self {
-        Self {
-            inner: self.inner.body(body),
-            url: self.url,
-            method: self.method,
-            headers: self.headers,
-            query_params: self.query_params,
-        }
+    pub fn body<T: Into<Bytes>>(mut self, body: T) -> Self {
+        self.body = body.into();
+        self
     }
 
-    pub fn headers(&self) -> &std::collections::HashMap<String, String> {
+    pub fn headers(&self) -> &HashMap<String, String> {
         &self.headers
     }
 
@@ -101,32 +140,53 @@ impl ClientRequest {
         &self.query_params
     }
 
-    pub async fn send(self) -> Result<ClientResponse, reqwest::Error> {
-        let response = self.inner.send().await?;
-        Ok(ClientResponse { inner: response })
+    pub async fn send(self) -> Result<ClientResponse, SendRequestError> {
+        let mut url = self.url;
+        if !self.query_params.is_empty() {
+            let query = match url.query() {
+                Some(existing) => format!("{existing}&{}", self.query_params),
+                None => self.query_params,
+            };
+            url.set_query(Some(&query));
+        }
+        let mut headers = HeaderMap::with_capacity(self.headers.len());
+        for (key, value) in &self.headers {
+            let name = HeaderName::try_from(key.as_str())
+                .map_err(|e| SendRequestError::InvalidRequest(e.to_string()))?;
+            let value = HeaderValue::try_from(value.as_str())
+                .map_err(|e| SendRequestError::InvalidRequest(e.to_string()))?;
+            headers.insert(name, value);
+        }
+        let request = HttpRequest {
+            method: self.method,
+            url,
+            headers,
+            body: self.body,
+        };
+        let inner = self.transport.send(request).await?;
+        Ok(ClientResponse { inner })
     }
 }
 
-// Wrapper around reqwest::Response to provide awc-compatible interface
 pub struct ClientResponse {
-    inner: reqwest::Response,
+    inner: HttpResponse,
 }
 
 impl ClientResponse {
     pub fn status(&self) -> StatusCode {
-        self.inner.status()
+        self.inner.status
     }
 
-    pub fn headers(&self) -> &reqwest::header::HeaderMap {
-        self.inner.headers()
+    pub fn headers(&self) -> &HeaderMap {
+        &self.inner.headers
     }
 
-    pub async fn bytes(self) -> Result<bytes::Bytes, reqwest::Error> {
-        self.inner.bytes().await
+    pub async fn bytes(self) -> Result<Bytes, SendRequestError> {
+        Ok(self.inner.body)
     }
 
-    pub async fn text(self) -> Result<String, reqwest::Error> {
-        self.inner.text().await
+    pub async fn text(self) -> Result<String, SendRequestError> {
+        Ok(String::from_utf8_lossy(&self.inner.body).into_owned())
     }
 }
 
@@ -137,6 +197,18 @@ pub fn make_client(_h1_only: bool, proxy: Option<&Proxy>) -> Client {
     }
 }
 
+/// Client sending through the given transport, or over the network when there is none.
+pub fn make_client_with(
+    transport: Option<&Arc<dyn HttpTransport>>,
+    h1_only: bool,
+    proxy: Option<&Proxy>,
+) -> Client {
+    match transport {
+        Some(transport) => Client::with_transport(transport.clone()),
+        None => make_client(h1_only, proxy),
+    }
+}
+
 pub fn client_without_proxy() -> Client {
     let inner = reqwest::Client::builder()
         .connect_timeout(CONNECT_TIMEOUT)
@@ -144,7 +216,7 @@ pub fn client_without_proxy() -> Client {
         .build()
         .expect("Failed to create HTTP client");
 
-    Client { inner }
+    Client::with_transport(Arc::new(ReqwestTransport::new(inner)))
 }
 
 pub fn client_with_proxy(proxy: &Proxy) -> Client {
@@ -158,5 +230,5 @@ pub fn client_with_proxy(proxy: &Proxy) -> Client {
         .build()
         .expect("Failed to create HTTP client with proxy");
 
-    Client { inner }
+    Client::with_transport(Arc::new(ReqwestTransport::new(inner)))
 }
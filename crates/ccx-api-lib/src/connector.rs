@@ -1,7 +1,6 @@
 use std::convert::TryFrom;
 use std::future::Future;
 use std::io::Error;
-use std::io::ErrorKind;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::Context;
@@ -24,7 +23,7 @@ use tokio_socks::TargetAddr;
 use tokio_socks::tcp::Socks5Stream;
 
 fn to_connect_error(e: SocksConnectError) -> ConnectError {
-    ConnectError::Io(Error::new(ErrorKind::Other, e))
+    ConnectError::Io(Error::other(e))
 }
 
 #[derive(Clone, Debug)]
@@ -108,6 +108,8 @@ where
     WsProtocolError(#[from] ProtocolError),
     #[error("Sign Error: {0}")]
     SignError(#[from] SignError),
+    #[error("Rate Limiter Error: {0}")]
+    RateLimiter(#[from] crate::RateLimiterError),
     #[error("Other Error: {0}")]
     Other(String),
 }
@@ -132,6 +134,16 @@ where
     }
 }
 
+#[cfg(any(feature = "with_awc", feature = "with_reqwest"))]
+impl<AE> From<crate::TransportError> for LibError<AE>
+where
+    AE: CcxApiError + 'static,
+{
+    fn from(e: crate::TransportError) -> Self {
+        Self::RequestError(e.to_string())
+    }
+}
+
 #[cfg(feature = "with_awc")]
 impl<AE> From<WsClientError> for LibError<AE>
 where
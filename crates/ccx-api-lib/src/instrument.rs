@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use thiserror::Error;
+
+use crate::Atom;
+
+/// Non-standard asset codes some exchanges use, with their common names.
+const ASSET_ALIASES: &[(&str, &str)] = &[("XBT", "BTC"), ("XDG", "DOGE")];
+
+/// Exchange-neutral asset code, e.g. `BTC`.
+///
+/// Codes are upper case with the aliases like `XBT` resolved to the common name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Asset(Atom);
+
+impl Asset {
+    pub fn new(code: &str) -> Self {
+        let code = code.trim().to_ascii_uppercase();
+        let code = ASSET_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == code)
+            .map_or(code.as_str(), |(_, name)| name);
+        Asset(code.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Asset {
+    fn from(code: &str) -> Self {
+        Asset::new(code)
+    }
+}
+
+impl From<&Atom> for Asset {
+    fn from(code: &Atom) -> Self {
+        Asset::new(code)
+    }
+}
+
+impl AsRef<str> for Asset {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for Asset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Asset {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Asset {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let code = String::deserialize(deserializer)?;
+        Ok(Asset::new(&code))
+    }
+}
+
+/// Exchange-neutral spot instrument, written as `BASE/QUOTE`, e.g. `BTC/USDT`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Instrument {
+    pub base: Asset,
+    pub quote: Asset,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("Invalid instrument {0:?}, expected BASE/QUOTE")]
+pub struct ParseInstrumentError(String);
+
+impl Instrument {
+    pub fn new(base: impl Into<Asset>, quote: impl Into<Asset>) -> Self {
+        Instrument {
+            base: base.into(),
+            quote: quote.into(),
+        }
+    }
+}
+
+impl fmt::Display for Instrument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.base, self.quote)
+    }
+}
+
+impl FromStr for Instrument {
+    type Err = ParseInstrumentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('/') {
+            Some((base, quote)) if !base.is_empty() && !quote.is_empty() => {
+                Ok(Instrument::new(base, quote))
+            }
+            _ => Err(ParseInstrumentError(s.to_string())),
+        }
+    }
+}
+
+impl Serialize for Instrument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Instrument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Bidirectional mapping between instruments and the symbols of one exchange.
+///
+/// Every instrument has a single primary symbol used in requests. Any number of additional
+/// symbols (e.g. legacy or websocket names) may resolve to an instrument.
+#[derive(Debug, Clone, Default)]
+pub struct InstrumentMap {
+    symbols: HashMap<Instrument, Atom>,
+    instruments: HashMap<Atom, Instrument>,
+}
+
+impl InstrumentMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps the instrument to its primary symbol and back.
+    ///
+    /// Returns the previous primary symbol of the instrument, which still resolves to it.
+    pub fn insert(&mut self, instrument: Instrument, symbol: impl Into<Atom>) -> Option<Atom> {
+        let symbol = symbol.into();
+        self.instruments.insert(symbol.clone(), instrument.clone());
+        self.symbols.insert(instrument, symbol)
+    }
+
+    /// Makes one more symbol resolve to the instrument without changing its primary symbol.
+    pub fn insert_alias(&mut self, symbol: impl Into<Atom>, instrument: Instrument) {
+        self.instruments.insert(symbol.into(), instrument);
+    }
+
+    /// The primary exchange symbol of the instrument.
+    pub fn symbol(&self, instrument: &Instrument) -> Option<&Atom> {
+        self.symbols.get(instrument)
+    }
+
+    /// The instrument an exchange symbol (primary or alias) stands for.
+    pub fn instrument(&self, symbol: &str) -> Option<&Instrument> {
+        self.instruments.get(&Atom::from(symbol))
+    }
+
+    /// Instruments with their primary symbols.
+    pub fn iter(&self) -> impl Iterator<Item = (&Instrument, &Atom)> {
+        self.symbols.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+
+impl<A: Into<Atom>> Extend<(Instrument, A)> for InstrumentMap {
+    fn extend<T: IntoIterator<Item = (Instrument, A)>>(&mut self, iter: T) {
+        for (instrument, symbol) in iter {
+            self.insert(instrument, symbol);
+        }
+    }
+}
+
+impl<A: Into<Atom>> FromIterator<(Instrument, A)> for InstrumentMap {
+    fn from_iter<T: IntoIterator<Item = (Instrument, A)>>(iter: T) -> Self {
+        let mut map = InstrumentMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asset_aliases() {
+        assert_eq!(Asset::new("xbt"), Asset::new("BTC"));
+        assert_eq!(Asset::new("XDG").as_str(), "DOGE");
+        assert_eq!(Asset::new("usdt").as_str(), "USDT");
+    }
+
+    #[test]
+    fn test_instrument_round_trip() {
+        let instrument: Instrument = "XBT/usd".parse().unwrap();
+        assert_eq!(instrument, Instrument::new("BTC", "USD"));
+        assert_eq!(instrument.to_string(), "BTC/USD");
+        assert!("BTCUSD".parse::<Instrument>().is_err());
+        assert!("BTC/".parse::<Instrument>().is_err());
+
+        let json = serde_json::to_string(&instrument).unwrap();
+        assert_eq!(json, r#""BTC/USD""#);
+        assert_eq!(
+            serde_json::from_str::<Instrument>(&json).unwrap(),
+            instrument
+        );
+    }
+
+    #[test]
+    fn test_instrument_map() {
+        let btc_usd = Instrument::new("BTC", "USD");
+        let mut map: InstrumentMap = [(btc_usd.clone(), "XXBTZUSD")].into_iter().collect();
+        map.insert_alias("XBTUSD", btc_usd.clone());
+
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.symbol(&btc_usd).map(|s| &**s), Some("XXBTZUSD"));
+        assert_eq!(map.instrument("XXBTZUSD"), Some(&btc_usd));
+        assert_eq!(map.instrument("XBTUSD"), Some(&btc_usd));
+        assert_eq!(map.instrument("ETHUSD"), None);
+    }
+}
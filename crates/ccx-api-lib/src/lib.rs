@@ -1,9 +1,11 @@
+pub use http;
 pub use rust_decimal;
 pub use rust_decimal::Decimal;
 pub use rust_decimal_macros::dec;
 pub use string_cache;
 pub use string_cache::DefaultAtom as Atom;
 
+mod backoff;
 #[cfg(any(feature = "with_awc", feature = "with_reqwest"))]
 mod client;
 #[cfg(feature = "with_awc")]
@@ -13,12 +15,17 @@ mod env;
 #[cfg(feature = "with_env_logger")]
 pub mod env_logger_util;
 mod error;
+mod instrument;
 mod proxy;
 mod rate_limiter;
+#[cfg(any(feature = "with_awc", feature = "with_reqwest"))]
+mod reconnecting;
 mod seq;
 pub mod serde_util;
+mod transport;
 
 // Export both client modules when their features are enabled
+pub use self::backoff::*;
 #[cfg(feature = "with_awc")]
 pub use self::client::awc;
 // For backward compatibility, re-export awc types at root level when only awc is enabled
@@ -39,5 +46,10 @@ pub use self::connector::*;
 pub use self::cred::*;
 pub use self::env::*;
 pub use self::error::*;
+pub use self::instrument::*;
 pub use self::proxy::*;
+pub use self::rate_limiter::*;
+#[cfg(any(feature = "with_awc", feature = "with_reqwest"))]
+pub use self::reconnecting::*;
 pub use self::seq::*;
+pub use self::transport::*;
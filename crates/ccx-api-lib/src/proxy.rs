@@ -21,3 +21,31 @@ impl Proxy {
         Some(Proxy { host, port })
     }
 }
+
+/// Opens a TCP connection to the host of the url, tunnelled through the SOCKS5 proxy if any.
+///
+/// The WebSocket backends on tokio run their handshake over the returned stream.
+#[cfg(any(feature = "with_awc", feature = "with_reqwest"))]
+pub async fn connect_tcp(
+    url: &url::Url,
+    proxy: Option<&Proxy>,
+) -> std::io::Result<tokio::net::TcpStream> {
+    use std::io;
+
+    let host = url
+        .host_str()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no host in the url"))?;
+    let port = url
+        .port_or_known_default()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no port for the url"))?;
+    match proxy {
+        Some(proxy) => {
+            let stream =
+                tokio_socks::tcp::Socks5Stream::connect(proxy.addr().as_str(), (host, port))
+                    .await
+                    .map_err(io::Error::other)?;
+            Ok(stream.into_inner())
+        }
+        None => tokio::net::TcpStream::connect((host, port)).await,
+    }
+}
@@ -1 +1,593 @@
+//! A runtime-agnostic rate limiter shared by the exchange clients.
+//!
+//! Tasks wait in a single queue ordered by priority (FIFO within the same priority) and
+//! are let through once every bucket they are charged against has room for their cost.
+//! Waiting happens in the future returned by [`RateLimiter::acquire`]: no background task
+//! is spawned, and dropping the future cancels the task and removes it from the queue.
 
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::collections::hash_map::Entry;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use futures::channel::oneshot;
+use thiserror::Error;
+
+pub type BucketName = Cow<'static, str>;
+pub type TaskCosts = HashMap<BucketName, u32>;
+
+/// A future completing after the requested duration.
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send>>;
+type SleepFn = Arc<dyn Fn(Duration) -> Sleep + Send + Sync>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum RateLimiterError {
+    #[error("RateLimiter: undefined bucket {0}")]
+    UndefinedBucket(BucketName),
+    #[error("RateLimiter: cost {cost} of bucket {bucket} exceeds its limit {limit}")]
+    CostExceedsLimit {
+        bucket: BucketName,
+        cost: u32,
+        limit: u32,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RateLimiterBucketMode {
+    /// The amount is reset when the interval since the start of the window elapses.
+    #[default]
+    Interval,
+    /// The amount is the sum of the costs charged within the last interval.
+    SlidingWindow,
+    /// The amount decreases by one every interval (Kraken-style counters).
+    Decrease,
+}
+
+pub struct RateLimiterBucket {
+    mode: RateLimiterBucketMode,
+    time_instant: Instant,
+    delay: Instant,
+    interval: Duration,
+    limit: u32,
+    amount: u32,
+    history: VecDeque<(Instant, u32)>,
+}
+
+impl Default for RateLimiterBucket {
+    fn default() -> Self {
+        Self {
+            mode: RateLimiterBucketMode::default(),
+            time_instant: Instant::now(),
+            delay: Instant::now(),
+            interval: Duration::default(),
+            limit: 0,
+            amount: 0,
+            history: VecDeque::new(),
+        }
+    }
+}
+
+impl fmt::Debug for RateLimiterBucket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimiterBucket")
+            .field("mode", &self.mode)
+            .field("interval", &self.interval)
+            .field("limit", &self.limit)
+            .field("amount", &self.amount)
+            .finish()
+    }
+}
+
+impl RateLimiterBucket {
+    pub fn mode(mut self, mode: RateLimiterBucketMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Instant::now() + delay;
+        self
+    }
+
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn get_interval(&self) -> Duration {
+        self.interval
+    }
+
+    pub fn get_limit(&self) -> u32 {
+        self.limit
+    }
+
+    /// The amount charged within the current window.
+    pub fn amount(&mut self) -> u32 {
+        self.update_state();
+        self.amount
+    }
+
+    pub fn set_limit(&mut self, limit: u32) {
+        self.limit = limit;
+    }
+
+    /// Holds off the tasks charged against the bucket until `until`.
+    pub fn delay_until(&mut self, until: Instant) {
+        self.delay = self.delay.max(until);
+    }
+
+    /// Takes into account the usage reported by a server whose windows are aligned to
+    /// the wall clock, which also counts the requests of other clients sharing the limit.
+    ///
+    /// Only applies to [`RateLimiterBucketMode::Interval`] buckets.
+    pub fn sync_used(&mut self, used: u32) {
+        if self.mode != RateLimiterBucketMode::Interval {
+            return;
+        }
+        let Ok(since_epoch) = SystemTime::now().duration_since(UNIX_EPOCH) else {
+            return;
+        };
+        let interval_ms = self.interval.as_millis();
+        if interval_ms == 0 {
+            return;
+        }
+        let elapsed = Duration::from_millis((since_epoch.as_millis() % interval_ms) as u64);
+        let Some(window_start) = Instant::now().checked_sub(elapsed) else {
+            return;
+        };
+        // Responses may come out of order, so a lower usage within the same window
+        // is not trusted.
+        if window_start > self.time_instant + self.interval / 2 {
+            self.amount = used;
+        } else {
+            self.amount = self.amount.max(used);
+        }
+        self.time_instant = window_start;
+    }
+
+    fn update_state(&mut self) {
+        let now = Instant::now();
+        match self.mode {
+            RateLimiterBucketMode::Interval => {
+                if now.duration_since(self.time_instant) > self.interval {
+                    self.time_instant = now;
+                    self.amount = 0;
+                }
+            }
+            RateLimiterBucketMode::SlidingWindow => {
+                while let Some((at, cost)) = self.history.front().copied() {
+                    if now.duration_since(at) < self.interval {
+                        break;
+                    }
+                    self.history.pop_front();
+                    self.amount = self.amount.saturating_sub(cost);
+                }
+            }
+            RateLimiterBucketMode::Decrease => {
+                if self.interval.is_zero() {
+                    self.amount = 0;
+                    return;
+                }
+                let elapsed = now.duration_since(self.time_instant);
+                let steps = elapsed.as_nanos() / self.interval.as_nanos();
+                if steps >= u128::from(self.amount) {
+                    self.amount = 0;
+                    self.time_instant = now;
+                } else if steps > 0 {
+                    // Less than `amount`, so it fits into u32.
+                    self.amount -= steps as u32;
+                    self.time_instant += self.interval * steps as u32;
+                }
+            }
+        }
+    }
+
+    /// How long the task has to wait before `cost` fits into the bucket.
+    fn timeout(&mut self, cost: u32) -> Option<Duration> {
+        let now = Instant::now();
+        let delay = self.delay.saturating_duration_since(now);
+        if !delay.is_zero() {
+            return Some(delay);
+        }
+
+        self.update_state();
+        if self.amount + cost <= self.limit {
+            return None;
+        }
+
+        let timeout = match self.mode {
+            RateLimiterBucketMode::Interval | RateLimiterBucketMode::Decrease => {
+                (self.time_instant + self.interval).saturating_duration_since(now)
+            }
+            RateLimiterBucketMode::SlidingWindow => {
+                let mut amount = self.amount;
+                let mut expires = now;
+                for (at, charged) in &self.history {
+                    amount = amount.saturating_sub(*charged);
+                    expires = *at + self.interval;
+                    if amount + cost <= self.limit {
+                        break;
+                    }
+                }
+                expires.saturating_duration_since(now)
+            }
+        };
+        // The state is updated on the next check anyway.
+        Some(timeout.max(Duration::from_millis(1)))
+    }
+
+    fn charge(&mut self, cost: u32) {
+        self.update_state();
+        self.amount += cost;
+        if self.mode == RateLimiterBucketMode::SlidingWindow {
+            self.history.push_back((Instant::now(), cost));
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct RateLimiterBuilder {
+    buckets: HashMap<BucketName, RateLimiterBucket>,
+    sleep: Option<SleepFn>,
+}
+
+impl RateLimiterBuilder {
+    pub fn bucket(mut self, key: impl Into<BucketName>, bucket: RateLimiterBucket) -> Self {
+        match self.buckets.entry(key.into()) {
+            Entry::Occupied(mut e) => *e.get_mut() = bucket,
+            Entry::Vacant(e) => {
+                e.insert(bucket);
+            }
+        }
+        self
+    }
+
+    /// Sets the timer used to wait for the buckets.
+    ///
+    /// Defaults to `tokio::time::sleep` when the `tokio` feature is enabled and to a
+    /// thread-backed timer otherwise.
+    pub fn sleep<F>(mut self, sleep: F) -> Self
+    where
+        F: Fn(Duration) -> Sleep + Send + Sync + 'static,
+    {
+        self.sleep = Some(Arc::new(sleep));
+        self
+    }
+
+    pub fn start(self) -> RateLimiter {
+        RateLimiter {
+            state: Arc::new(Mutex::new(State {
+                buckets: self.buckets,
+                queue: Vec::new(),
+                next_seq: 0,
+            })),
+            sleep: self.sleep.unwrap_or_else(|| Arc::new(default_sleep)),
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+fn default_sleep(duration: Duration) -> Sleep {
+    Box::pin(tokio::time::sleep(duration))
+}
+
+#[cfg(not(feature = "tokio"))]
+fn default_sleep(duration: Duration) -> Sleep {
+    let (tx, rx) = oneshot::channel();
+    std::thread::spawn(move || {
+        std::thread::sleep(duration);
+        let _ = tx.send(());
+    });
+    Box::pin(async move {
+        let _ = rx.await;
+    })
+}
+
+struct QueuedTask {
+    seq: u64,
+    priority: u8,
+    /// Wakes the task when it becomes the first in the queue.
+    turn: Option<oneshot::Sender<()>>,
+}
+
+struct State {
+    buckets: HashMap<BucketName, RateLimiterBucket>,
+    /// Sorted by priority (highest first), then by arrival.
+    queue: Vec<QueuedTask>,
+    next_seq: u64,
+}
+
+impl State {
+    fn enqueue(&mut self, priority: u8) -> u64 {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        let pos = self.queue.partition_point(|t| t.priority >= priority);
+        self.queue.insert(
+            pos,
+            QueuedTask {
+                seq,
+                priority,
+                turn: None,
+            },
+        );
+        seq
+    }
+
+    fn remove(&mut self, seq: u64) {
+        let Some(pos) = self.queue.iter().position(|t| t.seq == seq) else {
+            return;
+        };
+        self.queue.remove(pos);
+        if pos == 0
+            && let Some(turn) = self.queue.first_mut().and_then(|t| t.turn.take())
+        {
+            let _ = turn.send(());
+        }
+    }
+
+    fn is_first(&self, seq: u64) -> bool {
+        self.queue.first().is_some_and(|t| t.seq == seq)
+    }
+
+    fn timeout(&mut self, costs: &TaskCosts) -> Option<Duration> {
+        let mut timeout = None;
+        for (name, cost) in costs {
+            if let Some(bucket) = self.buckets.get_mut(name) {
+                timeout = timeout.max(bucket.timeout(*cost));
+            }
+        }
+        timeout
+    }
+
+    fn charge(&mut self, costs: &TaskCosts) {
+        for (name, cost) in costs {
+            if let Some(bucket) = self.buckets.get_mut(name) {
+                bucket.charge(*cost);
+            }
+        }
+    }
+}
+
+/// Removes the task from the queue when the waiting future is dropped.
+struct QueueGuard<'a> {
+    state: &'a Mutex<State>,
+    seq: u64,
+}
+
+impl Drop for QueueGuard<'_> {
+    fn drop(&mut self) {
+        lock(self.state).remove(self.seq);
+    }
+}
+
+enum Wait {
+    Sleep(Duration),
+    Turn(oneshot::Receiver<()>),
+}
+
+#[derive(Clone)]
+pub struct RateLimiter {
+    state: Arc<Mutex<State>>,
+    sleep: SleepFn,
+}
+
+impl fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimiter").finish_non_exhaustive()
+    }
+}
+
+impl RateLimiter {
+    /// Waits until the task with the given costs may run and charges the buckets.
+    ///
+    /// Fails right away if a bucket is undefined or the cost can never fit into it.
+    pub async fn acquire(&self, costs: &TaskCosts, priority: u8) -> Result<(), RateLimiterError> {
+        let seq = {
+            let mut state = lock(&self.state);
+            for (name, cost) in costs {
+                let bucket = state
+                    .buckets
+                    .get(name)
+                    .ok_or_else(|| RateLimiterError::UndefinedBucket(name.clone()))?;
+                if *cost > bucket.limit {
+                    Err(RateLimiterError::CostExceedsLimit {
+                        bucket: name.clone(),
+                        cost: *cost,
+                        limit: bucket.limit,
+                    })?
+                }
+            }
+            state.enqueue(priority)
+        };
+        let guard = QueueGuard {
+            state: &self.state,
+            seq,
+        };
+        log::debug!("RateLimiter: queued task with priority {}", priority);
+
+        loop {
+            let wait = {
+                let mut state = lock(&self.state);
+                if state.is_first(seq) {
+                    match state.timeout(costs) {
+                        None => {
+                            state.charge(costs);
+                            break;
+                        }
+                        Some(timeout) => Wait::Sleep(timeout),
+                    }
+                } else {
+                    let (tx, rx) = oneshot::channel();
+                    if let Some(task) = state.queue.iter_mut().find(|t| t.seq == seq) {
+                        task.turn = Some(tx);
+                    }
+                    Wait::Turn(rx)
+                }
+            };
+            match wait {
+                Wait::Sleep(timeout) => {
+                    log::debug!("RateLimiter: sleep for {:?}", timeout);
+                    (self.sleep)(timeout).await;
+                }
+                Wait::Turn(rx) => {
+                    let _ = rx.await;
+                }
+            }
+        }
+
+        drop(guard);
+        log::debug!("RateLimiter: completed task with priority {}", priority);
+        Ok(())
+    }
+
+    /// Gives access to the buckets, e.g. to update their limits or sync their usage.
+    pub fn update_buckets(&self, mut f: impl FnMut(&BucketName, &mut RateLimiterBucket)) {
+        let mut state = lock(&self.state);
+        for (name, bucket) in state.buckets.iter_mut() {
+            f(name, bucket);
+        }
+    }
+
+    /// Holds off all the tasks for `delay`, e.g. after the server asked to back off.
+    pub fn delay_all(&self, delay: Duration) {
+        let until = Instant::now() + delay;
+        self.update_buckets(|_, bucket| bucket.delay_until(until));
+    }
+}
+
+fn lock(state: &Mutex<State>) -> MutexGuard<'_, State> {
+    // The state is consistent between the statements, so a poisoned lock is still usable.
+    state.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[derive(Debug)]
+pub struct TaskMetadata {
+    pub costs: TaskCosts,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicU32;
+    use std::sync::atomic::Ordering;
+
+    use futures::FutureExt;
+
+    use super::*;
+
+    fn costs(items: &[(&'static str, u32)]) -> TaskCosts {
+        items.iter().map(|(k, v)| (Cow::Borrowed(*k), *v)).collect()
+    }
+
+    #[test]
+    fn test_sliding_window_timeout() {
+        let mut bucket = RateLimiterBucket::default()
+            .mode(RateLimiterBucketMode::SlidingWindow)
+            .interval(Duration::from_secs(10))
+            .limit(3);
+        bucket.charge(2);
+        bucket.charge(1);
+        assert_eq!(bucket.amount(), 3);
+
+        let timeout = bucket.timeout(1).unwrap();
+        assert!(timeout > Duration::from_secs(9) && timeout <= Duration::from_secs(10));
+        assert_eq!(bucket.timeout(0), None);
+    }
+
+    #[test]
+    fn test_bucket_sync_used() {
+        let mut bucket = RateLimiterBucket::default()
+            .interval(Duration::from_secs(60))
+            .limit(1_200);
+        bucket.amount = 5;
+
+        bucket.sync_used(300);
+        assert_eq!(bucket.amount, 300);
+        assert!(bucket.timeout(1_000).unwrap() <= Duration::from_secs(60));
+
+        // A stale report within the same window doesn't lower the usage.
+        bucket.sync_used(250);
+        assert_eq!(bucket.amount, 300);
+    }
+
+    #[test]
+    fn test_rate_limiter_errors() {
+        let rate_limiter = RateLimiterBuilder::default()
+            .bucket(
+                "interval_1__limit_1",
+                RateLimiterBucket::default()
+                    .interval(Duration::from_secs(1))
+                    .limit(1),
+            )
+            .start();
+
+        let res = rate_limiter
+            .acquire(&costs(&[("unknown", 1)]), 0)
+            .now_or_never();
+        assert_eq!(
+            res,
+            Some(Err(RateLimiterError::UndefinedBucket("unknown".into())))
+        );
+
+        let res = rate_limiter
+            .acquire(&costs(&[("interval_1__limit_1", 2)]), 0)
+            .now_or_never();
+        assert!(matches!(
+            res,
+            Some(Err(RateLimiterError::CostExceedsLimit { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_rate_limiter_priority_and_cancellation() {
+        let sleeps = Arc::new(AtomicU32::new(0));
+        let rate_limiter = {
+            let sleeps = sleeps.clone();
+            RateLimiterBuilder::default()
+                .bucket(
+                    "interval_60__limit_1",
+                    RateLimiterBucket::default()
+                        .interval(Duration::from_secs(60))
+                        .limit(1),
+                )
+                .sleep(move |_| {
+                    sleeps.fetch_add(1, Ordering::SeqCst);
+                    Box::pin(futures::future::pending())
+                })
+                .start()
+        };
+        let costs = costs(&[("interval_60__limit_1", 1)]);
+
+        assert_eq!(rate_limiter.acquire(&costs, 0).now_or_never(), Some(Ok(())));
+
+        // The bucket is full: each task sleeps once it is the first in the queue.
+        let mut low = rate_limiter.acquire(&costs, 0).boxed();
+        let mut high = rate_limiter.acquire(&costs, 1).boxed();
+        assert!((&mut low).now_or_never().is_none());
+        assert!((&mut high).now_or_never().is_none());
+        assert_eq!(sleeps.load(Ordering::SeqCst), 2);
+        assert_eq!(lock(&rate_limiter.state).queue.len(), 2);
+        assert_eq!(lock(&rate_limiter.state).queue[0].priority, 1);
+
+        // Dropping the tasks removes them from the queue.
+        drop(high);
+        assert_eq!(lock(&rate_limiter.state).queue.len(), 1);
+        drop(low);
+        assert!(lock(&rate_limiter.state).queue.is_empty());
+    }
+}
@@ -0,0 +1,368 @@
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use futures::FutureExt;
+use futures::Stream;
+use futures::StreamExt;
+use futures::channel::mpsc;
+use futures::future::Fuse;
+use tokio::time::Sleep;
+
+use crate::Backoff;
+
+/// A message of a reconnecting websocket stream.
+#[derive(Debug)]
+pub enum ReconnectingEvent<M> {
+    Message(M),
+    /// The connection has been lost; a new one is being established.
+    Disconnected,
+    /// The connection has been re-established and the active subscriptions replayed.
+    Reconnected,
+}
+
+/// Exchange specific part of a reconnecting websocket stream.
+///
+/// Opens the connections and keeps track of the active subscriptions, so that they can
+/// be replayed on a new connection.
+pub trait Reconnect {
+    /// The sending half of a connection.
+    type Sink;
+    /// The receiving half of a connection; ends when the connection is lost.
+    type Stream: Stream + Unpin;
+    type Command;
+    type Error: fmt::Debug;
+
+    /// Opens a new connection.
+    fn connect(&self) -> impl Future<Output = Result<(Self::Sink, Self::Stream), Self::Error>>;
+
+    /// Subscribes a new connection to the active subscriptions.
+    fn resubscribe(&self, sink: &Self::Sink) -> impl Future<Output = Result<(), Self::Error>>;
+
+    /// Keeps the active subscriptions up to date with the command.
+    fn track(&mut self, cmd: &Self::Command);
+
+    /// Sends the command over the connection.
+    fn send(
+        &self,
+        sink: &Self::Sink,
+        cmd: Self::Command,
+    ) -> impl Future<Output = Result<(), Self::Error>>;
+
+    /// Closes a connection which is no longer used.
+    fn close(sink: Self::Sink);
+}
+
+type Item<R> = <<R as Reconnect>::Stream as Stream>::Item;
+
+/// Drives a websocket stream surviving disconnections.
+///
+/// Forwards the messages of the current connection and the commands to it. A lost
+/// connection is re-established with an exponential backoff, and only reported as
+/// [`ReconnectingEvent::Reconnected`] once the active subscriptions have been replayed.
+///
+/// With [`ReconnectingSupervisor::max_age`] set, the connection is renewed ahead of
+/// a forced disconnect: the new connection is opened and subscribed before the old one
+/// is closed, so no events are reported. The supervisor stops when the receiving half
+/// of the events is dropped.
+pub struct ReconnectingSupervisor<R: Reconnect> {
+    connector: R,
+    max_age: Option<Duration>,
+    backoff: Backoff,
+    cmd_rx: mpsc::UnboundedReceiver<R::Command>,
+    tx: mpsc::UnboundedSender<ReconnectingEvent<Item<R>>>,
+}
+
+impl<R: Reconnect> ReconnectingSupervisor<R> {
+    pub fn new(
+        connector: R,
+        cmd_rx: mpsc::UnboundedReceiver<R::Command>,
+        tx: mpsc::UnboundedSender<ReconnectingEvent<Item<R>>>,
+    ) -> Self {
+        ReconnectingSupervisor {
+            connector,
+            max_age: None,
+            backoff: Backoff::default(),
+            cmd_rx,
+            tx,
+        }
+    }
+
+    /// Renews every connection once it gets that old.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Runs until the receiving half of the events is dropped.
+    pub async fn run(mut self, sink: R::Sink, stream: R::Stream) {
+        let (mut sink, mut stream) = (sink, stream);
+        loop {
+            if !self.session(sink, stream).await {
+                return;
+            }
+            if self
+                .tx
+                .unbounded_send(ReconnectingEvent::Disconnected)
+                .is_err()
+            {
+                return;
+            }
+            (sink, stream) = loop {
+                let delay = self.backoff.next_delay();
+                log::debug!("ReconnectingSupervisor: reconnecting in {:?}", delay);
+                tokio::time::sleep(delay).await;
+                if self.tx.is_closed() {
+                    return;
+                }
+                match self.connect().await {
+                    Ok(connection) => break connection,
+                    Err(e) => log::warn!("ReconnectingSupervisor: reconnect failed: {:?}", e),
+                }
+            };
+            self.backoff.reset();
+            if self
+                .tx
+                .unbounded_send(ReconnectingEvent::Reconnected)
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+
+    /// Opens a new connection and replays the active subscriptions on it.
+    async fn connect(&self) -> Result<(R::Sink, R::Stream), R::Error> {
+        let (sink, stream) = self.connector.connect().await?;
+        match self.connector.resubscribe(&sink).await {
+            Ok(()) => Ok((sink, stream)),
+            Err(e) => {
+                R::close(sink);
+                Err(e)
+            }
+        }
+    }
+
+    /// Forwards the messages of a connection, renewing it when it gets too old.
+    ///
+    /// Returns whether to reconnect.
+    async fn session(&mut self, sink: R::Sink, stream: R::Stream) -> bool {
+        let (mut sink, mut stream) = (sink, stream.fuse());
+        let mut expired = expiry(self.max_age);
+        loop {
+            futures::select! {
+                msg = stream.next() => match msg {
+                    Some(msg) => {
+                        if self.tx.unbounded_send(ReconnectingEvent::Message(msg)).is_err() {
+                            R::close(sink);
+                            return false;
+                        }
+                    }
+                    None => {
+                        log::warn!("ReconnectingSupervisor: connection lost");
+                        return true;
+                    }
+                },
+                cmd = self.cmd_rx.next() => {
+                    // Once all the senders are dropped only the events are forwarded.
+                    if let Some(cmd) = cmd {
+                        self.connector.track(&cmd);
+                        if let Err(e) = self.connector.send(&sink, cmd).await {
+                            log::warn!("ReconnectingSupervisor: command failed: {:?}", e);
+                        }
+                    }
+                }
+                _ = expired => match self.connect().await {
+                    Ok((new_sink, new_stream)) => {
+                        log::debug!("ReconnectingSupervisor: connection renewed");
+                        R::close(std::mem::replace(&mut sink, new_sink));
+                        stream = new_stream.fuse();
+                        self.backoff.reset();
+                        expired = expiry(self.max_age);
+                    }
+                    Err(e) => {
+                        // The old connection is still up; try again a bit later.
+                        log::warn!("ReconnectingSupervisor: renewal failed: {:?}", e);
+                        expired = expiry(Some(self.backoff.next_delay()));
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Resolves after the given time, or never.
+fn expiry(after: Option<Duration>) -> Fuse<Pin<Box<Sleep>>> {
+    match after {
+        Some(after) => Box::pin(tokio::time::sleep(after)).fuse(),
+        None => Fuse::terminated(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Server {
+        /// Pushes the messages to the open connections by id.
+        connections: HashMap<usize, mpsc::UnboundedSender<u32>>,
+        next_id: usize,
+        log: Vec<String>,
+        /// The number of the next resubscriptions to fail.
+        failing: usize,
+    }
+
+    struct FakeSink {
+        id: usize,
+        server: Arc<Mutex<Server>>,
+    }
+
+    #[derive(Clone, Default)]
+    struct Fake {
+        server: Arc<Mutex<Server>>,
+        subscriptions: Vec<u32>,
+    }
+
+    impl Reconnect for Fake {
+        type Sink = FakeSink;
+        type Stream = mpsc::UnboundedReceiver<u32>;
+        type Command = u32;
+        type Error = &'static str;
+
+        async fn connect(&self) -> Result<(FakeSink, Self::Stream), &'static str> {
+            let (tx, rx) = mpsc::unbounded();
+            let mut server = self.server.lock().unwrap();
+            let id = server.next_id;
+            server.next_id += 1;
+            server.connections.insert(id, tx);
+            server.log.push(format!("connect {id}"));
+            let server = self.server.clone();
+            Ok((FakeSink { id, server }, rx))
+        }
+
+        async fn resubscribe(&self, sink: &FakeSink) -> Result<(), &'static str> {
+            let mut server = self.server.lock().unwrap();
+            if server.failing > 0 {
+                server.failing -= 1;
+                return Err("resubscribe failed");
+            }
+            server
+                .log
+                .push(format!("resubscribe {} {:?}", sink.id, self.subscriptions));
+            Ok(())
+        }
+
+        fn track(&mut self, cmd: &u32) {
+            self.subscriptions.push(*cmd);
+        }
+
+        async fn send(&self, _sink: &FakeSink, _cmd: u32) -> Result<(), &'static str> {
+            Ok(())
+        }
+
+        fn close(sink: FakeSink) {
+            let mut server = sink.server.lock().unwrap();
+            server.connections.remove(&sink.id);
+            server.log.push(format!("close {}", sink.id));
+        }
+    }
+
+    impl Fake {
+        fn push(&self, id: usize, msg: u32) {
+            let server = self.server.lock().unwrap();
+            server.connections[&id].unbounded_send(msg).unwrap();
+        }
+
+        fn log(&self) -> Vec<String> {
+            self.server.lock().unwrap().log.clone()
+        }
+    }
+
+    fn start(
+        fake: &Fake,
+        max_age: Option<Duration>,
+    ) -> (
+        mpsc::UnboundedSender<u32>,
+        mpsc::UnboundedReceiver<ReconnectingEvent<u32>>,
+    ) {
+        let (cmd_tx, cmd_rx) = mpsc::unbounded();
+        let (tx, rx) = mpsc::unbounded();
+        let fake = fake.clone();
+        tokio::spawn(async move {
+            let (sink, stream) = fake.connect().await.unwrap();
+            let mut supervisor = ReconnectingSupervisor::new(fake, cmd_rx, tx);
+            if let Some(max_age) = max_age {
+                supervisor = supervisor.max_age(max_age);
+            }
+            supervisor.run(sink, stream).await
+        });
+        (cmd_tx, rx)
+    }
+
+    async fn settle() {
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_renewal_is_seamless() {
+        let fake = Fake::default();
+        let (cmd_tx, mut rx) = start(&fake, Some(Duration::from_secs(60)));
+        cmd_tx.unbounded_send(7).unwrap();
+        settle().await;
+        fake.push(0, 1);
+        assert!(matches!(
+            rx.next().await,
+            Some(ReconnectingEvent::Message(1))
+        ));
+
+        tokio::time::sleep(Duration::from_secs(61)).await;
+        settle().await;
+        // The old connection is closed only after the new one is subscribed.
+        assert_eq!(
+            fake.log(),
+            ["connect 0", "connect 1", "resubscribe 1 [7]", "close 0"]
+        );
+        fake.push(1, 2);
+        assert!(matches!(
+            rx.next().await,
+            Some(ReconnectingEvent::Message(2))
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reconnect_retries_failed_resubscribe() {
+        let fake = Fake::default();
+        let (cmd_tx, mut rx) = start(&fake, None);
+        cmd_tx.unbounded_send(7).unwrap();
+        settle().await;
+
+        fake.server.lock().unwrap().failing = 1;
+        fake.server.lock().unwrap().connections.remove(&0);
+        assert!(matches!(
+            rx.next().await,
+            Some(ReconnectingEvent::Disconnected)
+        ));
+        assert!(matches!(
+            rx.next().await,
+            Some(ReconnectingEvent::Reconnected)
+        ));
+        assert_eq!(
+            fake.log(),
+            [
+                "connect 0",
+                "connect 1",
+                "close 1",
+                "connect 2",
+                "resubscribe 2 [7]"
+            ]
+        );
+    }
+}
@@ -82,17 +82,21 @@ impl<'de> Deserialize<'de> for CompatF64 {
 
 #[cfg(test)]
 mod tests {
+    use serde::de::IntoDeserializer;
+    use serde::de::value::Error as ValueError;
+
     use super::*;
 
     #[test]
     fn parse_plain_number() {
-        let v: f64 = deserialize(1.5_f64.into_deserializer()).unwrap();
+        let v: f64 =
+            deserialize(IntoDeserializer::<ValueError>::into_deserializer(1.5_f64)).unwrap();
         assert_eq!(v, 1.5);
     }
 
     #[test]
     fn parse_string_number() {
-        let v: f64 = deserialize("1.5".into_deserializer()).unwrap();
+        let v: f64 = deserialize(IntoDeserializer::<ValueError>::into_deserializer("1.5")).unwrap();
         assert_eq!(v, 1.5);
     }
 
@@ -4,6 +4,7 @@ pub mod f64_arbitrary_precision;
 /// Unlike `serde_with::NoneAsEmptyString`, it uses `Serialize` & `Deserialize`
 /// instead of [FromStr](std::str::FromStr) & [Display](core::fmt::Display)
 pub mod none_as_empty_str;
+pub mod value_buffered;
 
 use serde::Deserialize;
 use serde::Deserializer;
@@ -60,6 +60,7 @@ mod tests {
         };
         let json = r#"{}"#;
         assert_eq!(case, serde_json::from_str(json).unwrap());
+        let json = r#"{"rate":"","description":""}"#;
         assert_eq!(json, serde_json::to_string(&case).unwrap());
     }
 
@@ -69,7 +70,7 @@ mod tests {
             rate: Decimal::from_str("1.25").ok(),
             description: Some("Sample text".to_owned()),
         };
-        let json = r#"{"rate": "1.25", "description": "Sample text"}"#;
+        let json = r#"{"rate":"1.25","description":"Sample text"}"#;
         assert_eq!(case, serde_json::from_str(json).unwrap());
         assert_eq!(json, serde_json::to_string(&case).unwrap());
     }
@@ -78,9 +79,9 @@ mod tests {
     fn all_some_escaped_str() {
         let case = Test {
             rate: Decimal::from_str("1.25").ok(),
-            description: Some(r#"Sample text: \"Sample\""#.to_owned()),
+            description: Some(r#"Sample text: "Sample""#.to_owned()),
         };
-        let json = r#"{"rate": "1.25", "description": "Sample text: \"Sample\""}"#;
+        let json = r#"{"rate":"1.25","description":"Sample text: \"Sample\""}"#;
         assert_eq!(case, serde_json::from_str(json).unwrap());
         assert_eq!(json, serde_json::to_string(&case).unwrap());
     }
@@ -91,7 +92,7 @@ mod tests {
             rate: None,
             description: None,
         };
-        let json = r#"{"rate": "", "description": ""}"#;
+        let json = r#"{"rate":"","description":""}"#;
         assert_eq!(case, serde_json::from_str(json).unwrap());
         assert_eq!(json, serde_json::to_string(&case).unwrap());
     }
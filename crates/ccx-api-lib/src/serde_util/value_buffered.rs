@@ -0,0 +1,43 @@
+//! Buffering through [`serde_json::Value`] in place of `#[serde(untagged)]` or
+//! `#[serde(tag = "..")]`, whose content buffering does not round-trip numbers
+//! with `arbitrary_precision` enabled.
+
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::de::DeserializeOwned;
+use serde::de::Error;
+use serde_json::Value;
+
+/// Buffers the input to pick the representation from.
+pub fn buffer<'de, D>(deserializer: D) -> Result<Value, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Value::deserialize(deserializer)
+}
+
+/// Decodes the buffered input into the picked representation.
+pub fn from_value<T, E>(value: Value) -> Result<T, E>
+where
+    T: DeserializeOwned,
+    E: Error,
+{
+    serde_json::from_value(value).map_err(E::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Qty {
+        qty: u64,
+    }
+
+    #[test]
+    fn test_from_value() {
+        let value = buffer(&mut serde_json::Deserializer::from_str(r#"{"qty": 42}"#)).unwrap();
+        let qty: Result<Qty, serde_json::Error> = from_value(value);
+        assert_eq!(qty.unwrap(), Qty { qty: 42 });
+    }
+}
@@ -0,0 +1,354 @@
+//! The HTTP layer the exchange REST clients send their requests through.
+//!
+//! [`HttpTransport`] takes a fully built request (signed, with the query in the url) and
+//! returns the whole response. The network implementation lives next to the client wrapper;
+//! [`MockTransport`] and [`RecordingTransport`] allow running the clients against canned
+//! responses without network access.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use bytes::Bytes;
+use futures::FutureExt;
+use futures::future::BoxFuture;
+use http::HeaderMap;
+use http::HeaderName;
+use http::HeaderValue;
+use http::Method;
+use http::StatusCode;
+use serde::Deserialize;
+use serde::Serialize;
+use thiserror::Error;
+use url::Url;
+
+#[derive(Clone, Debug, Error)]
+pub enum TransportError {
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("Request failed: {0}")]
+    Request(String),
+    #[error("No response for {0}")]
+    NoResponse(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+impl HttpResponse {
+    pub fn new(status: StatusCode, body: impl Into<Bytes>) -> Self {
+        HttpResponse {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// `200 OK` with the value serialized as JSON.
+    pub fn json(value: &impl Serialize) -> Self {
+        let body = serde_json::to_vec(value).expect("Failed to serialize response body");
+        HttpResponse::new(StatusCode::OK, body)
+    }
+}
+
+pub trait HttpTransport: Send + Sync {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'static, Result<HttpResponse, TransportError>>;
+}
+
+impl<T> HttpTransport for Arc<T>
+where
+    T: HttpTransport + ?Sized,
+{
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'static, Result<HttpResponse, TransportError>> {
+        (**self).send(request)
+    }
+}
+
+/// A request and the response it got, in a form that can be stored as a fixture.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecordedExchange {
+    pub method: String,
+    pub url: Url,
+    pub status: u16,
+    /// Response headers, e.g. the rate limit usage and `Retry-After`.
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// Stored base64-encoded, as a response may be binary (e.g. an archive).
+    #[serde(with = "base64_body")]
+    pub body: Bytes,
+}
+
+impl RecordedExchange {
+    /// The body as text; `None` when it isn't valid UTF-8.
+    pub fn text(&self) -> Option<&str> {
+        std::str::from_utf8(&self.body).ok()
+    }
+}
+
+mod base64_body {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use bytes::Bytes;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serializer;
+
+    pub fn serialize<S: Serializer>(body: &Bytes, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(body))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Bytes, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        STANDARD
+            .decode(encoded)
+            .map(Bytes::from)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Replays queued responses and keeps the requests it received.
+///
+/// A request gets the first queued response with the same method and url path; the query
+/// and the body are not compared. Clones share the queue, so a clone can be handed to a
+/// client and the original used to queue responses and inspect the requests.
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Default)]
+struct MockState {
+    responses: VecDeque<(Method, String, HttpResponse)>,
+    requests: Vec<HttpRequest>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replays the exchanges captured by a [`RecordingTransport`].
+    pub fn replay(
+        records: impl IntoIterator<Item = RecordedExchange>,
+    ) -> Result<Self, TransportError> {
+        let mock = MockTransport::new();
+        for record in records {
+            let method = Method::from_bytes(record.method.as_bytes())
+                .map_err(|e| TransportError::InvalidRequest(e.to_string()))?;
+            let status = StatusCode::from_u16(record.status)
+                .map_err(|e| TransportError::InvalidRequest(e.to_string()))?;
+            let mut response = HttpResponse::new(status, record.body);
+            for (name, value) in record.headers {
+                let name = HeaderName::from_bytes(name.as_bytes())
+                    .map_err(|e| TransportError::InvalidRequest(e.to_string()))?;
+                let value = HeaderValue::from_str(&value)
+                    .map_err(|e| TransportError::InvalidRequest(e.to_string()))?;
+                response.headers.append(name, value);
+            }
+            mock.respond(method, record.url.path(), response);
+        }
+        Ok(mock)
+    }
+
+    /// Queues a response for a request with the method and url path.
+    pub fn respond(&self, method: Method, path: &str, response: HttpResponse) -> &Self {
+        self.state()
+            .responses
+            .push_back((method, path.to_string(), response));
+        self
+    }
+
+    /// The requests received so far.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.state().requests.clone()
+    }
+
+    /// The number of queued responses not requested yet.
+    pub fn pending(&self) -> usize {
+        self.state().responses.len()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().expect("mock transport poisoned")
+    }
+}
+
+impl HttpTransport for MockTransport {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'static, Result<HttpResponse, TransportError>> {
+        let mut state = self.state();
+        let position = state
+            .responses
+            .iter()
+            .position(|(method, path, _)| *method == request.method && path == request.url.path());
+        let response = match position.and_then(|i| state.responses.remove(i)) {
+            Some((_, _, response)) => Ok(response),
+            None => Err(TransportError::NoResponse(format!(
+                "{} {}",
+                request.method, request.url
+            ))),
+        };
+        state.requests.push(request);
+        futures::future::ready(response).boxed()
+    }
+}
+
+/// Passes requests to another transport and records every exchange.
+#[derive(Clone)]
+pub struct RecordingTransport<T> {
+    inner: T,
+    records: Arc<Mutex<Vec<RecordedExchange>>>,
+}
+
+impl<T> RecordingTransport<T>
+where
+    T: HttpTransport,
+{
+    pub fn new(inner: T) -> Self {
+        RecordingTransport {
+            inner,
+            records: Default::default(),
+        }
+    }
+
+    /// The exchanges recorded so far, in the order the responses arrived.
+    pub fn records(&self) -> Vec<RecordedExchange> {
+        self.records.lock().expect("recording poisoned").clone()
+    }
+}
+
+impl<T> HttpTransport for RecordingTransport<T>
+where
+    T: HttpTransport,
+{
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> BoxFuture<'static, Result<HttpResponse, TransportError>> {
+        let method = request.method.to_string();
+        let url = request.url.clone();
+        let records = self.records.clone();
+        self.inner
+            .send(request)
+            .map(move |result| {
+                if let Ok(response) = &result {
+                    records
+                        .lock()
+                        .expect("recording poisoned")
+                        .push(RecordedExchange {
+                            method,
+                            url,
+                            status: response.status.as_u16(),
+                            headers: response
+                                .headers
+                                .iter()
+                                .map(|(name, value)| {
+                                    let value = String::from_utf8_lossy(value.as_bytes());
+                                    (name.to_string(), value.into_owned())
+                                })
+                                .collect(),
+                            body: response.body.clone(),
+                        });
+                }
+                result
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+
+    use super::*;
+
+    fn request(method: Method, url: &str) -> HttpRequest {
+        HttpRequest {
+            method,
+            url: url.parse().unwrap(),
+            headers: HeaderMap::new(),
+            body: Bytes::new(),
+        }
+    }
+
+    #[test]
+    fn test_mock_matches_method_and_path() {
+        let mock = MockTransport::new();
+        mock.respond(Method::GET, "/time", HttpResponse::new(StatusCode::OK, "1"))
+            .respond(
+                Method::POST,
+                "/time",
+                HttpResponse::new(StatusCode::OK, "2"),
+            );
+
+        let res = block_on(mock.send(request(Method::POST, "https://api.test/time?x=1"))).unwrap();
+        assert_eq!(&res.body[..], b"2");
+        let res = block_on(mock.send(request(Method::GET, "https://api.test/time"))).unwrap();
+        assert_eq!(&res.body[..], b"1");
+        assert!(block_on(mock.send(request(Method::GET, "https://api.test/time"))).is_err());
+
+        assert_eq!(mock.pending(), 0);
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let upstream = MockTransport::new();
+        let mut response = HttpResponse::json(&[1, 2]);
+        response
+            .headers
+            .insert("retry-after", HeaderValue::from_static("30"));
+        upstream.respond(Method::GET, "/depth", response);
+        let recorder = RecordingTransport::new(upstream);
+        block_on(recorder.send(request(Method::GET, "https://api.test/depth?limit=5"))).unwrap();
+
+        let json = serde_json::to_string(&recorder.records()).unwrap();
+        let records: Vec<RecordedExchange> = serde_json::from_str(&json).unwrap();
+        let replay = MockTransport::replay(records).unwrap();
+        let res = block_on(replay.send(request(Method::GET, "https://api.test/depth"))).unwrap();
+        assert_eq!(res.status, StatusCode::OK);
+        assert_eq!(&res.body[..], b"[1,2]");
+        assert_eq!(res.headers["retry-after"], "30");
+    }
+
+    #[test]
+    fn test_record_binary_body() {
+        let upstream = MockTransport::new();
+        let archive = &b"PK\x03\x04\xff\xfe"[..];
+        upstream.respond(
+            Method::POST,
+            "/export",
+            HttpResponse::new(StatusCode::OK, archive),
+        );
+        let recorder = RecordingTransport::new(upstream);
+        block_on(recorder.send(request(Method::POST, "https://api.test/export"))).unwrap();
+
+        let json = serde_json::to_string(&recorder.records()).unwrap();
+        let records: Vec<RecordedExchange> = serde_json::from_str(&json).unwrap();
+        assert_eq!(&records[0].body[..], archive);
+        assert_eq!(records[0].text(), None);
+        let replay = MockTransport::replay(records).unwrap();
+        let res = block_on(replay.send(request(Method::POST, "https://api.test/export"))).unwrap();
+        assert_eq!(&res.body[..], archive);
+    }
+}
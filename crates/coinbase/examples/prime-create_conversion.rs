@@ -8,7 +8,7 @@ use ccx_coinbase::api::prime::types::*;
 use ccx_coinbase::api::prime::*;
 use uuid1::Uuid;
 
-#[actix_rt::main]
+#[tokio::main]
 async fn main() {
     if let Err(err) = main_().await {
         println!("{:?}", err);
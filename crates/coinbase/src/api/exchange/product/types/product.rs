@@ -1,3 +1,5 @@
+use ccx_api_lib::Instrument;
+
 use crate::api::exchange::prelude::*;
 use crate::api::exchange::product::ProductStatus;
 use crate::util::maybe_str;
@@ -49,3 +51,9 @@ pub struct Product {
     #[serde(with = "maybe_str")]
     pub high_bid_limit_percentage: Option<String>,
 }
+
+impl Product {
+    pub fn instrument(&self) -> Instrument {
+        Instrument::new(&self.base_currency, &self.quote_currency)
+    }
+}
@@ -1,4 +1,7 @@
+use std::sync::Arc;
+
 pub use ccx_api_lib::ApiCred;
+pub use ccx_api_lib::HttpTransport;
 pub use ccx_api_lib::Proxy;
 use ccx_api_lib::env_var_with_prefix;
 use url::Url;
@@ -76,6 +79,8 @@ pub struct TradeConfig<S: CoinbaseTradeSigner> {
     pub stream_base: Url,
     pub proxy: Option<Proxy>,
     // pub tier: RateLimiterTier,
+    /// Sends the REST requests; over the network when `None`.
+    pub transport: Option<Arc<dyn HttpTransport>>,
 }
 
 impl<S> TradeConfig<S>
@@ -95,9 +100,15 @@ where
             stream_base,
             proxy,
             // tier,
+            transport: None,
         }
     }
 
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
     pub fn env_var(postfix: &str) -> Option<String> {
         env_var_with_prefix(CCX_COINBASE_TRADE_API_PREFIX, postfix)
     }
@@ -1,6 +0,0 @@
-#[derive(Default)]
-pub(crate) enum RateLimiterBucketMode {
-    #[default]
-    Interval,
-    // CoinbaseDecrease,
-}
@@ -1,18 +1,12 @@
 use std::fmt;
 
-use ccx_api_lib::LibError;
 use futures::FutureExt;
-use futures::SinkExt;
-use futures::channel::mpsc;
-use futures::channel::oneshot;
 
 use crate::client::CoinbasePrimeSigner;
 use crate::client::PrimeRequestBuilder;
 use crate::client::Task;
 use crate::client::rate_limiter::BucketName;
-use crate::client::rate_limiter::task_message::TaskMessage;
-use crate::client::rate_limiter::task_message::TaskMessageResult;
-use crate::client::rate_limiter::task_metadata::TaskCosts;
+use crate::client::rate_limiter::TaskCosts;
 
 pub(crate) struct PrimeTaskBuilder<S>
 where
@@ -21,7 +15,7 @@ where
     priority: u8,
     costs: TaskCosts,
     req_builder: PrimeRequestBuilder<S>,
-    tasks_tx: mpsc::UnboundedSender<TaskMessage>,
+    rate_limiter: ccx_api_lib::RateLimiter,
 }
 
 impl<S> PrimeTaskBuilder<S>
@@ -32,13 +26,13 @@ where
         priority: u8,
         costs: TaskCosts,
         req_builder: PrimeRequestBuilder<S>,
-        tasks_tx: mpsc::UnboundedSender<TaskMessage>,
+        rate_limiter: ccx_api_lib::RateLimiter,
     ) -> Self {
         PrimeTaskBuilder {
             priority,
             costs,
             req_builder,
-            tasks_tx,
+            rate_limiter,
         }
     }
 
@@ -48,10 +42,7 @@ where
     }
 
     pub fn cost(mut self, key: impl Into<BucketName>, weight: u32) -> Self {
-        self.costs
-            .entry(key.into())
-            .and_modify(|e| *e = weight)
-            .or_insert(weight);
+        self.costs.insert(key.into(), weight);
         self
     }
 
@@ -62,24 +53,13 @@ where
         let priority = self.priority;
         let costs = self.costs.clone();
         let req_builder = self.req_builder;
-        let mut tasks_tx = self.tasks_tx.clone();
+        let rate_limiter = self.rate_limiter;
 
         let fut = async move {
-            let (tx, rx) = oneshot::channel::<TaskMessageResult>();
-            tasks_tx
-                .send(TaskMessage {
-                    priority,
-                    costs,
-                    tx,
-                })
+            rate_limiter
+                .acquire(&costs, priority)
                 .await
-                .map_err(|_| LibError::other("RateLimiter: task channel was dropped"))?;
-            rx.await
-                .map_err(|_| LibError::other("RateLimiter: task channel was dropped"))?
-                .map_err(|e| {
-                    log::error!("RateLimiter: task err. {:?}", e);
-                    e
-                })?;
+                .inspect_err(|e| log::error!("RateLimiter: task err. {:?}", e))?;
 
             req_builder.send::<V>().await
         };
@@ -2,9 +2,10 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
-use ::reqwest::Client;
-use ::reqwest::Method;
-use ::reqwest::StatusCode;
+use ccx_api_lib::reqwest::Client;
+use ccx_api_lib::reqwest::Method;
+use ccx_api_lib::reqwest::StatusCode;
+use ccx_api_lib::reqwest::make_client_with;
 use serde::Deserialize;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
@@ -65,26 +66,11 @@ where
     S: CoinbaseTradeSigner,
 {
     pub fn new(config: TradeConfig<S>) -> Self {
-        let client = Self::create_client(config.proxy.as_ref());
+        let client = make_client_with(config.transport.as_ref(), false, config.proxy.as_ref());
         let inner = Arc::new(ClientInner { config, client });
         RestTradeClient { inner }
     }
 
-    fn create_client(proxy: Option<&crate::client::Proxy>) -> Client {
-        let mut builder = Client::builder()
-            .connect_timeout(std::time::Duration::from_secs(5))
-            .timeout(std::time::Duration::from_secs(60));
-
-        if let Some(proxy) = proxy {
-            let proxy_url = format!("socks5://{}", proxy.addr());
-            if let Ok(reqwest_proxy) = ::reqwest::Proxy::all(&proxy_url) {
-                builder = builder.proxy(reqwest_proxy);
-            }
-        }
-
-        builder.build().expect("Failed to create HTTP client")
-    }
-
     pub fn client(&self) -> &Client {
         &self.inner.client
     }
@@ -180,12 +166,12 @@ where
         let request_id = Uuid::new_v4();
         self = self.sign().await?;
 
-        // Build the request using reqwest API directly
+        // Build the request on the configured transport
         let mut request = self
             .api_client
             .inner
             .client
-            .request(self.method.clone(), self.url.clone());
+            .request(self.method.clone(), self.url.as_str());
 
         // Add query parameters
         if !self.query_params.is_empty() {
@@ -9,7 +9,7 @@ mod tests {
 
     use std::time::Duration;
 
-    use actix::clock::sleep;
+    use tokio::time::sleep;
 
     use crate::ApiCred;
     use crate::api::spot::SpotApi;
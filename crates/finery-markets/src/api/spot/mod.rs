@@ -1,13 +1,11 @@
 use std::time::Duration;
 
-use actix::clock::sleep;
 use ccx_api_lib::ApiCred;
 use ccx_api_lib::Proxy;
 use ccx_api_lib::env_var_with_prefix;
 use futures::StreamExt;
 use futures::channel::mpsc;
-use futures::future::Either;
-use futures::future::select;
+use tokio::time::timeout;
 use url::Url;
 
 mod add;
@@ -131,10 +129,9 @@ mod with_network {
         pub async fn ws(&self, nonce: Nonce, time: Time) -> LibResult<(WebSocket, WsReceiver)> {
             let (tx, mut rx) = mpsc::channel(CHANNEL_BUFFER_SIZE);
             let ws = self.client.web_socket(tx, nonce, time).await?;
-            let ws_handle = actix_rt::spawn(async move {
+            let connected = async move {
                 let msg = rx.next().await;
                 // log::debug!("msg :: {:?}", msg);
-                // sleep(Duration::from_secs(5)).await;
                 match msg {
                     Some(Ok(message)) => match message.connected()? {
                         true => Ok(rx),
@@ -149,17 +146,10 @@ mod with_network {
                         Err(LibError::other("channel was closed."))
                     }
                 }
-            });
-            let timeout_handle = actix_rt::spawn(async {
-                sleep(CONNECTION_TIMEOUT).await;
-            });
-            match select(ws_handle, timeout_handle).await {
-                Either::Left((rx_res, _)) => rx_res
-                    .map_err(|e| {
-                        LibError::other(format!("Failed awaiting connection status: {:?}", e))
-                    })?
-                    .map(|rx| (ws, rx)),
-                Either::Right(_) => {
+            };
+            match timeout(CONNECTION_TIMEOUT, connected).await {
+                Ok(rx_res) => rx_res.map(|rx| (ws, rx)),
+                Err(_elapsed) => {
                     ws.close().await;
                     Err(LibError::other("Finery WS not connected."))
                 }
@@ -1,9 +1,15 @@
 mod config;
 mod rest;
 mod signer;
+#[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
 mod websocket;
+#[cfg(feature = "with_tokio")]
+mod websocket_tokio;
 
 pub use self::config::*;
 pub use self::rest::RestClient;
 pub use self::signer::*;
+#[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
 pub use self::websocket::*;
+#[cfg(feature = "with_tokio")]
+pub use self::websocket_tokio::*;
@@ -2,14 +2,11 @@ use std::borrow::Cow;
 use std::sync::Arc;
 use std::time::Instant;
 
-use actix_http::BoxedPayloadStream;
-use actix_http::encoding::Decoder;
-use awc::Client;
-use awc::ClientRequest;
-use awc::ClientResponse;
-use awc::http::Method;
-use awc::http::StatusCode;
-use ccx_api_lib::awc::make_client;
+use ccx_api_lib::reqwest::Client;
+use ccx_api_lib::reqwest::ClientRequest;
+use ccx_api_lib::reqwest::Method;
+use ccx_api_lib::reqwest::StatusCode;
+use ccx_api_lib::reqwest::make_client_with;
 use serde::Serialize;
 use url::Url;
 
@@ -49,6 +46,7 @@ where
     S: FinerySigner,
 {
     config: Config<S>,
+    client: Client,
 }
 
 pub struct RequestBuilder<S, J>
@@ -68,14 +66,11 @@ where
     S: FinerySigner,
 {
     pub fn new(config: Config<S>) -> Self {
-        let inner = Arc::new(ClientInner { config });
+        let client = make_client_with(config.transport.as_ref(), false, config.proxy.as_ref());
+        let inner = Arc::new(ClientInner { config, client });
         RestClient { inner }
     }
 
-    fn client_(&self, h1_only: bool) -> Client {
-        make_client(h1_only, self.inner.config.proxy.as_ref())
-    }
-
     pub(crate) fn stream_url(&self) -> Url {
         self.inner.config.stream_base.clone()
     }
@@ -96,13 +91,15 @@ where
         WebSocket::connect(tx, self.clone(), nonce, time).await
     }
 
-    pub(super) fn client(&self) -> Client {
-        self.client_(false)
+    /// REST client sending through the configured transport.
+    pub(super) fn client(&self) -> &Client {
+        &self.inner.client
     }
 
-    #[allow(dead_code)]
-    pub(super) fn client_h1(&self) -> Client {
-        self.client_(true)
+    /// Client for the actix WebSocket connections.
+    #[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
+    pub(super) fn ws_client(&self) -> ccx_api_lib::awc::Client {
+        ccx_api_lib::awc::make_client(false, self.inner.config.proxy.as_ref())
     }
 
     pub fn request<T: Serialize>(
@@ -138,12 +135,12 @@ where
     fn header_key(mut self) -> LibResult<Self> {
         self.request = self
             .request
-            .append_header(("EFX-Key", self.api_client.inner.config.api_key()));
+            .header("EFX-Key", self.api_client.inner.config.api_key());
         Ok(self)
     }
 
     async fn header_sign(mut self) -> LibResult<Self> {
-        let path = self.request.get_uri().path();
+        let path = self.request.url().path();
         let method = basename(path);
         let payload = self.payload()?;
         let payload = serde_json::to_string(&payload)?;
@@ -155,12 +152,12 @@ where
 
         log::debug!("header_sign signature :: {}", signature);
 
-        self.request = self.request.append_header(("EFX-Sign", signature));
+        self.request = self.request.header("EFX-Sign", signature);
         Ok(self)
     }
 
     fn header_content_type(mut self) -> LibResult<Self> {
-        self.request = self.request.append_header(("Content-Type", "text/html"));
+        self.request = self.request.header("Content-Type", "text/html");
         Ok(self)
     }
 
@@ -204,19 +201,21 @@ where
 
         log::debug!(
             "send request :: {}  {}",
-            self.request.get_method(),
-            self.request.get_uri()
+            self.request.method(),
+            self.request.url()
         );
 
         let tm = Instant::now();
-        let mut res = {
+        let res = {
             let payload = self.payload()?;
             log::debug!("send request :: {:?} :: {:?}", Time::now(), payload);
-            self.request.send_json(&payload).await?
+            let body = serde_json::to_vec(&payload)?;
+            self.request.body(body).send().await?
         };
         log::debug!("Time::now() :: {:?}", Time::now());
         let d1 = tm.elapsed();
-        let resp = res.body().limit(16 * 1024 * 1024).await?;
+        let status = res.status();
+        let resp = res.bytes().await?;
         let d2 = tm.elapsed() - d1;
         log::debug!(
             "Request time elapsed:  {:0.1}ms + {:0.1}ms",
@@ -224,8 +223,8 @@ where
             d2.as_secs_f64() * 1000.0,
         );
 
-        log::debug!("Response: {} «{:#?}»", res.status(), resp);
-        check_response(res, &resp)?;
+        log::debug!("Response: {} «{:#?}»", status, resp);
+        check_response(status, &resp)?;
         match serde_json::from_slice(&resp) {
             Ok(json) => Ok(json),
             Err(err) => Err(LibError::Json(err)),
@@ -233,11 +232,9 @@ where
     }
 }
 
-type AwcClientResponse = ClientResponse<Decoder<actix_http::Payload<BoxedPayloadStream>>>;
-
-fn check_response(res: AwcClientResponse, resp: &[u8]) -> LibResult<AwcClientResponse> {
-    match res.status() {
-        StatusCode::OK => Ok(res),
+fn check_response(status: StatusCode, resp: &[u8]) -> LibResult<()> {
+    match status {
+        StatusCode::OK => Ok(()),
         StatusCode::INTERNAL_SERVER_ERROR => Err(ServiceError::ServerError.into()),
         StatusCode::SERVICE_UNAVAILABLE => Err(ServiceError::ServiceUnavailable.into()),
         StatusCode::BAD_REQUEST => match serde_json::from_slice::<ApiError>(resp) {
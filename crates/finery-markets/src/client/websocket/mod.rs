@@ -85,7 +85,7 @@ impl WebSocket {
     ) -> LibResult<Framed<BoxedSocket, Codec>> {
         let url = api_client.stream_url();
         log::debug!("raw_connect :: {}", url.as_str());
-        let client = api_client.client();
+        let client = api_client.ws_client();
         let request = client.ws(url.as_str());
         let content = EfxContent {
             nonce: nonce.0,
@@ -0,0 +1,209 @@
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::SinkExt;
+use futures::StreamExt;
+use futures::channel::mpsc;
+use futures::channel::mpsc::Receiver;
+use futures::channel::mpsc::Sender;
+use tokio::net::TcpStream;
+use tokio::time::Instant;
+use tokio::time::interval;
+use tokio_tungstenite::MaybeTlsStream;
+use tokio_tungstenite::WebSocketStream;
+use tokio_tungstenite::client_async_tls;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+
+use super::FinerySigner;
+use super::RestClient;
+use crate::error::LibError;
+use crate::error::LibResult;
+use crate::types::FeedId;
+use crate::types::FeedRequest;
+use crate::types::Nonce;
+use crate::types::Time;
+use crate::types::WsRequest;
+use crate::types::WsResponse;
+
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+const SERVER_TIMEOUT: Duration = Duration::from_secs(60);
+
+const HEADER_KEY: &str = "EFX-Key";
+const HEADER_SIGN: &str = "EFX-Sign";
+const HEADER_CONTENT: &str = "EFX-Content";
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+pub type WsSender = Sender<LibResult<WsResponse>>;
+pub type WsReceiver = Receiver<LibResult<WsResponse>>;
+
+enum Command {
+    Send(String),
+    Die,
+}
+
+#[derive(Clone)]
+pub struct WebSocket {
+    command_tx: mpsc::UnboundedSender<Command>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EfxContent {
+    nonce: u64,
+    timestamp: u64,
+}
+
+impl WebSocket {
+    pub(crate) async fn close(self) {
+        self.die().await;
+    }
+
+    pub async fn connect<S: FinerySigner>(
+        tx: WsSender,
+        api_client: RestClient<S>,
+        nonce: Nonce,
+        time: Time,
+    ) -> LibResult<WebSocket> {
+        let socket = WebSocket::raw_connect(api_client, nonce, time).await?;
+        let (command_tx, command_rx) = mpsc::unbounded();
+        tokio::spawn(run(socket, command_rx, tx));
+        Ok(Self { command_tx })
+    }
+
+    async fn raw_connect<S: FinerySigner>(
+        api_client: RestClient<S>,
+        nonce: Nonce,
+        time: Time,
+    ) -> LibResult<Socket> {
+        let url = api_client.stream_url();
+        log::debug!("raw_connect :: {}", url.as_str());
+        let mut request = url
+            .as_str()
+            .into_client_request()
+            .map_err(|e| LibError::other(format!("Invalid websocket request: {:?}", e)))?;
+        let content = EfxContent {
+            nonce: nonce.0,
+            timestamp: time.0,
+        };
+        let content = serde_json::to_string(&content)?;
+        let signature = api_client.signer().sign_data(&content).await?;
+
+        let headers = request.headers_mut();
+        headers.insert(HEADER_KEY, header_value(api_client.key())?);
+        headers.insert(HEADER_SIGN, header_value(&signature)?);
+        headers.insert(HEADER_CONTENT, header_value(&content)?);
+        let stream = ccx_api_lib::connect_tcp(&url, api_client.proxy().as_ref())
+            .await
+            .map_err(|e| LibError::other(format!("Failed to connect websocket: {:?}", e)))?;
+        let (socket, response) = client_async_tls(request, stream).await.map_err(|e| {
+            LibError::other(format!("Failed to open sign storage websocket: {:?}", e))
+        })?;
+
+        log::debug!("response: {:?}", response);
+
+        Ok(socket)
+    }
+
+    pub(crate) async fn die(&self) {
+        let _ = self.command_tx.unbounded_send(Command::Die);
+    }
+
+    pub(crate) async fn send_message(&self, msg: String) -> LibResult<()> {
+        self.command_tx
+            .unbounded_send(Command::Send(msg))
+            .map_err(|_e| LibError::other("WebSocket is closed."))
+    }
+
+    pub async fn subscribe(&self, feed: FeedRequest) -> LibResult<()> {
+        self.send_request(WsRequest::subscribe(feed)).await
+    }
+
+    pub async fn unsubscribe(&self, feed: FeedRequest) -> LibResult<()> {
+        self.send_request(WsRequest::unsubscribe(feed)).await
+    }
+
+    pub async fn subscribe_feed(&self, feed: FeedRequest, feed_id: FeedId) -> LibResult<()> {
+        self.send_request(WsRequest::subscribe_feed(feed, feed_id))
+            .await
+    }
+
+    pub async fn unsubscribe_feed(&self, feed: FeedRequest, feed_id: FeedId) -> LibResult<()> {
+        self.send_request(WsRequest::unsubscribe_feed(feed, feed_id))
+            .await
+    }
+
+    async fn send_request(&self, msg: WsRequest) -> LibResult<()> {
+        let message = serde_json::to_string(&msg)?;
+        self.send_message(message).await
+    }
+}
+
+fn header_value(value: &str) -> LibResult<HeaderValue> {
+    HeaderValue::from_str(value).map_err(|e| LibError::other(format!("Invalid header: {:?}", e)))
+}
+
+async fn run(socket: Socket, mut command_rx: mpsc::UnboundedReceiver<Command>, mut tx: WsSender) {
+    let (mut sink, mut stream) = socket.split();
+    let mut heartbeat = interval(HEARTBEAT_INTERVAL);
+    let mut hb = Instant::now();
+    // The connection outlives the senders as long as the events are received.
+    let mut commands_open = true;
+
+    loop {
+        tokio::select! {
+            msg = stream.next() => {
+                hb = Instant::now();
+                let bytes = match msg {
+                    Some(Ok(Message::Text(text))) => Bytes::from(text),
+                    Some(Ok(Message::Binary(bytes))) => bytes,
+                    Some(Ok(Message::Ping(data))) => {
+                        if sink.send(Message::Pong(data)).await.is_err() {
+                            log::error!("WebSocket failed to respond to server ws");
+                            break;
+                        }
+                        continue;
+                    }
+                    Some(Ok(Message::Pong(_))) | Some(Ok(Message::Frame(_))) => continue,
+                    Some(Ok(Message::Close(reason))) => {
+                        log::error!("WebSocket close connection with reason: {:?}", reason);
+                        break;
+                    }
+                    Some(Err(e)) => {
+                        log::error!("WebSocket broken: {:?}", e);
+                        break;
+                    }
+                    None => break,
+                };
+                if let Err(error) = tx.send(WsResponse::try_from(bytes)).await {
+                    log::error!("Failed notify by ws finary: {:?}", error);
+                    break;
+                }
+            },
+            cmd = command_rx.next(), if commands_open => match cmd {
+                Some(Command::Send(msg)) => {
+                    log::debug!("send msg :: {}", msg);
+                    if sink.send(Message::Text(msg.into())).await.is_err() {
+                        log::error!("WebSocket failed to send message to server ws.");
+                        break;
+                    }
+                }
+                Some(Command::Die) => break,
+                None => commands_open = false,
+            },
+            _ = heartbeat.tick() => {
+                if hb.elapsed() > SERVER_TIMEOUT {
+                    break;
+                }
+                if sink.send(Message::Ping(Bytes::new())).await.is_err() {
+                    log::error!("WebSocket failed to ping server ws");
+                    break;
+                }
+            }
+        }
+    }
+
+    tx.close_channel();
+    let _ = sink.close().await;
+}
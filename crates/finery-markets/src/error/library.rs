@@ -1,7 +1,8 @@
+#[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
 use actix::MailboxError;
-use awc::error::PayloadError;
-use awc::error::SendRequestError;
-use awc::http::header::InvalidHeaderValue;
+use ccx_api_lib::http::StatusCode;
+use ccx_api_lib::http::header::InvalidHeaderValue;
+use ccx_api_lib::reqwest::SendRequestError;
 use thiserror::Error;
 
 use crate::error::ApiFineryError;
@@ -28,12 +29,11 @@ pub enum LibError {
     Json(#[from] serde_json::Error),
     #[error("Request Error: {0}")]
     RequestError(#[from] SendRequestError),
-    #[error("Payload Error: {0}")]
-    Payload(#[from] PayloadError),
     #[error("Unknown Status: {0}")]
-    UnknownStatus(awc::http::StatusCode),
+    UnknownStatus(StatusCode),
     #[error("Service Error: {0}")]
     ServiceError(#[from] ServiceError),
+    #[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
     #[error("Service Error: {0}")]
     MailboxError(#[from] MailboxError),
     #[error("Other Error: {0}")]
@@ -1,6 +1,12 @@
 #![allow(warnings)]
 #![allow(dead_code)]
 
+#[cfg(all(
+    feature = "with_network",
+    not(any(feature = "with_actix", feature = "with_tokio"))
+))]
+compile_error!("`with_network` needs a WebSocket backend: enable `with_actix` or `with_tokio`");
+
 #[macro_use]
 extern crate serde;
 #[macro_use]
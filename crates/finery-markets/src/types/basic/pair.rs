@@ -11,6 +11,10 @@ impl Pair {
             quote: quote.into(),
         }
     }
+
+    pub fn instrument(&self) -> ccx_api_lib::Instrument {
+        ccx_api_lib::Instrument::new(self.base.as_str(), self.quote.as_str())
+    }
 }
 
 impl serde::Serialize for Pair {
@@ -1,3 +1,5 @@
+use ccx_api_lib::InstrumentMap;
+
 use crate::types::Pair;
 use crate::types::Price;
 use crate::types::Size;
@@ -14,6 +16,21 @@ pub struct InstrumentsResponse {
     pub networks: Vec<Network>,
 }
 
+impl InstrumentsResponse {
+    /// Maps the exchange-neutral instruments to the Finery pair names, e.g. `BTC-USD`.
+    pub fn instrument_map(&self) -> InstrumentMap {
+        self.instruments
+            .iter()
+            .map(|i| {
+                (
+                    i.pair.instrument(),
+                    format!("{}-{}", i.pair.base, i.pair.quote),
+                )
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
 pub struct Currency {
     pub name: String,
@@ -58,13 +75,18 @@ pub(crate) mod tests {
         let json = r#"
         [
             [
-                ["USD",4725,1000000,100000000,"fiat"],
-                ["EUR",19109,1000000,109000000,"fiat"],
-                ["USDT",660085,100,100085000,"stablecoin"],
-                ["BTC",3714,1,3016218000000,"crypto"]
+                ["USD",4725,1000000,100000000,"fiat",[]],
+                ["EUR",19109,1000000,109000000,"fiat",[]],
+                ["USDT",660085,100,100085000,"stablecoin",["ERC20","TRC20"]],
+                ["BTC",3714,1,3016218000000,"crypto",["BTC"]]
             ],
             [
                 ["BTC-USD",4955410050,"BTC","USD"]
+            ],
+            [
+                ["ERC20","Ethereum",1],
+                ["TRC20","Tron",2],
+                ["BTC","Bitcoin",3]
             ]
         ]
         "#;
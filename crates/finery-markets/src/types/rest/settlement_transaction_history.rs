@@ -63,7 +63,8 @@ pub(crate) mod tests {
                 0,
                 1558091300000,
                 567,
-                1000
+                1000,
+                "ERC20"
             ]
         ]
         "#;
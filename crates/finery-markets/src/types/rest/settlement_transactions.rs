@@ -40,7 +40,8 @@ pub(crate) mod tests {
                     0,
                     0,
                     0,
-                    100
+                    100,
+                    "BTC"
                 ]
             ],
             [
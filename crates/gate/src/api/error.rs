@@ -96,11 +96,10 @@ pub struct GateErrorInfo {
 /// | NO_MERGEABLE_ORDERS | Orders can be merged not found |
 /// | ORDER_BOOK_NOT_FOUND | Insufficient liquidity |
 /// | FAILED_RETRIEVE_ASSETS | Failed to retrieve account assets |
-
+//
 // TODO Futures related
 // TODO Collateral Loan related
-
-///
+//
 /// ## Portfolio related
 ///
 /// | label | Meaning |
@@ -1,4 +1,5 @@
 use ccx_api_lib::Decimal;
+use ccx_api_lib::Instrument;
 use ccx_api_lib::serde_util::none_as_empty_str;
 use chrono::DateTime;
 use chrono::Utc;
@@ -81,6 +82,16 @@ pub struct CurrencyPair {
     pub buy_start: Option<DateTime<Utc>>,
 }
 
+impl CurrencyPair {
+    /// `None` when the pair misses the base or quote currency.
+    pub fn instrument(&self) -> Option<Instrument> {
+        Some(Instrument::new(
+            self.base.as_deref()?,
+            self.quote.as_deref()?,
+        ))
+    }
+}
+
 /// How currency pair can be traded
 #[derive(Debug, Deserialize, PartialEq, Eq, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -82,7 +82,7 @@ mod with_network {
         /// ## Parameters
         ///
         /// * `currency` - Currency unit used to calculate the balance amount.
-        ///    BTC, CNY, USD and USDT are allowed. USDT is the default.
+        ///   BTC, CNY, USD and USDT are allowed. USDT is the default.
         pub async fn total_balance(
             &self,
             currency: Option<SmartString>,
@@ -87,9 +87,9 @@ mod with_network {
         /// * `to` - Account to transfer to
         /// * `amount` - Transfer amount
         /// * `currency_pair` - Margin currency pair. Required if transfer from or to margin
-        ///    account.
+        ///   account.
         /// * `settle` - Futures settle currency. Required if transferring from or to futures
-        ///    account.
+        ///   account.
         pub async fn transfer(
             &self,
             currency: SmartString,
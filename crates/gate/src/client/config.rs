@@ -1,3 +1,6 @@
+use std::sync::Arc;
+
+pub use ccx_api_lib::HttpTransport;
 pub use ccx_api_lib::PrimeApiCred;
 pub use ccx_api_lib::Proxy;
 use ccx_api_lib::env_var_with_prefix;
@@ -13,6 +16,8 @@ pub struct GateApiConfig<S> {
     pub stream_base: Url,
     pub proxy: Option<Proxy>,
     // pub tier: RateLimiterTier,
+    /// Sends the REST requests; over the network when `None`.
+    pub transport: Option<Arc<dyn HttpTransport>>,
 }
 
 impl<S> GateApiConfig<S> {
@@ -29,9 +34,15 @@ impl<S> GateApiConfig<S> {
             stream_base,
             proxy,
             // tier,
+            transport: None,
         }
     }
 
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
     pub fn env_var(postfix: &str) -> Option<String> {
         env_var_with_prefix(CCX_GATE_API_PREFIX, postfix)
     }
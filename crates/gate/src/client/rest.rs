@@ -1,10 +1,11 @@
 use std::sync::Arc;
 use std::time::Instant;
 
+use ccx_api_lib::reqwest::Client;
 use ccx_api_lib::reqwest::ClientRequest;
 use ccx_api_lib::reqwest::Method;
 use ccx_api_lib::reqwest::SendRequestError;
-use ccx_api_lib::reqwest::make_client;
+use ccx_api_lib::reqwest::make_client_with;
 use chrono::Utc;
 use smart_string::DisplayExt;
 use smart_string::SmartString;
@@ -54,6 +55,7 @@ impl<S> Clone for RestClient<S> {
 
 struct ClientInner<S> {
     config: GateApiConfig<S>,
+    client: Client,
 }
 
 pub struct GateRequest<R, S> {
@@ -80,13 +82,14 @@ pub struct GateSignedRequest<R> {
 
 impl<S> RestClient<S> {
     pub fn new(config: GateApiConfig<S>) -> Self {
-        let inner = Arc::new(ClientInner { config });
+        let client = make_client_with(config.transport.as_ref(), false, config.proxy.as_ref());
+        let inner = Arc::new(ClientInner { config, client });
         Self { inner }
     }
 
-    /// REST client from `reqwest` crate
-    pub(super) fn client(&self) -> ccx_api_lib::reqwest::Client {
-        make_client(false, self.inner.config.proxy.as_ref())
+    /// REST client sending through the configured transport.
+    pub(super) fn client(&self) -> &Client {
+        &self.inner.client
     }
 
     pub fn prepare_rest<R: Request>(&self, path: &str, request: &R) -> GateRequest<R, S> {
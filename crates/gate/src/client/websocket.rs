@@ -41,7 +41,7 @@ impl WebsocketStream {
 
         let (ws_stream, response) = connect_async(url.as_str())
             .await
-            .map_err(|e| GateError::IoError(std::io::Error::new(std::io::ErrorKind::Other, e)))?;
+            .map_err(|e| GateError::IoError(std::io::Error::other(e)))?;
 
         log::debug!("WebSocket connected: {:?}", response.status());
 
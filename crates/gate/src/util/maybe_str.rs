@@ -57,7 +57,11 @@ where
         }
 
         fn visit_map<A: serde::de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
-            T::deserialize(serde::de::value::MapAccessDeserializer::new(map)).map(Some)
+            // Going through `serde_json::Value` restores numbers that `serde_json` hands out as
+            // maps when its `arbitrary_precision` feature is enabled.
+            let value =
+                serde_json::Value::deserialize(serde::de::value::MapAccessDeserializer::new(map))?;
+            T::deserialize(value).map(Some).map_err(A::Error::custom)
         }
 
         fn visit_unit<E>(self) -> Result<Self::Value, E>
@@ -104,6 +104,7 @@ mod with_network {
 
     impl<S: GatepaySigner> GatepayApi<S> {
         /// # Create a prepay order
+        #[allow(clippy::too_many_arguments)]
         pub async fn create_order(
             &self,
             merchant_trade_no: SmartString<32>,
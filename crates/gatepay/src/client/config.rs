@@ -1,3 +1,6 @@
+use std::sync::Arc;
+
+pub use ccx_api_lib::HttpTransport;
 pub use ccx_api_lib::PrimeApiCred;
 pub use ccx_api_lib::Proxy;
 use ccx_api_lib::env_var_with_prefix;
@@ -15,6 +18,8 @@ pub struct GatepayApiConfig<S: GatepaySigner> {
     // pub stream_base: Url,
     pub proxy: Option<Proxy>,
     // pub tier: RateLimiterTier,
+    /// Sends the REST requests; over the network when `None`.
+    pub transport: Option<Arc<dyn HttpTransport>>,
 }
 
 impl<S> GatepayApiConfig<S>
@@ -34,9 +39,15 @@ where
             // stream_base,
             proxy,
             // tier,
+            transport: None,
         }
     }
 
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
     pub fn env_var(postfix: &str) -> Option<String> {
         env_var_with_prefix(CCX_GATEPAY_API_PREFIX, postfix)
     }
@@ -2,12 +2,11 @@ use std::borrow::Cow;
 use std::sync::Arc;
 use std::time::Instant;
 
-use ccx_api_lib::awc::Client;
-use ccx_api_lib::awc::ClientRequest;
-use ccx_api_lib::awc::Method;
-use ccx_api_lib::awc::PayloadError;
-use ccx_api_lib::awc::SendRequestError;
-use ccx_api_lib::awc::make_client;
+use ccx_api_lib::reqwest::Client;
+use ccx_api_lib::reqwest::ClientRequest;
+use ccx_api_lib::reqwest::Method;
+use ccx_api_lib::reqwest::SendRequestError;
+use ccx_api_lib::reqwest::make_client_with;
 use smart_string::DisplayExt;
 use smart_string::SmartString;
 use thiserror::Error;
@@ -27,8 +26,6 @@ use crate::util::dt_gatepay::DtGatepay;
 pub enum CallError {
     #[error("Send request error: {0}")]
     SendRequest(#[from] SendRequestError),
-    #[error("Payload error: {0}")]
-    Payload(#[from] PayloadError),
     #[error("Json error: {0}")]
     Json(#[from] serde_json::Error),
     #[error("Gatepay API error: {0}")]
@@ -76,6 +73,7 @@ where
     S: GatepaySigner,
 {
     config: GatepayApiConfig<S>,
+    client: Client,
 }
 
 pub struct GatepayRequest<R, S>
@@ -116,12 +114,14 @@ where
     S: GatepaySigner,
 {
     pub fn new(config: GatepayApiConfig<S>) -> Self {
-        let inner = Arc::new(ClientInner { config });
+        let client = make_client_with(config.transport.as_ref(), false, config.proxy.as_ref());
+        let inner = Arc::new(ClientInner { config, client });
         Self { inner }
     }
 
-    pub(super) fn rest_client(&self) -> Client {
-        make_client(false, self.inner.config.proxy.as_ref())
+    /// REST client sending through the configured transport.
+    pub(super) fn rest_client(&self) -> &Client {
+        &self.inner.client
     }
 
     pub fn client_id(&self) -> &str {
@@ -145,12 +145,12 @@ where
         let request = self
             .rest_client()
             .request(method, url.as_str())
-            .append_header(("Content-Type", "application/json"));
+            .header("Content-Type", "application/json");
 
-        let request = request.append_header((
+        let request = request.header(
             "X-GatePay-Certificate-SN",
             self.inner.config.signer.api_key(),
-        ));
+        );
 
         let api_client = self.clone();
 
@@ -179,8 +179,8 @@ where
         let nonce = Nonce::random();
         let timestamp = DtGatepay::now();
         let request = request
-            .append_header(("X-GatePay-Timestamp", timestamp.timestamp_ms()))
-            .append_header(("X-GatePay-Nonce", nonce.to_string()));
+            .header("X-GatePay-Timestamp", timestamp.timestamp_ms().to_string())
+            .header("X-GatePay-Nonce", &nonce);
 
         GatepayPreparedRequest {
             api_client,
@@ -215,7 +215,7 @@ where
             .sign_api(timestamp, &nonce, &body)
             .await?;
 
-        let request = request.append_header(("X-GatePay-Signature", sign.to_string()));
+        let request = request.header("X-GatePay-Signature", &sign);
 
         Ok(GatepaySignedRequest {
             request,
@@ -241,9 +241,9 @@ where
         log::debug!("[{request_id}]  Request body: {:?}", body);
 
         let tm = Instant::now();
-        let mut res = request.send_body(body).await?;
+        let res = request.body(body).send().await?;
         let d1 = tm.elapsed();
-        let body = res.body().limit(16 * 1024 * 1024).await?;
+        let body = res.bytes().await?;
         let d2 = tm.elapsed() - d1;
 
         log::debug!(
@@ -64,17 +64,7 @@ async fn main_() -> KrakenApiResult<()> {
             let mut i = 0;
 
             while let Ok(trace_event) = rx.recv() {
-                let ws_event = match trace_event {
-                    TraceEvent::Event(e) => e,
-                    // TraceEvent::Print => {
-                    //     // cache.iter().enumerate().for_each(|(index, item)| {
-                    //     //     println!("Cache item #{index}");
-                    //     //     println!("{:?}", item);
-                    //     //     println!();
-                    //     // });
-                    //     continue;
-                    // }
-                };
+                let TraceEvent::Event(ws_event) = trace_event;
 
                 cache[i % TRACE_CAPACITY] = ws_event;
                 i += 1;
@@ -121,7 +111,7 @@ async fn main_() -> KrakenApiResult<()> {
                 }
                 _ => continue 'outer,
             }
-            for (_symbol, updater) in &state {
+            for updater in state.values() {
                 match updater.state() {
                     None => {}
                     Some(book) => {
@@ -143,7 +133,7 @@ async fn main_() -> KrakenApiResult<()> {
 
                         let ask_avg = book.ask_avg().unwrap_or_default();
                         lines.push(format!("ask avg. {}: {}", ask_avg.0, ask_avg.1));
-                        lines.push(format!("-----------------"));
+                        lines.push("-----------------".to_string());
 
                         book.bids()
                             .iter()
@@ -0,0 +1,56 @@
+use ccx_kraken::KrakenApiError;
+use ccx_kraken::KrakenApiResult;
+use ccx_kraken::api::spot::SpotApi;
+use ccx_kraken::client::NonceSeq;
+use ccx_kraken::ws_stream::WsStream;
+use ccx_kraken::ws_stream::WsStreamBalancesParams;
+use ccx_kraken::ws_stream::WsStreamOpenOrdersParams;
+use ccx_kraken::ws_stream::WsStreamOwnTradesParams;
+use futures::StreamExt;
+
+#[tokio::main]
+async fn main() {
+    let _ = main_().await;
+}
+
+async fn main_() -> KrakenApiResult<()> {
+    let _ = dotenv::dotenv();
+    env_logger::init();
+
+    let mut seq = NonceSeq::new();
+
+    let kraken_spot = SpotApi::from_env();
+
+    let (token, _) = kraken_spot.get_websockets_token(seq.ts_next())?.await?;
+    let (sink, stream) = kraken_spot.ws_private().await?.split();
+    println!("Connected");
+
+    sink.subscribe(WsStream::OwnTrades(WsStreamOwnTradesParams {
+        token: token.token.clone(),
+        snapshot: Some(false),
+        consolidate_taker: None,
+    }))
+    .await?;
+    sink.subscribe(WsStream::OpenOrders(WsStreamOpenOrdersParams {
+        token: token.token.clone(),
+        ratecounter: None,
+    }))
+    .await?;
+
+    // Balances are only served by the v2 protocol.
+    let (balances_sink, balances_stream) = kraken_spot.ws_private_v2().await?.split();
+    balances_sink
+        .subscribe(WsStream::Balances(WsStreamBalancesParams {
+            token: token.token,
+            snapshot: Some(true),
+        }))
+        .await?;
+    println!("Subscribed");
+
+    let mut stream = futures::stream::select(stream, balances_stream);
+    while let Some(e) = stream.next().await {
+        println!("{:?}", e);
+    }
+
+    KrakenApiError::ok(())
+}
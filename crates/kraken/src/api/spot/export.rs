@@ -0,0 +1,270 @@
+use super::RL_PRIVATE_PER_MINUTE;
+use super::prelude::*;
+use crate::client::Task;
+
+pub const API_0_PRIVATE_ADD_EXPORT: &str = "/0/private/AddExport";
+pub const API_0_PRIVATE_EXPORT_STATUS: &str = "/0/private/ExportStatus";
+pub const API_0_PRIVATE_RETRIEVE_EXPORT: &str = "/0/private/RetrieveExport";
+pub const API_0_PRIVATE_REMOVE_EXPORT: &str = "/0/private/RemoveExport";
+
+/// Type of data to export.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportReport {
+    Trades,
+    Ledgers,
+}
+
+/// File format of an export.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ExportFormat {
+    Csv,
+    Tsv,
+}
+
+/// Status of an export report.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq)]
+pub enum ExportStatus {
+    Queued,
+    Processing,
+    Processed,
+}
+
+/// How to remove an export report.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RemoveExportType {
+    /// Cancel a queued or processing report.
+    Cancel,
+    /// Delete a processed report.
+    Delete,
+}
+
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct AddExportRequest<'a> {
+    report: ExportReport,
+    format: Option<ExportFormat>,
+    description: &'a str,
+    fields: Option<&'a str>,
+    starttm: Option<u64>,
+    endtm: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct AddExportResponse {
+    /// Report ID.
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct ExportStatusRequest {
+    report: ExportReport,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[serde(transparent)]
+pub struct ExportStatusResponse {
+    pub reports: Vec<ExportReportInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct ExportReportInfo {
+    /// Report ID.
+    pub id: String,
+    /// Description of the report.
+    pub descr: String,
+    /// File format of the report.
+    pub format: ExportFormat,
+    /// Type of the exported data.
+    pub report: ExportReport,
+    /// Status of the report.
+    pub status: ExportStatus,
+    /// Comma delimited list of the exported fields.
+    pub fields: String,
+    /// Unix timestamp of the report creation.
+    pub createdtm: String,
+    /// Unix timestamp of the report processing start.
+    pub starttm: String,
+    /// Unix timestamp of the report processing end.
+    pub completedtm: String,
+    /// Unix timestamp of the first exported record.
+    pub datastarttm: String,
+    /// Unix timestamp of the last exported record.
+    pub dataendtm: String,
+    /// Asset class of the exported records.
+    pub aclass: Option<String>,
+    /// Asset of the exported records.
+    pub asset: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct ExportIdRequest<'a> {
+    id: &'a str,
+}
+
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct RemoveExportRequest<'a> {
+    id: &'a str,
+    r#type: RemoveExportType,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct RemoveExportResponse {
+    /// Whether the report was deleted.
+    #[serde(default)]
+    pub delete: bool,
+    /// Whether the report was cancelled.
+    #[serde(default)]
+    pub cancel: bool,
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> SpotApi<S>
+    where
+        S: crate::client::KrakenSigner,
+        S: Unpin + 'static,
+    {
+        /// Request Export Report
+        ///
+        /// Request export of trades or ledgers data. `fields` is a comma delimited list
+        /// of fields to include, all by default; `starttm` and `endtm` are unix
+        /// timestamps bounding the exported records.
+        #[allow(clippy::too_many_arguments)]
+        pub fn add_export(
+            &self,
+            nonce: Nonce,
+            report: ExportReport,
+            format: Option<ExportFormat>,
+            description: &str,
+            fields: Option<&str>,
+            starttm: Option<u64>,
+            endtm: Option<u64>,
+        ) -> KrakenResult<Task<AddExportResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_ADD_EXPORT)?
+                        .signed(nonce)?
+                        .request_body(AddExportRequest {
+                            report,
+                            format,
+                            description,
+                            fields,
+                            starttm,
+                            endtm,
+                        })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Get Export Report Status
+        ///
+        /// Get status of the requested data exports.
+        pub fn export_status(
+            &self,
+            nonce: Nonce,
+            report: ExportReport,
+        ) -> KrakenResult<Task<ExportStatusResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_EXPORT_STATUS)?
+                        .signed(nonce)?
+                        .request_body(ExportStatusRequest { report })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Retrieve Data Export
+        ///
+        /// Retrieve a processed data export as a zip archive.
+        pub fn retrieve_export(&self, nonce: Nonce, id: &str) -> KrakenResult<Task<Vec<u8>>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_RETRIEVE_EXPORT)?
+                        .signed(nonce)?
+                        .request_body(ExportIdRequest { id })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .send_file())
+        }
+
+        /// Delete Export Report
+        ///
+        /// Delete a processed export report, or cancel one not processed yet.
+        pub fn remove_export(
+            &self,
+            nonce: Nonce,
+            id: &str,
+            r#type: RemoveExportType,
+        ) -> KrakenResult<Task<RemoveExportResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_REMOVE_EXPORT)?
+                        .signed(nonce)?
+                        .request_body(RemoveExportRequest { id, r#type })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .send())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "with_network")]
+    #[tokio::test]
+    async fn test_retrieve_export_through_transport() {
+        use ccx_api_lib::HttpResponse;
+        use ccx_api_lib::MockTransport;
+        use ccx_api_lib::http::HeaderValue;
+        use ccx_api_lib::http::Method;
+        use ccx_api_lib::http::StatusCode;
+        use ccx_api_lib::http::header::CONTENT_TYPE;
+
+        use super::*;
+        use crate::client::Config;
+
+        let mut archive = HttpResponse::new(StatusCode::OK, &b"PK\x03\x04"[..]);
+        archive
+            .headers
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/zip"));
+        let mut not_found =
+            HttpResponse::json(&serde_json::json!({"error": ["EGeneral:Invalid arguments"]}));
+        not_found
+            .headers
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        let mock = MockTransport::new();
+        mock.respond(Method::POST, API_0_PRIVATE_RETRIEVE_EXPORT, archive);
+        mock.respond(Method::POST, API_0_PRIVATE_RETRIEVE_EXPORT, not_found);
+        let api = SpotApi::with_config(Config::mock(&mock));
+
+        let (archive, _warnings) = api
+            .retrieve_export(Nonce::new(1_u64), "TCJA")
+            .unwrap()
+            .await
+            .unwrap();
+        assert_eq!(archive, b"PK\x03\x04");
+
+        let res = api
+            .retrieve_export(Nonce::new(2_u64), "TCJA")
+            .unwrap()
+            .await;
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+use ccx_api_lib::serde_util::f64_arbitrary_precision;
+
+use super::RL_PRIVATE_PER_MINUTE;
+use super::prelude::*;
+use crate::client::Task;
+
+pub const API_0_PRIVATE_LEDGERS: &str = "/0/private/Ledgers";
+pub const API_0_PRIVATE_QUERY_LEDGERS: &str = "/0/private/QueryLedgers";
+
+/// Type of ledger entry.
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerType {
+    All,
+    Trade,
+    Deposit,
+    Withdrawal,
+    Transfer,
+    Margin,
+    Adjustment,
+    Rollover,
+    Spend,
+    Receive,
+    Settled,
+    Credit,
+    Staking,
+    Reward,
+    Dividend,
+    Sale,
+    Conversion,
+    Earn,
+    #[serde(rename = "nfttrade")]
+    NftTrade,
+    #[serde(rename = "nftcreatorfee")]
+    NftCreatorFee,
+    #[serde(rename = "nftrebate")]
+    NftRebate,
+    #[serde(rename = "custodytransfer")]
+    CustodyTransfer,
+    /// A type this library does not know about yet.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct LedgersRequest<'a> {
+    asset: Option<&'a str>,
+    aclass: Option<&'a str>,
+    r#type: Option<LedgerType>,
+    start: Option<u64>,
+    end: Option<u64>,
+    ofs: Option<u64>,
+    without_count: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct LedgersResponse {
+    /// Ledger entries by ledger ID.
+    pub ledger: HashMap<String, LedgerEntry>,
+    /// Amount of available ledger info matching criteria; missing with `without_count`.
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct QueryLedgersRequest<'a> {
+    id: TxIds<'a>,
+    trades: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(transparent)]
+pub struct QueryLedgersResponse {
+    /// Ledger entries by ledger ID.
+    pub ledger: HashMap<String, LedgerEntry>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct LedgerEntry {
+    /// Reference Id.
+    pub refid: String,
+    /// Unix timestamp of ledger.
+    #[serde(deserialize_with = "f64_arbitrary_precision::deserialize")]
+    pub time: f64,
+    /// Type of ledger entry.
+    pub r#type: LedgerType,
+    /// Additional info relating to the ledger entry type, where applicable.
+    pub subtype: String,
+    /// Asset class.
+    pub aclass: String,
+    /// Asset.
+    pub asset: Atom,
+    /// Transaction amount.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub amount: Decimal,
+    /// Transaction fee.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub fee: Decimal,
+    /// Resulting balance.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub balance: Decimal,
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> SpotApi<S>
+    where
+        S: crate::client::KrakenSigner,
+        S: Unpin + 'static,
+    {
+        /// Get Ledgers Info
+        ///
+        /// Retrieve information about ledger entries. 50 results are returned at a time,
+        /// the most recent by default. `asset` is a comma delimited list of assets
+        /// to restrict output to.
+        #[allow(clippy::too_many_arguments)]
+        pub fn get_ledgers_info(
+            &self,
+            nonce: Nonce,
+            asset: Option<&str>,
+            aclass: Option<&str>,
+            r#type: Option<LedgerType>,
+            start: Option<u64>,
+            end: Option<u64>,
+            ofs: Option<u64>,
+            without_count: Option<bool>,
+        ) -> KrakenResult<Task<LedgersResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_LEDGERS)?
+                        .signed(nonce)?
+                        .request_body(LedgersRequest {
+                            asset,
+                            aclass,
+                            r#type,
+                            start,
+                            end,
+                            ofs,
+                            without_count,
+                        })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 2)
+                .send())
+        }
+
+        /// Query Ledgers
+        ///
+        /// Retrieve information about specific ledger entries, up to 20 at a time.
+        pub fn query_ledgers(
+            &self,
+            nonce: Nonce,
+            id: TxIds<'_>,
+            trades: Option<bool>,
+        ) -> KrakenResult<Task<QueryLedgersResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_QUERY_LEDGERS)?
+                        .signed(nonce)?
+                        .request_body(QueryLedgersRequest { id, trades })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 2)
+                .send())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ledgers_response() {
+        let json = r#"{
+            "ledger": {
+                "L4UESK-KG3EQ-UFO4T5": {
+                    "refid": "TJKLXX-PGMUI-4NTLXU",
+                    "time": 1688464484.1787,
+                    "type": "trade",
+                    "subtype": "",
+                    "aclass": "currency",
+                    "asset": "ZGBP",
+                    "amount": "-24.5000",
+                    "fee": "0.0490",
+                    "balance": "459567.9171"
+                },
+                "LMKZCZ-Z3GVL-CXKK4H": {
+                    "refid": "BOKD5P7-LC7IHK-UKZHOJ",
+                    "time": 1688444262.8888,
+                    "type": "earn",
+                    "subtype": "autoallocation",
+                    "aclass": "currency",
+                    "asset": "XXBT",
+                    "amount": "0.0000000000",
+                    "fee": "0.0000000000",
+                    "balance": "0.0000100000"
+                }
+            },
+            "count": 2
+        }"#;
+
+        let response: LedgersResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.count, Some(2));
+        let entry = &response.ledger["L4UESK-KG3EQ-UFO4T5"];
+        assert_eq!(entry.r#type, LedgerType::Trade);
+        assert_eq!(entry.time, 1688464484.1787);
+        assert_eq!(entry.amount, Decimal::new(-245000, 4));
+        assert_eq!(entry.balance, Decimal::new(4595679171, 4));
+        let entry = &response.ledger["LMKZCZ-Z3GVL-CXKK4H"];
+        assert_eq!(entry.r#type, LedgerType::Earn);
+        assert_eq!(entry.subtype, "autoallocation");
+
+        let unknown: LedgerType = serde_json::from_str(r#""notatype""#).unwrap();
+        assert_eq!(unknown, LedgerType::Unknown);
+    }
+}
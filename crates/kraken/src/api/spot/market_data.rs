@@ -1,4 +1,11 @@
 use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+
+use ccx_api_lib::Instrument;
+use ccx_api_lib::InstrumentMap;
+use ccx_api_lib::serde_util::f64_arbitrary_precision;
+use serde::de;
 
 use super::RL_PUBLIC_PER_SECOND;
 use super::RlPriorityLevel;
@@ -13,6 +20,15 @@ pub const API_0_PUBLIC_ASSETS: &str = "/0/public/Assets";
 pub const API_0_PUBLIC_ASSET_PAIRS: &str = "/0/public/AssetPairs";
 pub const API_0_PUBLIC_TICKER: &str = "/0/public/Ticker";
 pub const API_0_PUBLIC_DEPTH: &str = "/0/public/Depth";
+pub const API_0_PUBLIC_OHLC: &str = "/0/public/OHLC";
+pub const API_0_PUBLIC_TRADES: &str = "/0/public/Trades";
+pub const API_0_PUBLIC_SPREAD: &str = "/0/public/Spread";
+
+/// Legacy asset ids carrying the `X` (crypto) or `Z` (fiat) prefix.
+const LEGACY_ASSETS: &[&str] = &[
+    "XETC", "XETH", "XLTC", "XMLN", "XREP", "XXBT", "XXDG", "XXLM", "XXMR", "XXRP", "XZEC", "ZAUD",
+    "ZCAD", "ZCHF", "ZEUR", "ZGBP", "ZJPY", "ZUSD",
+];
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
 pub struct ServerTimeResponse {
@@ -96,6 +112,24 @@ pub struct AssetPairResponse {
     pub pair: HashMap<Atom, AssetPairInfo>,
 }
 
+impl AssetPairResponse {
+    /// Maps the exchange-neutral instruments to the alternate pair names and back.
+    ///
+    /// Pair ids and websocket pair names resolve to the instruments as well.
+    pub fn instrument_map(&self) -> InstrumentMap {
+        let mut map = InstrumentMap::new();
+        for (pair, info) in &self.pair {
+            let instrument = info.instrument();
+            map.insert(instrument.clone(), info.altname.clone());
+            map.insert_alias(pair.clone(), instrument.clone());
+            if let Some(wsname) = &info.wsname {
+                map.insert_alias(wsname.clone(), instrument);
+            }
+        }
+        map
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
 pub struct AssetPairInfo {
     /// Alternate pair name.
@@ -141,6 +175,25 @@ pub struct AssetPairInfo {
     pub ordermin: Option<Decimal>,
 }
 
+impl AssetPairInfo {
+    /// The websocket name is preferred as it carries the plain asset codes, e.g. `XBT/USD`.
+    pub fn instrument(&self) -> Instrument {
+        if let Some(instrument) = self.wsname.as_ref().and_then(|name| name.parse().ok()) {
+            return instrument;
+        }
+        Instrument::new(asset_code(&self.base), asset_code(&self.quote))
+    }
+}
+
+/// Strips the legacy prefix from asset ids like `XXBT` or `ZUSD`.
+fn asset_code(asset: &str) -> &str {
+    if LEGACY_ASSETS.contains(&asset) {
+        &asset[1..]
+    } else {
+        asset
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
 pub struct AssetPairFee {
     #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
@@ -283,6 +336,158 @@ impl From<AssetDepthInfo> for OrderBook {
     }
 }
 
+/// Time frame interval of the OHLC data.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum OhlcInterval {
+    M1 = 1,
+    M5 = 5,
+    M15 = 15,
+    M30 = 30,
+    H1 = 60,
+    H4 = 240,
+    D1 = 1440,
+    W1 = 10080,
+    D15 = 21600,
+}
+
+impl OhlcInterval {
+    /// Interval in minutes.
+    pub fn as_u16(&self) -> u16 {
+        *self as u16
+    }
+}
+
+/// Rows of a pair along with the cursor to poll for the rows committed since.
+///
+/// Kraken keys the rows by the pair name, next to the `last` cursor.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PairRows<R, C> {
+    /// Rows by pair name.
+    pub pair: HashMap<Atom, Vec<R>>,
+    /// Cursor to be used as `since` when polling for new data.
+    pub last: C,
+}
+
+impl<'de, R, C> Deserialize<'de> for PairRows<R, C>
+where
+    R: Deserialize<'de>,
+    C: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct PairRowsVisitor<R, C>(PhantomData<(R, C)>);
+
+        impl<'de, R, C> de::Visitor<'de> for PairRowsVisitor<R, C>
+        where
+            R: Deserialize<'de>,
+            C: Deserialize<'de>,
+        {
+            type Value = PairRows<R, C>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("map of pair rows with the `last` cursor")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let mut pair = HashMap::new();
+                let mut last = None;
+                while let Some(key) = map.next_key::<Atom>()? {
+                    if &*key == "last" {
+                        last = Some(map.next_value()?);
+                    } else {
+                        pair.insert(key, map.next_value()?);
+                    }
+                }
+                let last = last.ok_or_else(|| de::Error::missing_field("last"))?;
+                Ok(PairRows { pair, last })
+            }
+        }
+
+        deserializer.deserialize_map(PairRowsVisitor(PhantomData))
+    }
+}
+
+/// OHLC data with the id of the last committed entry.
+///
+/// The last entry of the rows is the current, not yet committed, frame.
+pub type OhlcResponse = PairRows<OhlcRow, u64>;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct OhlcRow {
+    /// Unix timestamp of the frame start.
+    pub time: u64,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub open: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub high: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub low: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub close: Decimal,
+    /// Volume weighted average price.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub vwap: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub volume: Decimal,
+    /// Number of trades.
+    pub count: u64,
+}
+
+/// Recent trades with the trade cursor to poll for the ones that follow.
+pub type RecentTradesResponse = PairRows<RecentTradeRow, String>;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RecentTradeRow {
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub price: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub volume: Decimal,
+    /// Unix timestamp of the trade.
+    #[serde(deserialize_with = "f64_arbitrary_precision::deserialize")]
+    pub time: f64,
+    /// Side of the taker.
+    pub side: RecentTradeSide,
+    /// Order type of the taker.
+    pub ordertype: RecentTradeOrderType,
+    /// Miscellaneous info.
+    pub misc: String,
+    pub trade_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq)]
+pub enum RecentTradeSide {
+    #[serde(rename = "b")]
+    Buy,
+    #[serde(rename = "s")]
+    Sell,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq)]
+pub enum RecentTradeOrderType {
+    #[serde(rename = "m")]
+    Market,
+    #[serde(rename = "l")]
+    Limit,
+}
+
+/// Recent spreads with the cursor to poll for the ones that follow.
+pub type RecentSpreadsResponse = PairRows<SpreadRow, u64>;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct SpreadRow {
+    /// Unix timestamp of the spread.
+    pub time: u64,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub bid: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub ask: Decimal,
+}
+
 #[cfg(feature = "with_network")]
 pub use with_network::*;
 
@@ -403,5 +608,176 @@ mod with_network {
                 .cost(RL_PUBLIC_PER_SECOND, 1)
                 .send())
         }
+
+        /// Get OHLC Data.
+        ///
+        /// Returns up to 720 OHLC entries of the most recent data, regardless of `since`.
+        ///
+        /// * pair - Asset pair to get data for.
+        /// * interval - Time frame interval. (optional, default: 1 minute)
+        /// * since - Return up to 720 OHLC entries committed since the given `last` id.
+        pub fn ohlc(
+            &self,
+            pair: &str,
+            interval: Option<OhlcInterval>,
+            since: Option<u64>,
+        ) -> KrakenResult<Task<OhlcResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(API_0_PUBLIC_OHLC)?
+                        .query_arg("pair", &pair)?
+                        .try_query_arg("interval", &interval.map(|i| i.as_u16()))?
+                        .try_query_arg("since", &since)?,
+                )
+                .cost(RL_PUBLIC_PER_SECOND, 1)
+                .send())
+        }
+
+        /// Get Recent Trades.
+        ///
+        /// Returns the last 1000 trades by default.
+        ///
+        /// * pair - Asset pair to get data for.
+        /// * since - Return trades since the given `last` cursor.
+        /// * count - Return specific number of trades, up to 1000.
+        pub fn recent_trades(
+            &self,
+            pair: &str,
+            since: Option<&str>,
+            count: Option<u16>,
+        ) -> KrakenResult<Task<RecentTradesResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(API_0_PUBLIC_TRADES)?
+                        .query_arg("pair", &pair)?
+                        .try_query_arg("since", &since)?
+                        .try_query_arg("count", &count)?,
+                )
+                .cost(RL_PUBLIC_PER_SECOND, 1)
+                .send())
+        }
+
+        /// Get Recent Spreads.
+        ///
+        /// Returns the last ~200 top-of-book spreads for a given pair.
+        ///
+        /// * pair - Asset pair to get data for.
+        /// * since - Return spreads since the given `last` cursor.
+        pub fn recent_spreads(
+            &self,
+            pair: &str,
+            since: Option<u64>,
+        ) -> KrakenResult<Task<RecentSpreadsResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .get(API_0_PUBLIC_SPREAD)?
+                        .query_arg("pair", &pair)?
+                        .try_query_arg("since", &since)?,
+                )
+                .cost(RL_PUBLIC_PER_SECOND, 1)
+                .send())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asset_pair_instrument_map() {
+        let json = r#"{
+            "XXBTZUSD": {
+                "altname": "XBTUSD",
+                "wsname": "XBT/USD",
+                "aclass_base": "currency",
+                "base": "XXBT",
+                "aclass_quote": "currency",
+                "quote": "ZUSD",
+                "lot": "unit",
+                "pair_decimals": 1,
+                "lot_decimals": 8,
+                "lot_multiplier": 1,
+                "leverage_buy": [],
+                "leverage_sell": [],
+                "fees": [],
+                "fee_volume_currency": "ZUSD",
+                "margin_call": 80,
+                "margin_stop": 40
+            },
+            "ETHEUR": {
+                "altname": "ETHEUR",
+                "aclass_base": "currency",
+                "base": "XETH",
+                "aclass_quote": "currency",
+                "quote": "ZEUR",
+                "lot": "unit",
+                "pair_decimals": 2,
+                "lot_decimals": 8,
+                "lot_multiplier": 1,
+                "leverage_buy": [],
+                "leverage_sell": [],
+                "fees": [],
+                "fee_volume_currency": "ZUSD",
+                "margin_call": 80,
+                "margin_stop": 40
+            }
+        }"#;
+        let response: AssetPairResponse = serde_json::from_str(json).unwrap();
+        let map = response.instrument_map();
+
+        let btc_usd = Instrument::new("BTC", "USD");
+        assert_eq!(map.symbol(&btc_usd).map(|s| &**s), Some("XBTUSD"));
+        assert_eq!(map.instrument("XXBTZUSD"), Some(&btc_usd));
+        assert_eq!(map.instrument("XBT/USD"), Some(&btc_usd));
+
+        let eth_eur = Instrument::new("ETH", "EUR");
+        assert_eq!(map.symbol(&eth_eur).map(|s| &**s), Some("ETHEUR"));
+    }
+
+    #[test]
+    fn test_ohlc_response() {
+        let json = r#"{
+            "XXBTZUSD": [
+                [1688671200, "30306.1", "30306.2", "30305.7", "30305.7", "30306.1", "3.39243896", 23],
+                [1688671260, "30304.5", "30304.5", "30300.0", "30300.0", "30300.3", "4.42996871", 18]
+            ],
+            "last": 1688672160
+        }"#;
+
+        let response: OhlcResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.last, 1688672160);
+        let rows = &response.pair[&Atom::from("XXBTZUSD")];
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].time, 1688671200);
+        assert_eq!(rows[0].open, Decimal::new(303061, 1));
+        assert_eq!(rows[1].volume, Decimal::new(442996871, 8));
+        assert_eq!(rows[1].count, 18);
+    }
+
+    #[test]
+    fn test_recent_trades_response() {
+        let json = r#"{
+            "XXBTZUSD": [
+                ["30243.40000", "0.34507674", 1688669597.8277369, "b", "m", "", 61044952],
+                ["30243.30000", "0.00376960", 1688669598.2804112, "s", "l", "", 61044953]
+            ],
+            "last": "1688671969993150842"
+        }"#;
+
+        let response: RecentTradesResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.last, "1688671969993150842");
+        let rows = &response.pair[&Atom::from("XXBTZUSD")];
+        assert_eq!(rows[0].price, Decimal::new(302434, 1));
+        assert_eq!(rows[0].side, RecentTradeSide::Buy);
+        assert_eq!(rows[0].ordertype, RecentTradeOrderType::Market);
+        assert_eq!(rows[1].side, RecentTradeSide::Sell);
+        assert_eq!(rows[1].trade_id, 61044953);
     }
 }
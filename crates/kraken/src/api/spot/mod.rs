@@ -10,11 +10,14 @@ use crate::client::RateLimiter;
 use crate::client::RateLimiterBucket;
 use crate::client::RateLimiterBucketMode;
 use crate::client::RateLimiterTier;
+use crate::client::ReconnectingWebsocketStream;
 use crate::client::RestClient;
 use crate::client::WebsocketStream;
 
 // TODO mod error;
 // TODO mod savings;
+mod export;
+mod ledgers;
 mod market_data;
 mod trades_history;
 mod user_data;
@@ -23,18 +26,23 @@ mod user_trading;
 // TODO mod user_staking;
 // pub mod util;
 pub mod types;
-// TODO mod websocket_auth;
+mod websocket_auth;
 
+pub use self::export::*;
+pub use self::ledgers::*;
 pub use self::market_data::*;
 pub use self::trades_history::*;
 pub use self::types::*;
 pub use self::user_data::*;
 pub use self::user_funding::*;
 pub use self::user_trading::*;
+pub use self::websocket_auth::*;
 use crate::client::KrakenSigner;
 
 pub const API_BASE: &str = "https://api.kraken.com/";
 pub const STREAM_BASE: &str = "wss://ws.kraken.com/";
+pub const PRIVATE_STREAM_BASE: &str = "wss://ws-auth.kraken.com/";
+pub const PRIVATE_STREAM_V2_BASE: &str = "wss://ws-auth.kraken.com/v2";
 
 pub const RL_PUBLIC_PER_SECOND: &str = "public";
 pub const RL_PRIVATE_PER_MINUTE: &str = "private";
@@ -57,14 +65,22 @@ pub use with_network::*;
 
 #[cfg(feature = "with_network")]
 mod with_network {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
     use super::*;
     use crate::KrakenResult;
+    use crate::client::Nonce;
+    use crate::client::NonceSeq;
     use crate::client::RateLimiterBuilder;
 
     #[derive(Clone)]
     pub struct SpotApi<S: KrakenSigner = ApiCred> {
         pub(crate) client: RestClient<S>,
         pub(crate) rate_limiter: RateLimiter,
+        /// Kraken rejects a nonce not greater than the previous one of the same API key,
+        /// so the clones of a client draw from one sequence.
+        nonce_seq: Arc<Mutex<NonceSeq>>,
     }
 
     impl SpotApi<ApiCred> {
@@ -114,7 +130,7 @@ mod with_network {
                 .bucket(
                     RL_PRIVATE_PER_MINUTE,
                     RateLimiterBucket::default()
-                        .mode(RateLimiterBucketMode::KrakenDecrease)
+                        .mode(RateLimiterBucketMode::Decrease)
                         .interval(limits.private.period)
                         .limit(limits.private.max),
                 )
@@ -129,12 +145,46 @@ mod with_network {
             SpotApi {
                 client,
                 rate_limiter,
+                nonce_seq: Default::default(),
             }
         }
 
+        /// The next nonce for a private request of this client.
+        pub fn next_nonce(&self) -> Nonce {
+            self.nonce_seq
+                .lock()
+                .expect("nonce sequence poisoned")
+                .ts_next()
+        }
+
         /// Creates multiplexed websocket stream.
         pub async fn ws(&self) -> KrakenResult<WebsocketStream> {
             self.client.web_socket().await
         }
+
+        /// Creates multiplexed websocket stream to the private feeds.
+        ///
+        /// The private feeds are subscribed with a token from
+        /// [`SpotApi::get_websockets_token`].
+        pub async fn ws_private(&self) -> KrakenResult<WebsocketStream> {
+            self.client.private_web_socket().await
+        }
+
+        /// Creates multiplexed websocket stream to the private feeds of the v2 protocol,
+        /// i.e. [`WsStream::Balances`](crate::ws_stream::WsStream::Balances).
+        ///
+        /// The feeds are subscribed with a token from [`SpotApi::get_websockets_token`].
+        pub async fn ws_private_v2(&self) -> KrakenResult<WebsocketStream> {
+            self.client.private_web_socket_v2().await
+        }
+
+        /// Creates multiplexed websocket stream which reconnects and resubscribes
+        /// after disconnections.
+        pub async fn ws_reconnecting(&self) -> KrakenResult<ReconnectingWebsocketStream>
+        where
+            S: 'static,
+        {
+            ReconnectingWebsocketStream::connect(self.client.clone()).await
+        }
     }
 }
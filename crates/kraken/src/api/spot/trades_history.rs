@@ -10,6 +10,8 @@ use super::prelude::*;
 use crate::client::Task;
 
 pub const API_0_PRIVATE_TRADES_HISTORY: &str = "/0/private/TradesHistory";
+pub const API_0_PRIVATE_QUERY_TRADES: &str = "/0/private/QueryTrades";
+pub const API_0_PRIVATE_OPEN_POSITIONS: &str = "/0/private/OpenPositions";
 
 #[derive(Debug, Default, Serialize, Clone, Eq, PartialEq)]
 struct TradesHistory {
@@ -90,25 +92,31 @@ pub struct TradeInfo {
     pub pos_status: Option<String>,
     /// Average price of closed portion of position (quote currency)
     #[serde(rename = "cprice")]
+    #[serde(default)]
     #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
     pub closed_price: Option<Decimal>,
     /// Total cost of closed portion of position (quote currency)
     #[serde(rename = "ccost")]
+    #[serde(default)]
     #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
     pub closed_cost: Option<Decimal>,
     /// Total fee of closed portion of position (quote currency)
     #[serde(rename = "cfee")]
+    #[serde(default)]
     #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
     pub closed_fee: Option<Decimal>,
     /// Total fee of closed portion of position (quote currency)
     #[serde(rename = "cvol")]
+    #[serde(default)]
     #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
     pub closed_vol: Option<Decimal>,
     /// Total margin freed in closed portion of position (quote currency)
     #[serde(rename = "cmargin")]
+    #[serde(default)]
     #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
     pub closed_margin: Option<Decimal>,
     /// Net profit/loss of closed portion of position (quote currency, quote currency scale)
+    #[serde(default)]
     #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
     pub net: Option<Decimal>,
     /// List of closing trades for position (if available)
@@ -123,6 +131,84 @@ pub enum TradeInfoType {
     Sell,
 }
 
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct QueryTradesRequest<'a> {
+    txid: TxIds<'a>,
+    trades: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(transparent)]
+pub struct QueryTradesResponse {
+    /// Trade info by trade ID.
+    pub trades: HashMap<String, TradeInfo>,
+}
+
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct OpenPositionsRequest<'a> {
+    txid: Option<TxIds<'a>>,
+    docalcs: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(transparent)]
+pub struct OpenPositionsResponse {
+    /// Position info by position ID.
+    pub positions: HashMap<String, PositionInfo>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PositionInfo {
+    /// Order ID responsible for the position.
+    #[serde(rename = "ordertxid")]
+    pub order_tx_id: String,
+    /// Position status.
+    #[serde(rename = "posstatus")]
+    pub pos_status: String,
+    /// Asset pair.
+    pub pair: Atom,
+    /// Unix timestamp of trade.
+    #[serde(deserialize_with = "f64_arbitrary_precision::deserialize")]
+    pub time: f64,
+    /// Direction (buy/sell) of position.
+    pub r#type: TradeInfoType,
+    /// Order type used to open position.
+    #[serde(rename = "ordertype")]
+    pub order_type: OrderType,
+    /// Opening cost of position (in quote currency).
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub cost: Decimal,
+    /// Opening fee of position (in quote currency).
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub fee: Decimal,
+    /// Position opening size (in base currency).
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub vol: Decimal,
+    /// Quantity closed (in base currency).
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub vol_closed: Decimal,
+    /// Initial margin consumed (in quote currency).
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub margin: Decimal,
+    /// Current value of remaining position (if `docalcs` requested).
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub value: Option<Decimal>,
+    /// Unrealised P&L of remaining position (if `docalcs` requested).
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub net: Option<Decimal>,
+    /// Funding cost and term of position.
+    pub terms: Option<String>,
+    /// Timestamp of next margin rollover fee.
+    #[serde(rename = "rollovertm")]
+    pub rollover_tm: Option<String>,
+    /// Comma delimited list of add'l info.
+    pub misc: String,
+    /// Comma delimited list of opening order flags.
+    pub oflags: String,
+}
+
 #[cfg(feature = "with_network")]
 pub use with_network::*;
 
@@ -176,5 +262,84 @@ mod with_network {
                 .priority(RlPriorityLevel::High as u8)
                 .send())
         }
+
+        /// Query Trades Info
+        ///
+        /// Retrieve information about specific trades/fills, up to 20 at a time.
+        pub fn query_trades_info(
+            &self,
+            nonce: Nonce,
+            txid: TxIds<'_>,
+            trades: Option<bool>,
+        ) -> KrakenResult<Task<QueryTradesResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_QUERY_TRADES)?
+                        .signed(nonce)?
+                        .request_body(QueryTradesRequest { txid, trades })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Get Open Positions
+        ///
+        /// Get information about open margin positions, optionally only the ones
+        /// of the given ids. With `docalcs` the current value and profit/loss
+        /// of the positions are included.
+        pub fn open_positions(
+            &self,
+            nonce: Nonce,
+            txid: Option<TxIds<'_>>,
+            docalcs: Option<bool>,
+        ) -> KrakenResult<Task<OpenPositionsResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_OPEN_POSITIONS)?
+                        .signed(nonce)?
+                        .request_body(OpenPositionsRequest { txid, docalcs })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .send())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_trades_response() {
+        let json = r#"{
+            "THVRQM-33VKH-UCI7BS": {
+                "ordertxid": "OQCLML-BW3P3-BUCMWZ",
+                "postxid": "TKH2SE-M7IF5-CFI7LT",
+                "pair": "XXBTZUSD",
+                "time": 1688667796.8802,
+                "type": "buy",
+                "ordertype": "limit",
+                "price": "30010.00000",
+                "cost": "600.20000",
+                "fee": "0.00000",
+                "vol": "0.02000000",
+                "margin": "0.00000",
+                "misc": "",
+                "trade_id": 93748276,
+                "maker": true
+            }
+        }"#;
+
+        let response: QueryTradesResponse = serde_json::from_str(json).unwrap();
+        let trade = &response.trades["THVRQM-33VKH-UCI7BS"];
+        assert_eq!(trade.order_tx_id, "OQCLML-BW3P3-BUCMWZ");
+        assert_eq!(trade.trade_id, 93748276);
+        assert_eq!(trade.time, 1688667796.8802);
+        assert_eq!(trade.price, Decimal::new(3001000000000, 8));
+        assert!(trade.maker);
     }
 }
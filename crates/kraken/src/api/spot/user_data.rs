@@ -8,8 +8,12 @@ use super::prelude::*;
 use crate::client::Task;
 
 pub const API_0_PRIVATE_BALANCE: &str = "/0/private/Balance";
+pub const API_0_PRIVATE_BALANCE_EX: &str = "/0/private/BalanceEx";
+pub const API_0_PRIVATE_TRADE_BALANCE: &str = "/0/private/TradeBalance";
+pub const API_0_PRIVATE_OPEN_ORDERS: &str = "/0/private/OpenOrders";
 pub const API_0_PRIVATE_CLOSED_ORDERS: &str = "/0/private/ClosedOrders";
 pub const API_0_PRIVATE_QUERY_ORDERS: &str = "/0/private/QueryOrders";
+pub const API_0_PRIVATE_TRADE_VOLUME: &str = "/0/private/TradeVolume";
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
 pub struct AccountBalanceResponse {
@@ -18,6 +22,92 @@ pub struct AccountBalanceResponse {
     pub asset: HashMap<Atom, Decimal>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct ExtendedBalanceResponse {
+    /// Extended balances by asset.
+    #[serde(flatten)]
+    pub asset: HashMap<Atom, ExtendedBalance>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct ExtendedBalance {
+    /// Total balance amount for an asset.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub balance: Decimal,
+    /// Total held amount for an asset.
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub hold_trade: Option<Decimal>,
+    /// Total credit amount for an asset, on accounts with a credit line.
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub credit: Option<Decimal>,
+    /// Used credit amount for an asset.
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub credit_used: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct TradeBalanceRequest<'a> {
+    asset: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct TradeBalanceResponse {
+    /// Equivalent balance (combined balance of all currencies).
+    #[serde(rename = "eb")]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub equivalent_balance: Decimal,
+    /// Trade balance (combined balance of all equity currencies).
+    #[serde(rename = "tb")]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub trade_balance: Decimal,
+    /// Margin amount of open positions.
+    #[serde(rename = "m")]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub margin: Decimal,
+    /// Unrealized net profit/loss of open positions.
+    #[serde(rename = "n")]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub net: Decimal,
+    /// Cost basis of open positions.
+    #[serde(rename = "c")]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub cost: Decimal,
+    /// Current floating valuation of open positions.
+    #[serde(rename = "v")]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub valuation: Decimal,
+    /// Equity: trade balance + unrealized net profit/loss.
+    #[serde(rename = "e")]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub equity: Decimal,
+    /// Free margin: equity - initial margin (maximum margin available to open new positions).
+    #[serde(rename = "mf")]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub free_margin: Decimal,
+    /// Margin level: (equity / initial margin) * 100; missing without open positions.
+    #[serde(rename = "ml", default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub margin_level: Option<Decimal>,
+    /// Unexecuted value: value of unfilled and partially filled orders.
+    #[serde(rename = "uv", default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub unexecuted_value: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+struct GetOpenOrdersRequest {
+    trades: Option<bool>,
+    userref: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GetOpenOrdersResponse {
+    pub open: HashMap<String, OrderInfo>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 struct GetClosedOrdersRequest {
     trades: Option<bool>,
@@ -56,6 +146,7 @@ pub struct OrderInfo {
     #[serde(deserialize_with = "f64_arbitrary_precision::deserialize")]
     pub expiretm: f64,
     /// Unix timestamp of when order was closed.
+    #[serde(default)]
     #[serde(deserialize_with = "f64_arbitrary_precision::deserialize_option")]
     pub closetm: Option<f64>,
     /// Order description info.
@@ -125,6 +216,53 @@ pub struct QueryOrdersInfoResponse {
     pub orders: HashMap<String, OrderInfo>,
 }
 
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct TradeVolumeRequest<'a> {
+    pair: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct TradeVolumeResponse {
+    /// Volume currency.
+    pub currency: Atom,
+    /// Current discount volume.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub volume: Decimal,
+    /// Taker fee tiers of the requested pairs.
+    #[serde(default)]
+    pub fees: HashMap<Atom, FeeTierInfo>,
+    /// Maker fee tiers of the requested pairs, for the pairs with maker/taker pricing.
+    #[serde(default)]
+    pub fees_maker: HashMap<Atom, FeeTierInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct FeeTierInfo {
+    /// Current fee (in percent).
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub fee: Decimal,
+    /// Minimum fee for pair if not fixed fee.
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub minfee: Option<Decimal>,
+    /// Maximum fee for pair if not fixed fee.
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub maxfee: Option<Decimal>,
+    /// Next tier's fee for pair; missing at the highest tier.
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub nextfee: Option<Decimal>,
+    /// Volume level of current tier.
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub tiervolume: Option<Decimal>,
+    /// Volume level of next tier; missing at the highest tier.
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub nextvolume: Option<Decimal>,
+}
+
 #[cfg(feature = "with_network")]
 pub use with_network::*;
 
@@ -156,6 +294,69 @@ mod with_network {
                 .send())
         }
 
+        /// Get Extended Balance.
+        ///
+        /// Retrieve all extended account balances, including credits and held amounts.
+        /// Balance available for trading is `balance + credit - credit_used - hold_trade`.
+        pub fn get_extended_balance(
+            &self,
+            nonce: Nonce,
+        ) -> KrakenResult<Task<ExtendedBalanceResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_BALANCE_EX)?
+                        .signed(nonce)?
+                        .request_body(())?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Get Trade Balance.
+        ///
+        /// Retrieve a summary of collateral balances, margin position valuations,
+        /// equity and margin level. `asset` is the base asset used to determine
+        /// the balance, `ZUSD` by default.
+        pub fn get_trade_balance(
+            &self,
+            nonce: Nonce,
+            asset: Option<&str>,
+        ) -> KrakenResult<Task<TradeBalanceResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_TRADE_BALANCE)?
+                        .signed(nonce)?
+                        .request_body(TradeBalanceRequest { asset })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .send())
+        }
+
+        /// Get Open Orders.
+        ///
+        /// Retrieve information about currently open orders.
+        pub fn get_open_orders(
+            &self,
+            nonce: Nonce,
+            trades: Option<bool>,
+            userref: Option<u32>,
+        ) -> KrakenResult<Task<GetOpenOrdersResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_OPEN_ORDERS)?
+                        .signed(nonce)?
+                        .request_body(GetOpenOrdersRequest { trades, userref })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .send())
+        }
+
         /// Get Closed Orders.
         ///
         /// Retrieve information about orders that have been closed (filled or cancelled). 50 results are returned at a time, the most recent by default.
@@ -218,6 +419,27 @@ mod with_network {
                 .cost(RL_MATCHING_ENGINE_PER_MINUTE, 1)
                 .send())
         }
+
+        /// Get Trade Volume.
+        ///
+        /// Returns 30 day USD trading volume and resulting fee schedule for any asset
+        /// pair(s) provided, given as a comma delimited list in `pair`.
+        pub fn get_trade_volume(
+            &self,
+            nonce: Nonce,
+            pair: Option<&str>,
+        ) -> KrakenResult<Task<TradeVolumeResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_TRADE_VOLUME)?
+                        .signed(nonce)?
+                        .request_body(TradeVolumeRequest { pair })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .send())
+        }
     }
 }
 
@@ -271,4 +493,38 @@ mod tests {
         assert_eq!(order_info.expiretm, 0.0);
         assert_eq!(order_info.closetm, Some(1766554023.422301));
     }
+
+    #[cfg(feature = "with_network")]
+    #[tokio::test]
+    async fn test_balance_through_transport() {
+        use ccx_api_lib::HttpResponse;
+        use ccx_api_lib::MockTransport;
+        use ccx_api_lib::http::Method;
+
+        use crate::client::Config;
+
+        let mock = MockTransport::new();
+        let answer = serde_json::json!({"error": [], "result": {"XXBT": "0.5", "ZUSD": "100"}});
+        mock.respond(
+            Method::POST,
+            API_0_PRIVATE_BALANCE,
+            HttpResponse::json(&answer),
+        );
+        let api = SpotApi::with_config(Config::mock(&mock));
+
+        let (balance, _warnings) = api
+            .get_account_balance(Nonce::new(1616492376594_u64))
+            .unwrap()
+            .await
+            .unwrap();
+        assert_eq!(balance.asset[&Atom::from("XXBT")], Decimal::new(5, 1));
+
+        let requests = mock.requests();
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://api.kraken.com/0/private/Balance"
+        );
+        assert_eq!(&requests[0].body[..], b"nonce=1616492376594");
+        assert!(requests[0].headers.contains_key("API-Sign"));
+    }
 }
@@ -5,6 +5,17 @@ use super::prelude::*;
 use crate::client::Task;
 
 pub const API_0_PRIVATE_ADD_ORDER: &str = "/0/private/AddOrder";
+pub const API_0_PRIVATE_ADD_ORDER_BATCH: &str = "/0/private/AddOrderBatch";
+pub const API_0_PRIVATE_AMEND_ORDER: &str = "/0/private/AmendOrder";
+pub const API_0_PRIVATE_EDIT_ORDER: &str = "/0/private/EditOrder";
+pub const API_0_PRIVATE_CANCEL_ORDER: &str = "/0/private/CancelOrder";
+pub const API_0_PRIVATE_CANCEL_ALL: &str = "/0/private/CancelAll";
+pub const API_0_PRIVATE_CANCEL_ALL_ORDERS_AFTER: &str = "/0/private/CancelAllOrdersAfter";
+pub const API_0_PRIVATE_CANCEL_ORDER_BATCH: &str = "/0/private/CancelOrderBatch";
+
+pub const ADD_ORDER_BATCH_MIN: usize = 2;
+pub const ADD_ORDER_BATCH_MAX: usize = 15;
+pub const CANCEL_ORDER_BATCH_MAX: usize = 50;
 
 #[derive(Debug, Serialize, Clone, Eq, PartialEq)]
 struct AddOrderRequest<'a> {
@@ -57,6 +68,168 @@ pub struct AddedOrderDescription {
     pub close: Option<String>,
 }
 
+/// An order of an [`SpotApi::add_order_batch`] request.
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+pub struct BatchOrder<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub userref: Option<u32>,
+    pub ordertype: OrderType,
+    #[serde(rename = "type")]
+    pub side: OrderSide,
+    pub volume: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price2: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oflags: Option<OrderFlags>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeinforce: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starttm: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiretm: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct AddOrderBatchRequest<'a> {
+    orders: &'a [BatchOrder<'a>],
+    pair: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deadline: Option<&'a str>,
+    validate: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct AddOrderBatchResponse {
+    /// Results of the orders in the order of the request.
+    pub orders: Vec<BatchOrderResult>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct BatchOrderResult {
+    /// Transaction ID of the order; missing when the order was rejected.
+    pub txid: Option<String>,
+    /// Order description info.
+    pub descr: Option<AddedOrderDescription>,
+    /// Why the order was rejected.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct EditOrderRequest<'a> {
+    userref: Option<u32>,
+    txid: &'a str,
+    volume: Option<Decimal>,
+    displayvol: Option<Decimal>,
+    pair: &'a str,
+    price: Option<Decimal>,
+    price2: Option<Decimal>,
+    oflags: Option<OrderFlags>,
+    deadline: Option<&'a str>,
+    cancel_response: Option<bool>,
+    validate: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct EditOrderResponse {
+    /// Order description info.
+    pub descr: AddedOrderDescription,
+    /// Transaction ID of the new order; missing when only validated.
+    pub txid: Option<String>,
+    /// Transaction ID of the replaced order.
+    pub originaltxid: String,
+    /// Status of the edit, "ok" or "err".
+    pub status: String,
+    /// Updated volume.
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub volume: Option<Decimal>,
+    /// Updated price.
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub price: Option<Decimal>,
+    /// Updated secondary price.
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub price2: Option<Decimal>,
+    /// Number of orders cancelled (either 0 or 1).
+    #[serde(default)]
+    pub orders_cancelled: u32,
+    /// Error message if unsuccessful.
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct AmendOrderRequest<'a> {
+    txid: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_qty: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_qty: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit_price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trigger_price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    post_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deadline: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct AmendOrderResponse {
+    /// Kraken identifier of the amend transaction.
+    pub amend_id: String,
+}
+
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct CancelOrderRequest<'a> {
+    txid: TxIds<'a>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct CancelOrderResponse {
+    /// Number of orders cancelled.
+    pub count: u32,
+    /// If set, order(s) is/are pending cancellation.
+    #[serde(default)]
+    pub pending: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct CancelAllResponse {
+    /// Number of orders cancelled.
+    pub count: u32,
+}
+
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct CancelAllOrdersAfterRequest {
+    timeout: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct CancelAllOrdersAfterResponse {
+    /// Timestamp (RFC3339 format) at which the request was received.
+    #[serde(rename = "currentTime")]
+    pub current_time: String,
+    /// Timestamp (RFC3339 format) after which all orders will be cancelled,
+    /// unless the timer is extended or disabled.
+    #[serde(rename = "triggerTime")]
+    pub trigger_time: String,
+}
+
+#[derive(Debug, Serialize, Clone, Eq, PartialEq)]
+struct CancelOrderBatchRequest<'a> {
+    orders: &'a [&'a str],
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct CancelOrderBatchResponse {
+    /// Number of orders cancelled.
+    pub count: u32,
+}
+
 #[cfg(feature = "with_network")]
 pub use with_network::*;
 
@@ -181,5 +354,381 @@ mod with_network {
                 .priority(RlPriorityLevel::High as u8)
                 .send())
         }
+
+        /// Place a batch of 2 to 15 orders on a single pair.
+        ///
+        /// The orders are validated as a whole and rejected together when any of them
+        /// fails the validation; once accepted, each one is placed on its own.
+        pub fn add_order_batch(
+            &self,
+            nonce: Nonce,
+            pair: &str,
+            orders: &[BatchOrder<'_>],
+            deadline: Option<&str>,
+            validate: bool,
+        ) -> KrakenResult<Task<AddOrderBatchResponse>> {
+            if !(ADD_ORDER_BATCH_MIN..=ADD_ORDER_BATCH_MAX).contains(&orders.len()) {
+                Err(ApiError::OutOfBounds)?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_ADD_ORDER_BATCH)?
+                        .signed(nonce)?
+                        .request_json_body(AddOrderBatchRequest {
+                            orders,
+                            pair,
+                            deadline,
+                            validate,
+                        })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .cost(RL_MATCHING_ENGINE_PER_MINUTE, orders.len() as u32)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Edit the volume and price of an open order.
+        ///
+        /// The order is cancelled and replaced by a new one with a new transaction ID.
+        /// Prefer [`SpotApi::amend_order`] which keeps the queue priority where possible.
+        #[allow(clippy::too_many_arguments)]
+        pub fn edit_order(
+            &self,
+            nonce: Nonce,
+            userref: Option<u32>,
+            txid: &str,
+            volume: Option<Decimal>,
+            displayvol: Option<Decimal>,
+            pair: &str,
+            price: Option<Decimal>,
+            price2: Option<Decimal>,
+            oflags: Option<OrderFlags>,
+            deadline: Option<&str>,
+            cancel_response: Option<bool>,
+            validate: bool,
+        ) -> KrakenResult<Task<EditOrderResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_EDIT_ORDER)?
+                        .signed(nonce)?
+                        .request_body(EditOrderRequest {
+                            userref,
+                            txid,
+                            volume,
+                            displayvol,
+                            pair,
+                            price,
+                            price2,
+                            oflags,
+                            deadline,
+                            cancel_response,
+                            validate,
+                        })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .cost(RL_MATCHING_ENGINE_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Amend the quantity and prices of an open order in place.
+        ///
+        /// The order keeps its transaction ID, and its queue priority too unless the
+        /// price changes or the quantity grows.
+        #[allow(clippy::too_many_arguments)]
+        pub fn amend_order(
+            &self,
+            nonce: Nonce,
+            txid: &str,
+            order_qty: Option<Decimal>,
+            display_qty: Option<Decimal>,
+            limit_price: Option<Decimal>,
+            trigger_price: Option<Decimal>,
+            post_only: Option<bool>,
+            deadline: Option<&str>,
+        ) -> KrakenResult<Task<AmendOrderResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_AMEND_ORDER)?
+                        .signed(nonce)?
+                        .request_json_body(AmendOrderRequest {
+                            txid,
+                            order_qty,
+                            display_qty,
+                            limit_price,
+                            trigger_price,
+                            post_only,
+                            deadline,
+                        })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .cost(RL_MATCHING_ENGINE_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Cancel a particular open order (or set of open orders) by `txid` or `userref`.
+        pub fn cancel_order(
+            &self,
+            nonce: Nonce,
+            txid: TxIds<'_>,
+        ) -> KrakenResult<Task<CancelOrderResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_CANCEL_ORDER)?
+                        .signed(nonce)?
+                        .request_body(CancelOrderRequest { txid })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .cost(RL_MATCHING_ENGINE_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Cancel all open orders.
+        pub fn cancel_all(&self, nonce: Nonce) -> KrakenResult<Task<CancelAllResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_CANCEL_ALL)?
+                        .signed(nonce)?
+                        .request_body(())?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Cancel all orders after a timeout (dead man's switch).
+        ///
+        /// Every call restarts the countdown of `timeout` seconds; all the open orders are
+        /// cancelled unless the next call comes before it expires. A `timeout` of 0
+        /// disables the timer.
+        pub fn cancel_all_orders_after(
+            &self,
+            nonce: Nonce,
+            timeout: u32,
+        ) -> KrakenResult<Task<CancelAllOrdersAfterResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_CANCEL_ALL_ORDERS_AFTER)?
+                        .signed(nonce)?
+                        .request_body(CancelAllOrdersAfterRequest { timeout })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+
+        /// Cancel up to 50 open orders by `txid` or `userref` at once.
+        pub fn cancel_order_batch(
+            &self,
+            nonce: Nonce,
+            orders: TxIds<'_>,
+        ) -> KrakenResult<Task<CancelOrderBatchResponse>> {
+            if orders.0.len() > CANCEL_ORDER_BATCH_MAX {
+                Err(ApiError::OutOfBounds)?
+            }
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_CANCEL_ORDER_BATCH)?
+                        .signed(nonce)?
+                        .request_json_body(CancelOrderBatchRequest { orders: orders.0 })?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .cost(RL_MATCHING_ENGINE_PER_MINUTE, orders.0.len() as u32)
+                .priority(RlPriorityLevel::High as u8)
+                .send())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_order_response() {
+        let json = r#"{
+            "status": "ok",
+            "txid": "OFVXHJ-KPQ3B-VS7ELA",
+            "originaltxid": "OHYO67-6LP66-HMQ437",
+            "volume": "0.00030000",
+            "price": "19500.0",
+            "price2": "32500.0",
+            "orders_cancelled": 1,
+            "descr": {
+                "order": "buy 0.00030000 XXBTZGBP @ limit 19500.0"
+            }
+        }"#;
+
+        let response: EditOrderResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.txid.as_deref(), Some("OFVXHJ-KPQ3B-VS7ELA"));
+        assert_eq!(response.originaltxid, "OHYO67-6LP66-HMQ437");
+        assert_eq!(response.volume, Some(Decimal::new(3, 4)));
+        assert_eq!(response.price, Some(Decimal::new(19500, 0)));
+        assert_eq!(response.orders_cancelled, 1);
+        assert_eq!(response.descr.close, None);
+    }
+
+    #[test]
+    fn test_add_order_batch_response() {
+        let json = r#"{
+            "orders": [
+                {
+                    "txid": "65LRD3-AHGRA-YAH8V5",
+                    "descr": {
+                        "order": "buy 1.02010000 XBTUSD @ limit 29000.0"
+                    }
+                },
+                {
+                    "error": "EOrder:Insufficient funds"
+                }
+            ]
+        }"#;
+
+        let response: AddOrderBatchResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.orders.len(), 2);
+        assert_eq!(
+            response.orders[0].txid.as_deref(),
+            Some("65LRD3-AHGRA-YAH8V5")
+        );
+        assert_eq!(response.orders[0].error, None);
+        assert_eq!(response.orders[1].txid, None);
+        assert_eq!(
+            response.orders[1].error.as_deref(),
+            Some("EOrder:Insufficient funds")
+        );
+    }
+
+    #[cfg(feature = "with_network")]
+    #[tokio::test]
+    async fn test_cancel_requests_through_transport() {
+        use ccx_api_lib::HttpResponse;
+        use ccx_api_lib::MockTransport;
+        use ccx_api_lib::http::Method;
+
+        use crate::client::Config;
+
+        let mock = MockTransport::new();
+        mock.respond(
+            Method::POST,
+            API_0_PRIVATE_CANCEL_ORDER,
+            HttpResponse::json(&serde_json::json!({"error": [], "result": {"count": 1}})),
+        );
+        mock.respond(
+            Method::POST,
+            API_0_PRIVATE_CANCEL_ORDER_BATCH,
+            HttpResponse::json(&serde_json::json!({"error": [], "result": {"count": 2}})),
+        );
+        let api = SpotApi::with_config(Config::mock(&mock));
+
+        let (cancelled, _warnings) = api
+            .cancel_order(Nonce::new(1_u64), TxIds(&["OYVGEW-VYV5B-UUEXSK"]))
+            .unwrap()
+            .await
+            .unwrap();
+        assert_eq!(cancelled.count, 1);
+        assert!(!cancelled.pending);
+
+        let (cancelled, _warnings) = api
+            .cancel_order_batch(
+                Nonce::new(2_u64),
+                TxIds(&["OYVGEW-VYV5B-UUEXSK", "OG5V2Y-RYKVL-DT3V3B"]),
+            )
+            .unwrap()
+            .await
+            .unwrap();
+        assert_eq!(cancelled.count, 2);
+
+        let requests = mock.requests();
+        assert_eq!(&requests[0].body[..], b"nonce=1&txid=OYVGEW-VYV5B-UUEXSK");
+        assert_eq!(
+            requests[0].headers["content-type"],
+            "application/x-www-form-urlencoded"
+        );
+        assert_eq!(
+            &requests[1].body[..],
+            br#"{"nonce":2,"orders":["OYVGEW-VYV5B-UUEXSK","OG5V2Y-RYKVL-DT3V3B"]}"#
+        );
+        assert_eq!(requests[1].headers["content-type"], "application/json");
+    }
+
+    #[cfg(feature = "with_network")]
+    #[test]
+    fn test_batch_size_is_checked_before_sending() {
+        use ccx_api_lib::MockTransport;
+
+        use crate::client::Config;
+
+        fn assert_out_of_bounds<T>(res: KrakenResult<T>) {
+            match res {
+                Err(KrakenError::ApiError(e)) => {
+                    assert!(matches!(e.0[..], [ApiError::OutOfBounds]))
+                }
+                Err(e) => panic!("unexpected error: {e:?}"),
+                Ok(_) => panic!("expected an error"),
+            }
+        }
+
+        let mock = MockTransport::new();
+        let api = SpotApi::with_config(Config::mock(&mock));
+
+        let orders = vec![limit_order(); ADD_ORDER_BATCH_MAX + 1];
+        for orders in [&orders[..1], &orders[..]] {
+            assert_out_of_bounds(api.add_order_batch(
+                Nonce::new(1_u64),
+                "XBTUSD",
+                orders,
+                None,
+                false,
+            ));
+        }
+
+        let txids = vec!["OYVGEW-VYV5B-UUEXSK"; CANCEL_ORDER_BATCH_MAX + 1];
+        assert_out_of_bounds(api.cancel_order_batch(Nonce::new(2_u64), TxIds(&txids)));
+
+        assert!(mock.requests().is_empty());
+    }
+
+    fn limit_order() -> BatchOrder<'static> {
+        BatchOrder {
+            userref: None,
+            ordertype: OrderType::Limit,
+            side: OrderSide::Buy,
+            volume: Decimal::new(125, 2),
+            price: Some(Decimal::new(270005, 1)),
+            price2: None,
+            oflags: Some(OrderFlags {
+                post: true,
+                ..Default::default()
+            }),
+            timeinforce: None,
+            starttm: None,
+            expiretm: None,
+        }
+    }
+
+    #[test]
+    fn test_batch_order_json() {
+        let order = limit_order();
+
+        assert_eq!(
+            serde_json::to_string(&order).unwrap(),
+            r#"{"ordertype":"limit","type":"buy","volume":"1.25","price":"27000.5","oflags":"post"}"#
+        );
     }
 }
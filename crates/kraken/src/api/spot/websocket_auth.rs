@@ -0,0 +1,69 @@
+use super::RL_PRIVATE_PER_MINUTE;
+use super::prelude::*;
+use crate::client::Task;
+
+pub const API_0_PRIVATE_GET_WEBSOCKETS_TOKEN: &str = "/0/private/GetWebSocketsToken";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct WebSocketsTokenResponse {
+    /// Websockets token.
+    pub token: String,
+    /// Time (in seconds) after which the token expires.
+    pub expires: u64,
+}
+
+#[cfg(feature = "with_network")]
+pub use with_network::*;
+
+#[cfg(feature = "with_network")]
+mod with_network {
+    use super::*;
+
+    impl<S> SpotApi<S>
+    where
+        S: crate::client::KrakenSigner,
+        S: Unpin + 'static,
+    {
+        /// Get Websockets Token.
+        ///
+        /// An authentication token to subscribe to the private feeds of [`SpotApi::ws_private`].
+        /// The token should be used within 15 minutes of creation, but it does not expire
+        /// once a successful websockets connection and private subscription has been made
+        /// and is maintained.
+        pub fn get_websockets_token(
+            &self,
+            nonce: Nonce,
+        ) -> KrakenResult<Task<WebSocketsTokenResponse>> {
+            Ok(self
+                .rate_limiter
+                .task(
+                    self.client
+                        .post(API_0_PRIVATE_GET_WEBSOCKETS_TOKEN)?
+                        .signed(nonce)?
+                        .request_body(())?,
+                )
+                .cost(RL_PRIVATE_PER_MINUTE, 1)
+                .send())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_websockets_token_response() {
+        let json = r#"{
+            "token": "1Dwc4lzSwNWOAwkMdqhssNNFhs1ed606d1WcF3XfEMw",
+            "expires": 900
+        }"#;
+
+        let response: WebSocketsTokenResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            response.token,
+            "1Dwc4lzSwNWOAwkMdqhssNNFhs1ed606d1WcF3XfEMw"
+        );
+        assert_eq!(response.expires, 900);
+    }
+}
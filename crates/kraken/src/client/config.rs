@@ -1,6 +1,8 @@
+use std::sync::Arc;
 use std::time::Duration;
 
 pub use ccx_api_lib::ApiCred;
+pub use ccx_api_lib::HttpTransport;
 pub use ccx_api_lib::Proxy;
 use ccx_api_lib::env_var_with_prefix;
 use url::Url;
@@ -76,8 +78,14 @@ pub struct Config<S: KrakenSigner> {
     pub signer: S,
     pub api_base: Url,
     pub stream_base: Url,
+    /// Private websocket feeds endpoint; the default one when `None`.
+    ///
+    /// The feeds of the v2 protocol are served under its `v2` path.
+    pub private_stream_base: Option<Url>,
     pub proxy: Option<Proxy>,
     pub tier: RateLimiterTier,
+    /// Sends the REST requests; over the network when `None`.
+    pub transport: Option<Arc<dyn HttpTransport>>,
 }
 
 impl<S> Config<S>
@@ -95,11 +103,23 @@ where
             signer,
             api_base,
             stream_base,
+            private_stream_base: None,
             proxy,
             tier,
+            transport: None,
         }
     }
 
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    pub fn with_private_stream_base(mut self, private_stream_base: Url) -> Self {
+        self.private_stream_base = Some(private_stream_base);
+        self
+    }
+
     pub fn env_var(postfix: &str) -> Option<String> {
         env_var_with_prefix(CCX_KRAKEN_API_PREFIX, postfix)
     }
@@ -112,3 +132,21 @@ where
         &self.signer
     }
 }
+
+#[cfg(test)]
+impl Config<ApiCred> {
+    /// Production urls with the requests answered by the mock.
+    pub(crate) fn mock(mock: &ccx_api_lib::MockTransport) -> Self {
+        use crate::api::spot::API_BASE;
+        use crate::api::spot::STREAM_BASE;
+
+        Config::new(
+            ApiCred::default(),
+            Url::parse(API_BASE).unwrap(),
+            Url::parse(STREAM_BASE).unwrap(),
+            None,
+            RateLimiterTier::Starter,
+        )
+        .with_transport(mock.clone())
+    }
+}
@@ -4,6 +4,7 @@ mod rate_limiter;
 mod rest;
 mod signer;
 mod websocket;
+mod websocket_reconnecting;
 
 pub use self::config::*;
 pub use self::nonce::*;
@@ -11,3 +12,4 @@ pub use self::rate_limiter::*;
 pub use self::rest::*;
 pub use self::signer::*;
 pub use self::websocket::*;
+pub use self::websocket_reconnecting::*;
@@ -1,76 +1,40 @@
-use std::borrow::Cow;
-use std::collections::HashMap;
-use std::collections::VecDeque;
-use std::collections::hash_map::Entry;
 use std::fmt::Debug;
 use std::pin::Pin;
-use std::sync::Arc;
-use std::time::Duration;
-use std::time::Instant;
 
-use futures::channel::mpsc;
-use futures::channel::oneshot;
-use futures::lock::Mutex;
+use ccx_api_lib::BucketName;
+pub(crate) use ccx_api_lib::RateLimiterBucket;
+pub(crate) use ccx_api_lib::RateLimiterBucketMode;
+use ccx_api_lib::TaskCosts;
+pub use ccx_api_lib::TaskMetadata;
 use futures::prelude::*;
 use futures::task::Context;
 use futures::task::Poll;
-use tokio::time::sleep;
 
 use super::KrakenSigner;
 use super::RequestBuilder;
 use crate::KrakenApiResult;
-use crate::KrakenResult;
-use crate::LibError;
-
-type BucketName = Cow<'static, str>;
-type TaskCosts = HashMap<BucketName, u32>;
-type TaskMessageResult = KrakenResult<()>;
-
-struct TaskMessage {
-    priority: u8,
-    costs: TaskCosts,
-    tx: oneshot::Sender<TaskMessageResult>,
-}
 
 #[derive(Default)]
 pub(crate) struct RateLimiterBuilder {
-    buckets: HashMap<BucketName, RateLimiterBucket>,
+    inner: ccx_api_lib::RateLimiterBuilder,
 }
 
 impl RateLimiterBuilder {
     pub fn bucket(mut self, key: impl Into<BucketName>, bucket: RateLimiterBucket) -> Self {
-        match self.buckets.entry(key.into()) {
-            Entry::Occupied(mut e) => *e.get_mut() = bucket,
-            Entry::Vacant(e) => {
-                e.insert(bucket);
-            }
-        }
+        self.inner = self.inner.bucket(key, bucket);
         self
     }
 
     pub fn start(self) -> RateLimiter {
-        let (tasks_tx, tasks_rx) = mpsc::unbounded::<TaskMessage>();
-        let buckets = self
-            .buckets
-            .into_iter()
-            .map(|(k, v)| (k, Mutex::new(v)))
-            .collect();
-
-        let rate_limiter = RateLimiter {
-            buckets: Arc::new(buckets),
-            tasks_tx,
-            queue: Arc::new(Mutex::new(Queue::new())),
-        };
-        rate_limiter.recv(tasks_rx);
-        rate_limiter
+        RateLimiter {
+            inner: self.inner.start(),
+        }
     }
 }
 
 #[derive(Clone)]
 pub(crate) struct RateLimiter {
-    buckets: Arc<HashMap<BucketName, Mutex<RateLimiterBucket>>>,
-    tasks_tx: mpsc::UnboundedSender<TaskMessage>,
-    queue: Arc<Mutex<Queue>>,
+    inner: ccx_api_lib::RateLimiter,
 }
 
 impl RateLimiter {
@@ -82,260 +46,11 @@ impl RateLimiter {
             priority: 0,
             costs: TaskCosts::new(),
             req_builder: builder,
-            tasks_tx: self.tasks_tx.clone(),
-        }
-    }
-
-    fn recv(&self, mut rx: mpsc::UnboundedReceiver<TaskMessage>) {
-        let buckets = self.buckets.clone();
-        let queue = self.queue.clone();
-        tokio::spawn(async move {
-            while let Some(task_message) = rx.next().await {
-                let is_first_task = queue.lock().await.add(task_message).is_first();
-                if is_first_task {
-                    Self::handler(buckets.clone(), queue.clone()).await;
-                }
-            }
-        });
-    }
-
-    async fn handler(
-        buckets: Arc<HashMap<BucketName, Mutex<RateLimiterBucket>>>,
-        queue: Arc<Mutex<Queue>>,
-    ) {
-        tokio::spawn(async move {
-            loop {
-                let TaskMessage {
-                    priority,
-                    costs,
-                    tx,
-                } = match queue.lock().await.next() {
-                    Some(task) => task,
-                    None => {
-                        log::debug!("RateLimiter: stop queue handler (queue is empty)");
-                        break;
-                    }
-                };
-                log::debug!("RateLimiter: received task with priority {}", priority);
-
-                let buckets = buckets.clone();
-                let res = async move {
-                    if let Some(dur) = Self::timeout(buckets.clone(), &costs).await? {
-                        log::debug!("RateLimiter: sleep for {:?}", dur);
-                        sleep(dur).await;
-                    }
-                    Self::set_costs(buckets, &costs).await?;
-                    Ok(())
-                }
-                .await;
-
-                log::debug!("RateLimiter: completed task with priority {}", priority);
-                let _ = tx.send(res);
-            }
-        });
-    }
-
-    async fn timeout(
-        buckets: Arc<HashMap<BucketName, Mutex<RateLimiterBucket>>>,
-        costs: &TaskCosts,
-    ) -> KrakenResult<Option<Duration>> {
-        let mut timeout = Duration::default();
-
-        for (name, cost) in costs {
-            let mut bucket = match buckets.get(name) {
-                Some(bucket) => bucket.lock().await,
-                None => Err(LibError::other(format!(
-                    "RateLimiter: undefined bucket {}",
-                    name
-                )))?,
-            };
-
-            let delay = bucket.delay.duration_since(Instant::now());
-            if !delay.is_zero() {
-                log::debug!("RateLimiter: bucket {} :: Delayed start {:?}", name, delay);
-                timeout = delay;
-                continue;
-            }
-
-            bucket.update_state();
-            let new_amount = bucket.amount + cost;
-            log::debug!(
-                "RateLimiter: bucket {} :: Task cost {}; prev amount {}; bucket limit: {};",
-                name,
-                cost,
-                bucket.amount,
-                bucket.limit
-            );
-
-            if new_amount > bucket.limit {
-                let bucket_timeout = bucket.get_timeout();
-                log::debug!("RateLimiter: bucket {} :: Limit has been reached", name);
-
-                if bucket_timeout > timeout {
-                    log::debug!(
-                        "RateLimiter: bucket {} :: Need sleep {:?}.",
-                        name,
-                        bucket_timeout
-                    );
-                    timeout = bucket_timeout;
-                }
-            }
-        }
-
-        Ok((!timeout.is_zero()).then_some(timeout))
-    }
-
-    async fn set_costs(
-        buckets: Arc<HashMap<BucketName, Mutex<RateLimiterBucket>>>,
-        costs: &TaskCosts,
-    ) -> KrakenResult<()> {
-        for (name, cost) in costs {
-            let mut bucket = match buckets.get(name) {
-                Some(bucket) => bucket.lock().await,
-                None => Err(LibError::other(format!(
-                    "RateLimiter: undefined bucket {}",
-                    name
-                )))?,
-            };
-
-            bucket.update_state();
-            bucket.amount += cost;
-
-            log::debug!(
-                "RateLimiter: bucket {} :: New amount {}; bucket limit: {}",
-                name,
-                bucket.amount,
-                bucket.limit
-            );
-        }
-
-        Ok(())
-    }
-}
-
-#[derive(Default)]
-pub(crate) enum RateLimiterBucketMode {
-    #[default]
-    Interval,
-    KrakenDecrease,
-}
-
-pub(crate) struct RateLimiterBucket {
-    mode: RateLimiterBucketMode,
-    time_instant: Instant,
-    delay: Instant,
-    interval: Duration,
-    limit: u32,
-    amount: u32,
-}
-
-impl Default for RateLimiterBucket {
-    fn default() -> Self {
-        Self {
-            mode: RateLimiterBucketMode::default(),
-            time_instant: Instant::now(),
-            delay: Instant::now(),
-            interval: Duration::default(),
-            limit: 0,
-            amount: 0,
+            rate_limiter: self.inner.clone(),
         }
     }
 }
 
-impl RateLimiterBucket {
-    pub fn mode(mut self, mode: RateLimiterBucketMode) -> Self {
-        self.mode = mode;
-        self
-    }
-
-    pub fn delay(mut self, delay: Duration) -> Self {
-        self.delay = Instant::now() + delay;
-        self
-    }
-
-    pub fn interval(mut self, interval: Duration) -> Self {
-        self.interval = interval;
-        self
-    }
-
-    pub fn limit(mut self, limit: u32) -> Self {
-        self.limit = limit;
-        self
-    }
-
-    fn update_state(&mut self) {
-        match self.mode {
-            RateLimiterBucketMode::Interval => {
-                let elapsed = Instant::now().duration_since(self.time_instant);
-                if elapsed > self.interval {
-                    self.time_instant = Instant::now();
-                    self.amount = 0;
-                }
-            }
-            RateLimiterBucketMode::KrakenDecrease => {
-                let elapsed = Instant::now().duration_since(self.time_instant);
-                let available =
-                    (elapsed.as_secs_f32() / self.interval.as_secs_f32()).floor() as u32;
-                if available > 0 {
-                    self.time_instant = Instant::now();
-                    self.amount = if self.amount > available {
-                        self.amount - available
-                    } else {
-                        0
-                    };
-                }
-            }
-        }
-    }
-
-    fn get_timeout(&self) -> Duration {
-        match self.mode {
-            RateLimiterBucketMode::Interval => {
-                let elapsed = Instant::now().duration_since(self.time_instant);
-                self.interval - elapsed
-            }
-            RateLimiterBucketMode::KrakenDecrease => self.interval,
-        }
-    }
-}
-
-struct Queue {
-    inner: VecDeque<TaskMessage>,
-}
-
-impl Queue {
-    fn new() -> Self {
-        Self {
-            inner: VecDeque::new(),
-        }
-    }
-
-    fn add(&mut self, msg: TaskMessage) -> &Self {
-        let priority = msg.priority;
-        self.inner.push_back(msg);
-
-        if priority > 0 {
-            self.inner
-                .make_contiguous()
-                .sort_by(|a, b| b.priority.cmp(&a.priority));
-        }
-
-        self
-    }
-
-    fn is_first(&self) -> bool {
-        self.inner.len() == 1
-    }
-}
-
-impl Iterator for Queue {
-    type Item = TaskMessage;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.inner.pop_front()
-    }
-}
-
 pub(crate) struct TaskBuilder<S>
 where
     S: KrakenSigner + Unpin + 'static,
@@ -343,7 +58,7 @@ where
     priority: u8,
     costs: TaskCosts,
     req_builder: RequestBuilder<S>,
-    tasks_tx: mpsc::UnboundedSender<TaskMessage>,
+    rate_limiter: ccx_api_lib::RateLimiter,
 }
 
 impl<S> TaskBuilder<S>
@@ -356,40 +71,38 @@ where
     }
 
     pub fn cost(mut self, key: impl Into<BucketName>, weight: u32) -> Self {
-        self.costs
-            .entry(key.into())
-            .and_modify(|e| *e = weight)
-            .or_insert(weight);
+        self.costs.insert(key.into(), weight);
         self
     }
 
     pub fn send<V>(self) -> Task<V>
     where
         V: serde::de::DeserializeOwned + Debug,
+    {
+        self.run(|req_builder| req_builder.send::<V>())
+    }
+
+    /// Sends the request answered with a file rather than a JSON document.
+    pub fn send_file(self) -> Task<Vec<u8>> {
+        self.run(|req_builder| req_builder.send_file())
+    }
+
+    fn run<V, F>(self, send: impl FnOnce(RequestBuilder<S>) -> F + 'static) -> Task<V>
+    where
+        F: Future<Output = KrakenApiResult<V>>,
     {
         let priority = self.priority;
         let costs = self.costs.clone();
         let req_builder = self.req_builder;
-        let mut tasks_tx = self.tasks_tx.clone();
+        let rate_limiter = self.rate_limiter;
 
         let fut = async move {
-            let (tx, rx) = oneshot::channel::<TaskMessageResult>();
-            tasks_tx
-                .send(TaskMessage {
-                    priority,
-                    costs,
-                    tx,
-                })
+            rate_limiter
+                .acquire(&costs, priority)
                 .await
-                .map_err(|_| LibError::other("RateLimiter: task channel was dropped"))?;
-            rx.await
-                .map_err(|_| LibError::other("RateLimiter: task channel was dropped"))?
-                .map_err(|e| {
-                    log::error!("RateLimiter: task err. {:?}", e);
-                    e
-                })?;
-
-            req_builder.send::<V>().await
+                .inspect_err(|e| log::error!("RateLimiter: task err. {:?}", e))?;
+
+            send(req_builder).await
         };
 
         Task {
@@ -399,18 +112,12 @@ where
     }
 }
 
-pub struct Task<V>
-where
-    V: serde::de::DeserializeOwned + Debug,
-{
+pub struct Task<V> {
     fut: Pin<Box<dyn Future<Output = KrakenApiResult<V>>>>,
     costs: TaskCosts,
 }
 
-impl<V> Task<V>
-where
-    V: serde::de::DeserializeOwned + Debug,
-{
+impl<V> Task<V> {
     pub fn metadata(&self) -> TaskMetadata {
         TaskMetadata {
             costs: self.costs.clone(),
@@ -418,10 +125,7 @@ where
     }
 }
 
-impl<V> Future for Task<V>
-where
-    V: serde::de::DeserializeOwned + Debug,
-{
+impl<V> Future for Task<V> {
     type Output = KrakenApiResult<V>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
@@ -429,15 +133,15 @@ where
     }
 }
 
-#[derive(Debug)]
-pub struct TaskMetadata {
-    pub costs: TaskCosts,
-}
-
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
     use std::sync::atomic::AtomicU8;
     use std::sync::atomic::Ordering;
+    use std::time::Duration;
+    use std::time::Instant;
+
+    use tokio::time::sleep;
 
     use super::*;
     use crate::ApiCred;
@@ -622,7 +326,7 @@ mod tests {
             .bucket(
                 "interval_3__limit_5",
                 RateLimiterBucket::default()
-                    .mode(RateLimiterBucketMode::KrakenDecrease)
+                    .mode(RateLimiterBucketMode::Decrease)
                     .interval(Duration::from_secs(3))
                     .limit(5),
             )
@@ -665,7 +369,7 @@ mod tests {
                     .bucket(
                         "interval_3__limit_5",
                         RateLimiterBucket::default()
-                            .mode(RateLimiterBucketMode::KrakenDecrease)
+                            .mode(RateLimiterBucketMode::Decrease)
                             .interval(Duration::from_secs(3))
                             .limit(5),
                     )
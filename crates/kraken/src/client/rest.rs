@@ -1,20 +1,27 @@
 use std::sync::Arc;
 use std::time::Instant;
 
+use ccx_api_lib::http::header::CONTENT_TYPE;
 use ccx_api_lib::reqwest::Client;
 use ccx_api_lib::reqwest::ClientRequest;
 use ccx_api_lib::reqwest::Method;
 use ccx_api_lib::reqwest::StatusCode;
-use ccx_api_lib::reqwest::make_client;
+use ccx_api_lib::reqwest::make_client_with;
 use serde::Deserialize;
 use serde::Serialize;
+// use crate::client::limits::UsedRateLimits;
+use url::Url;
 
 use super::*;
-// use crate::client::limits::UsedRateLimits;
+use crate::api::spot::PRIVATE_STREAM_BASE;
+use crate::api::spot::PRIVATE_STREAM_V2_BASE;
 use crate::client::WebsocketStream;
 use crate::error::*;
 // use crate::proto::TimeWindow;
 
+const CONTENT_TYPE_FORM: &str = "application/x-www-form-urlencoded";
+const CONTENT_TYPE_JSON: &str = "application/json";
+
 /// API client.
 pub struct RestClient<S>
 where
@@ -39,6 +46,7 @@ where
     S: KrakenSigner,
 {
     config: Config<S>,
+    client: Client,
 }
 
 pub struct RequestBuilder<S>
@@ -49,6 +57,7 @@ where
     request: ClientRequest,
     sign: Option<(Nonce,)>,
     body: String,
+    content_type: &'static str,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -75,12 +84,13 @@ where
     S: KrakenSigner,
 {
     pub fn new(config: Config<S>) -> Self {
-        let inner = Arc::new(ClientInner { config });
+        let client = make_client_with(config.transport.as_ref(), false, config.proxy.as_ref());
+        let inner = Arc::new(ClientInner { config, client });
         RestClient { inner }
     }
 
-    pub(super) fn client(&self) -> Client {
-        make_client(false, self.inner.config.proxy.as_ref())
+    pub(super) fn client(&self) -> &Client {
+        &self.inner.client
     }
 
     pub fn request(&self, method: Method, endpoint: &str) -> KrakenResult<RequestBuilder<S>> {
@@ -93,6 +103,7 @@ where
             request,
             sign: None,
             body: String::new(),
+            content_type: CONTENT_TYPE_FORM,
         })
     }
 
@@ -116,6 +127,22 @@ where
         let url = self.inner.config.stream_base.clone();
         WebsocketStream::connect(self.clone(), url).await
     }
+
+    pub async fn private_web_socket(&self) -> KrakenResult<WebsocketStream> {
+        let url = match &self.inner.config.private_stream_base {
+            Some(url) => url.clone(),
+            None => Url::parse(PRIVATE_STREAM_BASE)?,
+        };
+        WebsocketStream::connect(self.clone(), url).await
+    }
+
+    pub async fn private_web_socket_v2(&self) -> KrakenResult<WebsocketStream> {
+        let url = match &self.inner.config.private_stream_base {
+            Some(url) => url.join("v2")?,
+            None => Url::parse(PRIVATE_STREAM_V2_BASE)?,
+        };
+        WebsocketStream::connect(self.clone(), url).await
+    }
 }
 
 impl<S> RequestBuilder<S>
@@ -176,19 +203,53 @@ where
         Ok(self)
     }
 
+    /// Sends the payload as a JSON document, as the batch endpoints require.
+    pub fn request_json_body(mut self, payload: impl Serialize) -> KrakenResult<Self> {
+        self.body = match &self.sign {
+            Some((nonce,)) => serde_json::to_string(&nonce.wrap(payload)),
+            None => serde_json::to_string(&payload),
+        }?;
+        self.content_type = CONTENT_TYPE_JSON;
+        Ok(self)
+    }
+
     pub fn signed(mut self, nonce: Nonce) -> KrakenResult<Self> {
         self.sign = Some((nonce,));
         self.auth_header()
     }
 
-    pub async fn send<V>(mut self) -> KrakenApiResult<V>
+    pub async fn send<V>(self) -> KrakenApiResult<V>
     where
         V: serde::de::DeserializeOwned,
     {
+        let (_content_type, resp) = self.execute().await?;
+        let answer: KrakenApiAnswer<V> = match serde_json::from_slice(&resp) {
+            Ok(json) => json,
+            Err(err) => {
+                // log::debug!("Response: {}", String::from_utf8_lossy(&resp));
+                Err(err)?
+            }
+        };
+        answer.into_api_result()
+    }
+
+    /// Sends the request answered with a file rather than a JSON document.
+    ///
+    /// The errors still come as a JSON document.
+    pub async fn send_file(self) -> KrakenApiResult<Vec<u8>> {
+        let (content_type, resp) = self.execute().await?;
+        if !content_type.starts_with(CONTENT_TYPE_JSON) {
+            return KrakenApiError::ok(resp);
+        }
+        let answer: KrakenApiAnswer<serde_json::Value> = serde_json::from_slice(&resp)?;
+        answer.into_api_result()?;
+        Err(KrakenError::other("Expected a file, got a JSON document"))
+    }
+
+    /// Sends the request, returning the content type and the body of a successful response.
+    async fn execute(mut self) -> KrakenResult<(String, Vec<u8>)> {
         self = self.sign().await?;
-        self.request = self
-            .request
-            .header("content-type", "application/x-www-form-urlencoded");
+        self.request = self.request.header("content-type", self.content_type);
         log::debug!("{}  {}", self.request.method(), self.request.url());
         log::debug!("{}", self.body);
         let tm = Instant::now();
@@ -198,8 +259,14 @@ where
             self.request.body(self.body).send().await?
         };
         let status = res.status();
+        let content_type = res
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
         let d1 = tm.elapsed();
-        let resp = res.bytes().await?;
+        let resp = res.bytes().await?.to_vec();
         let d2 = tm.elapsed() - d1;
         log::debug!(
             "Request time elapsed:  {:0.1}ms + {:0.1}ms",
@@ -211,14 +278,7 @@ where
             // log::debug!("Response: {}", String::from_utf8_lossy(&resp));
             Err(err)?
         };
-        let answer: KrakenApiAnswer<V> = match serde_json::from_slice(&resp) {
-            Ok(json) => json,
-            Err(err) => {
-                // log::debug!("Response: {}", String::from_utf8_lossy(&resp));
-                Err(err)?
-            }
-        };
-        answer.into_api_result()
+        Ok((content_type, resp))
     }
 
     // pub async fn send_no_response(mut self) -> KrakenResult<()> {
@@ -16,7 +16,6 @@ use crate::client::KrakenSigner;
 use crate::client::RestClient;
 use crate::error::KrakenError;
 use crate::error::KrakenResult;
-use crate::ws_stream::UpstreamApiRequest;
 use crate::ws_stream::UpstreamWebsocketMessage;
 use crate::ws_stream::WsCommand;
 use crate::ws_stream::WsEvent;
@@ -89,6 +88,13 @@ impl WebsocketStreamTx {
             .unbounded_send(cmd)
             .map_err(|_| KrakenError::IoError(std::io::ErrorKind::ConnectionAborted.into()))
     }
+
+    pub async fn unsubscribe(&self, subscription: impl Into<WsSubscription>) -> KrakenResult<()> {
+        let cmd = WsCommand::Unsubscribe(subscription.into());
+        self.command_tx
+            .unbounded_send(cmd)
+            .map_err(|_| KrakenError::IoError(std::io::ErrorKind::ConnectionAborted.into()))
+    }
 }
 
 async fn run_websocket(
@@ -163,11 +169,7 @@ async fn run_websocket(
             cmd = command_rx.next() => {
                 match cmd {
                     Some(command) => {
-                        let msg = UpstreamApiRequest {
-                            reqid: id_seq.next(),
-                            payload: command,
-                        };
-                        let msg_str = serde_json::to_string(&msg).expect("json encode");
+                        let msg_str = command.to_request(id_seq.next());
                         log::debug!("Sending to server: `{}`", msg_str);
                         if let Err(e) = ws_sink.send(Message::Text(msg_str.into())).await {
                             log::error!("Failed to send message: {:?}", e);
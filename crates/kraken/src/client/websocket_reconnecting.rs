@@ -0,0 +1,199 @@
+use ccx_api_lib::Reconnect;
+use ccx_api_lib::ReconnectingEvent;
+use ccx_api_lib::ReconnectingSupervisor;
+use futures::StreamExt;
+use futures::channel::mpsc;
+use string_cache::DefaultAtom as Atom;
+
+use crate::client::KrakenSigner;
+use crate::client::RestClient;
+use crate::client::WebsocketStreamTx;
+use crate::error::KrakenError;
+use crate::error::KrakenResult;
+use crate::ws_stream::UpstreamWebsocketMessage;
+use crate::ws_stream::WsCommand;
+use crate::ws_stream::WsEvent;
+use crate::ws_stream::WsStream;
+use crate::ws_stream::WsSubscription;
+
+/// A message of the [`ReconnectingWebsocketStream`].
+pub type ReconnectingWsEvent = ReconnectingEvent<UpstreamWebsocketMessage<WsEvent>>;
+
+/// Multiplexed websocket stream surviving disconnections.
+///
+/// The active subscriptions are remembered and replayed on every new connection, which
+/// is established with an exponential backoff. The stream ends when the receiving half
+/// is dropped.
+pub struct ReconnectingWebsocketStream {
+    tx: ReconnectingWebsocketStreamTx,
+    rx: mpsc::UnboundedReceiver<ReconnectingWsEvent>,
+}
+
+#[derive(Clone)]
+pub struct ReconnectingWebsocketStreamTx {
+    tx: mpsc::UnboundedSender<WsCommand>,
+}
+
+impl ReconnectingWebsocketStream {
+    pub async fn connect<S>(api_client: RestClient<S>) -> KrakenResult<Self>
+    where
+        S: KrakenSigner + 'static,
+    {
+        let (sink, stream) = api_client.web_socket().await?.split();
+        let (cmd_tx, cmd_rx) = mpsc::unbounded();
+        let (tx, rx) = mpsc::unbounded();
+        let connector = Connector {
+            api_client,
+            subscriptions: Vec::new(),
+        };
+        let supervisor = ReconnectingSupervisor::new(connector, cmd_rx, tx);
+        tokio::spawn(supervisor.run(sink, stream));
+        let tx = ReconnectingWebsocketStreamTx { tx: cmd_tx };
+        Ok(ReconnectingWebsocketStream { tx, rx })
+    }
+
+    pub fn split(
+        self,
+    ) -> (
+        ReconnectingWebsocketStreamTx,
+        mpsc::UnboundedReceiver<ReconnectingWsEvent>,
+    ) {
+        (self.tx, self.rx)
+    }
+}
+
+impl std::ops::Deref for ReconnectingWebsocketStream {
+    type Target = ReconnectingWebsocketStreamTx;
+
+    fn deref(&self) -> &Self::Target {
+        &self.tx
+    }
+}
+
+impl futures::Stream for ReconnectingWebsocketStream {
+    type Item = ReconnectingWsEvent;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
+impl ReconnectingWebsocketStreamTx {
+    pub fn subscribe(&self, subscription: impl Into<WsSubscription>) -> KrakenResult<()> {
+        self.send(WsCommand::Subscribe(subscription.into()))
+    }
+
+    pub fn unsubscribe(&self, subscription: impl Into<WsSubscription>) -> KrakenResult<()> {
+        self.send(WsCommand::Unsubscribe(subscription.into()))
+    }
+
+    fn send(&self, cmd: WsCommand) -> KrakenResult<()> {
+        self.tx
+            .unbounded_send(cmd)
+            .map_err(|_| KrakenError::IoError(std::io::ErrorKind::ConnectionAborted.into()))
+    }
+}
+
+struct Connector<S>
+where
+    S: KrakenSigner,
+{
+    api_client: RestClient<S>,
+    /// Active subscriptions, one per pair.
+    subscriptions: Vec<(Atom, WsStream)>,
+}
+
+impl<S> Reconnect for Connector<S>
+where
+    S: KrakenSigner + 'static,
+{
+    type Sink = WebsocketStreamTx;
+    type Stream = mpsc::UnboundedReceiver<UpstreamWebsocketMessage<WsEvent>>;
+    type Command = WsCommand;
+    type Error = KrakenError;
+
+    async fn connect(&self) -> KrakenResult<(Self::Sink, Self::Stream)> {
+        Ok(self.api_client.web_socket().await?.split())
+    }
+
+    async fn resubscribe(&self, sink: &WebsocketStreamTx) -> KrakenResult<()> {
+        for subscription in group(&self.subscriptions) {
+            sink.subscribe(subscription).await?;
+        }
+        Ok(())
+    }
+
+    fn track(&mut self, cmd: &WsCommand) {
+        track(&mut self.subscriptions, cmd)
+    }
+
+    async fn send(&self, sink: &WebsocketStreamTx, cmd: WsCommand) -> KrakenResult<()> {
+        match cmd {
+            WsCommand::Subscribe(subscription) => sink.subscribe(subscription).await,
+            WsCommand::Unsubscribe(subscription) => sink.unsubscribe(subscription).await,
+        }
+    }
+
+    /// The connection is closed when its command sender is dropped.
+    fn close(_sink: WebsocketStreamTx) {}
+}
+
+/// Keeps the list of active subscriptions up to date with the command.
+fn track(subscriptions: &mut Vec<(Atom, WsStream)>, cmd: &WsCommand) {
+    match cmd {
+        WsCommand::Subscribe(subscription) => {
+            for pair in &subscription.pair {
+                let entry = (pair.clone(), subscription.stream.clone());
+                if !subscriptions.contains(&entry) {
+                    subscriptions.push(entry);
+                }
+            }
+        }
+        WsCommand::Unsubscribe(subscription) => {
+            subscriptions.retain(|(pair, stream)| {
+                *stream != subscription.stream || !subscription.pair.contains(pair)
+            });
+        }
+    }
+}
+
+/// Merges the pairs of the same stream into one subscription.
+fn group(subscriptions: &[(Atom, WsStream)]) -> Vec<WsSubscription> {
+    let mut grouped: Vec<WsSubscription> = Vec::new();
+    for (pair, stream) in subscriptions {
+        match grouped.iter_mut().find(|s| s.stream == *stream) {
+            Some(subscription) => subscription.pair.push(pair.clone()),
+            None => grouped.push((vec![pair.clone()], stream.clone()).into()),
+        }
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ws_stream::WsStreamTradeParams;
+
+    #[test]
+    fn test_track_subscriptions() {
+        let trade = || WsStream::Trade(WsStreamTradeParams {});
+        let mut subscriptions = Vec::new();
+
+        let cmd = WsCommand::Subscribe((vec!["XBT/USD".into(), "ETH/USD".into()], trade()).into());
+        track(&mut subscriptions, &cmd);
+        let cmd = WsCommand::Subscribe((vec!["XBT/USD".into()], trade()).into());
+        track(&mut subscriptions, &cmd);
+        assert_eq!(subscriptions.len(), 2);
+        assert_eq!(group(&subscriptions).len(), 1);
+
+        let cmd = WsCommand::Unsubscribe((vec!["XBT/USD".into()], trade()).into());
+        track(&mut subscriptions, &cmd);
+        assert_eq!(
+            group(&subscriptions),
+            vec![WsSubscription::from((vec!["ETH/USD".into()], trade()))]
+        );
+    }
+}
@@ -16,12 +16,16 @@ pub enum ApiError {
     InternalError,
     #[error("Permission denied")]
     PermissionDenied,
+    #[error("Order book checksum mismatch: expected {expected}, calculated {calculated}")]
+    ChecksumMismatch { expected: u32, calculated: u32 },
+    #[error("Invalid order book checksum: {0}")]
+    InvalidChecksum(String),
     // #[error("Unauthorized")]
     // Unauthorized,
     // #[error("Mandatory field(s) omitted: {0}")]
     // MandatoryFieldOmitted(Cow<'static, str>),
-    // #[error("Argument is out of bounds")]
-    // OutOfBounds,
+    #[error("Argument is out of bounds")]
+    OutOfBounds,
     #[error("Unrecognized")]
     Unrecognized(Cow<'static, str>),
 }
@@ -0,0 +1,203 @@
+use ccx_api_lib::Decimal;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+
+use super::expect_channel;
+use crate::Atom;
+
+pub const CHANNEL_BALANCES: &str = "balances";
+
+/// Balances of the account; a feed of the v2 protocol.
+///
+/// The first message carries the balances of every asset, the following ones
+/// the ledger entries changing them.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Balances {
+    #[serde(deserialize_with = "balances_channel")]
+    pub channel: String,
+    #[serde(flatten)]
+    pub data: BalancesData,
+    #[serde(default)]
+    pub sequence: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum BalancesData {
+    Snapshot(Vec<AssetBalance>),
+    Update(Vec<BalanceUpdate>),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct AssetBalance {
+    pub asset: Atom,
+    /// Type of the asset, e.g. `currency`.
+    pub asset_class: String,
+    /// Total balance of the asset over the wallets.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub balance: Decimal,
+    #[serde(default)]
+    pub wallets: Vec<WalletBalance>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct WalletBalance {
+    /// Type of the wallet: `spot` or `earn`.
+    #[serde(rename = "type")]
+    pub wallet_type: String,
+    /// Wallet id, e.g. `main` or `flex`.
+    pub id: String,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub balance: Decimal,
+}
+
+/// Ledger entry changing the balance of an asset.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct BalanceUpdate {
+    pub ledger_id: String,
+    /// Id of the trade, order or transfer behind the entry.
+    pub ref_id: String,
+    /// RFC 3339 time of the entry.
+    pub timestamp: String,
+    /// Type of the entry, e.g. `trade`, `deposit` or `withdrawal`.
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    /// Subtype of the entry, e.g. `spottostaking`.
+    #[serde(default)]
+    pub subtype: Option<String>,
+    pub asset: Atom,
+    pub asset_class: String,
+    pub category: String,
+    pub wallet_type: String,
+    pub wallet_id: String,
+    /// Change of the balance, with the fee included.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub amount: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub fee: Decimal,
+    /// Balance of the asset after the entry.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub balance: Decimal,
+}
+
+fn balances_channel<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    expect_channel(deserializer, CHANNEL_BALANCES)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::super::*;
+
+    #[test]
+    fn test_decode_balances_snapshot() {
+        let input = r#"{
+            "channel":"balances",
+            "type":"snapshot",
+            "data":[
+                {
+                    "asset":"BTC",
+                    "asset_class":"currency",
+                    "balance":0.12345678,
+                    "wallets":[
+                        {"type":"spot","id":"main","balance":0.1},
+                        {"type":"earn","id":"flex","balance":0.02345678}
+                    ]
+                },
+                {
+                    "asset":"USD",
+                    "asset_class":"currency",
+                    "balance":5000.5,
+                    "wallets":[{"type":"spot","id":"main","balance":5000.5}]
+                }
+            ],
+            "sequence":1
+        }"#;
+        let resp: UpstreamWebsocketMessage<WsEvent> = serde_json::from_str(input).unwrap();
+
+        match resp {
+            UpstreamWebsocketMessage::Event(WsEvent::Balances(e)) => {
+                assert_eq!(e.sequence, Some(1));
+                let BalancesData::Snapshot(balances) = e.data else {
+                    panic!("unexpected data: {:?}", e.data);
+                };
+                assert_eq!(balances.len(), 2);
+                assert_eq!(&*balances[0].asset, "BTC");
+                assert_eq!(
+                    balances[0].balance,
+                    Decimal::from_str_exact("0.12345678").unwrap()
+                );
+                assert_eq!(balances[0].wallets[1].wallet_type, "earn");
+                assert_eq!(
+                    balances[0].wallets[1].balance,
+                    Decimal::from_str_exact("0.02345678").unwrap()
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_decode_balances_update() {
+        let input = r#"{
+            "channel":"balances",
+            "type":"update",
+            "data":[
+                {
+                    "ledger_id":"DATKX6-PEHL1-HZKND8",
+                    "ref_id":"LKAKN2-N0N3J-AQSWA1",
+                    "timestamp":"2024-05-24T14:01:53.526524Z",
+                    "type":"trade",
+                    "asset":"BTC",
+                    "asset_class":"currency",
+                    "category":"trade",
+                    "wallet_type":"spot",
+                    "wallet_id":"main",
+                    "amount":-0.0125,
+                    "fee":0.0000125,
+                    "balance":0.1097
+                }
+            ],
+            "sequence":2
+        }"#;
+        let resp: UpstreamWebsocketMessage<WsEvent> = serde_json::from_str(input).unwrap();
+
+        match resp {
+            UpstreamWebsocketMessage::Event(WsEvent::Balances(e)) => {
+                let BalancesData::Update(updates) = e.data else {
+                    panic!("unexpected data: {:?}", e.data);
+                };
+                assert_eq!(updates[0].ledger_id, "DATKX6-PEHL1-HZKND8");
+                assert_eq!(updates[0].entry_type, "trade");
+                assert_eq!(updates[0].subtype, None);
+                assert_eq!(
+                    updates[0].amount,
+                    Decimal::from_str_exact("-0.0125").unwrap()
+                );
+                assert_eq!(
+                    updates[0].balance,
+                    Decimal::from_str_exact("0.1097").unwrap()
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_encode_balances_subscription() {
+        let stream = WsStream::Balances(WsStreamBalancesParams {
+            token: "token".to_string(),
+            snapshot: Some(true),
+        });
+        let cmd = WsCommand::Subscribe(stream.into());
+        assert_eq!(
+            cmd.to_request(3),
+            r#"{"method":"subscribe","params":{"channel":"balances","token":"token","snapshot":true},"req_id":3}"#
+        );
+    }
+}
@@ -3,6 +3,8 @@ use serde::Serialize;
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct Heartbeat {
+    /// The v2 protocol sends it as a channel.
+    #[serde(alias = "channel")]
     pub event: HeartbeatEvent,
 }
 
@@ -11,3 +13,19 @@ pub struct Heartbeat {
 pub enum HeartbeatEvent {
     Heartbeat,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn test_decode_heartbeat() {
+        for input in [r#"{"event":"heartbeat"}"#, r#"{"channel":"heartbeat"}"#] {
+            let resp: UpstreamWebsocketMessage<WsEvent> = serde_json::from_str(input).unwrap();
+            assert!(matches!(
+                resp,
+                UpstreamWebsocketMessage::Event(WsEvent::Heartbeat(_))
+            ));
+        }
+    }
+}
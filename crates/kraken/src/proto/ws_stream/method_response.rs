@@ -0,0 +1,71 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Response of the v2 protocol to a request, e.g. to a subscription.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct MethodResponse {
+    pub method: String,
+    #[serde(default)]
+    pub req_id: Option<u64>,
+    pub success: bool,
+    #[serde(default)]
+    pub result: Option<MethodResult>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct MethodResult {
+    pub channel: String,
+    #[serde(default)]
+    pub snapshot: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn test_decode_method_response() {
+        let input = r#"{
+            "method":"subscribe",
+            "req_id":1,
+            "result":{"channel":"balances","snapshot":true},
+            "success":true,
+            "time_in":"2024-05-24T14:01:50.123456Z",
+            "time_out":"2024-05-24T14:01:50.134567Z"
+        }"#;
+        let resp: UpstreamWebsocketMessage<WsEvent> = serde_json::from_str(input).unwrap();
+
+        match resp {
+            UpstreamWebsocketMessage::Event(WsEvent::MethodResponse(e)) => {
+                assert_eq!(e.method, "subscribe");
+                assert_eq!(e.req_id, Some(1));
+                assert!(e.success);
+                assert_eq!(e.result.unwrap().channel, "balances");
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_decode_method_response_error() {
+        let input = r#"{
+            "error":"EAPI:Invalid key",
+            "method":"subscribe",
+            "req_id":2,
+            "success":false,
+            "time_in":"2024-05-24T14:01:50.123456Z",
+            "time_out":"2024-05-24T14:01:50.134567Z"
+        }"#;
+        let resp: UpstreamWebsocketMessage<WsEvent> = serde_json::from_str(input).unwrap();
+
+        match resp {
+            UpstreamWebsocketMessage::Event(WsEvent::MethodResponse(e)) => {
+                assert!(!e.success);
+                assert_eq!(e.error.as_deref(), Some("EAPI:Invalid key"));
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -1,21 +1,37 @@
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
+use serde::de::Error;
 use string_cache::DefaultAtom as Atom;
 
+mod balances;
 mod heartbeat;
+mod method_response;
+mod ohlc;
+mod open_orders;
 mod orderbook_diff;
 mod orderbook_snap;
+mod own_trades;
 mod pong;
+mod spread;
 mod subscription_status;
 mod system_status;
+mod ticker;
 mod trade;
 
+pub use balances::*;
 pub use heartbeat::*;
+pub use method_response::*;
+pub use ohlc::*;
+pub use open_orders::*;
 pub use orderbook_diff::*;
 pub use orderbook_snap::*;
+pub use own_trades::*;
 pub use pong::*;
+pub use spread::*;
 pub use subscription_status::*;
 pub use system_status::*;
+pub use ticker::*;
 pub use trade::*;
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
@@ -23,11 +39,21 @@ pub use trade::*;
 pub enum WsEvent {
     // Public
     Trade(Trade),
+    Ticker(Ticker),
+    Ohlc(Ohlc),
+    Spread(Spread),
     OrderBookSnap(OrderBookSnap),
     OrderBookDiff(OrderBookDiff),
 
+    // Private
+    OwnTrades(OwnTrades),
+    OpenOrders(OpenOrders),
+    Balances(Balances),
+
     // General
     SystemStatus(SystemStatus),
+    SystemStatusUpdate(SystemStatusUpdate),
+    MethodResponse(MethodResponse),
     SubscriptionStatus(SubscriptionStatus),
     Pong(Pong),
     Heartbeat(Heartbeat),
@@ -40,8 +66,32 @@ pub enum WsCommand {
     Unsubscribe(WsSubscription),
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+impl WsCommand {
+    /// Encodes the command with the protocol version serving its feed.
+    pub fn to_request(&self, reqid: u64) -> String {
+        let (method, subscription) = match self {
+            WsCommand::Subscribe(subscription) => ("subscribe", subscription),
+            WsCommand::Unsubscribe(subscription) => ("unsubscribe", subscription),
+        };
+        let result = match &subscription.stream {
+            WsStream::Balances(params) => serde_json::to_string(&UpstreamApiRequestV2 {
+                method,
+                params: WsChannelV2::Balances(params),
+                req_id: reqid,
+            }),
+            _ => serde_json::to_string(&UpstreamApiRequest {
+                reqid,
+                payload: self,
+            }),
+        };
+        result.expect("json encode")
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct WsSubscription {
+    /// Empty for the private feeds.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub pair: Vec<Atom>,
     #[serde(rename = "subscription")]
     pub stream: WsStream,
@@ -63,20 +113,104 @@ where
     }
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+/// Subscription to a private feed, which isn't bound to pairs.
+impl From<WsStream> for WsSubscription {
+    fn from(stream: WsStream) -> Self {
+        WsSubscription::new(vec![], stream)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct WsStreamBookParams {
     pub depth: u16,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct WsStreamTradeParams {}
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct WsStreamTickerParams {}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct WsStreamOhlcParams {
+    /// Time interval in minutes: 1, 5, 15, 30, 60, 240, 1440, 10080 or 21600.
+    pub interval: u16,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct WsStreamSpreadParams {}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct WsStreamOwnTradesParams {
+    /// Token from `GetWebSocketsToken`.
+    pub token: String,
+    /// Whether to send the recent trades on subscription (default: true).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<bool>,
+    /// Whether to consolidate the taker trades by order (default: true).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consolidate_taker: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct WsStreamOpenOrdersParams {
+    /// Token from `GetWebSocketsToken`.
+    pub token: String,
+    /// Whether to send the rate-limit counter in the updates (default: false).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ratecounter: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct WsStreamBalancesParams {
+    /// Token from `GetWebSocketsToken`.
+    pub token: String,
+    /// Whether to send the balances on subscription (default: true).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(tag = "name", rename_all = "snake_case")]
 pub enum WsStream {
     Book(WsStreamBookParams),
     Trade(WsStreamTradeParams),
-    // Ticker(WsStreamTickerParams)
+    Ticker(WsStreamTickerParams),
+    Ohlc(WsStreamOhlcParams),
+    Spread(WsStreamSpreadParams),
+    #[serde(rename = "ownTrades")]
+    OwnTrades(WsStreamOwnTradesParams),
+    #[serde(rename = "openOrders")]
+    OpenOrders(WsStreamOpenOrdersParams),
+    /// Served by the v2 protocol only, see `SpotApi::ws_private_v2`.
+    Balances(WsStreamBalancesParams),
+}
+
+/// Feeds of the v2 protocol, which names them by `channel`.
+#[derive(Debug, Serialize)]
+#[serde(tag = "channel", rename_all = "snake_case")]
+enum WsChannelV2<'a> {
+    Balances(&'a WsStreamBalancesParams),
+}
+
+/// Sequence number of a private feed message.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct WsSequence {
+    pub sequence: u64,
+}
+
+/// The private feed messages look alike, so they are told apart by the channel name.
+fn expect_channel<'de, D>(deserializer: D, expected: &str) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let channel_name = String::deserialize(deserializer)?;
+    if channel_name != expected {
+        return Err(D::Error::custom(format!(
+            "expected `{expected}` channel, got `{channel_name}`"
+        )));
+    }
+    Ok(channel_name)
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -86,6 +220,14 @@ pub struct UpstreamApiRequest<T> {
     pub payload: T,
 }
 
+/// Request of the v2 protocol.
+#[derive(Debug, Serialize)]
+struct UpstreamApiRequestV2<T> {
+    method: &'static str,
+    params: T,
+    req_id: u64,
+}
+
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub enum UpstreamApiResult<T> {
     #[serde(rename = "result")]
@@ -0,0 +1,76 @@
+use ccx_api_lib::Decimal;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Atom;
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Ohlc {
+    pub channel_id: u64,
+    pub data: OhlcPayload,
+    pub channel_name: String,
+    pub pair: Atom,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct OhlcPayload {
+    /// Begin time of the interval.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub time: Decimal,
+    /// End time of the interval.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub etime: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub open: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub high: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub low: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub close: Decimal,
+    /// Volume weighted average price.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub vwap: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub volume: Decimal,
+    /// Number of trades.
+    pub count: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::super::*;
+
+    #[test]
+    fn test_decode_ohlc_event() {
+        let input = r#"[
+            42,
+            [
+                "1542057314.748456",
+                "1542057360.435743",
+                "3586.70000",
+                "3586.70000",
+                "3586.60000",
+                "3586.60000",
+                "3586.68894",
+                "0.03373000",
+                2
+            ],
+            "ohlc-5",
+            "XBT/USD"
+        ]"#;
+        let resp: UpstreamWebsocketMessage<WsEvent> = serde_json::from_str(input).unwrap();
+
+        match resp {
+            UpstreamWebsocketMessage::Event(WsEvent::Ohlc(e)) => {
+                assert_eq!(e.channel_id, 42);
+                assert_eq!(e.channel_name, "ohlc-5");
+                assert_eq!(e.data.close, Decimal::new(358660000, 5));
+                assert_eq!(e.data.count, 2);
+            }
+            _ => unreachable!(),
+        }
+    }
+}
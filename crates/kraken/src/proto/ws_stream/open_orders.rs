@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use ccx_api_lib::Decimal;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+
+use super::WsSequence;
+use super::expect_channel;
+use crate::Atom;
+
+pub const CHANNEL_OPEN_ORDERS: &str = "openOrders";
+
+/// Open orders of the account.
+///
+/// The first message carries every open order in full, the following ones
+/// only the fields changed, e.g. the status and the executed volume.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct OpenOrders {
+    /// Orders by order id.
+    pub orders: Vec<HashMap<String, OpenOrderUpdate>>,
+    #[serde(deserialize_with = "open_orders_channel")]
+    pub channel_name: String,
+    pub sequence: WsSequence,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct OpenOrderUpdate {
+    /// Referral order transaction ID that created this order.
+    #[serde(default)]
+    pub refid: Option<String>,
+    /// User reference id.
+    #[serde(default)]
+    pub userref: Option<u64>,
+    /// Status of order.
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Unix timestamp of when order was placed.
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub opentm: Option<Decimal>,
+    /// Unix timestamp of order start time (or 0 if not set).
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub starttm: Option<Decimal>,
+    /// Unix timestamp of order end time (or 0 if not set).
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub expiretm: Option<Decimal>,
+    /// Order description info.
+    #[serde(default)]
+    pub descr: Option<OpenOrderDescription>,
+    /// Volume of order (base currency).
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub vol: Option<Decimal>,
+    /// Volume executed (base currency).
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub vol_exec: Option<Decimal>,
+    /// Total cost (quote currency).
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub cost: Option<Decimal>,
+    /// Total fee (quote currency).
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub fee: Option<Decimal>,
+    /// Average price (quote currency).
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub avg_price: Option<Decimal>,
+    /// Stop price (quote currency).
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub stopprice: Option<Decimal>,
+    /// Triggered limit price (quote currency, when limit based order type triggered).
+    #[serde(default)]
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision_option::deserialize")]
+    pub limitprice: Option<Decimal>,
+    /// Comma delimited list of miscellaneous info.
+    #[serde(default)]
+    pub misc: Option<String>,
+    /// Comma delimited list of order flags.
+    #[serde(default)]
+    pub oflags: Option<String>,
+    /// Reason of the cancellation.
+    #[serde(default)]
+    pub cancel_reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct OpenOrderDescription {
+    /// Asset pair.
+    pub pair: Atom,
+    /// Type of order (buy/sell).
+    #[serde(rename = "type")]
+    pub side: String,
+    /// Order type.
+    pub ordertype: String,
+    /// Primary price.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub price: Decimal,
+    /// Secondary price.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub price2: Decimal,
+    /// Amount of leverage.
+    #[serde(default)]
+    pub leverage: Option<String>,
+    /// Order description.
+    pub order: String,
+    /// Conditional close order description (if conditional close set).
+    #[serde(default)]
+    pub close: Option<String>,
+}
+
+fn open_orders_channel<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    expect_channel(deserializer, CHANNEL_OPEN_ORDERS)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::super::*;
+
+    #[test]
+    fn test_decode_open_orders_event() {
+        let input = r#"[
+            [
+                {
+                    "OGTT3Y-C6I3P-XRI6HX": {
+                        "avg_price": "34.50000",
+                        "cost": "0.00000",
+                        "descr": {
+                            "close": "",
+                            "leverage": "0:1",
+                            "order": "sell 10.00345345 XBT/EUR @ limit 34.50000 with 0:1 leverage",
+                            "ordertype": "limit",
+                            "pair": "XBT/EUR",
+                            "price": "34.50000",
+                            "price2": "0.00000",
+                            "type": "sell"
+                        },
+                        "expiretm": "0.000000",
+                        "fee": "0.00000",
+                        "limitprice": "34.50000",
+                        "misc": "",
+                        "oflags": "fcib",
+                        "opentm": "0.000000",
+                        "refid": "OKIVMP-5GVZN-Z2D2UA",
+                        "starttm": "0.000000",
+                        "status": "open",
+                        "stopprice": "0.000000",
+                        "userref": 0,
+                        "vol": "10.00345345",
+                        "vol_exec": "0.00000000"
+                    }
+                }
+            ],
+            "openOrders",
+            {"sequence": 234}
+        ]"#;
+        let resp: UpstreamWebsocketMessage<WsEvent> = serde_json::from_str(input).unwrap();
+
+        match resp {
+            UpstreamWebsocketMessage::Event(WsEvent::OpenOrders(e)) => {
+                assert_eq!(e.sequence.sequence, 234);
+                let order = &e.orders[0]["OGTT3Y-C6I3P-XRI6HX"];
+                assert_eq!(order.status.as_deref(), Some("open"));
+                assert_eq!(order.userref, Some(0));
+                assert_eq!(order.vol, Some(Decimal::new(1000345345, 8)));
+                assert_eq!(order.descr.as_ref().unwrap().side, "sell");
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_decode_open_orders_update_event() {
+        let input = r#"[
+            [
+                {"OGTT3Y-C6I3P-XRI6HX": {"status": "closed"}},
+                {"OGTT3Y-C6I3P-XRI6HX": {"vol_exec": "10.00345345", "cost": "345.11914"}}
+            ],
+            "openOrders",
+            {"sequence": 235}
+        ]"#;
+        let resp: UpstreamWebsocketMessage<WsEvent> = serde_json::from_str(input).unwrap();
+
+        match resp {
+            UpstreamWebsocketMessage::Event(WsEvent::OpenOrders(e)) => {
+                assert_eq!(e.orders.len(), 2);
+                let order = &e.orders[0]["OGTT3Y-C6I3P-XRI6HX"];
+                assert_eq!(order.status.as_deref(), Some("closed"));
+                assert_eq!(order.vol, None);
+            }
+            _ => unreachable!(),
+        }
+    }
+}
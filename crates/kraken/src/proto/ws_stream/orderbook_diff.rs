@@ -1,7 +1,9 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::ApiError;
 use crate::Atom;
+use crate::KrakenResult;
 use crate::util::OrderLevel;
 
 #[derive(Debug, Clone, Serialize, PartialEq)]
@@ -13,6 +15,28 @@ pub struct OrderBookDiff {
     pub pair: Atom,
 }
 
+impl OrderBookDiff {
+    /// Book depth of the subscription, taken from the channel name, e.g. `book-10`.
+    pub fn depth(&self) -> Option<usize> {
+        self.channel_name.strip_prefix("book-")?.parse().ok()
+    }
+
+    /// CRC32 checksum of the top 10 levels of the book after this update.
+    ///
+    /// Comes along with the last side of the update.
+    pub fn checksum(&self) -> KrakenResult<Option<u32>> {
+        let bids = self.bids.as_ref().and_then(|b| b.checksum.as_ref());
+        let asks = self.asks.as_ref().and_then(|a| a.checksum.as_ref());
+        let Some(checksum) = bids.or(asks) else {
+            return Ok(None);
+        };
+        match checksum.parse() {
+            Ok(checksum) => Ok(Some(checksum)),
+            Err(_) => Err(ApiError::InvalidChecksum(checksum.clone()))?,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct OrderBookDiffAsk {
     #[serde(rename = "a", alias = "as")]
@@ -219,6 +243,8 @@ mod tests {
         match resp {
             UpstreamWebsocketMessage::Event(WsEvent::OrderBookDiff(e)) => {
                 assert_eq!(e.channel_id, 2240);
+                assert_eq!(e.checksum().unwrap(), Some(1208501689));
+                assert_eq!(e.depth(), Some(100));
 
                 if let Some(bids) = e.bids {
                     assert_eq!(bids.levels.len(), 2);
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use ccx_api_lib::Decimal;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+
+use super::WsSequence;
+use super::expect_channel;
+use crate::Atom;
+
+pub const CHANNEL_OWN_TRADES: &str = "ownTrades";
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct OwnTrades {
+    /// Trades by trade id.
+    pub trades: Vec<HashMap<String, OwnTrade>>,
+    #[serde(deserialize_with = "own_trades_channel")]
+    pub channel_name: String,
+    pub sequence: WsSequence,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct OwnTrade {
+    /// Order responsible for execution of trade.
+    pub ordertxid: String,
+    /// Position trade id.
+    pub postxid: String,
+    /// Asset pair.
+    pub pair: Atom,
+    /// Unix timestamp of trade.
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub time: Decimal,
+    /// Type of order (buy/sell).
+    #[serde(rename = "type")]
+    pub side: String,
+    /// Order type.
+    pub ordertype: String,
+    /// Average price order was executed at (quote currency).
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub price: Decimal,
+    /// Total cost of order (quote currency).
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub cost: Decimal,
+    /// Total fee (quote currency).
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub fee: Decimal,
+    /// Volume (base currency).
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub vol: Decimal,
+    /// Initial margin (quote currency).
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub margin: Decimal,
+    /// User reference id of the order.
+    #[serde(default)]
+    pub userref: Option<u64>,
+}
+
+fn own_trades_channel<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    expect_channel(deserializer, CHANNEL_OWN_TRADES)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::super::*;
+
+    #[test]
+    fn test_decode_own_trades_event() {
+        let input = r#"[
+            [
+                {
+                    "TDLH43-DVQXD-2KHVYY": {
+                        "cost": "1000000.00000",
+                        "fee": "1600.00000",
+                        "margin": "0.00000",
+                        "ordertxid": "TDLH43-DVQXD-2KHVYY",
+                        "ordertype": "limit",
+                        "pair": "XBT/EUR",
+                        "postxid": "OGTT3Y-C6I3P-XRI6HX",
+                        "price": "100000.00000",
+                        "time": "1560516023.070651",
+                        "type": "sell",
+                        "vol": "1000000000.00000000"
+                    }
+                }
+            ],
+            "ownTrades",
+            {"sequence": 2948}
+        ]"#;
+        let resp: UpstreamWebsocketMessage<WsEvent> = serde_json::from_str(input).unwrap();
+
+        match resp {
+            UpstreamWebsocketMessage::Event(WsEvent::OwnTrades(e)) => {
+                assert_eq!(e.sequence.sequence, 2948);
+                let trade = &e.trades[0]["TDLH43-DVQXD-2KHVYY"];
+                assert_eq!(trade.side, "sell");
+                assert_eq!(trade.fee, Decimal::new(160000000, 5));
+                assert_eq!(trade.userref, None);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_encode_own_trades_subscription() {
+        let stream = WsStream::OwnTrades(WsStreamOwnTradesParams {
+            token: "WW91ciBhdXRoZW50aWNhdGlvbiB0b2tlbiBnb2VzIGhlcmUu".to_string(),
+            snapshot: Some(false),
+            consolidate_taker: None,
+        });
+        let cmd = WsCommand::Subscribe(stream.into());
+
+        assert_eq!(
+            serde_json::to_string(&cmd).unwrap(),
+            r#"{"event":"subscribe","subscription":{"name":"ownTrades","#.to_string()
+                + r#""token":"WW91ciBhdXRoZW50aWNhdGlvbiB0b2tlbiBnb2VzIGhlcmUu","snapshot":false}}"#
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use ccx_api_lib::Decimal;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Atom;
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Spread {
+    pub channel_id: u64,
+    pub data: SpreadPayload,
+    pub channel_name: String,
+    pub pair: Atom,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct SpreadPayload {
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub bid: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub ask: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub timestamp: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub bid_volume: Decimal,
+    #[serde(deserialize_with = "rust_decimal::serde::arbitrary_precision::deserialize")]
+    pub ask_volume: Decimal,
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::super::*;
+
+    #[test]
+    fn test_decode_spread_event() {
+        let input = r#"[
+            0,
+            ["5698.40000","5700.00000","1542057299.545897","1.01234567","0.98765432"],
+            "spread",
+            "XBT/USD"
+        ]"#;
+        let resp: UpstreamWebsocketMessage<WsEvent> = serde_json::from_str(input).unwrap();
+
+        match resp {
+            UpstreamWebsocketMessage::Event(WsEvent::Spread(e)) => {
+                assert_eq!(e.channel_name, "spread");
+                assert_eq!(e.data.bid, Decimal::new(569840000, 5));
+                assert_eq!(e.data.ask_volume, Decimal::new(98765432, 8));
+            }
+            _ => unreachable!(),
+        }
+    }
+}
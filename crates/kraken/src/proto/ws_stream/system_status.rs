@@ -1,6 +1,11 @@
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
 
+use super::expect_channel;
+
+pub const CHANNEL_STATUS: &str = "status";
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct SystemStatus {
     #[serde(default, rename = "connectionID")]
@@ -10,6 +15,31 @@ pub struct SystemStatus {
     pub version: String,
 }
 
+/// Status of the v2 protocol, sent on connect and on the changes of the trading engine.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct SystemStatusUpdate {
+    #[serde(deserialize_with = "status_channel")]
+    pub channel: String,
+    pub data: Vec<SystemStatusData>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct SystemStatusData {
+    pub api_version: String,
+    #[serde(default)]
+    pub connection_id: Option<u64>,
+    /// Status of the trading engine, e.g. `online` or `maintenance`.
+    pub system: String,
+    pub version: String,
+}
+
+fn status_channel<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    expect_channel(deserializer, CHANNEL_STATUS)
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::*;
@@ -32,4 +62,27 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[test]
+    fn test_decode_system_status_update() {
+        let input = r#"{
+            "channel":"status",
+            "type":"update",
+            "data":[{
+                "api_version":"v2",
+                "connection_id":12393906104898154338,
+                "system":"online",
+                "version":"2.0.4"
+            }]
+        }"#;
+        let resp: UpstreamWebsocketMessage<WsEvent> = serde_json::from_str(input).unwrap();
+
+        match resp {
+            UpstreamWebsocketMessage::Event(WsEvent::SystemStatusUpdate(e)) => {
+                assert_eq!(e.data[0].connection_id, Some(12393906104898154338));
+                assert_eq!(e.data[0].system, "online");
+            }
+            _ => unreachable!(),
+        }
+    }
 }
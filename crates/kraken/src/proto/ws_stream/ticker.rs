@@ -0,0 +1,86 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Atom;
+use crate::api::spot::TickerLastTradeInfo;
+use crate::api::spot::TickerLotInfo;
+use crate::api::spot::TickerMetricInfo;
+use crate::api::spot::TickerTradesInfo;
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Ticker {
+    pub channel_id: u64,
+    pub data: TickerPayload,
+    pub channel_name: String,
+    pub pair: Atom,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct TickerPayload {
+    /// Ask.
+    #[serde(rename = "a")]
+    pub ask: TickerLotInfo,
+    /// Bid.
+    #[serde(rename = "b")]
+    pub bid: TickerLotInfo,
+    /// Last trade closed.
+    #[serde(rename = "c")]
+    pub close: TickerLastTradeInfo,
+    /// Volume.
+    #[serde(rename = "v")]
+    pub volume: TickerMetricInfo,
+    /// Volume weighted average price.
+    #[serde(rename = "p")]
+    pub vwap: TickerMetricInfo,
+    /// Number of trades.
+    #[serde(rename = "t")]
+    pub trades: TickerTradesInfo,
+    /// Low.
+    #[serde(rename = "l")]
+    pub low: TickerMetricInfo,
+    /// High.
+    #[serde(rename = "h")]
+    pub high: TickerMetricInfo,
+    /// Opening price.
+    #[serde(rename = "o")]
+    pub open: TickerMetricInfo,
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::super::*;
+
+    #[test]
+    fn test_decode_ticker_event() {
+        let input = r#"[
+            0,
+            {
+                "a":["5525.40000",1,"1.000"],
+                "b":["5525.10000",1,"1.000"],
+                "c":["5525.10000","0.00398963"],
+                "v":["2634.11501494","3591.17907851"],
+                "p":["5631.44067","5653.78939"],
+                "t":[11493,16267],
+                "l":["5505.00000","5505.00000"],
+                "h":["5783.00000","5783.00000"],
+                "o":["5760.70000","5763.40000"]
+            },
+            "ticker",
+            "XBT/USD"
+        ]"#;
+        let resp: UpstreamWebsocketMessage<WsEvent> = serde_json::from_str(input).unwrap();
+
+        match resp {
+            UpstreamWebsocketMessage::Event(WsEvent::Ticker(e)) => {
+                assert_eq!(e.channel_name, "ticker");
+                assert_eq!(e.data.ask.price, Decimal::new(552540000, 5));
+                assert_eq!(e.data.ask.whole_lot_volume, Decimal::ONE);
+                assert_eq!(e.data.trades.last_24_hours, 16267);
+                assert_eq!(e.data.open.today, Decimal::new(576070000, 5));
+            }
+            _ => unreachable!(),
+        }
+    }
+}
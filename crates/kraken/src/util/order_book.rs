@@ -5,6 +5,7 @@ use rust_decimal::prelude::*;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::ApiError;
 use crate::KrakenResult;
 use crate::ws_stream::OrderBookDiff;
 
@@ -18,6 +19,8 @@ pub enum OrderBookUpdater {
 pub struct OrderBookState {
     asks: BTreeMap<Decimal, Decimal>,
     bids: BTreeMap<Decimal, Decimal>,
+    /// Subscribed depth the book is truncated to, once known from the updates.
+    depth: Option<usize>,
 }
 
 #[derive(Debug)]
@@ -97,6 +100,7 @@ impl OrderBookState {
         OrderBookState {
             asks: snapshot.asks.iter().map(|v| (v.price, v.qty)).collect(),
             bids: snapshot.bids.iter().map(|v| (v.price, v.qty)).collect(),
+            depth: None,
         }
     }
 
@@ -208,12 +212,48 @@ impl OrderBookState {
         ask - bid
     }
 
-    pub fn verify_checksum(&self) -> KrakenResult<()> {
-        // TODO: need to implement checksum verification
+    /// CRC32 checksum of the top 10 levels as calculated by Kraken.
+    ///
+    /// Price and quantity of the asks from low to high and then the bids from high to low
+    /// are concatenated with the decimal point and the leading zeros removed.
+    pub fn checksum(&self) -> u32 {
+        let asks = self.asks.iter().take(CHECKSUM_LEVELS);
+        let bids = self.bids.iter().rev().take(CHECKSUM_LEVELS);
+        let mut hasher = crc32fast::Hasher::new();
+        for (price, qty) in asks.chain(bids) {
+            hasher.update(checksum_value(price).as_bytes());
+            hasher.update(checksum_value(qty).as_bytes());
+        }
+        hasher.finalize()
+    }
+
+    pub fn verify_checksum(&self, expected: u32) -> KrakenResult<()> {
+        let calculated = self.checksum();
+        if calculated != expected {
+            Err(ApiError::ChecksumMismatch {
+                expected,
+                calculated,
+            })?
+        }
         Ok(())
     }
 
+    /// Drops the levels beyond the subscribed depth, as Kraken doesn't send removals for them.
+    fn truncate(&mut self, depth: usize) {
+        while self.asks.len() > depth {
+            self.asks.pop_last();
+        }
+        while self.bids.len() > depth {
+            self.bids.pop_first();
+        }
+    }
+
     pub fn update(&mut self, diff: OrderBookDiff) -> KrakenResult<()> {
+        if let Some(depth) = diff.depth() {
+            self.depth = Some(depth);
+        }
+        let checksum = diff.checksum()?;
+
         if let Some(asks) = diff.asks {
             for ask_val in &asks.levels {
                 if ask_val.qty.is_zero() {
@@ -238,8 +278,128 @@ impl OrderBookState {
             }
         }
 
-        self.verify_checksum()?;
+        if let Some(depth) = self.depth {
+            self.truncate(depth);
+        }
+        if let Some(checksum) = checksum {
+            self.verify_checksum(checksum)?;
+        }
 
         Ok(())
     }
 }
+
+const CHECKSUM_LEVELS: usize = 10;
+
+fn checksum_value(value: &Decimal) -> String {
+    let value = value.to_string().replace('.', "");
+    value.trim_start_matches('0').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KrakenError;
+    use crate::ws_stream::OrderBookDiffAsk;
+    use crate::ws_stream::OrderBookDiffBid;
+
+    fn level(price: &str, qty: &str) -> OrderLevel {
+        OrderLevel {
+            price: price.parse().unwrap(),
+            qty: qty.parse().unwrap(),
+            timestamp: Decimal::zero(),
+            update_type: None,
+        }
+    }
+
+    fn snapshot() -> OrderBook {
+        OrderBook {
+            asks: vec![
+                level("0.05005", "0.00000500"),
+                level("0.05010", "0.00000500"),
+                level("0.05015", "0.00000500"),
+            ],
+            bids: vec![
+                level("0.05000", "0.00000500"),
+                level("0.04995", "0.00000500"),
+                level("0.04990", "0.00000500"),
+            ],
+        }
+    }
+
+    fn diff(asks: Vec<OrderLevel>, bids: Vec<OrderLevel>, checksum: u32) -> OrderBookDiff {
+        OrderBookDiff {
+            channel_id: 1,
+            asks: Some(OrderBookDiffAsk {
+                levels: asks,
+                checksum: None,
+            }),
+            bids: Some(OrderBookDiffBid {
+                levels: bids,
+                checksum: Some(checksum.to_string()),
+            }),
+            channel_name: "book-3".to_string(),
+            pair: "XBT/USD".into(),
+        }
+    }
+
+    #[test]
+    fn test_checksum() {
+        // The example book of the Kraken checksum guide.
+        let asks = [
+            "0.05005", "0.05010", "0.05015", "0.05020", "0.05025", "0.05030", "0.05035", "0.05040",
+            "0.05045", "0.05050",
+        ];
+        let bids = [
+            "0.05000", "0.04995", "0.04990", "0.04980", "0.04975", "0.04970", "0.04965", "0.04960",
+            "0.04955", "0.04950",
+        ];
+        let state = OrderBookState::new(OrderBook {
+            asks: asks.iter().map(|p| level(p, "0.00000500")).collect(),
+            bids: bids.iter().map(|p| level(p, "0.00000500")).collect(),
+        });
+        assert_eq!(state.checksum(), 974947235);
+    }
+
+    #[test]
+    fn test_update_truncates_to_depth() {
+        let mut state = OrderBookState::new(snapshot());
+        let asks = vec![level("0.05001", "0.00001000")];
+        let bids = vec![level("0.04998", "0.00000100")];
+        state.update(diff(asks, bids, 595647245)).unwrap();
+
+        let asks: Vec<_> = state.asks().keys().map(|p| p.to_string()).collect();
+        assert_eq!(asks, ["0.05001", "0.05005", "0.05010"]);
+        let bids: Vec<_> = state.bids().keys().map(|p| p.to_string()).collect();
+        assert_eq!(bids, ["0.04995", "0.04998", "0.05000"]);
+    }
+
+    #[test]
+    fn test_update_checksum_mismatch() {
+        let mut state = OrderBookState::new(snapshot());
+        let asks = vec![level("0.05001", "0.00001000")];
+        let err = state.update(diff(asks, vec![], 1)).unwrap_err();
+        match err {
+            KrakenError::ApiError(e) => assert!(matches!(
+                e.0[..],
+                [ApiError::ChecksumMismatch { expected: 1, .. }]
+            )),
+            e => panic!("unexpected error: {e:?}"),
+        }
+    }
+
+    #[test]
+    fn test_update_invalid_checksum() {
+        let mut state = OrderBookState::new(snapshot());
+        let mut diff = diff(vec![level("0.05001", "0.00001000")], vec![], 0);
+        diff.bids.as_mut().unwrap().checksum = Some("12ab".to_string());
+        let err = state.update(diff).unwrap_err();
+        match err {
+            KrakenError::ApiError(e) => assert!(matches!(
+                &e.0[..],
+                [ApiError::InvalidChecksum(c)] if c == "12ab"
+            )),
+            e => panic!("unexpected error: {e:?}"),
+        }
+    }
+}
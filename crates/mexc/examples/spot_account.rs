@@ -90,7 +90,7 @@ fn limit_order(
     price: Decimal,
     quantity: Quantity,
 ) -> MexcResult<Task<NewOrder>> {
-    let (quantity, quote_quantity) = quantity.to_arg();
+    let (quantity, quote_quantity) = quantity.into_arg();
     let task = mexc.create_order(
         symbol,
         side,
@@ -110,7 +110,7 @@ fn market_order(
     side: OrderSide,
     quantity: Quantity,
 ) -> MexcResult<Task<NewOrder>> {
-    let (quantity, quote_quantity) = quantity.to_arg();
+    let (quantity, quote_quantity) = quantity.into_arg();
     let task = mexc.create_order(
         symbol,
         side,
@@ -130,7 +130,7 @@ enum Quantity {
 }
 
 impl Quantity {
-    pub fn to_arg(self) -> (Option<Decimal>, Option<Decimal>) {
+    pub fn into_arg(self) -> (Option<Decimal>, Option<Decimal>) {
         match self {
             Quantity::Base(quantity) => (Some(quantity), None),
             Quantity::Quote(quantity) => (None, Some(quantity)),
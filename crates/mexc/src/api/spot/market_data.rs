@@ -1,3 +1,5 @@
+use ccx_api_lib::Instrument;
+use ccx_api_lib::InstrumentMap;
 use serde::de::Deserialize;
 use serde::de::Deserializer;
 use serde::de::{self};
@@ -46,6 +48,16 @@ pub struct ExchangeInformation {
     pub symbols: Vec<Symbol>,
 }
 
+impl ExchangeInformation {
+    /// Maps the exchange-neutral instruments to the symbols and back.
+    pub fn instrument_map(&self) -> InstrumentMap {
+        self.symbols
+            .iter()
+            .map(|symbol| (symbol.instrument(), symbol.symbol.clone()))
+            .collect()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
 #[serde(rename_all = "camelCase")]
 pub struct RateLimit {
@@ -103,6 +115,12 @@ pub struct Symbol {
     pub trade_side_type: TradeSideType,
 }
 
+impl Symbol {
+    pub fn instrument(&self) -> Instrument {
+        Instrument::new(&self.base_asset, &self.quote_asset)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum SymbolStatus {
     #[serde(rename = "1")]
@@ -7,6 +7,7 @@ use crate::client::Config;
 use crate::client::Proxy;
 use crate::client::RateLimiterBucket;
 use crate::client::RateLimiterBuilder;
+use crate::client::ReconnectingWebsocketStream;
 use crate::client::RestClient;
 use crate::client::WebsocketStream;
 use crate::error::*;
@@ -119,5 +120,14 @@ mod with_network {
         pub async fn ws(&self) -> MexcResult<WebsocketStream> {
             self.client.web_socket().await
         }
+
+        /// Creates multiplexed websocket stream which reconnects and resubscribes
+        /// after disconnections.
+        pub async fn ws_reconnecting(&self) -> MexcResult<ReconnectingWebsocketStream>
+        where
+            S: 'static,
+        {
+            ReconnectingWebsocketStream::connect(self.client.clone()).await
+        }
     }
 }
@@ -1,4 +1,7 @@
+use std::sync::Arc;
+
 pub use ccx_api_lib::ApiCred;
+pub use ccx_api_lib::HttpTransport;
 pub use ccx_api_lib::Proxy;
 use ccx_api_lib::env_var_with_prefix;
 use url::Url;
@@ -14,6 +17,8 @@ pub struct Config<S: MexcSigner> {
     pub api_base: Url,
     pub stream_base: Url,
     pub proxy: Option<Proxy>,
+    /// Sends the REST requests; over the network when `None`.
+    pub transport: Option<Arc<dyn HttpTransport>>,
 }
 
 impl<S> Config<S>
@@ -26,9 +31,15 @@ where
             api_base,
             stream_base,
             proxy,
+            transport: None,
         }
     }
 
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
     pub fn env_var(postfix: &str) -> Option<String> {
         env_var_with_prefix(CCX_MEXC_API_PREFIX, postfix)
     }
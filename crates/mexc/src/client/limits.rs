@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use actix_http::header::HeaderMap;
+use ccx_api_lib::http::HeaderMap;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -1,16 +1,12 @@
 use std::sync::Arc;
 use std::time::Instant;
 
-use actix_http::BoxedPayloadStream;
-use actix_http::Method;
-use actix_http::Payload;
-use actix_http::StatusCode;
-use actix_http::Uri;
-use actix_http::encoding::Decoder;
-use ccx_api_lib::awc::Client;
-use ccx_api_lib::awc::ClientRequest;
-use ccx_api_lib::awc::ClientResponse;
-use ccx_api_lib::awc::make_client;
+use ccx_api_lib::http::HeaderMap;
+use ccx_api_lib::reqwest::Client;
+use ccx_api_lib::reqwest::ClientRequest;
+use ccx_api_lib::reqwest::Method;
+use ccx_api_lib::reqwest::StatusCode;
+use ccx_api_lib::reqwest::make_client_with;
 use serde::Serialize;
 
 use super::*;
@@ -43,6 +39,7 @@ where
     S: MexcSigner,
 {
     config: Config<S>,
+    client: Client,
 }
 
 pub struct RequestBuilder<S>
@@ -59,20 +56,26 @@ where
     S: MexcSigner,
 {
     pub fn new(config: Config<S>) -> Self {
-        let inner = Arc::new(ClientInner { config });
+        let client = make_client_with(config.transport.as_ref(), false, config.proxy.as_ref());
+        let inner = Arc::new(ClientInner { config, client });
         RestClient { inner }
     }
 
-    fn client_(&self, h1_only: bool) -> Client {
-        make_client(h1_only, self.inner.config.proxy.as_ref())
+    /// REST client sending through the configured transport.
+    pub(super) fn client(&self) -> &Client {
+        &self.inner.client
     }
 
-    pub(super) fn client(&self) -> Client {
-        self.client_(false)
+    /// Client for the actix WebSocket connections.
+    #[cfg(all(feature = "with_actix", not(feature = "with_tokio")))]
+    pub(super) fn ws_client(&self) -> ccx_api_lib::awc::Client {
+        ccx_api_lib::awc::make_client(true, self.inner.config.proxy.as_ref())
     }
 
-    pub(super) fn client_h1(&self) -> Client {
-        self.client_(true)
+    /// Proxy for the tokio WebSocket connections.
+    #[cfg(feature = "with_tokio")]
+    pub(super) fn proxy(&self) -> Option<&Proxy> {
+        self.inner.config.proxy.as_ref()
     }
 
     pub fn request(&self, method: Method, endpoint: &str) -> MexcResult<RequestBuilder<S>> {
@@ -114,38 +117,27 @@ where
     S: MexcSigner,
 {
     pub fn uri(&self) -> String {
-        self.request.get_uri().to_string()
+        let query = self.request.query_params();
+        if query.is_empty() {
+            self.request.url().to_string()
+        } else {
+            format!("{}?{}", self.request.url(), query)
+        }
     }
 
     pub fn query_args<T: Serialize>(mut self, query: &T) -> MexcResult<Self> {
-        self.request = self.request.query(query)?;
+        // Surface the encoding errors the client would drop.
+        serde_urlencoded::to_string(query)?;
+        self.request = self.request.query(query);
         Ok(self)
     }
 
     pub fn query_arg<Name: AsRef<str>, T: Serialize + ?Sized>(
-        mut self,
+        self,
         name: Name,
         query: &T,
     ) -> MexcResult<Self> {
-        let mut parts = self.request.get_uri().clone().into_parts();
-
-        if let Some(path_and_query) = parts.path_and_query {
-            let mut buf = path_and_query.path().to_string();
-            buf.push('?');
-            match path_and_query.query().unwrap_or("") {
-                "" => {}
-                old_query => {
-                    buf.push_str(old_query);
-                    buf.push('&');
-                }
-            }
-            buf.push_str(&serde_urlencoded::to_string([(name.as_ref(), query)])?);
-            parts.path_and_query = buf.parse().ok();
-            let uri = Uri::from_parts(parts).map_err(|e| MexcError::other(format!("{:?}", e)))?;
-            self.request = self.request.uri(uri);
-        }
-
-        Ok(self)
+        self.query_args(&[(name.as_ref(), query)])
     }
 
     pub fn try_query_arg<Name: AsRef<str>, T: Serialize>(
@@ -162,7 +154,7 @@ where
     pub fn auth_header(mut self) -> MexcResult<Self> {
         self.request = self
             .request
-            .append_header(("X-MEXC-APIKEY", self.api_client.inner.config.api_key()));
+            .header("X-MEXC-APIKEY", self.api_client.inner.config.api_key());
         Ok(self)
     }
 
@@ -185,24 +177,22 @@ where
         } else {
             self
         };
-        log::debug!("{}  {}", self.request.get_method(), self.request.get_uri(),);
+        log::debug!("{}  {}", self.request.method(), self.uri());
 
         let tm = Instant::now();
-        let mut res = self.request.send().await?;
+        let res = self.request.send().await?;
         let d1 = tm.elapsed();
-        let resp = res.body().limit(16 * 1024 * 1024).await?;
+        let status = res.status();
+        let headers = res.headers().clone();
+        let resp = res.bytes().await?;
         let d2 = tm.elapsed() - d1;
         log::debug!(
             "Request time elapsed:  {:0.1}ms + {:0.1}ms",
             d1.as_secs_f64() * 1000.0,
             d2.as_secs_f64() * 1000.0,
         );
-        log::debug!(
-            "Response: {} «{}»",
-            res.status(),
-            String::from_utf8_lossy(&resp)
-        );
-        if let Err(err) = check_response(res) {
+        log::debug!("Response: {} «{}»", status, String::from_utf8_lossy(&resp));
+        if let Err(err) = check_response(status, &headers) {
             // log::debug!("Response: {}", String::from_utf8_lossy(&resp));
             Err(err)?
         };
@@ -250,7 +240,7 @@ where
     // }
 
     async fn sign(self) -> MexcResult<Self> {
-        let query = self.request.get_uri().query().unwrap_or("");
+        let query = self.request.query_params();
         let signature = self
             .api_client
             .inner
@@ -262,15 +252,13 @@ where
     }
 }
 
-type AwcClientResponse = ClientResponse<Decoder<Payload<BoxedPayloadStream>>>;
-
-fn check_response(res: AwcClientResponse) -> MexcResult<AwcClientResponse> {
-    let used_rate_limits = UsedRateLimits::from_headers(res.headers());
+fn check_response(status: StatusCode, headers: &HeaderMap) -> MexcResult<()> {
+    let used_rate_limits = UsedRateLimits::from_headers(headers);
 
     log::debug!("  used_rate_limits:  {:?}", used_rate_limits);
 
-    match res.status() {
-        StatusCode::OK => Ok(res),
+    match status {
+        StatusCode::OK => Ok(()),
         StatusCode::INTERNAL_SERVER_ERROR => Err(ApiServiceError::ServerError)?,
         StatusCode::SERVICE_UNAVAILABLE => Err(ApiServiceError::ServiceUnavailable)?,
         StatusCode::UNAUTHORIZED => Err(ApiError::Unauthorized)?,
@@ -279,7 +267,7 @@ fn check_response(res: AwcClientResponse) -> MexcResult<AwcClientResponse> {
         //
         //     Err(ErrorKind::MexcError(error_json.code, error_json.msg, response).into())
         // }
-        s => Err(MexcError::UnknownStatus(s))?,
+        s => Err(MexcError::UnknownStatusReqwest(s))?,
     }
 }
 
@@ -33,6 +33,11 @@ const CLIENT_TIMEOUT: Duration = Duration::from_secs(30);
 #[rtype(result = "()")]
 struct M<T>(pub T);
 
+/// Closes the connection.
+#[derive(actix::Message)]
+#[rtype(result = "()")]
+struct Close;
+
 pub struct WebsocketStream {
     tx: WebsocketStreamTx,
     rx: mpsc::UnboundedReceiver<UpstreamWebsocketMessage<WsEvent>>,
@@ -132,6 +137,15 @@ impl Handler<M<WsCommand>> for Websocket {
     }
 }
 
+impl Handler<Close> for Websocket {
+    type Result = ();
+
+    fn handle(&mut self, _msg: Close, ctx: &mut Self::Context) {
+        let _ = self.sink.write(ws::Message::Close(None));
+        ctx.stop();
+    }
+}
+
 impl Websocket {
     #[rustfmt::skip]
     pub(crate) fn new(
@@ -170,7 +184,7 @@ impl WebsocketStream {
 
         log::debug!("Connecting WS: {}", url.as_str());
 
-        let (response, connection) = api_client.client_h1().ws(url.as_str()).connect().await?;
+        let (response, connection) = api_client.ws_client().ws(url.as_str()).connect().await?;
         log::debug!("{:?}", response);
 
         let (sink, stream) = connection.split();
@@ -218,4 +232,17 @@ impl WebsocketStreamTx {
             .await
             .map_err(|_e| MexcError::IoError(io::ErrorKind::ConnectionAborted.into()))
     }
+
+    pub async fn unsubscribe_list(&self, subscriptions: Box<[WsSubscription]>) -> MexcResult<()> {
+        let cmd = WsCommand::Unsubscribe(subscriptions);
+        self.addr
+            .send(M(cmd))
+            .await
+            .map_err(|_e| MexcError::IoError(io::ErrorKind::ConnectionAborted.into()))
+    }
+
+    /// Closes the connection; the receiving half of the stream ends.
+    pub fn close(&self) {
+        self.addr.do_send(Close);
+    }
 }
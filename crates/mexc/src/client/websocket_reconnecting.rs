@@ -0,0 +1,192 @@
+use std::collections::HashSet;
+use std::io;
+use std::time::Duration;
+
+use ccx_api_lib::Reconnect;
+use ccx_api_lib::ReconnectingEvent;
+use ccx_api_lib::ReconnectingSupervisor;
+use futures::StreamExt;
+use futures::channel::mpsc;
+
+use crate::client::MexcSigner;
+use crate::client::RestClient;
+use crate::client::WebsocketStreamTx;
+use crate::error::MexcError;
+use crate::error::MexcResult;
+use crate::ws_stream::UpstreamWebsocketMessage;
+use crate::ws_stream::WsCommand;
+use crate::ws_stream::WsEvent;
+use crate::ws_stream::WsSubscription;
+
+/// MEXC drops every connection after 24 hours; reconnect a bit earlier on our own terms.
+const MAX_CONNECTION_AGE: Duration = Duration::from_secs(23 * 60 * 60 + 50 * 60);
+
+/// A message of the [`ReconnectingWebsocketStream`].
+pub type ReconnectingWsEvent = ReconnectingEvent<UpstreamWebsocketMessage<WsEvent>>;
+
+/// Multiplexed websocket stream surviving disconnections.
+///
+/// The active subscriptions are remembered and replayed on every new connection, which
+/// is established with an exponential backoff. The connection is also renewed ahead of
+/// the 24h forced disconnect without interrupting the stream. The stream ends when the
+/// receiving half is dropped.
+pub struct ReconnectingWebsocketStream {
+    tx: ReconnectingWebsocketStreamTx,
+    rx: mpsc::UnboundedReceiver<ReconnectingWsEvent>,
+}
+
+#[derive(Clone)]
+pub struct ReconnectingWebsocketStreamTx {
+    tx: mpsc::UnboundedSender<WsCommand>,
+}
+
+impl ReconnectingWebsocketStream {
+    pub async fn connect<S>(api_client: RestClient<S>) -> MexcResult<Self>
+    where
+        S: MexcSigner + 'static,
+    {
+        let (sink, stream) = api_client.web_socket().await?.split();
+        let (cmd_tx, cmd_rx) = mpsc::unbounded();
+        let (tx, rx) = mpsc::unbounded();
+        let connector = Connector {
+            api_client,
+            subscriptions: HashSet::new(),
+        };
+        let supervisor =
+            ReconnectingSupervisor::new(connector, cmd_rx, tx).max_age(MAX_CONNECTION_AGE);
+        crate::client::spawn(supervisor.run(sink, stream));
+        let tx = ReconnectingWebsocketStreamTx { tx: cmd_tx };
+        Ok(ReconnectingWebsocketStream { tx, rx })
+    }
+
+    pub fn split(
+        self,
+    ) -> (
+        ReconnectingWebsocketStreamTx,
+        mpsc::UnboundedReceiver<ReconnectingWsEvent>,
+    ) {
+        (self.tx, self.rx)
+    }
+}
+
+impl std::ops::Deref for ReconnectingWebsocketStream {
+    type Target = ReconnectingWebsocketStreamTx;
+
+    fn deref(&self) -> &Self::Target {
+        &self.tx
+    }
+}
+
+impl futures::Stream for ReconnectingWebsocketStream {
+    type Item = ReconnectingWsEvent;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
+impl ReconnectingWebsocketStreamTx {
+    pub fn subscribe_one(&self, subscription: impl Into<WsSubscription>) -> MexcResult<()> {
+        self.send(WsCommand::Subscribe1([subscription.into()]))
+    }
+
+    pub fn subscribe_list(&self, subscriptions: Box<[WsSubscription]>) -> MexcResult<()> {
+        self.send(WsCommand::Subscribe(subscriptions))
+    }
+
+    pub fn unsubscribe_list(&self, subscriptions: Box<[WsSubscription]>) -> MexcResult<()> {
+        self.send(WsCommand::Unsubscribe(subscriptions))
+    }
+
+    fn send(&self, cmd: WsCommand) -> MexcResult<()> {
+        self.tx
+            .unbounded_send(cmd)
+            .map_err(|_e| MexcError::IoError(io::ErrorKind::ConnectionAborted.into()))
+    }
+}
+
+struct Connector<S>
+where
+    S: MexcSigner,
+{
+    api_client: RestClient<S>,
+    subscriptions: HashSet<WsSubscription>,
+}
+
+impl<S> Reconnect for Connector<S>
+where
+    S: MexcSigner + 'static,
+{
+    type Sink = WebsocketStreamTx;
+    type Stream = mpsc::UnboundedReceiver<UpstreamWebsocketMessage<WsEvent>>;
+    type Command = WsCommand;
+    type Error = MexcError;
+
+    async fn connect(&self) -> MexcResult<(Self::Sink, Self::Stream)> {
+        Ok(self.api_client.web_socket().await?.split())
+    }
+
+    async fn resubscribe(&self, sink: &WebsocketStreamTx) -> MexcResult<()> {
+        if self.subscriptions.is_empty() {
+            return Ok(());
+        }
+        let subscriptions = self.subscriptions.iter().cloned().collect();
+        sink.subscribe_list(subscriptions).await
+    }
+
+    fn track(&mut self, cmd: &WsCommand) {
+        track(&mut self.subscriptions, cmd)
+    }
+
+    async fn send(&self, sink: &WebsocketStreamTx, cmd: WsCommand) -> MexcResult<()> {
+        match cmd {
+            WsCommand::Subscribe(subscriptions) => sink.subscribe_list(subscriptions).await,
+            WsCommand::Subscribe1([subscription]) => sink.subscribe_one(subscription).await,
+            WsCommand::Unsubscribe(subscriptions) => sink.unsubscribe_list(subscriptions).await,
+        }
+    }
+
+    fn close(sink: WebsocketStreamTx) {
+        sink.close();
+    }
+}
+
+/// Keeps the set of active subscriptions up to date with the command.
+fn track(subscriptions: &mut HashSet<WsSubscription>, cmd: &WsCommand) {
+    match cmd {
+        WsCommand::Subscribe(list) => subscriptions.extend(list.iter().cloned()),
+        WsCommand::Subscribe1(list) => subscriptions.extend(list.iter().cloned()),
+        WsCommand::Unsubscribe(list) => {
+            for subscription in list.iter() {
+                subscriptions.remove(subscription);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ws_stream::WsStream;
+
+    #[test]
+    fn test_track_subscriptions() {
+        let mut subscriptions = HashSet::new();
+        let btc = WsSubscription::new("BTCUSDT", WsStream::Trade);
+        let eth = WsSubscription::new("ETHUSDT", WsStream::Trade);
+
+        let cmd = WsCommand::Subscribe(vec![btc.clone(), eth.clone()].into());
+        track(&mut subscriptions, &cmd);
+        track(&mut subscriptions, &WsCommand::Subscribe1([btc.clone()]));
+        assert_eq!(subscriptions.len(), 2);
+
+        track(
+            &mut subscriptions,
+            &WsCommand::Unsubscribe(vec![btc].into()),
+        );
+        assert_eq!(subscriptions, HashSet::from([eth]));
+    }
+}
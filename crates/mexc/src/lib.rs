@@ -1,5 +1,11 @@
 #![allow(warnings)]
 
+#[cfg(all(
+    feature = "with_network",
+    not(any(feature = "with_actix", feature = "with_tokio"))
+))]
+compile_error!("`with_network` needs a WebSocket backend: enable `with_actix` or `with_tokio`");
+
 pub mod api;
 #[cfg(feature = "with_network")]
 pub mod client;
@@ -0,0 +1,373 @@
+use ccx_binance::BinanceSigner;
+use ccx_binance::SpotApi;
+use ccx_binance::TimeWindow;
+use ccx_binance::api::spot;
+use ccx_binance::api::spot::util::OrderFilters;
+
+use super::nearest_depth;
+use super::non_zero;
+use super::parse_order_id;
+use crate::Account;
+use crate::Balance;
+use crate::DepositAddress;
+use crate::Fill;
+use crate::Funding;
+use crate::InstrumentInfo;
+use crate::MarketData;
+use crate::NewOrder;
+use crate::Order;
+use crate::OrderBookSnapshot;
+use crate::OrderKind;
+use crate::OrderStatus;
+use crate::PriceLevel;
+use crate::Result;
+use crate::Side;
+use crate::Ticker;
+use crate::Trading;
+use crate::Transfer;
+use crate::TransferStatus;
+use crate::WithdrawRequest;
+
+const DEPTHS: &[(u32, spot::OrderBookLimit)] = &[
+    (5, spot::OrderBookLimit::N5),
+    (10, spot::OrderBookLimit::N10),
+    (20, spot::OrderBookLimit::N20),
+    (50, spot::OrderBookLimit::N50),
+    (100, spot::OrderBookLimit::N100),
+    (500, spot::OrderBookLimit::N500),
+    (1000, spot::OrderBookLimit::N1000),
+    (5000, spot::OrderBookLimit::N5000),
+];
+
+impl<S> MarketData for SpotApi<S>
+where
+    S: BinanceSigner + Unpin + 'static,
+{
+    async fn instruments(&self) -> Result<Vec<InstrumentInfo>> {
+        let info = self.exchange_info()?.await?;
+        Ok(info
+            .symbols
+            .into_iter()
+            .map(|symbol| {
+                let filters = OrderFilters::from_filters(&symbol.filters);
+                let min_notional = match (filters.notional, filters.min_notional) {
+                    (Some(f), _) => non_zero(f.min_notional),
+                    (None, Some(f)) => non_zero(f.min_notional),
+                    (None, None) => None,
+                };
+                InstrumentInfo {
+                    instrument: symbol.instrument(),
+                    symbol: symbol.symbol,
+                    base: symbol.base_asset,
+                    quote: symbol.quote_asset,
+                    price_tick: filters.price.and_then(|f| non_zero(f.tick_size)),
+                    qty_step: filters.lot_size.and_then(|f| non_zero(f.step_size)),
+                    min_qty: filters.lot_size.and_then(|f| non_zero(f.min_qty)),
+                    min_notional,
+                }
+            })
+            .collect())
+    }
+
+    async fn ticker(&self, symbol: &str) -> Result<Ticker> {
+        let ticker = self.ticker_book(symbol)?.await?;
+        Ok(Ticker {
+            symbol: ticker.symbol,
+            bid: non_zero(ticker.bid_price),
+            ask: non_zero(ticker.ask_price),
+            last: None,
+        })
+    }
+
+    async fn order_book(&self, symbol: &str, depth: Option<u32>) -> Result<OrderBookSnapshot> {
+        let limit = depth.map(|depth| nearest_depth(depth, DEPTHS));
+        let book = self.depth(symbol, limit)?.await?;
+        Ok(OrderBookSnapshot {
+            symbol: symbol.into(),
+            bids: book
+                .bids
+                .into_iter()
+                .map(|l| PriceLevel {
+                    price: l.price,
+                    qty: l.qty,
+                })
+                .collect(),
+            asks: book
+                .asks
+                .into_iter()
+                .map(|l| PriceLevel {
+                    price: l.price,
+                    qty: l.qty,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl<S> Trading for SpotApi<S>
+where
+    S: BinanceSigner + Unpin + 'static,
+{
+    async fn place_order(&self, order: &NewOrder) -> Result<String> {
+        let (r#type, time_in_force, price) = match order.kind {
+            OrderKind::Market => (spot::OrderType::Market, None, None),
+            OrderKind::Limit { price } => (
+                spot::OrderType::Limit,
+                Some(spot::TimeInForce::Gtc),
+                Some(price),
+            ),
+        };
+        let new_order = self.create_order(
+            order.symbol.as_ref(),
+            side(order.side),
+            r#type,
+            time_in_force,
+            Some(order.qty),
+            None,
+            None,
+            price,
+            None,
+            order.client_order_id.as_deref(),
+            Some(spot::OrderResponseType::Ack),
+            TimeWindow::now(),
+        )?;
+        let order_id = match new_order {
+            spot::NewOrder::Ack(task) => task.await?.order_id,
+            spot::NewOrder::Result(task) => task.await?.order_id,
+            spot::NewOrder::Full(task) => task.await?.order_id,
+        };
+        Ok(order_id.to_string())
+    }
+
+    async fn cancel_order(&self, symbol: &str, order_id: &str) -> Result<()> {
+        self.cancel_order(
+            symbol,
+            Some(parse_order_id(order_id)?),
+            None::<&str>,
+            None::<&str>,
+            TimeWindow::now(),
+        )?
+        .await?;
+        Ok(())
+    }
+
+    async fn get_order(&self, symbol: &str, order_id: &str) -> Result<Order> {
+        let order = self
+            .get_order(
+                symbol,
+                Some(parse_order_id(order_id)?),
+                None::<&str>,
+                TimeWindow::now(),
+            )?
+            .await?;
+        Ok(order_from(order))
+    }
+
+    async fn open_orders(&self, symbol: Option<&str>) -> Result<Vec<Order>> {
+        let orders = self.open_orders(symbol, TimeWindow::now())?.await?;
+        Ok(orders.into_iter().map(order_from).collect())
+    }
+}
+
+impl<S> Account for SpotApi<S>
+where
+    S: BinanceSigner + Unpin + 'static,
+{
+    async fn balances(&self) -> Result<Vec<Balance>> {
+        let account = self.account(TimeWindow::now())?.await?;
+        Ok(account
+            .balances
+            .into_iter()
+            .map(|b| Balance {
+                asset: b.asset,
+                free: b.free,
+                locked: b.locked,
+            })
+            .collect())
+    }
+
+    async fn fills(&self, symbol: &str) -> Result<Vec<Fill>> {
+        let trades = self
+            .my_trades(symbol, None, None, None, None, TimeWindow::now())?
+            .await?;
+        Ok(trades
+            .into_iter()
+            .map(|t| Fill {
+                id: t.id.to_string(),
+                order_id: t.order_id.to_string(),
+                symbol: t.symbol,
+                side: if t.is_buyer { Side::Buy } else { Side::Sell },
+                price: t.price,
+                qty: t.qty,
+                fee: t.commission,
+                fee_asset: Some(t.commission_asset),
+                is_maker: Some(t.is_maker),
+                time: t.time,
+            })
+            .collect())
+    }
+}
+
+impl<S> Funding for SpotApi<S>
+where
+    S: BinanceSigner + Unpin + 'static,
+{
+    async fn deposit_address(&self, asset: &str, network: Option<&str>) -> Result<DepositAddress> {
+        let address = self
+            .get_deposit_address(asset, network, TimeWindow::now())?
+            .await?;
+        Ok(DepositAddress {
+            asset: address.coin,
+            network: network.map(Into::into),
+            address: address.address,
+            tag: Some(address.tag).filter(|tag| !tag.is_empty()),
+        })
+    }
+
+    async fn deposits(&self, asset: Option<&str>) -> Result<Vec<Transfer>> {
+        let deposits = self
+            .deposit_history(None, asset, None, None, None, None, None, TimeWindow::now())?
+            .await?;
+        Ok(deposits
+            .into_iter()
+            .map(|d| Transfer {
+                id: d.tx_id.clone(),
+                asset: d.coin.into(),
+                amount: d.amount,
+                fee: None,
+                network: Some(d.network),
+                address: Some(d.address),
+                tx_id: Some(d.tx_id),
+                status: match d.status {
+                    spot::DepositStatus::Success => TransferStatus::Completed,
+                    spot::DepositStatus::Rejected | spot::DepositStatus::WrongDeposit => {
+                        TransferStatus::Failed
+                    }
+                    spot::DepositStatus::Pending
+                    | spot::DepositStatus::Processing
+                    | spot::DepositStatus::WaitingForConfirmation => TransferStatus::Pending,
+                },
+            })
+            .collect())
+    }
+
+    async fn withdrawals(&self, asset: Option<&str>) -> Result<Vec<Transfer>> {
+        let withdrawals = self
+            .withdraw_history(asset, None, None, None, None, None, TimeWindow::now())?
+            .await?;
+        Ok(withdrawals
+            .into_iter()
+            .map(|w| Transfer {
+                id: w.id,
+                asset: w.coin.into(),
+                amount: w.amount,
+                fee: Some(w.transaction_fee),
+                network: w.network,
+                address: Some(w.address),
+                tx_id: w.tx_id,
+                status: match w.status {
+                    spot::WithdrawStatus::Completed => TransferStatus::Completed,
+                    spot::WithdrawStatus::Cancelled
+                    | spot::WithdrawStatus::Rejected
+                    | spot::WithdrawStatus::Failure => TransferStatus::Failed,
+                    spot::WithdrawStatus::EmailSent
+                    | spot::WithdrawStatus::AwaitingApproval
+                    | spot::WithdrawStatus::Processing => TransferStatus::Pending,
+                },
+            })
+            .collect())
+    }
+
+    async fn withdraw(&self, request: &WithdrawRequest) -> Result<String> {
+        let withdrawal = self
+            .withdraw(
+                request.asset.as_ref(),
+                None::<&str>,
+                request.network.as_deref(),
+                &request.address,
+                request.tag.as_deref(),
+                request.amount,
+                None,
+                None::<&str>,
+                TimeWindow::now(),
+            )?
+            .await?;
+        Ok(withdrawal.id)
+    }
+}
+
+fn side(side: Side) -> spot::OrderSide {
+    match side {
+        Side::Buy => spot::OrderSide::Buy,
+        Side::Sell => spot::OrderSide::Sell,
+    }
+}
+
+fn order_from(order: spot::Order) -> Order {
+    let status = match order.status {
+        spot::OrderStatus::PendingNew | spot::OrderStatus::New => OrderStatus::Open,
+        spot::OrderStatus::PartiallyFilled => OrderStatus::PartiallyFilled,
+        // Still on the book until the cancel goes through, so it can be filled.
+        spot::OrderStatus::PendingCancel if order.executed_qty.is_zero() => OrderStatus::Open,
+        spot::OrderStatus::PendingCancel => OrderStatus::PartiallyFilled,
+        spot::OrderStatus::Filled => OrderStatus::Filled,
+        spot::OrderStatus::Canceled => OrderStatus::Canceled,
+        spot::OrderStatus::Rejected => OrderStatus::Rejected,
+        spot::OrderStatus::Expired | spot::OrderStatus::ExpiredInMatch => OrderStatus::Expired,
+    };
+    Order {
+        id: order.order_id.to_string(),
+        client_order_id: Some(order.client_order_id),
+        symbol: order.symbol,
+        side: match order.side {
+            spot::OrderSide::Buy => Side::Buy,
+            spot::OrderSide::Sell => Side::Sell,
+        },
+        price: non_zero(order.price),
+        qty: order.orig_qty,
+        filled_qty: order.executed_qty,
+        status,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(status: &str, executed_qty: &str) -> spot::Order {
+        serde_json::from_value(serde_json::json!({
+            "symbol": "LTCBTC",
+            "orderId": 1,
+            "orderListId": -1,
+            "clientOrderId": "myOrder1",
+            "price": "0.1",
+            "origQty": "1.0",
+            "executedQty": executed_qty,
+            "cummulativeQuoteQty": "0.0",
+            "status": status,
+            "timeInForce": "GTC",
+            "type": "LIMIT",
+            "side": "BUY",
+            "stopPrice": "0.0",
+            "icebergQty": "0.0",
+            "time": 1499827319559u64,
+            "updateTime": 1499827319559u64,
+            "isWorking": true,
+            "origQuoteOrderQty": "0.000000"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_pending_cancel_is_active() {
+        let pending = order_from(order("PENDING_CANCEL", "0.0"));
+        assert_eq!(pending.status, OrderStatus::Open);
+        assert!(pending.status.is_active());
+        let pending = order_from(order("PENDING_CANCEL", "0.5"));
+        assert_eq!(pending.status, OrderStatus::PartiallyFilled);
+        assert_eq!(
+            order_from(order("CANCELED", "0.0")).status,
+            OrderStatus::Canceled
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use ccx_bitstamp::api::Api;
+use ccx_bitstamp::api::order;
+use ccx_bitstamp::api::order_book::OrderBookLevel;
+use ccx_bitstamp::client::BitstampSigner;
+
+use super::step;
+use crate::Account;
+use crate::Balance;
+use crate::DepositAddress;
+use crate::Error;
+use crate::Fill;
+use crate::Funding;
+use crate::Instrument;
+use crate::InstrumentInfo;
+use crate::MarketData;
+use crate::NewOrder;
+use crate::Order;
+use crate::OrderBookSnapshot;
+use crate::OrderKind;
+use crate::OrderStatus;
+use crate::PriceLevel;
+use crate::Result;
+use crate::Side;
+use crate::Ticker;
+use crate::Trading;
+use crate::Transfer;
+use crate::WithdrawRequest;
+
+/// Symbols are the Bitstamp url symbols, e.g. `btcusd`.
+impl<S> MarketData for Api<S>
+where
+    S: BitstampSigner + Unpin + 'static,
+{
+    async fn instruments(&self) -> Result<Vec<InstrumentInfo>> {
+        let pairs = self.list_trading_pairs()?.await?;
+        pairs
+            .into_iter()
+            .map(|pair| {
+                let (base, quote) = pair.name.split_once('/').ok_or_else(|| {
+                    Error::unexpected_response(format!("malformed pair name: {}", pair.name))
+                })?;
+                let min_notional =
+                    (&*pair.minimum_order.currency == quote).then_some(pair.minimum_order.amount);
+                Ok(InstrumentInfo {
+                    instrument: Instrument::new(base, quote),
+                    symbol: pair.url_symbol.as_ref().into(),
+                    base: base.into(),
+                    quote: quote.into(),
+                    price_tick: Some(step(pair.counter_decimals.into())),
+                    qty_step: Some(step(pair.base_decimals.into())),
+                    min_qty: None,
+                    min_notional,
+                })
+            })
+            .collect()
+    }
+
+    /// Bitstamp has no book ticker endpoint, so the top of the book is taken from the order book.
+    async fn ticker(&self, symbol: &str) -> Result<Ticker> {
+        let book = self.get_order_book(symbol, None)?.await?;
+        Ok(Ticker {
+            symbol: symbol.into(),
+            bid: book.bids.first().map(|l| l.price),
+            ask: book.asks.first().map(|l| l.price),
+            last: None,
+        })
+    }
+
+    /// Bitstamp always returns the full book; it is truncated to `depth` here.
+    async fn order_book(&self, symbol: &str, depth: Option<u32>) -> Result<OrderBookSnapshot> {
+        let book = self.get_order_book(symbol, None)?.await?;
+        let depth = depth.map_or(usize::MAX, |depth| depth as usize);
+        let levels = |levels: Vec<OrderBookLevel>| {
+            levels
+                .into_iter()
+                .take(depth)
+                .map(|l| PriceLevel {
+                    price: l.price,
+                    qty: l.volume,
+                })
+                .collect()
+        };
+        Ok(OrderBookSnapshot {
+            symbol: symbol.into(),
+            bids: levels(book.bids),
+            asks: levels(book.asks),
+        })
+    }
+}
+
+impl<S> Trading for Api<S>
+where
+    S: BitstampSigner + Unpin + 'static,
+{
+    async fn place_order(&self, order: &NewOrder) -> Result<String> {
+        let OrderKind::Market = order.kind else {
+            return Err(Error::Unsupported("Bitstamp limit orders"));
+        };
+        let symbol = order.symbol.as_ref();
+        let client_order_id = order.client_order_id.as_deref();
+        let placed = match order.side {
+            Side::Buy => {
+                self.buy_market_order(symbol, order.qty, client_order_id)?
+                    .await?
+            }
+            Side::Sell => {
+                self.sell_market_order(symbol, order.qty, client_order_id)?
+                    .await?
+            }
+        };
+        Ok(placed.id.to_string())
+    }
+
+    async fn cancel_order(&self, _symbol: &str, _order_id: &str) -> Result<()> {
+        Err(Error::Unsupported("Bitstamp order cancellation"))
+    }
+
+    /// The Bitstamp order status reports neither the side nor the original amount, so only
+    /// open orders can be looked up.
+    async fn get_order(&self, symbol: &str, order_id: &str) -> Result<Order> {
+        let orders = self.list_open_orders(Some(symbol))?.await?;
+        orders
+            .into_iter()
+            .find(|o| o.id.to_string() == order_id)
+            .map(order_from)
+            .ok_or_else(|| Error::invalid_request(format!("no open order {order_id} on {symbol}")))
+    }
+
+    async fn open_orders(&self, symbol: Option<&str>) -> Result<Vec<Order>> {
+        let orders = self.list_open_orders(symbol)?.await?;
+        Ok(orders.into_iter().map(order_from).collect())
+    }
+}
+
+impl<S> Account for Api<S>
+where
+    S: BitstampSigner + Unpin + 'static,
+{
+    async fn balances(&self) -> Result<Vec<Balance>> {
+        let balances = self.list_account_balances()?.await?;
+        Ok(balances
+            .into_iter()
+            .map(|b| Balance {
+                asset: b.currency.as_str().into(),
+                free: b.available,
+                locked: b.reserved,
+            })
+            .collect())
+    }
+
+    async fn fills(&self, _symbol: &str) -> Result<Vec<Fill>> {
+        Err(Error::Unsupported("Bitstamp trade history"))
+    }
+}
+
+impl<S> Funding for Api<S>
+where
+    S: BitstampSigner + Unpin + 'static,
+{
+    async fn deposit_address(
+        &self,
+        _asset: &str,
+        _network: Option<&str>,
+    ) -> Result<DepositAddress> {
+        Err(Error::Unsupported("Bitstamp deposit addresses"))
+    }
+
+    async fn deposits(&self, _asset: Option<&str>) -> Result<Vec<Transfer>> {
+        Err(Error::Unsupported("Bitstamp deposit history"))
+    }
+
+    async fn withdrawals(&self, _asset: Option<&str>) -> Result<Vec<Transfer>> {
+        Err(Error::Unsupported("Bitstamp withdrawal history"))
+    }
+
+    async fn withdraw(&self, _request: &WithdrawRequest) -> Result<String> {
+        Err(Error::Unsupported("Bitstamp withdrawals"))
+    }
+}
+
+fn order_from(order: order::OpenOrder) -> Order {
+    let filled_qty = order.amount_at_create - order.amount;
+    Order {
+        id: order.id.to_string(),
+        client_order_id: order.client_order_id,
+        symbol: order.currency_pair.as_ref().into(),
+        side: match order.r#type {
+            order::OpenOrderType::Buy => Side::Buy,
+            order::OpenOrderType::Sell => Side::Sell,
+        },
+        price: Some(order.price),
+        qty: order.amount_at_create,
+        filled_qty,
+        status: if filled_qty.is_zero() {
+            OrderStatus::Open
+        } else {
+            OrderStatus::PartiallyFilled
+        },
+    }
+}
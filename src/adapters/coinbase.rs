@@ -0,0 +1,283 @@
+use ccx_coinbase::api::exchange;
+use ccx_coinbase::api::exchange::ExchangeApi;
+use ccx_coinbase::client::CoinbaseExchangeSigner;
+
+use super::non_zero;
+use super::parse_order_id;
+use crate::Account;
+use crate::Balance;
+use crate::DepositAddress;
+use crate::Error;
+use crate::Fill;
+use crate::Funding;
+use crate::InstrumentInfo;
+use crate::MarketData;
+use crate::NewOrder;
+use crate::Order;
+use crate::OrderBookSnapshot;
+use crate::OrderKind;
+use crate::OrderStatus;
+use crate::PriceLevel;
+use crate::Result;
+use crate::Side;
+use crate::Ticker;
+use crate::Trading;
+use crate::Transfer;
+use crate::TransferStatus;
+use crate::WithdrawRequest;
+
+/// The largest page of orders Coinbase returns at once.
+const ORDERS_LIMIT: u32 = 1000;
+
+impl<S> MarketData for ExchangeApi<S>
+where
+    S: CoinbaseExchangeSigner + Unpin + 'static,
+{
+    async fn instruments(&self) -> Result<Vec<InstrumentInfo>> {
+        let products = self.list_products(None)?.await?;
+        Ok(products
+            .into_iter()
+            .map(|product| InstrumentInfo {
+                instrument: product.instrument(),
+                symbol: product.id,
+                base: product.base_currency,
+                quote: product.quote_currency,
+                price_tick: non_zero(product.quote_increment),
+                qty_step: non_zero(product.base_increment),
+                min_qty: None,
+                min_notional: non_zero(product.min_market_funds),
+            })
+            .collect())
+    }
+
+    async fn ticker(&self, symbol: &str) -> Result<Ticker> {
+        let ticker = self.get_product_ticker(symbol.into())?.await?;
+        Ok(Ticker {
+            symbol: symbol.into(),
+            bid: non_zero(ticker.bid),
+            ask: non_zero(ticker.ask),
+            last: non_zero(ticker.price),
+        })
+    }
+
+    /// Coinbase returns either the top of the book (level 1) or the aggregated book (level 2);
+    /// the latter is truncated to `depth` here.
+    async fn order_book(&self, symbol: &str, depth: Option<u32>) -> Result<OrderBookSnapshot> {
+        let level = match depth {
+            Some(1) => 1,
+            _ => 2,
+        };
+        let book = self.get_product_book(symbol, Some(level))?.await?;
+        let depth = depth.map_or(usize::MAX, |depth| depth as usize);
+        let levels = |levels: Vec<exchange::ProductBookItem>| {
+            levels
+                .into_iter()
+                .take(depth)
+                .map(|l| PriceLevel {
+                    price: l.price,
+                    qty: l.size,
+                })
+                .collect()
+        };
+        Ok(OrderBookSnapshot {
+            symbol: symbol.into(),
+            bids: levels(book.bids),
+            asks: levels(book.asks),
+        })
+    }
+}
+
+impl<S> Trading for ExchangeApi<S>
+where
+    S: CoinbaseExchangeSigner + Unpin + 'static,
+{
+    /// Coinbase client order ids are UUIDs.
+    async fn place_order(&self, order: &NewOrder) -> Result<String> {
+        let client_order_id = order
+            .client_order_id
+            .as_deref()
+            .map(parse_order_id)
+            .transpose()?;
+        let (r#type, price, time_in_force) = match order.kind {
+            OrderKind::Market => (exchange::OrderType::Market, None, None),
+            OrderKind::Limit { price } => (
+                exchange::OrderType::Limit,
+                Some(price),
+                Some(exchange::OrderTimeInForce::Gtc),
+            ),
+        };
+        let created = self
+            .create_order(
+                None,
+                r#type,
+                match order.side {
+                    Side::Buy => exchange::OrderSide::Buy,
+                    Side::Sell => exchange::OrderSide::Sell,
+                },
+                order.symbol.as_ref(),
+                None,
+                None,
+                None,
+                price,
+                Some(order.qty),
+                None,
+                time_in_force,
+                None,
+                None,
+                client_order_id,
+            )?
+            .await?;
+        Ok(created.id.to_string())
+    }
+
+    async fn cancel_order(&self, symbol: &str, order_id: &str) -> Result<()> {
+        let order_id = exchange::EitherOrderId::Coinbase(parse_order_id(order_id)?);
+        self.cancel_order(order_id, None, Some(symbol))?.await?;
+        Ok(())
+    }
+
+    async fn get_order(&self, _symbol: &str, order_id: &str) -> Result<Order> {
+        let order_id = exchange::EitherOrderId::Coinbase(parse_order_id(order_id)?);
+        let order = self.get_order(order_id, None)?.await?;
+        Ok(order_from(order))
+    }
+
+    async fn open_orders(&self, symbol: Option<&str>) -> Result<Vec<Order>> {
+        let orders = self
+            .list_orders(
+                None,
+                symbol,
+                None,
+                None,
+                ORDERS_LIMIT,
+                &[
+                    exchange::OrderStatus::Open,
+                    exchange::OrderStatus::Pending,
+                    exchange::OrderStatus::Active,
+                ],
+                None,
+            )?
+            .await?;
+        Ok(orders.into_iter().map(order_from).collect())
+    }
+}
+
+impl<S> Account for ExchangeApi<S>
+where
+    S: CoinbaseExchangeSigner + Unpin + 'static,
+{
+    async fn balances(&self) -> Result<Vec<Balance>> {
+        let accounts = self.list_accounts()?.await?;
+        Ok(accounts
+            .into_iter()
+            .map(|a| Balance {
+                asset: a.currency,
+                free: a.available,
+                locked: a.hold,
+            })
+            .collect())
+    }
+
+    async fn fills(&self, _symbol: &str) -> Result<Vec<Fill>> {
+        Err(Error::Unsupported("Coinbase fills"))
+    }
+}
+
+impl<S> Funding for ExchangeApi<S>
+where
+    S: CoinbaseExchangeSigner + Unpin + 'static,
+{
+    async fn deposit_address(
+        &self,
+        _asset: &str,
+        _network: Option<&str>,
+    ) -> Result<DepositAddress> {
+        Err(Error::Unsupported("Coinbase deposit addresses"))
+    }
+
+    async fn deposits(&self, asset: Option<&str>) -> Result<Vec<Transfer>> {
+        transfers(self, exchange::TransferType::Deposit, asset).await
+    }
+
+    async fn withdrawals(&self, asset: Option<&str>) -> Result<Vec<Transfer>> {
+        transfers(self, exchange::TransferType::Withdraw, asset).await
+    }
+
+    async fn withdraw(&self, request: &WithdrawRequest) -> Result<String> {
+        let withdrawal = self
+            .withdraw_to_address(
+                None,
+                request.amount,
+                request.asset.as_ref(),
+                &request.address,
+                request.tag.as_deref(),
+                request.tag.is_none(),
+                request.network.as_deref(),
+                None,
+                None,
+                None,
+            )?
+            .await?;
+        Ok(withdrawal.id.to_string())
+    }
+}
+
+/// Coinbase lists transfers of all currencies, so the asset is filtered here.
+async fn transfers<S>(
+    api: &ExchangeApi<S>,
+    r#type: exchange::TransferType,
+    asset: Option<&str>,
+) -> Result<Vec<Transfer>>
+where
+    S: CoinbaseExchangeSigner + Unpin + 'static,
+{
+    let transfers = api
+        .list_transfers(None, Some(r#type), None, None, None)?
+        .await?;
+    Ok(transfers
+        .into_iter()
+        .filter(|t| asset.is_none_or(|asset| &*t.currency == asset))
+        .map(|t| Transfer {
+            id: t.id.to_string(),
+            asset: t.currency,
+            amount: t.amount,
+            fee: t.details.fee,
+            network: t.details.network,
+            address: t.details.sent_to_address,
+            tx_id: t.details.crypto_transaction_hash,
+            status: if t.completed_at.is_some() {
+                TransferStatus::Completed
+            } else if t.canceled_at.is_some() {
+                TransferStatus::Failed
+            } else {
+                TransferStatus::Pending
+            },
+        })
+        .collect())
+}
+
+fn order_from(order: exchange::Order) -> Order {
+    let qty = order.size.unwrap_or(order.filled_size);
+    let status = match order.status {
+        exchange::OrderStatus::Rejected => OrderStatus::Rejected,
+        exchange::OrderStatus::Done => match order.done_reason.as_deref() {
+            Some("canceled") => OrderStatus::Canceled,
+            _ => OrderStatus::Filled,
+        },
+        _ if order.filled_size.is_zero() => OrderStatus::Open,
+        _ => OrderStatus::PartiallyFilled,
+    };
+    Order {
+        id: order.id.to_string(),
+        client_order_id: order.client_order_id.map(|id| id.to_string()),
+        symbol: order.product_id,
+        side: match order.side {
+            exchange::OrderSide::Buy => Side::Buy,
+            exchange::OrderSide::Sell => Side::Sell,
+        },
+        price: order.price,
+        qty,
+        filled_qty: order.filled_size,
+        status,
+    }
+}
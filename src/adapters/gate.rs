@@ -0,0 +1,417 @@
+use ccx_gate::GateApi;
+use ccx_gate::api::spot;
+use ccx_gate::api::spot::order::create::CreateOrderRequest;
+use ccx_gate::api::spot::order::get::GetOrderParams;
+use ccx_gate::api::spot::order::list::ListOrdersRequest;
+use ccx_gate::api::wallet;
+use ccx_gate::api::withdrawal;
+use ccx_gate::client::GateSigner;
+
+use super::step;
+use crate::Account;
+use crate::Balance;
+use crate::DepositAddress;
+use crate::Error;
+use crate::Fill;
+use crate::Funding;
+use crate::InstrumentInfo;
+use crate::MarketData;
+use crate::NewOrder;
+use crate::Order;
+use crate::OrderBookSnapshot;
+use crate::OrderKind;
+use crate::OrderStatus;
+use crate::PriceLevel;
+use crate::Result;
+use crate::Side;
+use crate::Ticker;
+use crate::Trading;
+use crate::Transfer;
+use crate::TransferStatus;
+use crate::WithdrawRequest;
+
+impl<S> MarketData for GateApi<S> {
+    async fn instruments(&self) -> Result<Vec<InstrumentInfo>> {
+        let pairs = self
+            .spot()
+            .all_currency_pairs()
+            .await
+            .map_err(Error::exchange)?;
+        Ok(pairs
+            .into_iter()
+            .filter_map(|pair| {
+                Some(InstrumentInfo {
+                    instrument: pair.instrument()?,
+                    symbol: pair.id?.as_str().into(),
+                    base: pair.base?.as_str().into(),
+                    quote: pair.quote?.as_str().into(),
+                    price_tick: pair.precision.map(step),
+                    qty_step: pair.amount_precision.map(step),
+                    min_qty: pair.min_base_amount,
+                    min_notional: pair.min_quote_amount,
+                })
+            })
+            .collect())
+    }
+
+    async fn ticker(&self, symbol: &str) -> Result<Ticker> {
+        let request = spot::SpotTickersRequest {
+            currency_pair: Some(symbol.into()),
+            ..Default::default()
+        };
+        let tickers = self
+            .spot()
+            .tickers(&request)
+            .await
+            .map_err(Error::exchange)?;
+        let ticker = tickers.into_iter().next().ok_or_else(|| {
+            Error::unexpected_response(format!("no ticker for {symbol} in the response"))
+        })?;
+        Ok(Ticker {
+            symbol: ticker.currency_pair.as_str().into(),
+            bid: ticker.highest_bid,
+            ask: ticker.lowest_ask,
+            last: ticker.last,
+        })
+    }
+
+    async fn order_book(&self, symbol: &str, depth: Option<u32>) -> Result<OrderBookSnapshot> {
+        let request = spot::SpotOrderBookRequest {
+            limit: depth,
+            ..spot::SpotOrderBookRequest::currency_pair(symbol.into())
+        };
+        let book = self
+            .spot()
+            .order_book(&request)
+            .await
+            .map_err(Error::exchange)?;
+        Ok(OrderBookSnapshot {
+            symbol: symbol.into(),
+            bids: book
+                .bids
+                .into_iter()
+                .map(|l| PriceLevel {
+                    price: l.price,
+                    qty: l.amount,
+                })
+                .collect(),
+            asks: book
+                .asks
+                .into_iter()
+                .map(|l| PriceLevel {
+                    price: l.price,
+                    qty: l.amount,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl<S> Trading for GateApi<S>
+where
+    S: GateSigner,
+{
+    /// Gate requires client order ids to start with `t-`.
+    ///
+    /// Market buys are not supported, as Gate takes their amount in the quote currency.
+    async fn place_order(&self, order: &NewOrder) -> Result<String> {
+        let side = match order.side {
+            Side::Buy => spot::order::create::OrderSide::Buy,
+            Side::Sell => spot::order::create::OrderSide::Sell,
+        };
+        let mut request = CreateOrderRequest::new(order.symbol.as_ref(), side, order.qty);
+        match order.kind {
+            OrderKind::Market => {
+                if let Side::Buy = order.side {
+                    return Err(Error::Unsupported("Gate market buy by base quantity"));
+                }
+                request.order_type = Some(spot::order::create::OrderType::Market);
+                // Gate only accepts market orders which don't rest on the book.
+                request.time_in_force =
+                    Some(spot::order::create::TimeInForce::ImmediateOrCancelled);
+            }
+            OrderKind::Limit { price } => {
+                request.order_type = Some(spot::order::create::OrderType::Limit);
+                request.price = Some(price);
+            }
+        }
+        request.text = order.client_order_id.as_deref().map(Into::into);
+        let created = self
+            .spot()
+            .create_order(&request)
+            .await
+            .map_err(Error::exchange)?;
+        Ok(created.id.to_string())
+    }
+
+    async fn cancel_order(&self, _symbol: &str, _order_id: &str) -> Result<()> {
+        Err(Error::Unsupported("Gate order cancellation"))
+    }
+
+    async fn get_order(&self, symbol: &str, order_id: &str) -> Result<Order> {
+        let params = GetOrderParams {
+            currency_pair: Some(symbol.into()),
+            account: None,
+        };
+        let order = self
+            .spot()
+            .get_order(order_id, &params)
+            .await
+            .map_err(Error::exchange)?;
+        Ok(order_from(order))
+    }
+
+    async fn open_orders(&self, symbol: Option<&str>) -> Result<Vec<Order>> {
+        let symbol = symbol
+            .ok_or_else(|| Error::invalid_request("Gate lists open orders per symbol only"))?;
+        let request = ListOrdersRequest::new(symbol, spot::order::OrderStatus::Open);
+        let orders = self
+            .spot()
+            .list_orders(&request)
+            .await
+            .map_err(Error::exchange)?;
+        Ok(orders.into_iter().map(order_from).collect())
+    }
+}
+
+impl<S> Account for GateApi<S>
+where
+    S: GateSigner,
+{
+    async fn balances(&self) -> Result<Vec<Balance>> {
+        let accounts = self.spot().accounts(None).await.map_err(Error::exchange)?;
+        Ok(accounts
+            .into_iter()
+            .map(|a| Balance {
+                asset: a.currency.as_str().into(),
+                free: a.available,
+                locked: a.locked,
+            })
+            .collect())
+    }
+
+    async fn fills(&self, _symbol: &str) -> Result<Vec<Fill>> {
+        Err(Error::Unsupported("Gate trade history"))
+    }
+}
+
+impl<S> Funding for GateApi<S>
+where
+    S: GateSigner,
+{
+    /// The network is the Gate chain name; the default address of the currency when `None`.
+    async fn deposit_address(&self, asset: &str, network: Option<&str>) -> Result<DepositAddress> {
+        let response = self
+            .wallet()
+            .deposit_address(asset.into())
+            .await
+            .map_err(Error::exchange)?;
+        let Some(network) = network else {
+            return Ok(DepositAddress {
+                asset: asset.into(),
+                network: None,
+                address: response.address.to_string(),
+                tag: None,
+            });
+        };
+        let address = response
+            .multichain_addresses
+            .into_iter()
+            .find(|a| a.chain == network)
+            .ok_or_else(|| {
+                Error::unexpected_response(format!("no {asset} deposit address on {network}"))
+            })?;
+        Ok(DepositAddress {
+            asset: asset.into(),
+            network: Some(address.chain.to_string()),
+            address: address.address.to_string(),
+            tag: Some(address.payment_id.to_string()).filter(|tag| !tag.is_empty()),
+        })
+    }
+
+    async fn deposits(&self, asset: Option<&str>) -> Result<Vec<Transfer>> {
+        let deposits = self
+            .wallet()
+            .deposits(asset.map(Into::into), None, None, None, None)
+            .await
+            .map_err(Error::exchange)?;
+        deposits
+            .into_iter()
+            .map(|d| {
+                Ok(Transfer {
+                    amount: d.amount.parse().map_err(|_| {
+                        Error::unexpected_response(format!("malformed amount: {}", d.amount))
+                    })?,
+                    id: d.id.to_string(),
+                    asset: d.currency.as_str().into(),
+                    fee: None,
+                    network: Some(d.chain.to_string()),
+                    address: Some(d.address.to_string()),
+                    tx_id: Some(d.txid.to_string()).filter(|txid| !txid.is_empty()),
+                    status: match d.status {
+                        wallet::WalletDepositsStatus::Done
+                        | wallet::WalletDepositsStatus::DepCredited => TransferStatus::Completed,
+                        wallet::WalletDepositsStatus::Invalid
+                        | wallet::WalletDepositsStatus::Blocked => TransferStatus::Failed,
+                        wallet::WalletDepositsStatus::Review
+                        | wallet::WalletDepositsStatus::Pend
+                        | wallet::WalletDepositsStatus::Track => TransferStatus::Pending,
+                    },
+                })
+            })
+            .collect()
+    }
+
+    async fn withdrawals(&self, asset: Option<&str>) -> Result<Vec<Transfer>> {
+        let request = wallet::WalletWithdrawalHistoryRequest {
+            currency: asset.map(Into::into),
+            ..Default::default()
+        };
+        let withdrawals = self
+            .wallet()
+            .withdrawal_history(&request)
+            .await
+            .map_err(Error::exchange)?;
+        Ok(withdrawals
+            .into_iter()
+            .map(|w| Transfer {
+                id: w.id.to_string(),
+                asset: w.currency.as_str().into(),
+                amount: w.amount,
+                fee: Some(w.fee),
+                network: Some(w.chain.to_string()),
+                address: Some(w.address.to_string()),
+                tx_id: w.txid.map(|txid| txid.to_string()),
+                status: match w.status {
+                    withdrawal::WithdrawalWithdrawStatus::Done => TransferStatus::Completed,
+                    status if status.is_finished() => TransferStatus::Failed,
+                    withdrawal::WithdrawalWithdrawStatus::Invalid => TransferStatus::Failed,
+                    _ => TransferStatus::Pending,
+                },
+            })
+            .collect())
+    }
+
+    /// Gate withdraws on a chain, so the network is required.
+    async fn withdraw(&self, request: &WithdrawRequest) -> Result<String> {
+        let chain = request
+            .network
+            .as_deref()
+            .ok_or_else(|| Error::invalid_request("Gate withdrawals need a network"))?;
+        let withdrawal = self
+            .withdrawal()
+            .withdraw(&withdrawal::WithdrawalWithdrawRequest {
+                withdraw_order_id: None,
+                amount: request.amount,
+                currency: request.asset.as_ref().into(),
+                address: Some(request.address.as_str().into()),
+                memo: request.tag.as_deref().map(Into::into),
+                chain: chain.into(),
+            })
+            .await
+            .map_err(Error::exchange)?;
+        Ok(withdrawal.id.to_string())
+    }
+}
+
+fn order_from(order: spot::order::Order) -> Order {
+    let request = order.request;
+    let filled_qty = order
+        .filled_amount
+        .or_else(|| order.left.map(|left| request.amount - left))
+        .unwrap_or_default();
+    let status = match order.status {
+        spot::order::OrderStatus::Open if filled_qty.is_zero() => OrderStatus::Open,
+        spot::order::OrderStatus::Open => OrderStatus::PartiallyFilled,
+        spot::order::OrderStatus::Closed => OrderStatus::Filled,
+        spot::order::OrderStatus::Cancelled => OrderStatus::Canceled,
+    };
+    Order {
+        id: order.id.to_string(),
+        client_order_id: request.text.map(|text| text.to_string()),
+        symbol: request.currency_pair.as_str().into(),
+        side: match request.side {
+            spot::order::create::OrderSide::Buy => Side::Buy,
+            spot::order::create::OrderSide::Sell => Side::Sell,
+        },
+        price: request.price,
+        qty: request.amount,
+        filled_qty,
+        status,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ccx_api_lib::HttpResponse;
+    use ccx_api_lib::MockTransport;
+    use ccx_api_lib::http::Method;
+    use ccx_gate::client::config::GateApiConfig;
+    use ccx_gate::util::GateApiCred;
+    use serde_json::json;
+
+    use super::*;
+
+    fn api(mock: &MockTransport) -> GateApi<GateApiCred> {
+        let config = GateApiConfig::new(
+            GateApiCred::default(),
+            ccx_gate::api::API_BASE.parse().unwrap(),
+            ccx_gate::api::STREAM_BASE.parse().unwrap(),
+            None,
+        )
+        .with_transport(mock.clone());
+        GateApi::with_config(config)
+    }
+
+    fn market(side: Side) -> NewOrder {
+        NewOrder::market("BTC_USDT", side, "0.001".parse().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_place_market_order() {
+        let mock = MockTransport::new();
+        let created = json!({
+            "id": "1852454420",
+            "create_time_ms": 1710488334073u64,
+            "update_time_ms": 1710488334074u64,
+            "status": "closed",
+            "currency_pair": "BTC_USDT",
+            "type": "market",
+            "account": "spot",
+            "side": "sell",
+            "amount": "0.001",
+            "time_in_force": "ioc",
+            "left": "0",
+            "filled_amount": "0.001",
+            "filled_total": "63.4693",
+            "avg_deal_price": "63469.3",
+            "finish_as": "filled"
+        });
+        mock.respond(
+            Method::POST,
+            "/api/v4/spot/orders",
+            HttpResponse::json(&created),
+        );
+        let api = api(&mock);
+
+        let id = api.place_order(&market(Side::Sell)).await.unwrap();
+        assert_eq!(id, "1852454420");
+        let requests = mock.requests();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "currency_pair": "BTC_USDT",
+                "side": "sell",
+                "amount": "0.001",
+                "time_in_force": "ioc",
+                "type": "market"
+            })
+        );
+
+        // The amount of a market buy would be read in USDT.
+        let err = api.place_order(&market(Side::Buy)).await.unwrap_err();
+        assert!(matches!(err, Error::Unsupported(_)));
+        assert_eq!(mock.requests().len(), 1);
+    }
+}
@@ -0,0 +1,633 @@
+use std::collections::HashSet;
+
+use ccx_api_lib::Decimal;
+use ccx_kraken::KrakenSigner;
+use ccx_kraken::SpotApi;
+use ccx_kraken::api::spot;
+
+use super::nearest_depth;
+use super::step;
+use crate::Account;
+use crate::Balance;
+use crate::DepositAddress;
+use crate::Error;
+use crate::Fill;
+use crate::Funding;
+use crate::InstrumentInfo;
+use crate::InstrumentMap;
+use crate::MarketData;
+use crate::NewOrder;
+use crate::Order;
+use crate::OrderBookSnapshot;
+use crate::OrderKind;
+use crate::OrderStatus;
+use crate::PriceLevel;
+use crate::Result;
+use crate::Side;
+use crate::Ticker;
+use crate::Trading;
+use crate::Transfer;
+use crate::TransferStatus;
+use crate::WithdrawRequest;
+
+const DEPTHS: &[(u32, spot::OrderBookLimit)] = &[
+    (5, spot::OrderBookLimit::N5),
+    (10, spot::OrderBookLimit::N10),
+    (20, spot::OrderBookLimit::N20),
+    (50, spot::OrderBookLimit::N50),
+    (100, spot::OrderBookLimit::N100),
+    (500, spot::OrderBookLimit::N500),
+];
+
+impl<S> MarketData for SpotApi<S>
+where
+    S: KrakenSigner + Unpin + 'static,
+{
+    async fn instruments(&self) -> Result<Vec<InstrumentInfo>> {
+        let (pairs, _warnings) = self.asset_pairs(None, None)?.await?;
+        Ok(pairs
+            .pair
+            .into_values()
+            .map(|pair| InstrumentInfo {
+                instrument: pair.instrument(),
+                symbol: pair.altname,
+                base: pair.base,
+                quote: pair.quote,
+                price_tick: Some(step(pair.pair_decimals)),
+                qty_step: Some(step(pair.lot_decimals)),
+                min_qty: pair.ordermin,
+                min_notional: None,
+            })
+            .collect())
+    }
+
+    /// The instruments map to the alternate pair names, as in [`MarketData::instruments`];
+    /// pair ids and websocket names resolve to the instruments as well.
+    async fn instrument_map(&self) -> Result<InstrumentMap> {
+        let (pairs, _warnings) = self.asset_pairs(None, None)?.await?;
+        Ok(pairs.instrument_map())
+    }
+
+    async fn ticker(&self, symbol: &str) -> Result<Ticker> {
+        let (ticker, _warnings) = self.ticker(symbol)?.await?;
+        // The response is keyed by the pair name Kraken prefers, which may differ from the
+        // requested one.
+        let info = ticker.pair.into_values().next().ok_or_else(|| {
+            Error::unexpected_response(format!("no ticker for {symbol} in the response"))
+        })?;
+        Ok(Ticker {
+            symbol: symbol.into(),
+            bid: Some(info.bid.price),
+            ask: Some(info.ask.price),
+            last: Some(info.close.price),
+        })
+    }
+
+    async fn order_book(&self, symbol: &str, depth: Option<u32>) -> Result<OrderBookSnapshot> {
+        let limit = depth.map(|depth| nearest_depth(depth, DEPTHS));
+        let (depth, _warnings) = self.depth(symbol, limit)?.await?;
+        let book = depth.pair.into_values().next().ok_or_else(|| {
+            Error::unexpected_response(format!("no order book for {symbol} in the response"))
+        })?;
+        Ok(OrderBookSnapshot {
+            symbol: symbol.into(),
+            bids: book
+                .bids
+                .into_iter()
+                .map(|l| PriceLevel {
+                    price: l.price,
+                    qty: l.volume,
+                })
+                .collect(),
+            asks: book
+                .asks
+                .into_iter()
+                .map(|l| PriceLevel {
+                    price: l.price,
+                    qty: l.volume,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl<S> Trading for SpotApi<S>
+where
+    S: KrakenSigner + Unpin + 'static,
+{
+    /// Kraken only tags orders with a numeric `userref`, so the client order id must be a `u32`.
+    async fn place_order(&self, order: &NewOrder) -> Result<String> {
+        let userref = order
+            .client_order_id
+            .as_deref()
+            .map(|id| {
+                id.parse::<u32>().map_err(|_| {
+                    Error::invalid_request(format!("Kraken client order id must be a u32: {id}"))
+                })
+            })
+            .transpose()?;
+        let (ordertype, price) = match order.kind {
+            OrderKind::Market => (spot::OrderType::Market, None),
+            OrderKind::Limit { price } => (spot::OrderType::Limit, Some(price)),
+        };
+        let (added, _warnings) = self
+            .add_order(
+                self.next_nonce(),
+                userref,
+                ordertype,
+                match order.side {
+                    Side::Buy => spot::OrderSide::Buy,
+                    Side::Sell => spot::OrderSide::Sell,
+                },
+                Some(order.qty),
+                order.symbol.as_ref(),
+                price,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )?
+            .await?;
+        added
+            .txid
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::unexpected_response("no txid for the added order"))
+    }
+
+    async fn cancel_order(&self, _symbol: &str, order_id: &str) -> Result<()> {
+        self.cancel_order(self.next_nonce(), spot::TxIds(&[order_id]))?
+            .await?;
+        Ok(())
+    }
+
+    async fn get_order(&self, _symbol: &str, order_id: &str) -> Result<Order> {
+        let (info, _warnings) = self
+            .query_orders_info(self.next_nonce(), None, None, spot::TxIds(&[order_id]))?
+            .await?;
+        let (id, order) = info.orders.into_iter().next().ok_or_else(|| {
+            Error::unexpected_response(format!("no order {order_id} in the response"))
+        })?;
+        Ok(order_from(id, order))
+    }
+
+    async fn open_orders(&self, symbol: Option<&str>) -> Result<Vec<Order>> {
+        let (orders, _warnings) = self.get_open_orders(self.next_nonce(), None, None)?.await?;
+        Ok(orders
+            .open
+            .into_iter()
+            .filter(|(_, o)| symbol.is_none_or(|symbol| &*o.descr.pair == symbol))
+            .map(|(id, o)| order_from(id, o))
+            .collect())
+    }
+}
+
+impl<S> Account for SpotApi<S>
+where
+    S: KrakenSigner + Unpin + 'static,
+{
+    /// Kraken reports balances net of pending withdrawals only, so nothing is shown as locked.
+    async fn balances(&self) -> Result<Vec<Balance>> {
+        let (balance, _warnings) = self.get_account_balance(self.next_nonce())?.await?;
+        Ok(balance
+            .asset
+            .into_iter()
+            .map(|(asset, free)| Balance {
+                asset,
+                free,
+                locked: Decimal::ZERO,
+            })
+            .collect())
+    }
+
+    /// Pages through the whole trade history, which is not filtered by pair on Kraken's side.
+    async fn fills(&self, symbol: &str) -> Result<Vec<Fill>> {
+        // The trades are reported with the pair id, e.g. `XXBTZUSD` for `XBTUSD`.
+        let (pairs, _warnings) = self.asset_pairs(None, None)?.await?;
+        let (pair_id, pair) = pairs
+            .pair
+            .into_iter()
+            .find(|(id, pair)| &**id == symbol || &*pair.altname == symbol)
+            .ok_or_else(|| Error::invalid_request(format!("unknown Kraken pair: {symbol}")))?;
+
+        let mut fills = Vec::new();
+        // New trades shift the pages while paging, so a trade may come up twice.
+        let mut seen = HashSet::new();
+        let mut ofs = 0;
+        loop {
+            let (history, _warnings) = self
+                .trades_history(
+                    self.next_nonce(),
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(ofs),
+                    None,
+                    None,
+                )?
+                .await?;
+            if history.trades.is_empty() {
+                break;
+            }
+            ofs += history.trades.len() as u64;
+            for (id, t) in history.trades {
+                if t.pair != pair_id || !seen.insert(id.clone()) {
+                    continue;
+                }
+                fills.push(Fill {
+                    id,
+                    order_id: t.order_tx_id,
+                    symbol: pair.altname.clone(),
+                    side: match t.r#type {
+                        spot::TradeInfoType::Buy => Side::Buy,
+                        spot::TradeInfoType::Sell => Side::Sell,
+                    },
+                    price: t.price,
+                    qty: t.vol,
+                    fee: t.fee,
+                    fee_asset: None,
+                    is_maker: Some(t.maker),
+                    time: (t.time * 1000.0) as u64,
+                });
+            }
+            if ofs >= history.count {
+                break;
+            }
+        }
+        fills.sort_by_key(|fill| fill.time);
+        Ok(fills)
+    }
+}
+
+impl<S> Funding for SpotApi<S>
+where
+    S: KrakenSigner + Unpin + 'static,
+{
+    /// The network is the Kraken deposit method; the first available one when `None`.
+    async fn deposit_address(&self, asset: &str, network: Option<&str>) -> Result<DepositAddress> {
+        let method = match network {
+            Some(network) => network.into(),
+            None => {
+                let (methods, _warnings) =
+                    self.get_deposit_methods(self.next_nonce(), asset)?.await?;
+                methods
+                    .deposit_methods
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| {
+                        Error::unexpected_response(format!("no deposit methods for {asset}"))
+                    })?
+                    .method
+            }
+        };
+        let (addresses, _warnings) = self
+            .get_deposit_addresses(self.next_nonce(), asset, &method)?
+            .await?;
+        let address = addresses
+            .deposit_addresses
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                Error::unexpected_response(format!("no {asset} deposit address for {method}"))
+            })?;
+        Ok(DepositAddress {
+            asset: asset.into(),
+            network: Some(method.to_string()),
+            address: address.address,
+            tag: address.tag,
+        })
+    }
+
+    async fn deposits(&self, asset: Option<&str>) -> Result<Vec<Transfer>> {
+        let (deposits, _warnings) = self
+            .get_status_of_recent_deposits(self.next_nonce(), asset, None)?
+            .await?;
+        Ok(deposits
+            .deposits
+            .into_iter()
+            .map(|d| Transfer {
+                id: d.refid,
+                asset: d.asset.into(),
+                amount: d.amount,
+                fee: d.fee,
+                network: Some(d.method),
+                address: None,
+                tx_id: Some(d.txid).filter(|txid| !txid.is_empty()),
+                status: match d.status {
+                    spot::DepositStatus::Success => TransferStatus::Completed,
+                    spot::DepositStatus::Failure => TransferStatus::Failed,
+                    spot::DepositStatus::Initial
+                    | spot::DepositStatus::Pending
+                    | spot::DepositStatus::Settled => TransferStatus::Pending,
+                },
+            })
+            .collect())
+    }
+
+    async fn withdrawals(&self, asset: Option<&str>) -> Result<Vec<Transfer>> {
+        let asset = asset
+            .ok_or_else(|| Error::invalid_request("Kraken lists withdrawals per asset only"))?;
+        let (withdrawals, _warnings) = self
+            .get_status_of_recent_withdrawals(self.next_nonce(), asset, None)?
+            .await?;
+        withdrawals
+            .0
+            .into_iter()
+            .map(|w| {
+                Ok(Transfer {
+                    amount: parse_decimal(&w.amount)?,
+                    fee: Some(parse_decimal(&w.fee)?),
+                    id: w.refid,
+                    asset: w.asset.into(),
+                    network: Some(w.method),
+                    address: None,
+                    tx_id: w.txid,
+                    status: match w.status {
+                        spot::WithdrawStatus::Success => TransferStatus::Completed,
+                        spot::WithdrawStatus::Failure => TransferStatus::Failed,
+                        spot::WithdrawStatus::Initial
+                        | spot::WithdrawStatus::Pending
+                        | spot::WithdrawStatus::Settled => TransferStatus::Pending,
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// Kraken withdraws only to the addresses whitelisted on the account, so the address is the
+    /// name of the withdrawal key; the tag and the network come with the key.
+    async fn withdraw(&self, request: &WithdrawRequest) -> Result<String> {
+        let (withdrawal, _warnings) = self
+            .withdraw_funds(
+                self.next_nonce(),
+                request.asset.as_ref(),
+                &request.address,
+                &request.amount.to_string(),
+            )?
+            .await?;
+        Ok(withdrawal.refid)
+    }
+}
+
+fn parse_decimal(value: &str) -> Result<Decimal> {
+    value
+        .parse()
+        .map_err(|_| Error::unexpected_response(format!("malformed decimal: {value}")))
+}
+
+fn order_from(id: String, order: spot::OrderInfo) -> Order {
+    let status = match order.status {
+        spot::OrderStatus::Pending => OrderStatus::Open,
+        spot::OrderStatus::Open if order.vol_exec.is_zero() => OrderStatus::Open,
+        spot::OrderStatus::Open => OrderStatus::PartiallyFilled,
+        spot::OrderStatus::Closed => OrderStatus::Filled,
+        spot::OrderStatus::Canceled => OrderStatus::Canceled,
+        spot::OrderStatus::Expired => OrderStatus::Expired,
+    };
+    Order {
+        id,
+        client_order_id: order.userref.map(|userref| userref.to_string()),
+        symbol: order.descr.pair,
+        side: match order.descr.r#type {
+            spot::OrderSide::Buy => Side::Buy,
+            spot::OrderSide::Sell => Side::Sell,
+        },
+        price: match order.descr.ordertype {
+            spot::OrderType::Market => None,
+            _ => Some(order.descr.price),
+        },
+        qty: order.vol,
+        filled_qty: order.vol_exec,
+        status,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ccx_api_lib::ApiCred;
+    use ccx_api_lib::HttpResponse;
+    use ccx_api_lib::Instrument;
+    use ccx_api_lib::MockTransport;
+    use ccx_api_lib::http::Method;
+    use ccx_kraken::client::Config;
+    use ccx_kraken::client::RateLimiterTier;
+    use serde_json::json;
+    use url::Url;
+
+    use super::*;
+
+    fn api(mock: &MockTransport) -> SpotApi {
+        let config = Config::new(
+            ApiCred::default(),
+            Url::parse(spot::API_BASE).unwrap(),
+            Url::parse(spot::STREAM_BASE).unwrap(),
+            None,
+            RateLimiterTier::Starter,
+        )
+        .with_transport(mock.clone());
+        SpotApi::with_config(config)
+    }
+
+    fn respond(mock: &MockTransport, method: Method, path: &str, result: serde_json::Value) {
+        let answer = json!({"error": [], "result": result});
+        mock.respond(method, path, HttpResponse::json(&answer));
+    }
+
+    fn asset_pairs() -> serde_json::Value {
+        json!({
+            "XXBTZUSD": {
+                "altname": "XBTUSD",
+                "wsname": "XBT/USD",
+                "aclass_base": "currency",
+                "base": "XXBT",
+                "aclass_quote": "currency",
+                "quote": "ZUSD",
+                "lot": "unit",
+                "pair_decimals": 1,
+                "lot_decimals": 8,
+                "lot_multiplier": 1,
+                "leverage_buy": [],
+                "leverage_sell": [],
+                "fees": [],
+                "fee_volume_currency": "ZUSD",
+                "margin_call": 80,
+                "margin_stop": 40,
+                "ordermin": "0.0001"
+            }
+        })
+    }
+
+    fn trade(pair: &str, time: f64) -> serde_json::Value {
+        json!({
+            "ordertxid": "OQCLML-BW3P3-BUCMWZ",
+            "postxid": "TKH2SE-M7IF5-CFI7LT",
+            "pair": pair,
+            "time": time,
+            "type": "buy",
+            "ordertype": "limit",
+            "price": "30010.00000",
+            "cost": "600.20000",
+            "fee": "0.00000",
+            "vol": "0.02000000",
+            "margin": "0.00000",
+            "misc": "",
+            "trade_id": 93748276,
+            "maker": true
+        })
+    }
+
+    fn order(status: &str, vol_exec: &str) -> serde_json::Value {
+        json!({
+            "OVVDHC-CA22A-NESYIB": {
+                "refid": null,
+                "userref": 131280319,
+                "status": status,
+                "opentm": 1766554023.422301,
+                "starttm": 0,
+                "expiretm": 0,
+                "descr": {
+                    "pair": "XBTUSD",
+                    "type": "sell",
+                    "ordertype": "limit",
+                    "price": "73920.5",
+                    "price2": "0",
+                    "leverage": "none",
+                    "order": "sell 0.00138240 XBTUSD @ limit 73920.5",
+                    "close": ""
+                },
+                "vol": "0.00138240",
+                "vol_exec": vol_exec,
+                "cost": "0",
+                "fee": "0",
+                "price": "0",
+                "stopprice": "0.00000",
+                "limitprice": "0.00000",
+                "misc": "",
+                "oflags": "fciq",
+                "reason": null
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_instruments() {
+        let mock = MockTransport::new();
+        respond(
+            &mock,
+            Method::GET,
+            spot::API_0_PUBLIC_ASSET_PAIRS,
+            asset_pairs(),
+        );
+
+        respond(
+            &mock,
+            Method::GET,
+            spot::API_0_PUBLIC_ASSET_PAIRS,
+            asset_pairs(),
+        );
+
+        let api = api(&mock);
+        let instruments = api.instruments().await.unwrap();
+        assert_eq!(
+            instruments,
+            vec![InstrumentInfo {
+                instrument: Instrument::new("BTC", "USD"),
+                symbol: "XBTUSD".into(),
+                base: "XXBT".into(),
+                quote: "ZUSD".into(),
+                price_tick: Some(Decimal::new(1, 1)),
+                qty_step: Some(Decimal::new(1, 8)),
+                min_qty: Some(Decimal::new(1, 4)),
+                min_notional: None,
+            }]
+        );
+
+        // The map uses the same symbols as the instruments.
+        let map = api.instrument_map().await.unwrap();
+        let btc_usd = Instrument::new("BTC", "USD");
+        assert_eq!(map.symbol(&btc_usd).map(|s| &**s), Some("XBTUSD"));
+        assert_eq!(map.instrument("XXBTZUSD"), Some(&btc_usd));
+    }
+
+    #[tokio::test]
+    async fn test_order_status() {
+        let mock = MockTransport::new();
+        let cases = [
+            ("pending", "0", OrderStatus::Open),
+            ("open", "0", OrderStatus::Open),
+            ("open", "0.001", OrderStatus::PartiallyFilled),
+            ("closed", "0.00138240", OrderStatus::Filled),
+            ("canceled", "0.001", OrderStatus::Canceled),
+            ("expired", "0", OrderStatus::Expired),
+        ];
+        for (status, vol_exec, _) in cases {
+            respond(
+                &mock,
+                Method::POST,
+                spot::API_0_PRIVATE_QUERY_ORDERS,
+                order(status, vol_exec),
+            );
+        }
+
+        let api = api(&mock);
+        for (status, _, expected) in cases {
+            let order = api
+                .get_order("XBTUSD", "OVVDHC-CA22A-NESYIB")
+                .await
+                .unwrap();
+            assert_eq!(order.status, expected, "{status}");
+            assert_eq!(order.client_order_id.as_deref(), Some("131280319"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fills() {
+        let mock = MockTransport::new();
+        respond(
+            &mock,
+            Method::GET,
+            spot::API_0_PUBLIC_ASSET_PAIRS,
+            asset_pairs(),
+        );
+        respond(
+            &mock,
+            Method::POST,
+            spot::API_0_PRIVATE_TRADES_HISTORY,
+            json!({
+                "trades": {
+                    "THVRQM-33VKH-UCI7BS": trade("XXBTZUSD", 1688667796.8802),
+                    "TCWJEG-FL4SZ-3FKGH6": trade("XETHZEUR", 1688667790.0),
+                },
+                "count": 3
+            }),
+        );
+        respond(
+            &mock,
+            Method::POST,
+            spot::API_0_PRIVATE_TRADES_HISTORY,
+            json!({
+                "trades": {"TZX2WP-XSEOP-FP7WYR": trade("XXBTZUSD", 1688660000.5)},
+                "count": 3
+            }),
+        );
+
+        let fills = api(&mock).fills("XBTUSD").await.unwrap();
+        let ids: Vec<_> = fills.iter().map(|fill| fill.id.as_str()).collect();
+        assert_eq!(ids, ["TZX2WP-XSEOP-FP7WYR", "THVRQM-33VKH-UCI7BS"]);
+        assert!(fills.iter().all(|fill| &*fill.symbol == "XBTUSD"));
+        assert_eq!(fills[0].time, 1688660000500);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3);
+        let body = String::from_utf8_lossy(&requests[2].body);
+        assert!(body.contains("ofs=2"), "{body}");
+        assert_eq!(mock.pending(), 0);
+    }
+}
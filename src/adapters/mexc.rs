@@ -0,0 +1,323 @@
+use ccx_mexc::MexcSigner;
+use ccx_mexc::SpotApi;
+use ccx_mexc::TimeWindow;
+use ccx_mexc::api::spot;
+
+use super::non_zero;
+use super::step;
+use crate::Account;
+use crate::Balance;
+use crate::DepositAddress;
+use crate::Error;
+use crate::Fill;
+use crate::Funding;
+use crate::InstrumentInfo;
+use crate::MarketData;
+use crate::NewOrder;
+use crate::Order;
+use crate::OrderBookSnapshot;
+use crate::OrderKind;
+use crate::OrderStatus;
+use crate::PriceLevel;
+use crate::Result;
+use crate::Side;
+use crate::Ticker;
+use crate::Trading;
+use crate::Transfer;
+use crate::TransferStatus;
+use crate::WithdrawRequest;
+
+const MAX_DEPTH: u32 = 5000;
+
+impl<S> MarketData for SpotApi<S>
+where
+    S: MexcSigner + Unpin + 'static,
+{
+    async fn instruments(&self) -> Result<Vec<InstrumentInfo>> {
+        let info = self.exchange_info()?.await?;
+        Ok(info.symbols.into_iter().map(instrument_from).collect())
+    }
+
+    async fn ticker(&self, symbol: &str) -> Result<Ticker> {
+        let ticker = self.ticker_book(symbol)?.await?;
+        Ok(Ticker {
+            symbol: ticker.symbol,
+            bid: ticker.bid_price.and_then(non_zero),
+            ask: ticker.ask_price.and_then(non_zero),
+            last: None,
+        })
+    }
+
+    async fn order_book(&self, symbol: &str, depth: Option<u32>) -> Result<OrderBookSnapshot> {
+        let limit = depth.map(|depth| depth.min(MAX_DEPTH) as u16);
+        let book = self.depth(symbol, limit)?.await?;
+        Ok(OrderBookSnapshot {
+            symbol: symbol.into(),
+            bids: book
+                .bids
+                .into_iter()
+                .map(|l| PriceLevel {
+                    price: l.price,
+                    qty: l.qty,
+                })
+                .collect(),
+            asks: book
+                .asks
+                .into_iter()
+                .map(|l| PriceLevel {
+                    price: l.price,
+                    qty: l.qty,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl<S> Trading for SpotApi<S>
+where
+    S: MexcSigner + Unpin + 'static,
+{
+    async fn place_order(&self, order: &NewOrder) -> Result<String> {
+        let (r#type, price) = match order.kind {
+            OrderKind::Market => (spot::OrderType::Market, None),
+            OrderKind::Limit { price } => (spot::OrderType::Limit, Some(price)),
+        };
+        let new_order = self
+            .create_order(
+                order.symbol.as_ref(),
+                side(order.side),
+                r#type,
+                Some(order.qty),
+                None,
+                price,
+                order.client_order_id.as_deref(),
+                TimeWindow::now(),
+            )?
+            .await?;
+        Ok(new_order.order_id)
+    }
+
+    async fn cancel_order(&self, symbol: &str, order_id: &str) -> Result<()> {
+        self.cancel_order(
+            symbol,
+            Some(order_id),
+            None::<&str>,
+            None::<&str>,
+            TimeWindow::now(),
+        )?
+        .await?;
+        Ok(())
+    }
+
+    async fn get_order(&self, symbol: &str, order_id: &str) -> Result<Order> {
+        let order = self
+            .get_order(symbol, Some(order_id), None::<&str>, TimeWindow::now())?
+            .await?;
+        Ok(order_from(order))
+    }
+
+    async fn open_orders(&self, symbol: Option<&str>) -> Result<Vec<Order>> {
+        let symbol = symbol
+            .ok_or_else(|| Error::invalid_request("MEXC lists open orders per symbol only"))?;
+        let orders = self.open_orders(symbol, TimeWindow::now())?.await?;
+        Ok(orders.into_iter().map(order_from).collect())
+    }
+}
+
+impl<S> Account for SpotApi<S>
+where
+    S: MexcSigner + Unpin + 'static,
+{
+    async fn balances(&self) -> Result<Vec<Balance>> {
+        let account = self.account(TimeWindow::now())?.await?;
+        Ok(account
+            .balances
+            .into_iter()
+            .map(|b| Balance {
+                asset: b.asset,
+                free: b.free,
+                locked: b.locked,
+            })
+            .collect())
+    }
+
+    async fn fills(&self, symbol: &str) -> Result<Vec<Fill>> {
+        let trades = self
+            .my_trades(symbol, None, None, None, TimeWindow::now())?
+            .await?;
+        Ok(trades
+            .into_iter()
+            .map(|t| Fill {
+                id: t.id,
+                order_id: t.order_id,
+                symbol: t.symbol,
+                side: if t.is_buyer { Side::Buy } else { Side::Sell },
+                price: t.price,
+                qty: t.qty,
+                fee: t.commission,
+                fee_asset: Some(t.commission_asset),
+                is_maker: Some(t.is_maker),
+                time: t.time,
+            })
+            .collect())
+    }
+}
+
+impl<S> Funding for SpotApi<S>
+where
+    S: MexcSigner + Unpin + 'static,
+{
+    async fn deposit_address(&self, asset: &str, network: Option<&str>) -> Result<DepositAddress> {
+        let network = network
+            .ok_or_else(|| Error::invalid_request("MEXC deposit addresses are per network"))?;
+        let addresses = self
+            .get_deposit_address(asset, network, TimeWindow::now())?
+            .await?;
+        let address = addresses.into_iter().next().ok_or_else(|| {
+            Error::unexpected_response(format!("no {asset} deposit address on {network}"))
+        })?;
+        Ok(DepositAddress {
+            asset: address.coin,
+            network: Some(address.network.to_string()),
+            address: address.address,
+            tag: Some(address.memo).filter(|memo| !memo.is_empty()),
+        })
+    }
+
+    async fn deposits(&self, asset: Option<&str>) -> Result<Vec<Transfer>> {
+        let deposits = self
+            .deposit_history(asset, None, None, None, None, TimeWindow::now())?
+            .await?;
+        Ok(deposits
+            .into_iter()
+            .map(|d| Transfer {
+                id: d.tx_id.clone(),
+                asset: d.coin.into(),
+                amount: d.amount,
+                fee: None,
+                network: Some(d.network.to_string()),
+                address: Some(d.address),
+                tx_id: Some(d.tx_id),
+                status: match d.status {
+                    spot::DepositStatus::Success => TransferStatus::Completed,
+                    spot::DepositStatus::Rejected => TransferStatus::Failed,
+                    spot::DepositStatus::Small
+                    | spot::DepositStatus::TimeDelay
+                    | spot::DepositStatus::LargeDelay
+                    | spot::DepositStatus::Pending
+                    | spot::DepositStatus::Auditing => TransferStatus::Pending,
+                },
+            })
+            .collect())
+    }
+
+    async fn withdrawals(&self, asset: Option<&str>) -> Result<Vec<Transfer>> {
+        let withdrawals = self
+            .withdraw_history(asset, None, None, None, None, TimeWindow::now())?
+            .await?;
+        Ok(withdrawals
+            .into_iter()
+            .map(|w| Transfer {
+                id: w.id,
+                asset: w.coin.into(),
+                amount: w.amount,
+                fee: Some(w.transaction_fee),
+                network: w.network,
+                address: Some(w.address),
+                tx_id: w.tx_id,
+                status: match w.status {
+                    spot::WithdrawStatus::Success => TransferStatus::Completed,
+                    spot::WithdrawStatus::Failed | spot::WithdrawStatus::Cancel => {
+                        TransferStatus::Failed
+                    }
+                    _ => TransferStatus::Pending,
+                },
+            })
+            .collect())
+    }
+
+    async fn withdraw(&self, request: &WithdrawRequest) -> Result<String> {
+        let withdrawal = self
+            .withdraw(
+                request.asset.as_ref(),
+                None::<&str>,
+                request.network.as_deref(),
+                None::<&str>,
+                &request.address,
+                request.tag.as_deref(),
+                request.amount,
+                None::<&str>,
+                TimeWindow::now(),
+            )?
+            .await?;
+        Ok(withdrawal.id)
+    }
+}
+
+fn instrument_from(symbol: spot::Symbol) -> InstrumentInfo {
+    let mut price_tick = None;
+    let mut qty_step = None;
+    let mut min_qty = None;
+    let mut min_notional = None;
+    for filter in &symbol.filters {
+        match filter {
+            spot::Filter::Price(f) => price_tick = non_zero(f.tick_size),
+            spot::Filter::LotSize(f) => {
+                qty_step = non_zero(f.step_size);
+                min_qty = non_zero(f.min_qty);
+            }
+            spot::Filter::MinNotional(f) => {
+                min_notional = min_notional.or(non_zero(f.min_notional));
+            }
+            spot::Filter::Notional(f) => min_notional = non_zero(f.min_notional),
+            _ => {}
+        }
+    }
+    // MEXC usually leaves the filters empty and reports the rules as plain fields;
+    // the minimum order value comes as `quoteAmountPrecision`.
+    InstrumentInfo {
+        instrument: symbol.instrument(),
+        price_tick: price_tick.or_else(|| Some(step(symbol.quote_precision.into()))),
+        qty_step: qty_step.or_else(|| symbol.base_size_precision.parse().ok().and_then(non_zero)),
+        min_qty,
+        min_notional: min_notional.or_else(|| {
+            symbol
+                .quote_amount_precision
+                .parse()
+                .ok()
+                .and_then(non_zero)
+        }),
+        symbol: symbol.symbol,
+        base: symbol.base_asset,
+        quote: symbol.quote_asset,
+    }
+}
+
+fn side(side: Side) -> spot::OrderSide {
+    match side {
+        Side::Buy => spot::OrderSide::Buy,
+        Side::Sell => spot::OrderSide::Sell,
+    }
+}
+
+fn order_from(order: spot::Order) -> Order {
+    let status = match order.status {
+        spot::OrderStatus::New => OrderStatus::Open,
+        spot::OrderStatus::PartiallyFilled => OrderStatus::PartiallyFilled,
+        spot::OrderStatus::Filled => OrderStatus::Filled,
+        spot::OrderStatus::PartiallyCanceled | spot::OrderStatus::Canceled => OrderStatus::Canceled,
+    };
+    Order {
+        id: order.order_id,
+        client_order_id: Some(order.client_order_id).filter(|id| !id.is_empty()),
+        symbol: order.symbol,
+        side: match order.side {
+            spot::OrderSide::Buy => Side::Buy,
+            spot::OrderSide::Sell => Side::Sell,
+        },
+        price: non_zero(order.price),
+        qty: order.orig_qty,
+        filled_qty: order.executed_qty,
+        status,
+    }
+}
@@ -0,0 +1,64 @@
+//! Implementations of the exchange-neutral traits for the exchange API clients.
+//!
+//! Operations an exchange crate does not provide yet fail with [`Error::Unsupported`].
+
+mod binance;
+mod bitstamp;
+mod coinbase;
+mod gate;
+mod kraken;
+mod mexc;
+
+use std::str::FromStr;
+
+use ccx_api_lib::Decimal;
+
+use crate::Error;
+use crate::Result;
+
+/// Treats zero as "no restriction", as the exchanges do in their trading rules.
+fn non_zero(value: Decimal) -> Option<Decimal> {
+    (!value.is_zero()).then_some(value)
+}
+
+/// The smallest increment with the given number of decimal places.
+fn step(decimals: u32) -> Decimal {
+    Decimal::new(1, decimals)
+}
+
+/// Picks the smallest supported depth not below the requested one, or the largest one.
+fn nearest_depth<T: Copy>(depth: u32, supported: &[(u32, T)]) -> T {
+    supported
+        .iter()
+        .find(|(n, _)| *n >= depth)
+        .or(supported.last())
+        .map(|(_, v)| *v)
+        .expect("supported depths must not be empty")
+}
+
+fn parse_order_id<T: FromStr>(order_id: &str) -> Result<T> {
+    order_id
+        .parse()
+        .map_err(|_| Error::invalid_request(format!("malformed order id: {order_id}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest_depth() {
+        let supported = [(5, 'a'), (10, 'b'), (20, 'c')];
+        assert_eq!(nearest_depth(1, &supported), 'a');
+        assert_eq!(nearest_depth(10, &supported), 'b');
+        assert_eq!(nearest_depth(11, &supported), 'c');
+        assert_eq!(nearest_depth(100, &supported), 'c');
+    }
+
+    #[test]
+    fn test_step() {
+        assert_eq!(step(0), Decimal::ONE);
+        assert_eq!(step(3), "0.001".parse().unwrap());
+        assert_eq!(non_zero(Decimal::new(0, 3)), None);
+    }
+}
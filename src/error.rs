@@ -0,0 +1,45 @@
+use ccx_api_lib::CcxApiError;
+use ccx_api_lib::LibError;
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The request failed on the exchange side or on the way to it.
+    #[error("Exchange Error: {0}")]
+    Exchange(Box<dyn std::error::Error + Send + Sync>),
+    /// The exchange crate does not provide the operation.
+    #[error("Unsupported: {0}")]
+    Unsupported(&'static str),
+    /// The request cannot be expressed with the exchange API.
+    #[error("Invalid Request: {0}")]
+    InvalidRequest(String),
+    /// The exchange returned something the adapter cannot interpret.
+    #[error("Unexpected Response: {0}")]
+    UnexpectedResponse(String),
+}
+
+impl Error {
+    pub fn exchange(e: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Error::Exchange(Box::new(e))
+    }
+
+    pub fn invalid_request(s: impl Into<String>) -> Self {
+        Error::InvalidRequest(s.into())
+    }
+
+    pub fn unexpected_response(s: impl Into<String>) -> Self {
+        Error::UnexpectedResponse(s.into())
+    }
+}
+
+impl<AE> From<LibError<AE>> for Error
+where
+    AE: CcxApiError + 'static,
+    LibError<AE>: Send + Sync,
+{
+    fn from(e: LibError<AE>) -> Self {
+        Error::exchange(e)
+    }
+}
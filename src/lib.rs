@@ -1 +1,22 @@
+//! Exchange-neutral trading interface.
+//!
+//! The traits in this crate are implemented for the API clients of the individual exchange
+//! crates, so a strategy can be written once against [`MarketData`], [`Trading`], [`Account`]
+//! and [`Funding`] and run on any supported venue. Symbols and assets are passed in the
+//! exchange's own notation; [`MarketData::instrument_map`] translates them from and to the
+//! exchange-neutral [`Instrument`].
 
+mod adapters;
+mod error;
+mod traits;
+mod types;
+
+pub use ccx_api_lib::Asset;
+pub use ccx_api_lib::Atom;
+pub use ccx_api_lib::Decimal;
+pub use ccx_api_lib::Instrument;
+pub use ccx_api_lib::InstrumentMap;
+
+pub use self::error::*;
+pub use self::traits::*;
+pub use self::types::*;
@@ -0,0 +1,81 @@
+use std::future::Future;
+
+use crate::Balance;
+use crate::DepositAddress;
+use crate::Fill;
+use crate::InstrumentInfo;
+use crate::InstrumentMap;
+use crate::NewOrder;
+use crate::Order;
+use crate::OrderBookSnapshot;
+use crate::Result;
+use crate::Ticker;
+use crate::Transfer;
+use crate::WithdrawRequest;
+
+/// Public market data.
+pub trait MarketData {
+    /// All the instruments traded on the exchange.
+    fn instruments(&self) -> impl Future<Output = Result<Vec<InstrumentInfo>>>;
+
+    /// Mapping between the exchange symbols and the exchange-neutral instruments.
+    fn instrument_map(&self) -> impl Future<Output = Result<InstrumentMap>> {
+        async {
+            let instruments = self.instruments().await?;
+            Ok(instruments
+                .into_iter()
+                .map(|info| (info.instrument, info.symbol))
+                .collect())
+        }
+    }
+
+    fn ticker(&self, symbol: &str) -> impl Future<Output = Result<Ticker>>;
+
+    /// Order book with up to `depth` levels per side; the exchange default when `None`.
+    ///
+    /// Exchanges accepting only a fixed set of depths get the nearest one that is not smaller.
+    fn order_book(
+        &self,
+        symbol: &str,
+        depth: Option<u32>,
+    ) -> impl Future<Output = Result<OrderBookSnapshot>>;
+}
+
+/// Order management.
+pub trait Trading {
+    /// Places an order and returns its exchange id.
+    fn place_order(&self, order: &NewOrder) -> impl Future<Output = Result<String>>;
+
+    fn cancel_order(&self, symbol: &str, order_id: &str) -> impl Future<Output = Result<()>>;
+
+    fn get_order(&self, symbol: &str, order_id: &str) -> impl Future<Output = Result<Order>>;
+
+    /// Open orders on the symbol, or on all symbols when `None`.
+    fn open_orders(&self, symbol: Option<&str>) -> impl Future<Output = Result<Vec<Order>>>;
+}
+
+/// Account state.
+pub trait Account {
+    fn balances(&self) -> impl Future<Output = Result<Vec<Balance>>>;
+
+    /// Recent own trades on the symbol.
+    fn fills(&self, symbol: &str) -> impl Future<Output = Result<Vec<Fill>>>;
+}
+
+/// Deposits and withdrawals.
+pub trait Funding {
+    fn deposit_address(
+        &self,
+        asset: &str,
+        network: Option<&str>,
+    ) -> impl Future<Output = Result<DepositAddress>>;
+
+    /// Recent deposits of the asset, or of all assets when `None`.
+    fn deposits(&self, asset: Option<&str>) -> impl Future<Output = Result<Vec<Transfer>>>;
+
+    /// Recent withdrawals of the asset, or of all assets when `None`.
+    fn withdrawals(&self, asset: Option<&str>) -> impl Future<Output = Result<Vec<Transfer>>>;
+
+    /// Requests a withdrawal and returns its exchange id.
+    fn withdraw(&self, request: &WithdrawRequest) -> impl Future<Output = Result<String>>;
+}
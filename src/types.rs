@@ -0,0 +1,216 @@
+use ccx_api_lib::Atom;
+use ccx_api_lib::Decimal;
+use ccx_api_lib::Instrument;
+
+/// Trading rules of an instrument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstrumentInfo {
+    pub instrument: Instrument,
+    /// Exchange symbol of the instrument.
+    pub symbol: Atom,
+    pub base: Atom,
+    pub quote: Atom,
+    /// Minimum price increment.
+    pub price_tick: Option<Decimal>,
+    /// Minimum quantity increment.
+    pub qty_step: Option<Decimal>,
+    /// Minimum order quantity (in the base asset).
+    pub min_qty: Option<Decimal>,
+    /// Minimum order value (in the quote asset).
+    pub min_notional: Option<Decimal>,
+}
+
+/// Top of the book and the last trade price.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ticker {
+    pub symbol: Atom,
+    pub bid: Option<Decimal>,
+    pub ask: Option<Decimal>,
+    pub last: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PriceLevel {
+    pub price: Decimal,
+    pub qty: Decimal,
+}
+
+/// Order book snapshot; bids are sorted from the best (highest) price, asks from the best
+/// (lowest) price.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderBookSnapshot {
+    pub symbol: Atom,
+    pub bids: Vec<PriceLevel>,
+    pub asks: Vec<PriceLevel>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderKind {
+    Market,
+    Limit { price: Decimal },
+}
+
+/// An order to be placed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewOrder {
+    pub symbol: Atom,
+    pub side: Side,
+    pub kind: OrderKind,
+    /// Quantity in the base asset.
+    pub qty: Decimal,
+    pub client_order_id: Option<String>,
+}
+
+impl NewOrder {
+    pub fn market(symbol: impl Into<Atom>, side: Side, qty: Decimal) -> Self {
+        NewOrder {
+            symbol: symbol.into(),
+            side,
+            kind: OrderKind::Market,
+            qty,
+            client_order_id: None,
+        }
+    }
+
+    pub fn limit(symbol: impl Into<Atom>, side: Side, price: Decimal, qty: Decimal) -> Self {
+        NewOrder {
+            symbol: symbol.into(),
+            side,
+            kind: OrderKind::Limit { price },
+            qty,
+            client_order_id: None,
+        }
+    }
+
+    pub fn client_order_id(mut self, client_order_id: impl Into<String>) -> Self {
+        self.client_order_id = Some(client_order_id.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderStatus {
+    /// Accepted and waiting for a match, nothing filled yet.
+    Open,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    Rejected,
+    Expired,
+}
+
+impl OrderStatus {
+    /// Whether the order can still be filled.
+    pub fn is_active(&self) -> bool {
+        matches!(self, OrderStatus::Open | OrderStatus::PartiallyFilled)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Order {
+    pub id: String,
+    pub client_order_id: Option<String>,
+    pub symbol: Atom,
+    pub side: Side,
+    /// Limit price; `None` for market orders.
+    pub price: Option<Decimal>,
+    pub qty: Decimal,
+    pub filled_qty: Decimal,
+    pub status: OrderStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Balance {
+    pub asset: Atom,
+    pub free: Decimal,
+    pub locked: Decimal,
+}
+
+impl Balance {
+    pub fn total(&self) -> Decimal {
+        self.free + self.locked
+    }
+}
+
+/// An execution of an own order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fill {
+    pub id: String,
+    pub order_id: String,
+    pub symbol: Atom,
+    pub side: Side,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub fee: Decimal,
+    pub fee_asset: Option<Atom>,
+    pub is_maker: Option<bool>,
+    /// Unix timestamp in milliseconds.
+    pub time: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepositAddress {
+    pub asset: Atom,
+    pub network: Option<String>,
+    pub address: String,
+    /// Memo or destination tag required alongside the address.
+    pub tag: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransferStatus {
+    Pending,
+    Completed,
+    Failed,
+}
+
+/// A deposit or a withdrawal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transfer {
+    pub id: String,
+    pub asset: Atom,
+    pub amount: Decimal,
+    pub fee: Option<Decimal>,
+    pub network: Option<String>,
+    pub address: Option<String>,
+    pub tx_id: Option<String>,
+    pub status: TransferStatus,
+}
+
+/// A withdrawal to be requested.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawRequest {
+    pub asset: Atom,
+    pub amount: Decimal,
+    pub address: String,
+    pub tag: Option<String>,
+    pub network: Option<String>,
+}
+
+impl WithdrawRequest {
+    pub fn new(asset: impl Into<Atom>, amount: Decimal, address: impl Into<String>) -> Self {
+        WithdrawRequest {
+            asset: asset.into(),
+            amount,
+            address: address.into(),
+            tag: None,
+            network: None,
+        }
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    pub fn network(mut self, network: impl Into<String>) -> Self {
+        self.network = Some(network.into());
+        self
+    }
+}